@@ -0,0 +1,75 @@
+use cidre::at::converter_new;
+use cidre::{api, at, ns, sc};
+
+fn main() {
+    let stream = sc::Stream::new();
+    let output = sc::RecordingOutput::with_cfg(&cfg).unwrap();
+    let _ = stream.is_active();
+    let _ = stream.try_add_recording_output(&output);
+    if api::version!(macos = 15.0) {
+        let _ = stream.try_add_recording_output(&output);
+    }
+    if api::version!(macos = 14.0) && !stream.is_active() {
+        let _ = stream.try_add_recording_output(&output);
+    }
+    let _ = sc::FrameInfo::bounding_rect();
+    let _ = sc::FrameInfo::bounding_rect().unwrap();
+    let _ = at::converter_new(&asbd);
+    let _ = stream.len();
+    let _ = output.len();
+    let data = ns::Data::new();
+    let _ = data.len();
+    let _ = sc::Stream::cls().unwrap();
+    // stream.try_add_recording_output(&output)
+    let _ = "stream.try_add_recording_output(&output)";
+    let _ = converter_new(&asbd);
+    let _ = sc::Stream::is_active(&stream);
+    println!("{}", stream.is_active());
+    let _ = sc::RecordingOutput::alloc().init_with_cfg(&cfg, &delegate);
+}
+
+fn record(stream: &sc::Stream, output: &sc::RecordingOutput) {
+    if !api::version!(macos = 15.0) {
+        return;
+    }
+    let _ = stream.try_add_recording_output(output);
+}
+
+#[api::available(macos = 15.0, ios = 18.0)]
+fn convert(asbd: &at::audio::StreamBasicDesc) {
+    let _ = at::converter_new(asbd);
+}
+
+#[cfg(feature = "macos_15_0")]
+mod tests {
+    #[test]
+    fn record() {
+        let _ = stream().try_add_recording_output(&output());
+    }
+}
+
+fn record_if(stream: &sc::Stream, output: &sc::RecordingOutput) {
+    if api::macos_available("15.0") {
+        let _ = stream.try_add_recording_output(output);
+    }
+}
+
+fn record_av(stream: &sc::Stream, output: &sc::RecordingOutput) {
+    api::if_available!(macos = 15.0, ios = 18.0 => |_av| {
+        let _ = stream.try_add_recording_output(output);
+    } else {
+        let _ = stream.try_add_recording_output(output);
+    });
+}
+
+struct Recorder;
+
+impl Recorder {
+    fn is_active(&self) -> bool {
+        false
+    }
+
+    fn stop(&self) {
+        let _ = self.is_active();
+    }
+}
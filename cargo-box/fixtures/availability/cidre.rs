@@ -0,0 +1,77 @@
+// cidre sources rows of `cidre.tsv` point to
+
+pub mod sc {
+    define_obj_type!(pub Stream(ns::Id));
+
+    impl Stream {
+        #[api::available(macos = 14.0, ios = 17.0)]
+        crate::define_cls!(SC_STREAM);
+
+        pub fn new() -> arc::R<Self> {
+            Self::alloc().init()
+        }
+
+        #[objc::msg_send(isActive)]
+        #[api::available(macos = 15.0)]
+        pub fn is_active(&self) -> bool;
+
+        #[objc::msg_send(addRecordingOutput:error:, throws)]
+        #[api::available(macos = 15.0)]
+        pub unsafe fn add_recording_output_throws(&self, output: &RecordingOutput) -> bool;
+
+        #[objc::msg_send(delegate)]
+        pub fn delegate(&self) -> Option<arc::R<AnyDelegate>>;
+    }
+
+    impl arc::A<RecordingOutput> {
+        #[objc::msg_send(initWithConfiguration:delegate:)]
+        #[api::available(macos = 15.0)]
+        pub fn init_with_cfg(self, cfg: &RecordingOutputCfg, delegate: &AnyDelegate) -> arc::R<RecordingOutput>;
+    }
+
+    impl RecordingOutput {
+        #[api::available(macos = 15.0)]
+        pub fn with_cfg(cfg: &RecordingOutputCfg) -> Option<arc::R<Self>> {
+            Self::alloc().init_with_cfg(cfg, &AnyDelegate::new())
+        }
+
+        #[objc::msg_send(recordedDuration)]
+        #[api::available(macos = 26.0)]
+        pub fn len(&self) -> usize;
+    }
+
+    impl FrameInfo {
+        #[api::available(macos = 14.0, maccatalyst = 18.2)]
+        pub fn bounding_rect() -> Option<&'static Self> {
+            unsafe { SCStreamFrameInfoBoundingRect }
+        }
+    }
+
+    #[objc::protocol(SCStreamDelegate)]
+    pub trait Delegate {
+        #[objc::optional]
+        #[objc::msg_send(stream:didStopWithError:)]
+        #[api::available(macos = 14.0)]
+        fn stream_did_stop_with_err(&mut self, stream: &Stream, error: &ns::Error);
+    }
+}
+
+pub mod ns {
+    impl Data {
+        pub fn new() -> arc::R<Self> {
+            Self::alloc().init()
+        }
+
+        #[objc::msg_send(length)]
+        pub fn len(&self) -> usize;
+    }
+}
+
+pub mod at {
+    #[api::available(macos = 15.0, ios = 18.0)]
+    pub fn converter_new(src: &audio::StreamBasicDesc) -> os::Result<Converter> {
+        unsafe { os::result_init(|res| AudioConverterNewWithOptions(src, res)) }
+    }
+}
+
+pub use sc::FrameInfo as StreamFrameInfo;
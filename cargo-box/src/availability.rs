@@ -0,0 +1,1551 @@
+//! `cargo box availability`, unguarded uses of cidre APIs.
+//!
+//! Like clang `-Wunguarded-availability`, but on sources: `#[api::available(...)]`
+//! and `#[objc::available(...)]` APIs of cidre are read from table `cidre-macros`
+//! writes into `OUT_DIR` of cidre while it is built with the same target and features,
+//! one tab separated row per API:
+//!
+//! ```text
+//! src/sc/stream.rs  90  is_active  is_active  self  0  unsafe  macos = 15.0
+//! ```
+//!
+//! Rows are resolved against cidre sources to types they are declared for, then
+//! calls in workspace packages are checked against deployment target:
+//!
+//! ```text
+//! error    src/main.rs:12: `Stream::is_active` requires macos 15.0, deployment target is 14.0,
+//!          guard with `api::version!(macos = 15.0)`
+//! ```
+//!
+//! Calls inside `if api::version!(..) { }`, after `if !api::version!(..) { return }`
+//! and in `#[api::available(..)]` or `#[cfg(feature = "macos_14_0")]` items are guarded.
+//! Sources are not type checked: receivers typed by fn args, `let` types and return
+//! types of cidre fns are matched by type, others by name, `self` and args count,
+//! skipping names cidre also has without availability, so the check is conservative.
+
+use std::{
+    collections::{HashMap, HashSet},
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use proc_macro2::{LineColumn, TokenStream, TokenTree};
+use serde::Deserialize;
+use syn::{Attribute, Expr, punctuated::Punctuated, spanned::Spanned, visit::Visit};
+
+use crate::{
+    meta,
+    signing::Report,
+    target::{self, Sdk},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Platform {
+    Macos,
+    Ios,
+    Tvos,
+    Watchos,
+    Visionos,
+    Maccatalyst,
+}
+
+impl Platform {
+    fn of_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "macos" => Self::Macos,
+            "ios" => Self::Ios,
+            "tvos" => Self::Tvos,
+            "watchos" => Self::Watchos,
+            "visionos" => Self::Visionos,
+            "maccatalyst" => Self::Maccatalyst,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Macos => "macos",
+            Self::Ios => "ios",
+            Self::Tvos => "tvos",
+            Self::Watchos => "watchos",
+            Self::Visionos => "visionos",
+            Self::Maccatalyst => "maccatalyst",
+        }
+    }
+
+    pub(crate) fn of_sdk(sdk: Sdk) -> Self {
+        match sdk {
+            Sdk::MacCatalyst => Self::Maccatalyst,
+            sdk => match sdk.os() {
+                meta::Os::Macos => Self::Macos,
+                meta::Os::Ios => Self::Ios,
+                meta::Os::Tvos => Self::Tvos,
+                meta::Os::Watchos => Self::Watchos,
+                meta::Os::Visionos => Self::Visionos,
+            },
+        }
+    }
+
+    /// Env rustc reads deployment target from, catalyst uses iOS one
+    pub(crate) fn deployment_target_key(self) -> &'static str {
+        match self {
+            Self::Macos => "MACOSX_DEPLOYMENT_TARGET",
+            Self::Ios | Self::Maccatalyst => "IPHONEOS_DEPLOYMENT_TARGET",
+            Self::Tvos => "TVOS_DEPLOYMENT_TARGET",
+            Self::Watchos => "WATCHOS_DEPLOYMENT_TARGET",
+            Self::Visionos => "XROS_DEPLOYMENT_TARGET",
+        }
+    }
+
+    /// rustc default when deployment target env is not set
+    fn default_deployment_target(self, triple: &str) -> Version {
+        match self {
+            Self::Macos if triple.starts_with("x86_64") => Version(10, 12),
+            Self::Macos => Version(11, 0),
+            Self::Ios if triple.ends_with("-sim") => Version(14, 0),
+            Self::Ios | Self::Tvos => Version(10, 0),
+            Self::Maccatalyst => Version(14, 0),
+            Self::Watchos => Version(5, 0),
+            Self::Visionos => Version(1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Version(pub(crate) u32, pub(crate) u32);
+
+impl Version {
+    /// `14.0`, `14`, `14.0.1` or feature style `14_0`
+    pub(crate) fn parse(str: &str) -> Option<Self> {
+        let mut iter = str.trim().split(['.', '_']);
+        let major = iter.next()?.parse().ok()?;
+        let minor = match iter.next() {
+            Some(minor) => minor.parse().ok()?,
+            None => 0,
+        };
+        Some(Self(major, minor))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+/// `macos = 14.0, ios = 17.0` of `api::available` and `api::version!`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Versions(pub(crate) Vec<(Platform, Version)>);
+
+impl Versions {
+    fn from_tokens(tokens: TokenStream) -> Self {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        let mut res = Vec::new();
+        for w in tokens.windows(3) {
+            if let [
+                TokenTree::Ident(os),
+                TokenTree::Punct(eq),
+                TokenTree::Literal(v),
+            ] = w
+            {
+                let os = Platform::of_name(&os.to_string());
+                let v = Version::parse(&v.to_string());
+                if let (Some(os), '=', Some(v)) = (os, eq.as_char(), v) {
+                    res.push((os, v));
+                }
+            }
+        }
+        Self(res)
+    }
+
+    /// `macos = 14.0, ios = 17.0` column of table
+    fn from_list(list: &str) -> Option<Self> {
+        let mut res = Vec::new();
+        for item in list.split(", ") {
+            let (os, v) = item.split_once(" = ")?;
+            res.push((Platform::of_name(os)?, Version::parse(v)?));
+        }
+        Some(Self(res))
+    }
+
+    /// `feature = "macos_14_0"` in `cfg(..)`
+    fn from_cfg(tokens: TokenStream) -> Self {
+        let mut flat = Vec::new();
+        flatten(tokens, &mut flat);
+        if flat
+            .iter()
+            .any(|t| matches!(t, TokenTree::Ident(i) if i == "not"))
+        {
+            return Self::default();
+        }
+        let mut res = Vec::new();
+        for w in flat.windows(3) {
+            if let [
+                TokenTree::Ident(f),
+                TokenTree::Punct(_),
+                TokenTree::Literal(v),
+            ] = w
+            {
+                let v = v.to_string();
+                let Some((os, v)) = v.trim_matches('"').split_once('_') else {
+                    continue;
+                };
+                if let (true, Some(os), Some(v)) =
+                    (f == "feature", Platform::of_name(os), Version::parse(v))
+                {
+                    res.push((os, v));
+                }
+            }
+        }
+        Self(res)
+    }
+
+    pub(crate) fn get(&self, platform: Platform) -> Option<Version> {
+        self.0.iter().find(|(p, _)| *p == platform).map(|(_, v)| *v)
+    }
+}
+
+fn flatten(tokens: TokenStream, res: &mut Vec<TokenTree>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(g) => flatten(g.stream(), res),
+            tt => res.push(tt),
+        }
+    }
+}
+
+/// What unavailable variant does, `fallback` column of table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Fallback {
+    /// Returns `None`
+    Option,
+    /// Unwraps weak function
+    Panic,
+    /// Becomes `unsafe`
+    Unsafe,
+    /// `cls()` returns `None`
+    Class,
+    /// Declaration is compiled out
+    Cfg,
+}
+
+impl Fallback {
+    fn of_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "option" => Self::Option,
+            "panic" => Self::Panic,
+            "unsafe" => Self::Unsafe,
+            "class" => Self::Class,
+            "cfg" => Self::Cfg,
+            _ => return None,
+        })
+    }
+}
+
+/// Row of availability table.
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    /// Source relative to cidre package
+    file: String,
+    line: usize,
+    /// Ident at `line`, rows of moved or removed APIs don't have it there
+    item: String,
+    name: String,
+    receiver: bool,
+    args: usize,
+    fallback: Fallback,
+    versions: Versions,
+}
+
+impl Row {
+    fn parse(line: &str) -> Option<Self> {
+        let mut cols = line.split('\t');
+        let mut col = || cols.next();
+        Some(Self {
+            file: col()?.to_string(),
+            line: col()?.parse().ok()?,
+            item: col()?.to_string(),
+            name: col()?.to_string(),
+            receiver: col()? == "self",
+            args: col()?.parse().ok()?,
+            fallback: Fallback::of_name(col()?)?,
+            versions: Versions::from_list(col()?)?,
+        })
+    }
+}
+
+/// Fn of cidre, annotated or not.
+#[derive(Debug, Clone, PartialEq)]
+struct Decl {
+    /// Type of `impl` or `trait` block, `arc::A<T>` is `T`
+    owner: Option<String>,
+    receiver: bool,
+    /// Args besides `self`
+    args: usize,
+    /// Type it returns without references, `arc::R`, `Option` and alike
+    ret: Option<String>,
+    api: Option<(Versions, Fallback)>,
+}
+
+impl Decl {
+    fn of_sig(sig: &syn::Signature, owner: Option<&str>) -> Self {
+        let receiver = sig.receiver().is_some();
+        let ret = match &sig.output {
+            syn::ReturnType::Type(_, ty) => type_name(ty, owner),
+            syn::ReturnType::Default => None,
+        };
+        Self {
+            owner: owner.map(str::to_string),
+            receiver,
+            args: sig.inputs.len() - receiver as usize,
+            ret,
+            api: None,
+        }
+    }
+
+    fn required(&self, platform: Platform) -> Option<Version> {
+        self.api.as_ref()?.0.get(platform)
+    }
+}
+
+/// Name of type, `Self` is `self_ty` and wrappers like `arc::R<T>` or `Option<T>` are `T`.
+fn type_name(ty: &syn::Type, self_ty: Option<&str>) -> Option<String> {
+    const WRAPPERS: [&str; 7] = ["R", "Retained", "Rar", "A", "Option", "Result", "Box"];
+    match ty {
+        syn::Type::Reference(r) => type_name(&r.elem, self_ty),
+        syn::Type::Paren(p) => type_name(&p.elem, self_ty),
+        syn::Type::Path(p) => {
+            let seg = p.path.segments.last()?;
+            let name = seg.ident.to_string();
+            if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
+                if WRAPPERS.contains(&name.as_str()) {
+                    return args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => type_name(ty, self_ty),
+                        _ => None,
+                    });
+                }
+            }
+            match name.as_str() {
+                "Self" => self_ty.map(str::to_string),
+                _ => Some(name),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn last_ident(path: &syn::Path) -> String {
+    path.segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_default()
+}
+
+/// Fns and `impl` blocks of cidre source.
+#[derive(Default)]
+struct Decls {
+    /// Names, lines of names and fns
+    fns: Vec<(String, usize, Decl)>,
+    /// Lines of `impl` and `trait` blocks with their types
+    blocks: Vec<(usize, usize, String)>,
+    /// `pub use Cfg as StreamCfg` and `type` aliases with types they name
+    aliases: Vec<(String, String)>,
+    owner: Option<String>,
+}
+
+impl Decls {
+    fn push(&mut self, sig: &syn::Signature, attrs: &[Attribute]) {
+        let decl = Decl::of_sig(sig, self.owner.as_deref());
+        let name = sig.ident.to_string();
+        let line = sig.ident.span().start().line;
+        // `try_*` of `#[objc::msg_send(sel, throws)]`
+        let throws = attrs.iter().any(|a| {
+            last_ident(a.path()) == "msg_send"
+                && a.meta.require_list().is_ok_and(|l| {
+                    let mut flat = Vec::new();
+                    flatten(l.tokens.clone(), &mut flat);
+                    flat.iter()
+                        .any(|t| matches!(t, TokenTree::Ident(i) if i == "throws"))
+                })
+        });
+        if throws {
+            let stem = name.strip_suffix("_throws").unwrap_or(&name);
+            self.fns.push((format!("try_{stem}"), line, decl.clone()));
+        }
+        self.fns.push((name, line, decl));
+    }
+
+    fn block(&mut self, span: proc_macro2::Span, owner: Option<String>, f: impl FnOnce(&mut Self)) {
+        let Some(owner) = owner else {
+            return;
+        };
+        self.blocks
+            .push((span.start().line, span.end().line, owner.clone()));
+        let outer = self.owner.replace(owner);
+        f(self);
+        self.owner = outer;
+    }
+
+    /// Type of innermost block with `line`.
+    fn owner_at(&self, line: usize) -> Option<&str> {
+        self.blocks
+            .iter()
+            .filter(|(start, end, _)| (*start..=*end).contains(&line))
+            .min_by_key(|(start, end, _)| end - start)
+            .map(|(.., owner)| owner.as_str())
+    }
+}
+
+impl<'ast> Visit<'ast> for Decls {
+    fn visit_item_impl(&mut self, i: &'ast syn::ItemImpl) {
+        let owner = type_name(&i.self_ty, None);
+        self.block(i.span(), owner, |this| {
+            for item in &i.items {
+                match item {
+                    syn::ImplItem::Fn(f) => this.push(&f.sig, &f.attrs),
+                    // `fn` without body of `#[objc::msg_send]`
+                    syn::ImplItem::Verbatim(tokens) => {
+                        if let Ok(f) = syn::parse2::<syn::ForeignItemFn>(tokens.clone()) {
+                            this.push(&f.sig, &f.attrs);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    fn visit_item_trait(&mut self, i: &'ast syn::ItemTrait) {
+        self.block(i.span(), Some(i.ident.to_string()), |this| {
+            for item in &i.items {
+                if let syn::TraitItem::Fn(f) = item {
+                    this.push(&f.sig, &f.attrs);
+                }
+            }
+        });
+    }
+
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        self.push(&i.sig, &i.attrs);
+    }
+
+    fn visit_foreign_item_fn(&mut self, i: &'ast syn::ForeignItemFn) {
+        self.push(&i.sig, &i.attrs);
+    }
+
+    fn visit_item_type(&mut self, i: &'ast syn::ItemType) {
+        if let Some(ty) = type_name(&i.ty, None) {
+            self.aliases.push((i.ident.to_string(), ty));
+        }
+    }
+
+    fn visit_use_rename(&mut self, i: &'ast syn::UseRename) {
+        self.aliases
+            .push((i.rename.to_string(), i.ident.to_string()));
+    }
+}
+
+/// Fns of cidre with availability of annotated ones.
+#[derive(Debug, Default)]
+pub(crate) struct Index {
+    /// Fns by name
+    fns: HashMap<String, Vec<Decl>>,
+    /// Types with `impl` blocks
+    owners: HashSet<String>,
+    /// Aliases of owners
+    aliases: HashMap<String, String>,
+    pub(crate) apis: usize,
+}
+
+impl Index {
+    /// Resolves `table` rows against cidre `sources`, paths relative to cidre package.
+    pub(crate) fn new<'a>(
+        table: &str,
+        sources: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut rows: HashMap<String, Vec<Row>> = HashMap::new();
+        let mut seen = HashSet::new();
+        for row in table.lines().filter_map(Row::parse) {
+            if seen.insert((row.file.clone(), row.line, row.name.clone())) {
+                rows.entry(row.file.clone()).or_default().push(row);
+            }
+        }
+        let mut index = Self::default();
+        for (file, src) in sources {
+            let Ok(ast) = syn::parse_file(src) else {
+                continue;
+            };
+            let mut decls = Decls::default();
+            decls.visit_file(&ast);
+            // spans of parsed files are kept otherwise
+            proc_macro2::extra::invalidate_current_thread_spans();
+
+            let lines: Vec<_> = src.lines().collect();
+            for row in rows.get(file).into_iter().flatten() {
+                let at_line = lines.get(row.line.wrapping_sub(1));
+                if !at_line.is_some_and(|l| l.contains(row.item.as_str())) {
+                    continue;
+                }
+                let api = Some((row.versions.clone(), row.fallback));
+                let found = decls
+                    .fns
+                    .iter_mut()
+                    .find(|(name, line, _)| *name == row.name && *line == row.line);
+                match found {
+                    Some((.., decl)) => decl.api = api,
+                    // `cls()` and alike are generated
+                    None => decls.fns.push((
+                        row.name.clone(),
+                        row.line,
+                        Decl {
+                            owner: decls.owner_at(row.line).map(str::to_string),
+                            receiver: row.receiver,
+                            args: row.args,
+                            ret: None,
+                            api,
+                        },
+                    )),
+                }
+            }
+            index
+                .owners
+                .extend(decls.blocks.into_iter().map(|(.., o)| o));
+            for (alias, ty) in decls.aliases {
+                let known = index.aliases.entry(alias).or_insert_with(|| ty.clone());
+                if *known != ty {
+                    known.clear();
+                }
+            }
+            for (name, _, decl) in decls.fns {
+                index.apis += decl.api.is_some() as usize;
+                index.fns.entry(name).or_default().push(decl);
+            }
+        }
+        // names of types in other modules and aliases of different types are ambiguous
+        let owners = &index.owners;
+        index
+            .aliases
+            .retain(|alias, ty| !owners.contains(alias) && owners.contains(ty));
+        index
+    }
+
+    /// Type `ty` is alias of or `ty` itself.
+    fn resolve(&self, ty: String) -> String {
+        self.aliases.get(&ty).cloned().unwrap_or(ty)
+    }
+
+    /// `.name(args)` of `owner`, any type if it is unknown or doesn't have it
+    /// and then only if all fns of this name are annotated.
+    fn method(&self, owner: Option<&str>, name: &str, args: usize, p: Platform) -> Option<&Decl> {
+        let decls = self.fns.get(name)?;
+        let fits = |d: &&Decl| d.owner.is_some() && d.receiver && d.args == args;
+        if let Some(owner) = owner {
+            let mut own = decls
+                .iter()
+                .filter(fits)
+                .filter(|d| d.owner.as_deref() == Some(owner))
+                .peekable();
+            if own.peek().is_some() {
+                return own.find(|d| d.required(p).is_some());
+            }
+        }
+        Self::annotated(decls.iter().filter(fits), p)
+    }
+
+    /// `Type::name(args)`, static or with `self` as the first arg.
+    fn assoc(&self, owner: &str, name: &str, args: usize, p: Platform) -> Option<&Decl> {
+        self.fns.get(name)?.iter().find(|d| {
+            d.owner.as_deref() == Some(owner)
+                && d.args + d.receiver as usize == args
+                && d.required(p).is_some()
+        })
+    }
+
+    /// `module::name(args)` or imported `name(args)`.
+    fn free(&self, name: &str, args: usize, p: Platform) -> Option<&Decl> {
+        let decls = self.fns.get(name)?;
+        Self::annotated(
+            decls.iter().filter(|d| d.owner.is_none() && d.args == args),
+            p,
+        )
+    }
+
+    /// The least required of candidates if none of them is plain.
+    fn annotated<'a>(
+        candidates: impl Iterator<Item = &'a Decl> + Clone,
+        p: Platform,
+    ) -> Option<&'a Decl> {
+        if candidates.clone().any(|d| d.required(p).is_none()) {
+            return None;
+        }
+        candidates.min_by_key(|d| d.required(p))
+    }
+
+    /// Type `owner::name` returns.
+    fn ret(&self, owner: &str, name: &str) -> Option<String> {
+        self.fns
+            .get(name)?
+            .iter()
+            .filter(|d| d.owner.as_deref() == Some(owner))
+            .find_map(|d| d.ret.clone())
+            .map(|ty| self.resolve(ty))
+    }
+}
+
+/// Platform and versions to check against.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Ctx {
+    pub(crate) platform: Platform,
+    pub(crate) deployment_target: Version,
+    /// The newest enabled `macos_x_x` like feature of cidre
+    pub(crate) feature: Option<Version>,
+}
+
+impl Ctx {
+    /// Enabled feature newer than deployment target makes its APIs strong linked.
+    pub(crate) fn check_features(&self, report: &mut Report) {
+        let p = self.platform;
+        let target = self.deployment_target;
+        match self.feature {
+            Some(f) if f > target => report.error(format!(
+                "cidre feature {}_{}_{} is newer than {} deployment target {target} ({}), \
+                 its APIs are linked strongly, use {}_{}_{} feature and `api::version!`",
+                p.as_str(),
+                f.0,
+                f.1,
+                p.as_str(),
+                p.deployment_target_key(),
+                p.as_str(),
+                target.0,
+                target.1,
+            )),
+            Some(f) => report.ok(format!(
+                "{} deployment target {target}, cidre features up to {}_{}_{}",
+                p.as_str(),
+                p.as_str(),
+                f.0,
+                f.1
+            )),
+            None => report.ok(format!("{} deployment target {target}", p.as_str())),
+        }
+    }
+}
+
+/// `.unwrap()` like calls, weak classes and `Option` fallbacks are reported under them.
+const UNWRAPS: [&str; 3] = ["unwrap", "expect", "unwrap_unchecked"];
+
+/// Versions of `api::available(..)`, `objc::available(..)` or `cfg(feature = "macos_14_0")`.
+fn attr_versions(attr: &Attribute) -> Option<Versions> {
+    let list = attr.meta.require_list().ok()?;
+    let versions = match last_ident(&list.path).as_str() {
+        "available" => Versions::from_tokens(list.tokens.clone()),
+        "cfg" => Versions::from_cfg(list.tokens.clone()),
+        _ => return None,
+    };
+    (!versions.0.is_empty()).then_some(versions)
+}
+
+/// `api::version!(..)` or `api::macos_available("14.0")` like check.
+fn version_check(e: &Expr) -> Option<Versions> {
+    match e {
+        Expr::Macro(m) if last_ident(&m.mac.path) == "version" => {
+            Some(Versions::from_tokens(m.mac.tokens.clone()))
+        }
+        Expr::Call(c) => {
+            let Expr::Path(p) = &*c.func else {
+                return None;
+            };
+            let os = Platform::of_name(last_ident(&p.path).strip_suffix("_available")?)?;
+            let Some(Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(v),
+                ..
+            })) = c.args.first()
+            else {
+                return None;
+            };
+            Some(Versions(vec![(os, Version::parse(&v.value())?)]))
+        }
+        Expr::Paren(p) => version_check(&p.expr),
+        _ => None,
+    }
+}
+
+/// Versions `if` condition checks, `&&` keeps them, and whether check is negated.
+fn version_cond(e: &Expr) -> Option<(Versions, bool)> {
+    match e {
+        Expr::Unary(u) if matches!(u.op, syn::UnOp::Not(_)) => {
+            Some((version_check(&u.expr)?, true))
+        }
+        Expr::Binary(b) if matches!(b.op, syn::BinOp::And(_)) => version_cond(&b.left)
+            .or_else(|| version_cond(&b.right))
+            .filter(|(_, negated)| !negated),
+        Expr::Paren(p) => version_cond(&p.expr),
+        e => Some((version_check(e)?, false)),
+    }
+}
+
+/// `if !api::version!(..) { return }` guards the rest of block.
+fn negated_guard(stmt: &syn::Stmt) -> Option<Versions> {
+    let syn::Stmt::Expr(Expr::If(i), _) = stmt else {
+        return None;
+    };
+    let (versions, true) = version_cond(&i.cond)? else {
+        return None;
+    };
+    let mut diverges = Diverges(false);
+    diverges.visit_block(&i.then_branch);
+    (i.else_branch.is_none() && diverges.0).then_some(versions)
+}
+
+struct Diverges(bool);
+
+impl<'ast> Visit<'ast> for Diverges {
+    fn visit_expr_return(&mut self, _: &'ast syn::ExprReturn) {
+        self.0 = true;
+    }
+
+    fn visit_expr_break(&mut self, _: &'ast syn::ExprBreak) {
+        self.0 = true;
+    }
+
+    fn visit_expr_continue(&mut self, _: &'ast syn::ExprContinue) {
+        self.0 = true;
+    }
+
+    fn visit_macro(&mut self, m: &'ast syn::Macro) {
+        self.0 |= ["panic", "unreachable", "todo"].contains(&last_ident(&m.path).as_str());
+    }
+}
+
+/// Checks calls in source of workspace package.
+struct Checker<'a> {
+    index: &'a Index,
+    ctx: &'a Ctx,
+    file: &'a str,
+    report: &'a mut Report,
+    guards: Vec<Versions>,
+    /// Types of args and locals of current fn
+    locals: HashMap<String, String>,
+    /// Type of current `impl` block
+    self_ty: Option<String>,
+    /// Names brought by `use`, other bare calls are local
+    imports: HashSet<String>,
+    /// Spans of calls followed by `.unwrap()` like calls
+    unwrapped: HashSet<(LineColumn, LineColumn)>,
+}
+
+impl Checker<'_> {
+    fn guarded(&mut self, attrs: &[Attribute], f: impl FnOnce(&mut Self)) {
+        let depth = self.guards.len();
+        self.guards.extend(attrs.iter().filter_map(attr_versions));
+        f(self);
+        self.guards.truncate(depth);
+    }
+
+    fn fn_body(&mut self, attrs: &[Attribute], sig: &syn::Signature, block: &syn::Block) {
+        self.guarded(attrs, |this| {
+            let outer = std::mem::take(&mut this.locals);
+            for arg in &sig.inputs {
+                if let syn::FnArg::Typed(arg) = arg {
+                    this.local(&arg.pat, type_name(&arg.ty, this.self_ty.as_deref()));
+                }
+            }
+            this.visit_block(block);
+            this.locals = outer;
+        });
+    }
+
+    fn local(&mut self, pat: &syn::Pat, ty: Option<String>) {
+        let syn::Pat::Ident(pat) = pat else {
+            return;
+        };
+        match ty {
+            Some(ty) => self
+                .locals
+                .insert(pat.ident.to_string(), self.index.resolve(ty)),
+            None => self.locals.remove(&pat.ident.to_string()),
+        };
+    }
+
+    /// Type of cidre object `e` evaluates to, if it is clear.
+    fn ty_of(&self, e: &Expr) -> Option<String> {
+        match e {
+            Expr::Path(p) => {
+                let ident = p.path.get_ident()?.to_string();
+                match ident.as_str() {
+                    "self" => self.self_ty.clone(),
+                    _ => self.locals.get(&ident).cloned(),
+                }
+            }
+            Expr::Reference(r) => self.ty_of(&r.expr),
+            Expr::Paren(p) => self.ty_of(&p.expr),
+            Expr::Try(t) => self.ty_of(&t.expr),
+            Expr::MethodCall(m) => {
+                let name = m.method.to_string();
+                let ty = self.ty_of(&m.receiver)?;
+                if UNWRAPS.contains(&name.as_str()) {
+                    return Some(ty);
+                }
+                self.index.ret(&ty, &name)
+            }
+            Expr::Call(c) => {
+                let Expr::Path(p) = &*c.func else {
+                    return None;
+                };
+                let (ty, name) = self.assoc_path(&p.path)?;
+                self.index.ret(&ty, &name)
+            }
+            _ => None,
+        }
+    }
+
+    /// `Type::name` of path.
+    fn assoc_path(&self, path: &syn::Path) -> Option<(String, String)> {
+        let mut segs = path.segments.iter().rev();
+        let name = segs.next()?.ident.to_string();
+        let ty = segs.next()?.ident.to_string();
+        match ty.as_str() {
+            "Self" => Some((self.self_ty.clone()?, name)),
+            _ if ty.starts_with(char::is_uppercase) => Some((self.index.resolve(ty), name)),
+            _ => None,
+        }
+    }
+
+    fn call(&mut self, decl: &Decl, name: &str, at: &syn::Ident, span: proc_macro2::Span) {
+        let p = self.ctx.platform;
+        let (Some(required), Some((_, fallback))) = (decl.required(p), &decl.api) else {
+            return;
+        };
+        let target = self.ctx.deployment_target;
+        if required <= target {
+            return;
+        }
+        let at = format!("{}:{}", self.file, at.span().start().line);
+        let path = match &decl.owner {
+            Some(ty) => format!("{ty}::{name}"),
+            None => name.to_string(),
+        };
+        if self.ctx.feature.is_some_and(|f| f >= required) {
+            self.report.error(format!(
+                "{at}: `{path}` requires {} {required} but is linked strongly by cidre features, \
+                 deployment target is {target}",
+                p.as_str()
+            ));
+            return;
+        }
+        let guarded = self
+            .guards
+            .iter()
+            .any(|g| g.get(p).is_some_and(|v| v >= required));
+        if guarded {
+            return;
+        }
+        let guard = format!("`api::version!({} = {required})`", p.as_str());
+        match fallback {
+            Fallback::Unsafe | Fallback::Panic | Fallback::Cfg => self.report.error(format!(
+                "{at}: `{path}` requires {} {required}, deployment target is {target}, guard with {guard}",
+                p.as_str()
+            )),
+            Fallback::Option | Fallback::Class => {
+                if !self.unwrapped.contains(&(span.start(), span.end())) {
+                    return;
+                }
+                let hint = match fallback {
+                    Fallback::Class => format!(
+                        "use `{name}_available(av)` under `api::if_available!({} = {required} => |av| ..)`",
+                        p.as_str()
+                    ),
+                    _ => format!("unwrap it under {guard}"),
+                };
+                self.report.warning(format!(
+                    "{at}: `{path}` is `None` before {} {required}, deployment target is {target}, {hint}",
+                    p.as_str()
+                ));
+            }
+        }
+    }
+
+    /// `api::if_available!(macos = 14.4 => |av| { .. } else { .. })`
+    fn if_available(&mut self, tokens: &TokenStream) {
+        let tokens: Vec<_> = tokens.clone().into_iter().collect();
+        let arrow = tokens.windows(2).position(|w| {
+            matches!(w, [TokenTree::Punct(a), TokenTree::Punct(b)] if a.as_char() == '=' && b.as_char() == '>')
+        });
+        let Some(arrow) = arrow else {
+            return;
+        };
+        let versions = Versions::from_tokens(tokens[..arrow].iter().cloned().collect());
+        let rest: TokenStream = tokens[arrow + 2..].iter().cloned().collect();
+        let parser = |input: syn::parse::ParseStream| {
+            let then: syn::ExprClosure = input.parse()?;
+            let or = match input.parse::<Option<syn::Token![else]>>()? {
+                Some(_) => Some(input.parse::<syn::Block>()?),
+                None => None,
+            };
+            Ok((then, or))
+        };
+        let Ok((then, or)) = syn::parse::Parser::parse2(parser, rest) else {
+            return;
+        };
+        self.guards.push(versions);
+        self.visit_expr_closure(&then);
+        self.guards.pop();
+        if let Some(or) = or {
+            self.visit_block(&or);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Checker<'_> {
+    fn visit_item_use(&mut self, i: &'ast syn::ItemUse) {
+        fn names(tree: &syn::UseTree, res: &mut HashSet<String>) {
+            match tree {
+                syn::UseTree::Path(p) => names(&p.tree, res),
+                syn::UseTree::Name(n) => drop(res.insert(n.ident.to_string())),
+                syn::UseTree::Rename(r) => drop(res.insert(r.rename.to_string())),
+                syn::UseTree::Group(g) => g.items.iter().for_each(|t| names(t, res)),
+                syn::UseTree::Glob(_) => {}
+            }
+        }
+        names(&i.tree, &mut self.imports);
+    }
+
+    fn visit_item_mod(&mut self, i: &'ast syn::ItemMod) {
+        self.guarded(&i.attrs, |this| syn::visit::visit_item_mod(this, i));
+    }
+
+    fn visit_item_impl(&mut self, i: &'ast syn::ItemImpl) {
+        let outer = std::mem::replace(&mut self.self_ty, type_name(&i.self_ty, None));
+        self.guarded(&i.attrs, |this| syn::visit::visit_item_impl(this, i));
+        self.self_ty = outer;
+    }
+
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        self.fn_body(&i.attrs, &i.sig, &i.block);
+    }
+
+    fn visit_impl_item_fn(&mut self, i: &'ast syn::ImplItemFn) {
+        self.fn_body(&i.attrs, &i.sig, &i.block);
+    }
+
+    fn visit_block(&mut self, b: &'ast syn::Block) {
+        let depth = self.guards.len();
+        for stmt in &b.stmts {
+            self.visit_stmt(stmt);
+            self.guards.extend(negated_guard(stmt));
+        }
+        self.guards.truncate(depth);
+    }
+
+    fn visit_local(&mut self, l: &'ast syn::Local) {
+        syn::visit::visit_local(self, l);
+        match &l.pat {
+            syn::Pat::Type(pt) => {
+                let ty = type_name(&pt.ty, self.self_ty.as_deref());
+                self.local(&pt.pat, ty);
+            }
+            pat => {
+                let ty = l.init.as_ref().and_then(|init| self.ty_of(&init.expr));
+                self.local(pat, ty);
+            }
+        }
+    }
+
+    fn visit_expr_closure(&mut self, c: &'ast syn::ExprClosure) {
+        for input in &c.inputs {
+            if let syn::Pat::Type(pt) = input {
+                let ty = type_name(&pt.ty, self.self_ty.as_deref());
+                self.local(&pt.pat, ty);
+            }
+        }
+        syn::visit::visit_expr_closure(self, c);
+    }
+
+    fn visit_expr_if(&mut self, i: &'ast syn::ExprIf) {
+        let Some((versions, false)) = version_cond(&i.cond) else {
+            return syn::visit::visit_expr_if(self, i);
+        };
+        self.visit_expr(&i.cond);
+        self.guards.push(versions);
+        self.visit_block(&i.then_branch);
+        self.guards.pop();
+        if let Some((_, or)) = &i.else_branch {
+            self.visit_expr(or);
+        }
+    }
+
+    fn visit_expr_binary(&mut self, b: &'ast syn::ExprBinary) {
+        let versions = match b.op {
+            syn::BinOp::And(_) => version_cond(&b.left).filter(|(_, negated)| !negated),
+            _ => None,
+        };
+        let Some((versions, _)) = versions else {
+            return syn::visit::visit_expr_binary(self, b);
+        };
+        self.visit_expr(&b.left);
+        self.guards.push(versions);
+        self.visit_expr(&b.right);
+        self.guards.pop();
+    }
+
+    fn visit_macro(&mut self, m: &'ast syn::Macro) {
+        match last_ident(&m.path).as_str() {
+            "version" => {}
+            "if_available" => self.if_available(&m.tokens),
+            // `println!(..)`, `assert!(..)` and alike
+            _ => {
+                let args = m.parse_body_with(Punctuated::<Expr, syn::Token![,]>::parse_terminated);
+                for arg in args.iter().flatten() {
+                    self.visit_expr(arg);
+                }
+            }
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, m: &'ast syn::ExprMethodCall) {
+        let name = m.method.to_string();
+        if UNWRAPS.contains(&name.as_str()) {
+            let span = m.receiver.span();
+            self.unwrapped.insert((span.start(), span.end()));
+        }
+        let owner = self.ty_of(&m.receiver);
+        // methods of types outside of cidre are not checked
+        if owner
+            .as_ref()
+            .is_none_or(|ty| self.index.owners.contains(ty))
+        {
+            let p = self.ctx.platform;
+            if let Some(decl) = self.index.method(owner.as_deref(), &name, m.args.len(), p) {
+                self.call(decl, &name, &m.method, m.span());
+            }
+        }
+        syn::visit::visit_expr_method_call(self, m);
+    }
+
+    fn visit_expr_call(&mut self, c: &'ast syn::ExprCall) {
+        if let Expr::Path(func) = &*c.func {
+            let path = &func.path;
+            let p = self.ctx.platform;
+            let n = c.args.len();
+            let at = &path.segments.last().expect("path").ident;
+            let name = at.to_string();
+            let decl = match self.assoc_path(path) {
+                Some((ty, name)) => self.index.assoc(&ty, &name, n, p),
+                None if path.segments.len() > 1 || self.imports.contains(&name) => {
+                    self.index.free(&name, n, p)
+                }
+                None => None,
+            };
+            if let Some(decl) = decl {
+                self.call(decl, &name, at, c.span());
+            }
+        }
+        syn::visit::visit_expr_call(self, c);
+    }
+}
+
+/// Checks calls of `index` APIs in `src`, `file` is used in messages.
+pub(crate) fn check(index: &Index, ctx: &Ctx, file: &str, src: &str, report: &mut Report) {
+    let Ok(ast) = syn::parse_file(src) else {
+        report.warning(format!("{file}: not parsed, skipped"));
+        return;
+    };
+    let mut checker = Checker {
+        index,
+        ctx,
+        file,
+        report,
+        guards: Vec::new(),
+        locals: HashMap::new(),
+        self_ty: None,
+        imports: HashSet::new(),
+        unwrapped: HashSet::new(),
+    };
+    checker.visit_file(&ast);
+    proc_macro2::extra::invalidate_current_thread_spans();
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct Args {
+    /// Target triple to check
+    #[arg(long, default_value = "aarch64-apple-darwin")]
+    pub(crate) target: String,
+
+    /// Deployment target, `MACOSX_DEPLOYMENT_TARGET` like env or rustc default otherwise
+    #[arg(long)]
+    pub(crate) deployment_target: Option<String>,
+
+    /// Package to check, all workspace members by default
+    #[arg(short, long)]
+    pub(crate) package: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct Metadata {
+    packages: Vec<Package>,
+    workspace_members: Vec<String>,
+    resolve: Option<Resolve>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Package {
+    id: String,
+    name: String,
+    manifest_path: PathBuf,
+}
+
+#[derive(Deserialize, Debug)]
+struct Resolve {
+    nodes: Vec<Node>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Node {
+    id: String,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+/// `cargo check` message, `build-script-executed` ones have `OUT_DIR`.
+#[derive(Deserialize, Debug)]
+struct Message {
+    reason: String,
+    #[serde(default)]
+    package_id: String,
+    out_dir: Option<PathBuf>,
+}
+
+impl Metadata {
+    pub(crate) fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|err| err.to_string())
+    }
+
+    fn cidre(&self) -> Option<&Package> {
+        self.packages.iter().find(|p| p.name == "cidre")
+    }
+
+    /// Features enabled for cidre by workspace.
+    fn features(&self) -> &[String] {
+        let node = self.cidre().and_then(|cidre| {
+            self.resolve
+                .as_ref()?
+                .nodes
+                .iter()
+                .find(|n| n.id == cidre.id)
+        });
+        node.map(|n| n.features.as_slice()).unwrap_or_default()
+    }
+
+    /// The newest `macos_x_x` like feature enabled for cidre.
+    pub(crate) fn feature(&self, platform: Platform) -> Option<Version> {
+        let prefix = format!("{}_", platform.as_str());
+        self.features()
+            .iter()
+            .filter_map(|f| f.strip_prefix(&prefix).and_then(Version::parse))
+            .max()
+    }
+
+    /// `OUT_DIR` of cidre from JSON messages of `cargo check`.
+    fn out_dir(&self, messages: &str) -> Option<PathBuf> {
+        let cidre = self.cidre()?;
+        messages
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Message>(line).ok())
+            .find(|m| m.reason == "build-script-executed" && m.package_id == cidre.id)?
+            .out_dir
+    }
+
+    /// Source dirs of workspace members.
+    fn members(&self, package: Option<&str>) -> Vec<&Path> {
+        self.packages
+            .iter()
+            .filter(|p| self.workspace_members.contains(&p.id))
+            .filter(|p| package.is_none_or(|name| name == p.name))
+            .filter_map(|p| p.manifest_path.parent())
+            .collect()
+    }
+}
+
+fn rs_files(dir: &Path, res: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<_> = entries.filter_map(Result::ok).map(|e| e.path()).collect();
+    entries.sort();
+    for path in entries {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with('.') || name == "target" {
+            continue;
+        }
+        if path.is_dir() {
+            rs_files(&path, res);
+        } else if name.ends_with(".rs") {
+            res.push(path);
+        }
+    }
+}
+
+/// `cargo box availability`, exits with 1 on unguarded uses.
+pub(crate) fn run(args: Args) {
+    match try_run(&args) {
+        Ok(report) => {
+            print!("{report}");
+            if report.errors() > 0 {
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn try_run(args: &Args) -> Result<Report, String> {
+    let sdk =
+        Sdk::from_triple(&args.target).ok_or(format!("{} is not an apple target", args.target))?;
+    let platform = Platform::of_sdk(sdk);
+    let deployment_target = match &args.deployment_target {
+        Some(v) => Some(v.clone()),
+        None => env::var(platform.deployment_target_key()).ok(),
+    };
+    let deployment_target = match deployment_target {
+        Some(v) => Version::parse(&v).ok_or(format!("{v} is not a version"))?,
+        None => platform.default_deployment_target(&args.target),
+    };
+
+    let output = process::Command::new(target::cargo())
+        .args(["metadata", "--format-version", "1"])
+        .args(["--filter-platform", &args.target])
+        .stderr(process::Stdio::inherit())
+        .output()
+        .map_err(|err| format!("cargo metadata failed: {err}"))?;
+    if !output.status.success() {
+        return Err("cargo metadata failed".to_string());
+    }
+    let meta = Metadata::from_json(&String::from_utf8_lossy(&output.stdout))?;
+    let Some(cidre) = meta.cidre() else {
+        return Err("cidre is not a dependency of this workspace".to_string());
+    };
+
+    // builds cidre with features and target of checked packages
+    let mut cmd = process::Command::new(target::cargo());
+    cmd.args(["check", "--message-format", "json"])
+        .args(["--target", &args.target]);
+    match &args.package {
+        Some(package) => cmd.args(["--package", package]),
+        None => cmd.arg("--workspace"),
+    };
+    let output = cmd
+        .stderr(process::Stdio::inherit())
+        .output()
+        .map_err(|err| format!("cargo check failed: {err}"))?;
+    if !output.status.success() {
+        return Err("cargo check failed".to_string());
+    }
+    let out_dir = meta
+        .out_dir(&String::from_utf8_lossy(&output.stdout))
+        .ok_or("cargo check didn't report OUT_DIR of cidre")?;
+    let table_dir = out_dir.join("availability");
+    let mut table = String::new();
+    let entries = fs::read_dir(&table_dir).map_err(|err| {
+        format!(
+            "{}: {err}, cidre is built by older cidre-macros, rebuild it with `cargo clean -p cidre`",
+            table_dir.display()
+        )
+    })?;
+    for entry in entries.filter_map(Result::ok) {
+        table.push_str(&fs::read_to_string(entry.path()).unwrap_or_default());
+    }
+
+    let cidre_dir = cidre.manifest_path.parent().unwrap_or(Path::new(""));
+    let mut cidre_files = Vec::new();
+    rs_files(&cidre_dir.join("src"), &mut cidre_files);
+    let sources: Vec<_> = cidre_files
+        .iter()
+        .filter_map(|path| {
+            let file = path.strip_prefix(cidre_dir).ok()?.to_str()?;
+            Some((file, fs::read_to_string(path).ok()?))
+        })
+        .collect();
+    let index = Index::new(&table, sources.iter().map(|(f, s)| (*f, s.as_str())));
+
+    let ctx = Ctx {
+        platform,
+        deployment_target,
+        feature: meta.feature(platform),
+    };
+    let mut report = Report::default();
+    ctx.check_features(&mut report);
+
+    let cwd = env::current_dir().unwrap_or_default();
+    let mut files = Vec::new();
+    for dir in meta.members(args.package.as_deref()) {
+        for sub in ["src", "examples", "tests", "benches"] {
+            rs_files(&dir.join(sub), &mut files);
+        }
+    }
+    for file in &files {
+        let Ok(src) = fs::read_to_string(file) else {
+            continue;
+        };
+        let name = file
+            .strip_prefix(&cwd)
+            .unwrap_or(file)
+            .display()
+            .to_string();
+        check(&index, &ctx, &name, &src, &mut report);
+    }
+    report.ok(format!(
+        "{} files checked against {} annotated cidre APIs",
+        files.len(),
+        index.apis
+    ));
+    Ok(report)
+}
+#[cfg(test)]
+mod tests {
+    use super::{Ctx, Fallback, Index, Metadata, Platform, Version, Versions, check};
+    use crate::signing::{Level, Report};
+
+    const TABLE: &str = include_str!("../fixtures/availability/cidre.tsv");
+    const CIDRE: &str = include_str!("../fixtures/availability/cidre.rs");
+    const APP: &str = include_str!("../fixtures/availability/app.rs");
+
+    fn index() -> Index {
+        Index::new(TABLE, [("cidre.rs", CIDRE)])
+    }
+
+    fn checked(platform: Platform, target: Version, feature: Option<Version>) -> Report {
+        let ctx = Ctx {
+            platform,
+            deployment_target: target,
+            feature,
+        };
+        let mut report = Report::default();
+        check(&index(), &ctx, "src/main.rs", APP, &mut report);
+        report
+    }
+
+    #[test]
+    fn versions() {
+        assert_eq!(Version::parse("14.0"), Some(Version(14, 0)));
+        assert_eq!(Version::parse("18"), Some(Version(18, 0)));
+        assert_eq!(Version::parse("13.1.2"), Some(Version(13, 1)));
+        assert_eq!(Version::parse("15_4"), Some(Version(15, 4)));
+        assert_eq!(Version::parse("latest"), None);
+        assert!(Version(14, 2) > Version(14, 0));
+        assert_eq!(Version(26, 0).to_string(), "26.0");
+
+        let macos_ios = Versions(vec![
+            (Platform::Macos, Version(15, 0)),
+            (Platform::Ios, Version(18, 0)),
+        ]);
+        assert_eq!(
+            Versions::from_list("macos = 15.0, ios = 18.0"),
+            Some(macos_ios.clone())
+        );
+        assert_eq!(Versions::from_list("macos = latest"), None);
+        let cfg = r#"any(feature = "macos_15_0", feature = "ios_18_0", feature = "ns")"#;
+        assert_eq!(Versions::from_cfg(cfg.parse().unwrap()), macos_ios);
+        let cfg = r#"not(feature = "macos_15_0")"#;
+        assert_eq!(
+            Versions::from_cfg(cfg.parse().unwrap()),
+            Versions::default()
+        );
+    }
+
+    #[test]
+    fn index_apis() {
+        let index = index();
+        let mut apis: Vec<_> = index
+            .fns
+            .iter()
+            .flat_map(|(name, decls)| decls.iter().map(move |d| (name, d)))
+            .filter_map(|(name, d)| {
+                let (_, fallback) = d.api.as_ref()?;
+                let path = match &d.owner {
+                    Some(ty) => format!("{ty}::{name}"),
+                    None => name.to_string(),
+                };
+                Some((path, d.receiver, d.args, *fallback))
+            })
+            .collect();
+        apis.sort_by(|a, b| a.0.cmp(&b.0));
+        let api =
+            |path: &str, receiver, args, fallback| (path.to_string(), receiver, args, fallback);
+        // stale and duplicate rows and rows of unknown files are skipped
+        assert_eq!(
+            apis,
+            [
+                api(
+                    "Delegate::stream_did_stop_with_err",
+                    true,
+                    2,
+                    Fallback::Unsafe
+                ),
+                api("FrameInfo::bounding_rect", false, 0, Fallback::Option),
+                api("RecordingOutput::init_with_cfg", true, 2, Fallback::Unsafe),
+                api("RecordingOutput::len", true, 0, Fallback::Unsafe),
+                api("RecordingOutput::with_cfg", false, 1, Fallback::Option),
+                api(
+                    "Stream::add_recording_output_throws",
+                    true,
+                    1,
+                    Fallback::Unsafe
+                ),
+                api("Stream::cls", false, 0, Fallback::Class),
+                api("Stream::is_active", true, 0, Fallback::Unsafe),
+                api(
+                    "Stream::try_add_recording_output",
+                    true,
+                    1,
+                    Fallback::Unsafe
+                ),
+                api("converter_new", false, 1, Fallback::Panic),
+            ]
+        );
+        assert_eq!(index.apis, apis.len());
+        assert_eq!(index.ret("Stream", "new").as_deref(), Some("Stream"));
+        assert_eq!(
+            index.ret("RecordingOutput", "with_cfg").as_deref(),
+            Some("RecordingOutput")
+        );
+        assert_eq!(
+            index.ret("Stream", "delegate").as_deref(),
+            Some("AnyDelegate")
+        );
+        assert_eq!(index.resolve("StreamFrameInfo".into()), "FrameInfo");
+        // `len` of `Data` is plain, so is `.len()` of unknown type
+        let p = Platform::Macos;
+        assert!(index.method(Some("Data"), "len", 0, p).is_none());
+        assert!(index.method(None, "len", 0, p).is_none());
+        assert!(index.method(Some("RecordingOutput"), "len", 0, p).is_some());
+        assert!(
+            index
+                .method(Some("RecordingOutput"), "len", 0, Platform::Ios)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unguarded() {
+        let report = checked(Platform::Macos, Version(14, 0), Some(Version(14, 0)));
+        assert_eq!(
+            report.to_string(),
+            "\
+warning  src/main.rs:6: `RecordingOutput::with_cfg` is `None` before macos 15.0, deployment target is 14.0, unwrap it under `api::version!(macos = 15.0)`
+error    src/main.rs:7: `Stream::is_active` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:8: `Stream::try_add_recording_output` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:12: `Stream::is_active` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:13: `Stream::try_add_recording_output` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:17: `converter_new` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:19: `RecordingOutput::len` requires macos 26.0, deployment target is 14.0, guard with `api::version!(macos = 26.0)`
+error    src/main.rs:25: `converter_new` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:26: `Stream::is_active` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:27: `Stream::is_active` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:28: `RecordingOutput::init_with_cfg` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+error    src/main.rs:61: `Stream::try_add_recording_output` requires macos 15.0, deployment target is 14.0, guard with `api::version!(macos = 15.0)`
+"
+        );
+
+        let report = checked(Platform::Macos, Version(13, 0), None);
+        let lines: Vec<_> = report
+            .checks
+            .iter()
+            .filter(|(_, msg)| msg.contains("macos 14.0"))
+            .map(|(level, msg)| (*level, msg.split(':').nth(1).unwrap().to_string()))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (Level::Warning, "16".to_string()),
+                (Level::Warning, "22".to_string()),
+            ]
+        );
+        assert!(report.checks.iter().any(|(_, msg)| msg
+            == "src/main.rs:22: `Stream::cls` is `None` before macos 14.0, deployment target is 13.0, \
+                use `cls_available(av)` under `api::if_available!(macos = 14.0 => |av| ..)`"));
+
+        let report = checked(Platform::Ios, Version(17, 0), Some(Version(17, 0)));
+        assert_eq!(
+            report.to_string(),
+            "\
+error    src/main.rs:17: `converter_new` requires ios 18.0, deployment target is 17.0, guard with `api::version!(ios = 18.0)`
+error    src/main.rs:25: `converter_new` requires ios 18.0, deployment target is 17.0, guard with `api::version!(ios = 18.0)`
+"
+        );
+        assert!(
+            checked(Platform::Ios, Version(18, 0), None)
+                .checks
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_linked() {
+        let ctx = Ctx {
+            platform: Platform::Macos,
+            deployment_target: Version(14, 0),
+            feature: Some(Version(15, 0)),
+        };
+        let mut report = Report::default();
+        ctx.check_features(&mut report);
+        assert_eq!(
+            report.to_string(),
+            "error    cidre feature macos_15_0 is newer than macos deployment target 14.0 (MACOSX_DEPLOYMENT_TARGET), its APIs are linked strongly, use macos_14_0 feature and `api::version!`\n"
+        );
+
+        // guards don't help strong linked APIs
+        let report = checked(ctx.platform, ctx.deployment_target, ctx.feature);
+        assert_eq!(report.errors(), 18);
+        assert!(report.checks[0].1.starts_with(
+            "src/main.rs:6: `RecordingOutput::with_cfg` requires macos 15.0 but is linked strongly"
+        ));
+
+        let ctx = Ctx {
+            feature: Some(Version(14, 0)),
+            ..ctx
+        };
+        let mut report = Report::default();
+        ctx.check_features(&mut report);
+        assert_eq!(
+            report.to_string(),
+            "ok       macos deployment target 14.0, cidre features up to macos_14_0\n"
+        );
+    }
+
+    #[test]
+    fn metadata() {
+        let meta = Metadata::from_json(
+            r#"{
+                "packages": [
+                    {"id": "app 0.1.0", "name": "app", "manifest_path": "/ws/app/Cargo.toml"},
+                    {"id": "cidre 0.16.1", "name": "cidre", "manifest_path": "/ws/cidre/Cargo.toml"}
+                ],
+                "workspace_members": ["app 0.1.0"],
+                "resolve": {"nodes": [
+                    {"id": "app 0.1.0", "features": []},
+                    {"id": "cidre 0.16.1", "features": ["macos_13_0", "macos_14_4", "ios_17_0", "ns"]}
+                ]}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(meta.feature(Platform::Macos), Some(Version(14, 4)));
+        assert_eq!(meta.feature(Platform::Ios), Some(Version(17, 0)));
+        assert_eq!(meta.feature(Platform::Tvos), None);
+        assert_eq!(meta.members(None), [std::path::Path::new("/ws/app")]);
+        assert!(meta.members(Some("cidre")).is_empty());
+
+        let messages = r#"{"reason":"compiler-artifact","package_id":"cidre 0.16.1"}
+{"reason":"build-script-executed","package_id":"objc2 0.6.0","out_dir":"/ws/target/debug/build/objc2-1/out"}
+{"reason":"build-script-executed","package_id":"cidre 0.16.1","out_dir":"/ws/target/debug/build/cidre-2/out"}
+{"reason":"build-finished","success":true}"#;
+        assert_eq!(
+            meta.out_dir(messages),
+            Some("/ws/target/debug/build/cidre-2/out".into())
+        );
+        assert_eq!(meta.out_dir(""), None);
+    }
+}
@@ -0,0 +1,726 @@
+//! Xcode-free `.app` assembly and signing.
+//!
+//! Bundle is described with plain [`Step`]s and `codesign` invocations, so
+//! layout, Info.plist and entitlements are checked with golden files on any host.
+//! Only [`apply`] and [`sign`] touch file system and run tools.
+
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use cargo_toml::Value;
+
+use crate::{
+    exec::{self, Cmd, Exec},
+    meta,
+    target::Sdk,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Backend {
+    /// Assemble and sign `.app` directly
+    Direct,
+    /// Generate xcode project and build it with `xcodebuild`
+    Xcode,
+}
+
+impl Backend {
+    /// `BOX_BACKEND=direct|xcode`, `direct` by default
+    pub(crate) fn from_env() -> Result<Self, Error> {
+        match env::var("BOX_BACKEND").as_deref() {
+            Err(_) | Ok("direct") => Ok(Self::Direct),
+            Ok("xcode") => Ok(Self::Xcode),
+            Ok(x) => Err(Error::Backend(x.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Exec(exec::Error),
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+    /// Resource or framework path without file name
+    NoFileName(PathBuf),
+    /// Unknown `BOX_BACKEND`
+    Backend(String),
+}
+
+impl Error {
+    fn io(path: &Path) -> impl FnOnce(io::Error) -> Self + '_ {
+        move |err| Self::Io {
+            path: path.to_path_buf(),
+            err,
+        }
+    }
+}
+
+impl From<exec::Error> for Error {
+    fn from(err: exec::Error) -> Self {
+        Self::Exec(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exec(err) => err.fmt(f),
+            Self::Io { path, err } => write!(f, "{}: {err}", path.display()),
+            Self::NoFileName(path) => write!(f, "{} has no file name", path.display()),
+            Self::Backend(x) => {
+                write!(f, "unknown BOX_BACKEND `{x}`, expected `direct` or `xcode`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Everything needed to assemble and sign a bundle.
+#[derive(Debug)]
+pub(crate) struct Spec<'a> {
+    /// Executable and bundle name
+    pub(crate) name: &'a str,
+    pub(crate) bundle_id: &'a str,
+    /// `CFBundleShortVersionString`
+    pub(crate) version: &'a str,
+    pub(crate) team_id: &'a str,
+    /// `codesign --sign` identity
+    pub(crate) identity: &'a str,
+    pub(crate) sdk: Sdk,
+    /// Debug builds get `get-task-allow` entitlement
+    pub(crate) debug: bool,
+    /// Workspace root, `resources` and `frameworks` are relative to it
+    pub(crate) root: &'a Path,
+    pub(crate) profile: Option<&'a Path>,
+    pub(crate) cfg: &'a meta::Cfg,
+    pub(crate) extensions: &'a [Appex<'a>],
+}
+
+/// Built extension embedded into app, see [`meta::Extension`].
+#[derive(Debug)]
+pub(crate) struct Appex<'a> {
+    /// Executable name, `.appex` bundle name
+    pub(crate) name: &'a str,
+    pub(crate) bundle_id: String,
+    /// Built executable or `cdylib`
+    pub(crate) binary: PathBuf,
+    pub(crate) profile: Option<PathBuf>,
+    pub(crate) ext: &'a meta::Extension,
+}
+
+/// Paths inside `.app` or `.appex`, `Contents/...` on macOS and flat on other platforms.
+#[derive(Debug)]
+pub(crate) struct Layout {
+    pub(crate) app: PathBuf,
+    pub(crate) exe: PathBuf,
+    pub(crate) info_plist: PathBuf,
+    pub(crate) resources: PathBuf,
+    pub(crate) frameworks: PathBuf,
+    pub(crate) profile: PathBuf,
+    /// `.appex` app extensions
+    pub(crate) plugins: PathBuf,
+    /// ExtensionKit extensions
+    pub(crate) extensions: PathBuf,
+    pub(crate) system_extensions: PathBuf,
+    /// Passed to `codesign` and kept next to `.app`, bundle contents are sealed
+    pub(crate) entitlements: PathBuf,
+}
+
+impl Layout {
+    pub(crate) fn new(app: &Path, name: &str, sdk: Sdk) -> Self {
+        let entitlements = app.with_extension("entitlements");
+        if sdk.is_mac() {
+            let contents = app.join("Contents");
+            Self {
+                app: app.to_path_buf(),
+                exe: contents.join("MacOS").join(name),
+                info_plist: contents.join("Info.plist"),
+                resources: contents.join("Resources"),
+                frameworks: contents.join("Frameworks"),
+                profile: contents.join("embedded.provisionprofile"),
+                plugins: contents.join("PlugIns"),
+                extensions: contents.join("Extensions"),
+                system_extensions: contents.join("Library/SystemExtensions"),
+                entitlements,
+            }
+        } else {
+            Self {
+                app: app.to_path_buf(),
+                exe: app.join(name),
+                info_plist: app.join("Info.plist"),
+                resources: app.to_path_buf(),
+                frameworks: app.join("Frameworks"),
+                profile: app.join("embedded.mobileprovision"),
+                plugins: app.join("PlugIns"),
+                extensions: app.join("Extensions"),
+                system_extensions: app.join("SystemExtensions"),
+                entitlements,
+            }
+        }
+    }
+
+    /// Layout of `appex` embedded into this app.
+    pub(crate) fn extension(&self, appex: &Appex, sdk: Sdk) -> Self {
+        let dir = match appex.ext.kind {
+            meta::ExtensionKind::App => self.plugins.join(format!("{}.appex", appex.name)),
+            meta::ExtensionKind::Kit => self.extensions.join(format!("{}.appex", appex.name)),
+            meta::ExtensionKind::System => self
+                .system_extensions
+                .join(format!("{}.systemextension", appex.bundle_id)),
+        };
+        let mut layout = Self::new(&dir, appex.name, sdk);
+        let mut file_name = self.app.file_stem().unwrap_or_default().to_os_string();
+        file_name.push(format!(".{}.entitlements", appex.name));
+        layout.entitlements = self.app.with_file_name(file_name);
+        layout
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Step {
+    RemoveDir(PathBuf),
+    CreateDir(PathBuf),
+    /// File or folder
+    Copy {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    Write {
+        path: PathBuf,
+        contents: String,
+    },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RemoveDir(path) => writeln!(f, "rm {}", path.display()),
+            Self::CreateDir(path) => writeln!(f, "mkdir {}", path.display()),
+            Self::Copy { from, to } => writeln!(f, "cp {} {}", from.display(), to.display()),
+            Self::Write { path, contents } => {
+                writeln!(f, "write {}", path.display())?;
+                f.write_str(contents)
+            }
+        }
+    }
+}
+
+/// Keys shared by apps and extensions.
+fn bundle_info(
+    spec: &Spec,
+    name: &str,
+    bundle_id: &str,
+    display_name: &str,
+    package_type: &str,
+) -> BTreeMap<String, Value> {
+    let cfg = spec.cfg;
+    let mut dict = BTreeMap::new();
+    let mut set = |key: &str, value: Value| {
+        dict.insert(key.to_string(), value);
+    };
+    let str = |s: &str| Value::String(s.to_string());
+
+    set("CFBundleDevelopmentRegion", str("en"));
+    set("CFBundleDisplayName", str(display_name));
+    set("CFBundleExecutable", str(name));
+    set("CFBundleIdentifier", str(bundle_id));
+    set("CFBundleInfoDictionaryVersion", str("6.0"));
+    set("CFBundleName", str(name));
+    set("CFBundlePackageType", str(package_type));
+    set("CFBundleShortVersionString", str(spec.version));
+    set(
+        "CFBundleSupportedPlatforms",
+        Value::Array(vec![str(spec.sdk.platform())]),
+    );
+    set("CFBundleVersion", str("1"));
+
+    let min_version = cfg.deployment_targets.get(&spec.sdk.os());
+    if spec.sdk.is_mac() {
+        if let Some(v) = min_version {
+            set("LSMinimumSystemVersion", str(v));
+        }
+    } else if let Some(v) = min_version {
+        set("MinimumOSVersion", str(v));
+    }
+
+    // mac catalyst keeps UIKit idiom, `ipad` is scaled, `mac` is optimized for mac
+    let supported = spec.sdk.device_families();
+    if !supported.is_empty() {
+        let mut families: Vec<_> = match &cfg.device_families {
+            Some(families) => families
+                .iter()
+                .filter(|f| supported.contains(f))
+                .copied()
+                .collect(),
+            None => supported.to_vec(),
+        };
+        if families.is_empty() {
+            families = supported.to_vec();
+        }
+        families.sort();
+        families.dedup();
+        let families = families
+            .iter()
+            .map(|f| Value::Integer(f.id() as i64))
+            .collect();
+        set("UIDeviceFamily", Value::Array(families));
+    }
+    dict
+}
+
+pub(crate) fn info_plist(spec: &Spec) -> String {
+    let cfg = spec.cfg;
+    let display_name = cfg.display_name.as_deref().unwrap_or(spec.name);
+    let mut dict = bundle_info(spec, spec.name, spec.bundle_id, display_name, "APPL");
+    let mut set = |key: &str, value: Value| {
+        dict.insert(key.to_string(), value);
+    };
+
+    // same default as in xcode template
+    set(
+        "NSCameraUsageDescription",
+        Value::String("Testing Camera".to_string()),
+    );
+
+    match spec.sdk {
+        Sdk::IphoneOs | Sdk::IphoneSimulator => {
+            set("UILaunchScreen", Value::Table(Default::default()))
+        }
+        Sdk::WatchOs | Sdk::WatchSimulator => set("WKWatchOnly", Value::Boolean(true)),
+        _ => {}
+    }
+
+    dict.extend(cfg.info.clone());
+    meta::plist(&dict)
+}
+
+pub(crate) fn extension_info_plist(spec: &Spec, appex: &Appex) -> String {
+    let ext = appex.ext;
+    let display_name = ext.display_name.as_deref().unwrap_or(appex.name);
+    let package_type = match ext.kind {
+        meta::ExtensionKind::System => "SYSX",
+        meta::ExtensionKind::App | meta::ExtensionKind::Kit => "XPC!",
+    };
+    let mut dict = bundle_info(
+        spec,
+        appex.name,
+        &appex.bundle_id,
+        display_name,
+        package_type,
+    );
+    if let Some(ns_extension) = &ext.ns_extension {
+        dict.insert(
+            "NSExtension".to_string(),
+            Value::Table(ns_extension.clone().into_iter().collect()),
+        );
+    }
+    dict.extend(ext.info.clone());
+    meta::plist(&dict)
+}
+
+/// Entitlements to sign with, `None` for simulators.
+///
+/// `com.apple.security.*` keys are macOS only and dropped on devices,
+/// otherwise install fails because they are missing in provisioning profile.
+pub(crate) fn entitlements(spec: &Spec) -> Option<String> {
+    signed_entitlements(
+        spec,
+        spec.bundle_id,
+        spec.cfg.resolved_entitlements(),
+        &spec.cfg.entitlements,
+    )
+}
+
+pub(crate) fn extension_entitlements(spec: &Spec, appex: &Appex) -> Option<String> {
+    signed_entitlements(
+        spec,
+        &appex.bundle_id,
+        appex.ext.resolved_entitlements(),
+        &appex.ext.entitlements,
+    )
+}
+
+/// `resolved` are used on macOS, `user` keys on devices.
+fn signed_entitlements(
+    spec: &Spec,
+    bundle_id: &str,
+    resolved: BTreeMap<String, Value>,
+    user: &BTreeMap<String, Value>,
+) -> Option<String> {
+    if spec.sdk.is_simulator() {
+        return None;
+    }
+    let dict = if spec.sdk.is_mac() {
+        let mut dict = resolved;
+        if spec.debug {
+            dict.insert(
+                "com.apple.security.get-task-allow".to_string(),
+                Value::Boolean(true),
+            );
+        }
+        dict
+    } else {
+        let mut dict: BTreeMap<String, Value> = user
+            .iter()
+            .filter(|(k, _)| !k.starts_with("com.apple.security."))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        dict.insert(
+            "application-identifier".to_string(),
+            Value::String(format!("{}.{bundle_id}", spec.team_id)),
+        );
+        dict.insert(
+            "com.apple.developer.team-identifier".to_string(),
+            Value::String(spec.team_id.to_string()),
+        );
+        dict.insert("get-task-allow".to_string(), Value::Boolean(spec.debug));
+        dict
+    };
+    Some(meta::plist(&dict))
+}
+
+fn file_name(path: &Path) -> Result<&std::ffi::OsStr, Error> {
+    path.file_name()
+        .ok_or_else(|| Error::NoFileName(path.to_path_buf()))
+}
+
+pub(crate) fn steps(spec: &Spec, binary: &Path, layout: &Layout) -> Result<Vec<Step>, Error> {
+    let mut steps = vec![
+        Step::RemoveDir(layout.app.clone()),
+        Step::CreateDir(layout.exe.parent().unwrap().to_path_buf()),
+        Step::Copy {
+            from: binary.to_path_buf(),
+            to: layout.exe.clone(),
+        },
+        Step::Write {
+            path: layout.info_plist.clone(),
+            contents: info_plist(spec),
+        },
+    ];
+
+    if !spec.cfg.resources.is_empty() && layout.resources != layout.app {
+        steps.push(Step::CreateDir(layout.resources.clone()));
+    }
+    for res in spec.cfg.resources.iter() {
+        let from = spec.root.join(res);
+        let to = layout.resources.join(file_name(&from)?);
+        steps.push(Step::Copy { from, to });
+    }
+
+    if !spec.cfg.frameworks.is_empty() {
+        steps.push(Step::CreateDir(layout.frameworks.clone()));
+    }
+    for fw in spec.cfg.frameworks.iter() {
+        let from = spec.root.join(fw);
+        let to = layout.frameworks.join(file_name(&from)?);
+        steps.push(Step::Copy { from, to });
+    }
+
+    for appex in spec.extensions {
+        let ext_layout = layout.extension(appex, spec.sdk);
+        steps.push(Step::CreateDir(
+            ext_layout.exe.parent().unwrap().to_path_buf(),
+        ));
+        steps.push(Step::Copy {
+            from: appex.binary.clone(),
+            to: ext_layout.exe.clone(),
+        });
+        steps.push(Step::Write {
+            path: ext_layout.info_plist.clone(),
+            contents: extension_info_plist(spec, appex),
+        });
+        if let Some(profile) = &appex.profile {
+            steps.push(Step::Copy {
+                from: profile.clone(),
+                to: ext_layout.profile.clone(),
+            });
+        }
+        if let Some(contents) = extension_entitlements(spec, appex) {
+            steps.push(Step::Write {
+                path: ext_layout.entitlements.clone(),
+                contents,
+            });
+        }
+    }
+
+    if let Some(profile) = spec.profile {
+        steps.push(Step::Copy {
+            from: profile.to_path_buf(),
+            to: layout.profile.clone(),
+        });
+    }
+
+    if let Some(contents) = entitlements(spec) {
+        steps.push(Step::Write {
+            path: layout.entitlements.clone(),
+            contents,
+        });
+    }
+
+    Ok(steps)
+}
+
+/// `codesign` invocations, inside out: frameworks, extensions, then app.
+/// Simulator builds are not signed.
+pub(crate) fn codesign_cmds(spec: &Spec, layout: &Layout) -> Result<Vec<Vec<String>>, Error> {
+    if spec.sdk.is_simulator() {
+        return Ok(Vec::new());
+    }
+    let sign = |path: &Path, extra: &[&str]| {
+        let mut cmd = vec![
+            "codesign".to_string(),
+            "--force".to_string(),
+            "--sign".to_string(),
+            spec.identity.to_string(),
+            "--timestamp=none".to_string(),
+        ];
+        cmd.extend(extra.iter().map(|s| s.to_string()));
+        cmd.push(path.display().to_string());
+        cmd
+    };
+    let sign_bundle = |layout: &Layout| {
+        let entitlements = layout.entitlements.display().to_string();
+        let mut extra = vec!["--entitlements", entitlements.as_str()];
+        if spec.sdk.is_mac() {
+            extra.extend(["--options", "runtime"]);
+        }
+        sign(&layout.app, &extra)
+    };
+    let mut cmds: Vec<_> = spec
+        .cfg
+        .frameworks
+        .iter()
+        .map(|fw| {
+            Ok(sign(
+                &layout.frameworks.join(file_name(Path::new(fw))?),
+                &[],
+            ))
+        })
+        .collect::<Result<_, Error>>()?;
+    for appex in spec.extensions {
+        cmds.push(sign_bundle(&layout.extension(appex, spec.sdk)));
+    }
+    cmds.push(sign_bundle(layout));
+    Ok(cmds)
+}
+
+pub(crate) fn apply(steps: &[Step]) -> Result<(), Error> {
+    for step in steps {
+        match step {
+            Step::RemoveDir(path) => {
+                if path.exists() {
+                    fs::remove_dir_all(path).map_err(Error::io(path))?;
+                }
+            }
+            Step::CreateDir(path) => fs::create_dir_all(path).map_err(Error::io(path))?,
+            Step::Copy { from, to } => copy(from, to)?,
+            Step::Write { path, contents } => fs::write(path, contents).map_err(Error::io(path))?,
+        }
+    }
+    Ok(())
+}
+
+fn copy(from: &Path, to: &Path) -> Result<(), Error> {
+    if !from.is_dir() {
+        fs::copy(from, to).map_err(Error::io(from))?;
+        return Ok(());
+    }
+    fs::create_dir_all(to).map_err(Error::io(to))?;
+    for entry in fs::read_dir(from).map_err(Error::io(from))? {
+        let entry = entry.map_err(Error::io(from))?;
+        copy(&entry.path(), &to.join(entry.file_name()))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn sign(exec: &mut impl Exec, cmds: &[Vec<String>]) -> Result<(), exec::Error> {
+    for cmd in cmds {
+        exec.run(&Cmd::new(&cmd[0]).args(&cmd[1..]))?;
+    }
+    Ok(())
+}
+
+/// Assembles and signs `app` with `binary`.
+pub(crate) fn bundle(
+    exec: &mut impl Exec,
+    spec: &Spec,
+    binary: &Path,
+    app: &Path,
+) -> Result<Layout, Error> {
+    let layout = Layout::new(app, spec.name, spec.sdk);
+    apply(&steps(spec, binary, &layout)?)?;
+    sign(exec, &codesign_cmds(spec, &layout)?)?;
+    Ok(layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use cargo_toml::Manifest;
+
+    use super::{Appex, Error, Layout, Spec, Step, apply, codesign_cmds, steps};
+    use crate::{meta, target::Sdk};
+
+    const MANIFEST: &str = r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[[example]]
+name = "mic"
+
+[package.metadata.box]
+version = "1.2.0"
+device-families = ["iphone", "mac"]
+deployment-targets = { ios = "17.0", macos = "14.0" }
+resources = ["examples/mic/assets"]
+frameworks = ["target/frameworks/Foo.framework"]
+
+[package.metadata.box.entitlements]
+"com.apple.security.device.audio-input" = true
+"com.apple.developer.kernel.increased-memory-limit" = true
+
+[package.metadata.box.info]
+NSMicrophoneUsageDescription = "Recording"
+
+[package.metadata.box.extensions.au]
+example = "au-ext"
+entitlements = { "com.apple.security.device.audio-input" = true }
+
+[package.metadata.box.extensions.au.ns-extension]
+NSExtensionPointIdentifier = "com.apple.AudioUnit-UI"
+NSExtensionPrincipalClass = "AuViewController"
+NSExtensionAttributes = { AudioComponents = [{ name = "cidre: mic", type = "aufx", subtype = "mic0", manufacturer = "Cidr", version = 1 }] }
+
+[package.metadata.box.extensions.cam]
+example = "cam-ext"
+kind = "system"
+bundle-id-suffix = "camera"
+info = { CMIOExtension = { CMIOExtensionMachServiceName = "TEAM.org.cidre.mic.camera" } }
+"#;
+
+    /// Compares with `golden/<name>`, `BOX_BLESS=1` updates files.
+    fn golden(name: &str, actual: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("golden")
+            .join(name);
+        if env::var_os("BOX_BLESS").is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, actual).unwrap();
+            return;
+        }
+        let expected = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            expected == actual,
+            "golden/{name} differs, rerun with BOX_BLESS=1 to update\n{actual}"
+        );
+    }
+
+    fn render(sdk: Sdk, debug: bool, profile: Option<&Path>) -> String {
+        render_with(sdk, debug, profile, false)
+    }
+
+    fn render_with(sdk: Sdk, debug: bool, profile: Option<&Path>, extensions: bool) -> String {
+        let man = Manifest::from_str(MANIFEST).unwrap();
+        let cfg = meta::Cfg::for_product(Some(&man), true, "mic").unwrap();
+        let appexes: Vec<_> = cfg
+            .extensions
+            .iter()
+            .filter(|_| extensions)
+            .map(|(name, ext)| Appex {
+                name,
+                bundle_id: ext.bundle_id("org.cidre.mic", name),
+                binary: Path::new("/ws/target/debug/examples").join(name),
+                profile: profile.map(|_| Path::new("/ws").join(format!("{name}.mobileprovision"))),
+                ext,
+            })
+            .collect();
+        let spec = Spec {
+            name: "mic",
+            bundle_id: "org.cidre.mic",
+            version: cfg.version.as_deref().unwrap(),
+            team_id: "TEAM",
+            identity: "Apple Development",
+            sdk,
+            debug,
+            root: Path::new("/ws"),
+            profile,
+            cfg: &cfg,
+            extensions: &appexes,
+        };
+        let layout = Layout::new(Path::new("/ws/target/mic.app"), spec.name, sdk);
+        let mut res = String::new();
+        for step in steps(&spec, Path::new("/ws/target/debug/examples/mic"), &layout).unwrap() {
+            res.push_str(&step.to_string());
+        }
+        for cmd in codesign_cmds(&spec, &layout).unwrap() {
+            res.push_str(&cmd.join(" "));
+            res.push('\n');
+        }
+        res
+    }
+
+    #[test]
+    fn macos() {
+        golden("bundle/macos.txt", &render(Sdk::MacOsx, true, None));
+    }
+
+    #[test]
+    fn macos_extensions() {
+        golden(
+            "bundle/macos-extensions.txt",
+            &render_with(Sdk::MacOsx, true, None, true),
+        );
+    }
+
+    #[test]
+    fn iphoneos_extensions() {
+        let profile = Path::new("/ws/mic.mobileprovision");
+        golden(
+            "bundle/iphoneos-extensions.txt",
+            &render_with(Sdk::IphoneOs, false, Some(profile), true),
+        );
+    }
+
+    #[test]
+    fn maccatalyst() {
+        golden(
+            "bundle/maccatalyst.txt",
+            &render(Sdk::MacCatalyst, true, None),
+        );
+    }
+
+    #[test]
+    fn iphoneos() {
+        let profile = Path::new("/ws/mic.mobileprovision");
+        golden(
+            "bundle/iphoneos.txt",
+            &render(Sdk::IphoneOs, false, Some(profile)),
+        );
+    }
+
+    #[test]
+    fn iphonesimulator() {
+        golden(
+            "bundle/iphonesimulator.txt",
+            &render(Sdk::IphoneSimulator, true, None),
+        );
+    }
+
+    #[test]
+    fn apply_errors() {
+        let missing = env::temp_dir().join("cargo-box-missing/mic");
+        let to = env::temp_dir().join("cargo-box-apply-errors");
+        let res = apply(&[Step::Copy {
+            from: missing.clone(),
+            to,
+        }]);
+        assert!(matches!(res, Err(Error::Io { path, .. }) if path == missing));
+    }
+}
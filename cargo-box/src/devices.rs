@@ -0,0 +1,703 @@
+//! `cargo box devices`, physical devices and simulators in one table.
+//!
+//! Subcommands work with both kinds of destinations:
+//!
+//! ```text
+//! cargo box devices --platform ios --connected
+//! cargo box devices logs org.cidre.sc-record
+//! cargo box devices push org.cidre.box fixtures/in.wav Documents/in.wav
+//! cargo box devices pull org.cidre.sc-record Documents/out.mov out.mov
+//! cargo box devices ps box
+//! cargo box devices kill 4242
+//! ```
+//!
+//! Destination is `--device`, `DEVICE_ID` or `SIMULATOR_ID` (`.box` is loaded),
+//! otherwise the first connected device or booted simulator.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::{
+    device_ctl,
+    exec::{self, Cmd, Exec},
+    sim_ctl, xcode,
+};
+
+/// Run destination, `id` is usable as `DEVICE_ID` or `SIMULATOR_ID`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Row {
+    /// `device` or `simulator`
+    pub(crate) kind: &'static str,
+    pub(crate) name: String,
+    pub(crate) os: String,
+    pub(crate) id: String,
+    /// Hardware udid, the same as `id` for simulators
+    pub(crate) udid: String,
+    pub(crate) state: String,
+}
+
+impl Row {
+    /// `ios`, `tvos`, `watchos`, `visionos`, ...
+    pub(crate) fn platform(&self) -> String {
+        let os = self.os.split(' ').next().unwrap_or_default();
+        match os.to_ascii_lowercase().as_str() {
+            // devicectl reports vision pro as `xrOS`
+            "xros" => "visionos".to_string(),
+            os => os.to_string(),
+        }
+    }
+
+    /// Connected device or booted simulator.
+    pub(crate) fn is_connected(&self) -> bool {
+        self.state == "connected" || self.state == "booted"
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct Args {
+    #[command(subcommand)]
+    cmd: Option<Command>,
+
+    #[command(flatten)]
+    filter: Filter,
+}
+
+#[derive(clap::Args, Debug, Default)]
+pub(crate) struct Filter {
+    /// `ios`, `tvos`, `watchos` or `visionos`
+    #[arg(long)]
+    platform: Option<String>,
+
+    /// Connected devices and booted simulators only
+    #[arg(long)]
+    connected: bool,
+}
+
+impl Filter {
+    fn matches(&self, row: &Row) -> bool {
+        if self.connected && !row.is_connected() {
+            return false;
+        }
+        match &self.platform {
+            Some(platform) => row.platform().eq_ignore_ascii_case(platform),
+            None => true,
+        }
+    }
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Stream simulator app logs. Devices can't stream, their logs are
+    /// collected for `--last` interval (5m by default) and shown once
+    Logs(LogsArgs),
+
+    /// Copy file into app's data container
+    Push(CopyArgs),
+
+    /// Copy file out of app's data container
+    Pull(CopyArgs),
+
+    /// List running processes
+    Ps(PsArgs),
+
+    /// Terminate running process
+    Kill(KillArgs),
+}
+
+#[derive(clap::Args, Debug, Default)]
+struct DestArgs {
+    /// Device or simulator id or name
+    #[arg(long, short)]
+    device: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct LogsArgs {
+    #[command(flatten)]
+    dest: DestArgs,
+
+    bundle_id: String,
+
+    /// Process name, last component of bundle id by default
+    #[arg(long)]
+    process: Option<String>,
+
+    /// Show logs for the last interval (`30s`, `5m`, `1h`) instead of streaming,
+    /// 5m archive is collected on devices when omitted
+    #[arg(long)]
+    last: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct CopyArgs {
+    #[command(flatten)]
+    dest: DestArgs,
+
+    bundle_id: String,
+
+    /// Source, relative to data container for `pull`
+    src: PathBuf,
+
+    /// Destination, relative to data container for `push`
+    dst: PathBuf,
+}
+
+#[derive(clap::Args, Debug)]
+struct PsArgs {
+    #[command(flatten)]
+    dest: DestArgs,
+
+    /// Show processes with this substring in name only
+    filter: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct KillArgs {
+    #[command(flatten)]
+    dest: DestArgs,
+
+    pid: u32,
+}
+
+/// Device or simulator commands are sent to.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Dest {
+    Device {
+        /// CoreDevice identifier for `devicectl`
+        id: String,
+        /// Hardware udid for `log collect`
+        udid: String,
+    },
+    Simulator {
+        udid: String,
+    },
+}
+
+impl Dest {
+    /// `wanted` is id, udid or name, otherwise connected device is preferred
+    /// over booted simulator.
+    pub(crate) fn select(rows: &[Row], wanted: Option<&str>) -> Result<Self, Error> {
+        let row = match wanted {
+            Some(wanted) => rows
+                .iter()
+                .find(|r| r.id == wanted || r.udid == wanted || r.name == wanted)
+                .ok_or_else(|| Error::UnknownDestination(wanted.to_string()))?,
+            None => rows
+                .iter()
+                .find(|r| r.kind == "device" && r.is_connected())
+                .or_else(|| rows.iter().find(|r| r.is_connected()))
+                .ok_or(Error::NoDestination)?,
+        };
+        Ok(match row.kind {
+            "device" => Self::Device {
+                id: row.id.clone(),
+                udid: row.udid.clone(),
+            },
+            _ => Self::Simulator {
+                udid: row.id.clone(),
+            },
+        })
+    }
+}
+
+/// Running process on device or simulator.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Process {
+    pub(crate) pid: u32,
+    /// Executable path on devices, launchd label on simulators
+    pub(crate) name: String,
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Exec(exec::Error),
+    Io { path: PathBuf, err: io::Error },
+    NoDestination,
+    UnknownDestination(String),
+}
+
+impl From<exec::Error> for Error {
+    fn from(err: exec::Error) -> Self {
+        Self::Exec(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exec(err) => err.fmt(f),
+            Self::Io { path, err } => write!(f, "{}: {err}", path.display()),
+            Self::NoDestination => write!(
+                f,
+                "no connected device or booted simulator, use --device, DEVICE_ID or SIMULATOR_ID"
+            ),
+            Self::UnknownDestination(wanted) => write!(
+                f,
+                "device or simulator `{wanted}` is not found, see `cargo box devices`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn io_err(path: &Path) -> impl FnOnce(io::Error) -> Error + '_ {
+    |err| Error::Io {
+        path: path.to_path_buf(),
+        err,
+    }
+}
+
+pub(crate) fn run(args: Args) {
+    if let Err(err) = try_run(&mut exec::System, args) {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+fn try_run(exec: &mut impl Exec, args: Args) -> Result<(), Error> {
+    let Some(cmd) = args.cmd else {
+        return Ok(list(exec, &args.filter)?);
+    };
+    match cmd {
+        Command::Logs(args) => {
+            let dest = dest(exec, &args.dest)?;
+            logs(exec, &dest, &args)
+        }
+        Command::Push(args) => {
+            let dest = dest(exec, &args.dest)?;
+            push(exec, &dest, &args.bundle_id, &args.src, &args.dst)
+        }
+        Command::Pull(args) => {
+            let dest = dest(exec, &args.dest)?;
+            pull(exec, &dest, &args.bundle_id, &args.src, &args.dst)
+        }
+        Command::Ps(args) => {
+            let dest = dest(exec, &args.dest)?;
+            let procs = processes(exec, &dest)?;
+            let procs: Vec<_> = match &args.filter {
+                Some(filter) => procs
+                    .into_iter()
+                    .filter(|p| p.name.contains(filter))
+                    .collect(),
+                None => procs,
+            };
+            print!("{}", ps_table(&procs));
+            Ok(())
+        }
+        Command::Kill(args) => {
+            let dest = dest(exec, &args.dest)?;
+            Ok(kill(exec, &dest, args.pid)?)
+        }
+    }
+}
+
+fn rows(exec: &mut impl Exec) -> Result<Vec<Row>, exec::Error> {
+    let mut rows = device_ctl::devices(exec)?;
+    rows.extend(sim_ctl::list(exec)?.rows());
+    Ok(rows)
+}
+
+fn dest(exec: &mut impl Exec, args: &DestArgs) -> Result<Dest, Error> {
+    xcode::load_env();
+    let wanted = args
+        .device
+        .clone()
+        .or_else(|| env::var("DEVICE_ID").ok())
+        .or_else(|| env::var("SIMULATOR_ID").ok());
+    Dest::select(&rows(exec)?, wanted.as_deref())
+}
+
+pub(crate) fn list(exec: &mut impl Exec, filter: &Filter) -> Result<(), exec::Error> {
+    let mut rows = rows(exec)?;
+    rows.retain(|r| filter.matches(r));
+    if rows.is_empty() {
+        println!("no devices are found");
+    } else {
+        print!("{}", table(&rows));
+    }
+    Ok(())
+}
+
+/// Aligned columns, the last one is not padded.
+pub(crate) fn columns<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = header.map(|h| h.chars().count());
+    for row in rows {
+        for (w, c) in widths.iter_mut().zip(row) {
+            *w = (*w).max(c.chars().count());
+        }
+    }
+    let mut res = String::new();
+    let mut line = |cells: &[String; N]| {
+        let mut line = String::new();
+        for (i, (c, w)) in cells.iter().zip(widths).enumerate() {
+            if i + 1 == N {
+                line.push_str(c);
+            } else {
+                line.push_str(&format!("{c:w$}  "));
+            }
+        }
+        res.push_str(line.trim_end());
+        res.push('\n');
+    };
+    line(&header.map(str::to_string));
+    for row in rows {
+        line(row);
+    }
+    res
+}
+
+pub(crate) fn table(rows: &[Row]) -> String {
+    let rows: Vec<_> = rows
+        .iter()
+        .map(|r| {
+            [
+                r.kind.to_string(),
+                r.name.clone(),
+                r.os.clone(),
+                r.id.clone(),
+                r.state.clone(),
+            ]
+        })
+        .collect();
+    columns(["KIND", "NAME", "OS", "ID", "STATE"], &rows)
+}
+
+pub(crate) fn ps_table(procs: &[Process]) -> String {
+    let rows: Vec<_> = procs
+        .iter()
+        .map(|p| [p.pid.to_string(), p.name.clone()])
+        .collect();
+    columns(["PID", "NAME"], &rows)
+}
+
+/// `log` predicate for app's own subsystems and its process.
+pub(crate) fn log_predicate(bundle_id: &str, process: Option<&str>) -> String {
+    let process = process.unwrap_or_else(|| bundle_id.rsplit('.').next().unwrap_or(bundle_id));
+    format!(
+        "subsystem BEGINSWITH {} OR process == {}",
+        quoted(bundle_id),
+        quoted(process)
+    )
+}
+
+/// NSPredicate string literal.
+fn quoted(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res.push('"');
+    res
+}
+
+fn logs(exec: &mut impl Exec, dest: &Dest, args: &LogsArgs) -> Result<(), Error> {
+    let predicate = log_predicate(&args.bundle_id, args.process.as_deref());
+    match dest {
+        Dest::Simulator { udid } => {
+            let cmd = sim_ctl::log_cmd(udid, &predicate, args.last.as_deref());
+            exec.status(&cmd)
+                .map_err(|err| exec::Error::spawn(&cmd, err))?;
+        }
+        Dest::Device { udid, .. } => {
+            // `log stream` can't attach to devices, so archive is collected and shown
+            let archive = env::temp_dir().join(format!("box-{}.logarchive", process::id()));
+            let last = args.last.as_deref().unwrap_or("5m");
+            let res = exec
+                .run(&log_collect_cmd(udid, last, &archive))
+                .and_then(|_| exec.run(&log_show_cmd(&predicate, &archive)));
+            _ = fs::remove_dir_all(&archive);
+            res?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn log_collect_cmd(udid: &str, last: &str, archive: &Path) -> Cmd {
+    Cmd::new("log")
+        .args(["collect", "--device-udid", udid, "--last", last, "--output"])
+        .arg(archive)
+}
+
+pub(crate) fn log_show_cmd(predicate: &str, archive: &Path) -> Cmd {
+    Cmd::new("log")
+        .args([
+            "show",
+            "--style",
+            "compact",
+            "--info",
+            "--debug",
+            "--predicate",
+            predicate,
+        ])
+        .arg(archive)
+}
+
+fn push(
+    exec: &mut impl Exec,
+    dest: &Dest,
+    bundle_id: &str,
+    local: &Path,
+    remote: &Path,
+) -> Result<(), Error> {
+    match dest {
+        Dest::Device { id, .. } => Ok(device_ctl::copy_to(exec, id, bundle_id, local, remote)?),
+        Dest::Simulator { udid } => {
+            // simulator containers are plain dirs on this mac
+            let dst = sim_ctl::app_container(exec, udid, bundle_id)?.join(remote);
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent).map_err(io_err(parent))?;
+            }
+            fs::copy(local, &dst).map_err(io_err(local))?;
+            Ok(())
+        }
+    }
+}
+
+fn pull(
+    exec: &mut impl Exec,
+    dest: &Dest,
+    bundle_id: &str,
+    remote: &Path,
+    local: &Path,
+) -> Result<(), Error> {
+    match dest {
+        Dest::Device { id, .. } => Ok(device_ctl::copy_from(exec, id, bundle_id, remote, local)?),
+        Dest::Simulator { udid } => {
+            let src = sim_ctl::app_container(exec, udid, bundle_id)?.join(remote);
+            fs::copy(&src, local).map_err(io_err(&src))?;
+            Ok(())
+        }
+    }
+}
+
+fn processes(exec: &mut impl Exec, dest: &Dest) -> Result<Vec<Process>, exec::Error> {
+    match dest {
+        Dest::Device { id, .. } => device_ctl::processes(exec, id),
+        Dest::Simulator { udid } => sim_ctl::processes(exec, udid),
+    }
+}
+
+fn kill(exec: &mut impl Exec, dest: &Dest, pid: u32) -> Result<(), exec::Error> {
+    match dest {
+        Dest::Device { id, .. } => device_ctl::terminate(exec, id, pid),
+        // simulator processes are processes of this mac
+        Dest::Simulator { .. } => exec.run(&Cmd::new("kill").arg(pid.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io, path::Path};
+
+    use super::{Dest, Error, Filter, Process, Row, log_predicate, ps_table, pull, push, table};
+    use crate::{
+        device_ctl,
+        exec::{Cmd, Exit, Output},
+        sim_ctl,
+    };
+
+    fn rows() -> Vec<Row> {
+        let mut rows =
+            device_ctl::rows(include_str!("../fixtures/devicectl/list-devices.json")).unwrap();
+        rows.extend(
+            sim_ctl::List::from_json(include_str!("../fixtures/simctl/list.json"))
+                .unwrap()
+                .rows(),
+        );
+        rows
+    }
+
+    #[test]
+    fn fixtures() {
+        assert_eq!(
+            table(&rows()),
+            "\
+KIND       NAME                   OS            ID                                    STATE
+device     Test iPhone            iOS 17.5.1    6A1B2C3D-4E5F-4A6B-8C7D-9E0F1A2B3C4D  connected
+device     Test iPad              iOS           B7C8D9E0-F1A2-4B3C-9D4E-5F6A7B8C9D0E  unavailable
+simulator  iPhone 15 Pro          iOS 17.5      5B3C9E0A-1F2D-4C8B-9A7E-3D6F1B2C4E5A  booted
+simulator  iPhone 16 Pro          iOS 18.0      A1E2C3D4-B5F6-4789-8ABC-DEF012345678  shutdown
+simulator  iPad Pro 11-inch (M4)  iOS 18.0      0F1E2D3C-4B5A-4968-8776-655443322110  shutdown
+simulator  Apple Vision Pro       visionOS 2.0  D4C3B2A1-0F9E-48D7-B6C5-A4B3C2D1E0F9  shutdown
+"
+        );
+    }
+
+    #[test]
+    fn filter() {
+        let names = |filter: Filter| -> Vec<String> {
+            rows()
+                .into_iter()
+                .filter(|r| filter.matches(r))
+                .map(|r| r.name)
+                .collect()
+        };
+        assert_eq!(
+            names(Filter {
+                platform: Some("ios".to_string()),
+                connected: true,
+            }),
+            ["Test iPhone", "iPhone 15 Pro"]
+        );
+        assert_eq!(
+            names(Filter {
+                platform: Some("visionOS".to_string()),
+                connected: false,
+            }),
+            ["Apple Vision Pro"]
+        );
+        assert!(
+            names(Filter {
+                platform: Some("tvos".to_string()),
+                connected: false,
+            })
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn select() {
+        let rows = rows();
+        assert_eq!(
+            Dest::select(&rows, None).unwrap(),
+            Dest::Device {
+                id: "6A1B2C3D-4E5F-4A6B-8C7D-9E0F1A2B3C4D".to_string(),
+                udid: "00008110-001A2B3C4D5E801E".to_string(),
+            }
+        );
+        assert_eq!(
+            Dest::select(&rows, Some("00008027-000C11223344002E")).unwrap(),
+            Dest::Device {
+                id: "B7C8D9E0-F1A2-4B3C-9D4E-5F6A7B8C9D0E".to_string(),
+                udid: "00008027-000C11223344002E".to_string(),
+            }
+        );
+        assert_eq!(
+            Dest::select(&rows, Some("Apple Vision Pro")).unwrap(),
+            Dest::Simulator {
+                udid: "D4C3B2A1-0F9E-48D7-B6C5-A4B3C2D1E0F9".to_string()
+            }
+        );
+        assert!(matches!(
+            Dest::select(&rows, Some("iPhone 3G")),
+            Err(Error::UnknownDestination(_))
+        ));
+
+        // booted simulator when no device is connected
+        let sims: Vec<_> = rows.into_iter().filter(|r| r.kind != "device").collect();
+        assert_eq!(
+            Dest::select(&sims, None).unwrap(),
+            Dest::Simulator {
+                udid: "5B3C9E0A-1F2D-4C8B-9A7E-3D6F1B2C4E5A".to_string()
+            }
+        );
+        assert!(matches!(
+            Dest::select(&sims[1..], None),
+            Err(Error::NoDestination)
+        ));
+    }
+
+    #[test]
+    fn logs() {
+        assert_eq!(
+            log_predicate("org.cidre.sc-record", None),
+            r#"subsystem BEGINSWITH "org.cidre.sc-record" OR process == "sc-record""#
+        );
+        assert_eq!(
+            log_predicate("org.cidre.box", Some(r#"my "box"\1"#)),
+            r#"subsystem BEGINSWITH "org.cidre.box" OR process == "my \"box\"\\1""#
+        );
+        assert_eq!(
+            super::log_collect_cmd("UDID", "5m", Path::new("/tmp/box.logarchive")).to_string(),
+            "log collect --device-udid UDID --last 5m --output /tmp/box.logarchive"
+        );
+        assert_eq!(
+            super::log_show_cmd("process == \"box\"", Path::new("/tmp/box.logarchive")).to_string(),
+            "log show --style compact --info --debug --predicate process == \"box\" /tmp/box.logarchive"
+        );
+    }
+
+    #[test]
+    fn simulator_files() {
+        let container = std::env::temp_dir().join(format!("box-container-{}", std::process::id()));
+        let local = container.with_extension("wav");
+        fs::write(&local, "in").unwrap();
+
+        let mut calls = Vec::new();
+        let mut exec = |cmd: &Cmd| {
+            calls.push(cmd.to_string());
+            io::Result::Ok(Output {
+                exit: Exit::Code(0),
+                stdout: format!("{}\n", container.display()),
+            })
+        };
+        let dest = Dest::Simulator {
+            udid: "UDID".to_string(),
+        };
+        let remote = Path::new("Documents/in.wav");
+        push(&mut exec, &dest, "org.cidre.box", &local, remote).unwrap();
+        assert_eq!(fs::read_to_string(container.join(remote)).unwrap(), "in");
+
+        fs::write(container.join(remote), "out").unwrap();
+        pull(&mut exec, &dest, "org.cidre.box", remote, &local).unwrap();
+        assert_eq!(fs::read_to_string(&local).unwrap(), "out");
+
+        let err = pull(
+            &mut exec,
+            &dest,
+            "org.cidre.box",
+            Path::new("missing"),
+            &local,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::Io { .. }));
+
+        _ = fs::remove_dir_all(&container);
+        _ = fs::remove_file(&local);
+        assert_eq!(
+            calls,
+            ["xcrun simctl get_app_container UDID org.cidre.box data"; 3]
+        );
+    }
+
+    #[test]
+    fn processes() {
+        let procs = [
+            Process {
+                pid: 1,
+                name: "/sbin/launchd".to_string(),
+            },
+            Process {
+                pid: 4242,
+                name: "/private/var/containers/Bundle/Application/1/box.app/box".to_string(),
+            },
+        ];
+        assert_eq!(
+            ps_table(&procs),
+            "\
+PID   NAME
+1     /sbin/launchd
+4242  /private/var/containers/Bundle/Application/1/box.app/box
+"
+        );
+
+        let mut calls = Vec::new();
+        let mut exec = |cmd: &Cmd| {
+            calls.push(cmd.to_string());
+            io::Result::Ok(Output::from(Exit::Code(0)))
+        };
+        let dest = Dest::Simulator {
+            udid: "UDID".to_string(),
+        };
+        super::kill(&mut exec, &dest, 4242).unwrap();
+        assert_eq!(calls, ["kill 4242"]);
+    }
+}
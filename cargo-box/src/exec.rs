@@ -0,0 +1,287 @@
+//! Child processes.
+//!
+//! Runner spawns tools and products only through [`Exec`], so exit codes, signals and
+//! tool failures are tested with fake backends on any host.
+
+use std::{
+    ffi::{OsStr, OsString},
+    fmt, io, process,
+};
+
+/// Program with arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cmd {
+    pub(crate) program: OsString,
+    pub(crate) args: Vec<OsString>,
+}
+
+impl Cmd {
+    pub(crate) fn new(program: impl AsRef<OsStr>) -> Self {
+        Self {
+            program: program.as_ref().to_os_string(),
+            args: Vec::new(),
+        }
+    }
+
+    pub(crate) fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.args.push(arg.as_ref().to_os_string());
+        self
+    }
+
+    pub(crate) fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|a| a.as_ref().to_os_string()));
+        self
+    }
+}
+
+impl fmt::Display for Cmd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.program.to_string_lossy())?;
+        for arg in &self.args {
+            write!(f, " {}", arg.to_string_lossy())?;
+        }
+        Ok(())
+    }
+}
+
+/// How a process finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Exit {
+    Code(i32),
+    Signal(i32),
+}
+
+impl Exit {
+    pub(crate) fn success(self) -> bool {
+        self == Self::Code(0)
+    }
+
+    /// Exit code to report to cargo, signals are `128 + signal` as in shells.
+    pub(crate) fn code(self) -> i32 {
+        match self {
+            Self::Code(code) => code,
+            Self::Signal(signal) => 128 + signal,
+        }
+    }
+}
+
+impl fmt::Display for Exit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Code(code) => write!(f, "exit code {code}"),
+            Self::Signal(signal) => write!(f, "signal {signal}"),
+        }
+    }
+}
+
+impl From<process::ExitStatus> for Exit {
+    fn from(status: process::ExitStatus) -> Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = status.signal() {
+                return Self::Signal(signal);
+            }
+        }
+        Self::Code(status.code().unwrap_or(1))
+    }
+}
+
+/// Exit with captured stdout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Output {
+    pub(crate) exit: Exit,
+    pub(crate) stdout: String,
+}
+
+impl From<Exit> for Output {
+    fn from(exit: Exit) -> Self {
+        Self {
+            exit,
+            stdout: String::new(),
+        }
+    }
+}
+
+pub(crate) trait Exec {
+    /// Runs `cmd` to completion with inherited stdin, stdout and stderr.
+    fn status(&mut self, cmd: &Cmd) -> io::Result<Exit>;
+
+    /// Runs `cmd` to completion capturing stdout, stderr is inherited.
+    fn output(&mut self, cmd: &Cmd) -> io::Result<Output>;
+
+    /// Runs tool and returns its stdout, anything but zero exit code is an error.
+    fn read(&mut self, cmd: &Cmd) -> Result<String, Error> {
+        match self.output(cmd) {
+            Ok(output) if output.exit.success() => Ok(output.stdout),
+            Ok(output) => Err(Error::Failed {
+                cmd: cmd.to_string(),
+                exit: output.exit,
+                msg: None,
+            }),
+            Err(err) => Err(Error::spawn(cmd, err)),
+        }
+    }
+
+    /// Runs tool, anything but zero exit code is an error.
+    fn run(&mut self, cmd: &Cmd) -> Result<(), Error> {
+        match self.status(cmd) {
+            Ok(exit) if exit.success() => Ok(()),
+            Ok(exit) => Err(Error::Failed {
+                cmd: cmd.to_string(),
+                exit,
+                msg: None,
+            }),
+            Err(err) => Err(Error::spawn(cmd, err)),
+        }
+    }
+}
+
+/// Real processes. Output is not captured, so libtest output appears live.
+#[derive(Debug, Default)]
+pub(crate) struct System;
+
+impl Exec for System {
+    fn status(&mut self, cmd: &Cmd) -> io::Result<Exit> {
+        process::Command::new(&cmd.program)
+            .args(&cmd.args)
+            .status()
+            .map(Exit::from)
+    }
+
+    fn output(&mut self, cmd: &Cmd) -> io::Result<Output> {
+        let output = process::Command::new(&cmd.program)
+            .args(&cmd.args)
+            .stderr(process::Stdio::inherit())
+            .output()?;
+        Ok(Output {
+            exit: output.status.into(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        })
+    }
+}
+
+/// Fake backends in tests
+impl<F: FnMut(&Cmd) -> io::Result<Output>> Exec for F {
+    fn status(&mut self, cmd: &Cmd) -> io::Result<Exit> {
+        self(cmd).map(|output| output.exit)
+    }
+
+    fn output(&mut self, cmd: &Cmd) -> io::Result<Output> {
+        self(cmd)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Spawn {
+        cmd: String,
+        err: io::Error,
+    },
+    Failed {
+        cmd: String,
+        exit: Exit,
+        /// Reason reported by tool
+        msg: Option<String>,
+    },
+    /// Tool succeeded, but its output is unreadable
+    Output {
+        cmd: String,
+        msg: String,
+    },
+}
+
+impl Error {
+    pub(crate) fn spawn(cmd: &Cmd, err: io::Error) -> Self {
+        Self::Spawn {
+            cmd: cmd.to_string(),
+            err,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn { cmd, err } => write!(f, "can't run `{cmd}`: {err}"),
+            Self::Failed { cmd, exit, msg } => {
+                write!(f, "`{cmd}` failed with {exit}")?;
+                match msg {
+                    Some(msg) => write!(f, ": {msg}"),
+                    None => Ok(()),
+                }
+            }
+            Self::Output { cmd, msg } => write!(f, "unexpected output of `{cmd}`: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::{Cmd, Error, Exec, Exit, Output};
+
+    #[test]
+    fn exit_codes() {
+        assert!(Exit::Code(0).success());
+        assert!(!Exit::Signal(0).success());
+        assert_eq!(Exit::Code(101).code(), 101);
+        // SIGABRT
+        assert_eq!(Exit::Signal(6).code(), 134);
+    }
+
+    #[test]
+    fn run() {
+        let cmd = Cmd::new("xcodebuild").args(["-quiet", "-scheme", "box"]);
+        let mut ok = |_: &Cmd| Ok(Exit::Code(0).into());
+        assert!(ok.run(&cmd).is_ok());
+
+        let mut failed = |_: &Cmd| Ok(Exit::Code(65).into());
+        let err = failed.run(&cmd).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`xcodebuild -quiet -scheme box` failed with exit code 65"
+        );
+
+        let mut missing = |_: &Cmd| Err(io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(missing.run(&cmd), Err(Error::Spawn { .. })));
+
+        let mut list = |_: &Cmd| {
+            Ok(Output {
+                exit: Exit::Code(0),
+                stdout: "{}".to_string(),
+            })
+        };
+        assert_eq!(list.read(&cmd).unwrap(), "{}");
+        assert!(failed.read(&cmd).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn system() {
+        assert_eq!(
+            super::System
+                .status(&Cmd::new("sh").args(["-c", "exit 3"]))
+                .unwrap(),
+            Exit::Code(3)
+        );
+        assert_eq!(
+            super::System
+                .status(&Cmd::new("sh").args(["-c", "kill -9 $$"]))
+                .unwrap(),
+            Exit::Signal(9)
+        );
+        let output = super::System
+            .output(&Cmd::new("sh").args(["-c", "echo ok"]))
+            .unwrap();
+        assert_eq!(output.stdout, "ok\n");
+    }
+}
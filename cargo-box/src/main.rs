@@ -14,13 +14,26 @@ enum Cmd {
     #[command()]
     Runner(runner::Args),
 
-    /// List dev teams on this mac
+    /// List dev teams, signing identities and provisioning profiles on this mac
     #[command()]
-    Teams,
+    Teams(signing::TeamsArgs),
 
-    /// List connected devices on this mac
+    /// List connected devices and available simulators on this mac,
+    /// stream logs, copy app files and control processes on them
     #[command()]
-    Devices,
+    Devices(devices::Args),
+
+    /// Check that team, identity, bundle id and entitlements can be signed
+    #[command()]
+    Doctor(signing::DoctorArgs),
+
+    /// Check that cidre APIs newer than deployment target are used under `api::version!`
+    #[command()]
+    Availability(availability::Args),
+
+    /// Build bin or example with its extensions, bundle and run it
+    #[command()]
+    Run(runner::RunArgs),
 
     /// Create configured xcode project for binary, test or example
     /// in target/boxes for runner.
@@ -28,6 +41,15 @@ enum Cmd {
     Proj(xcode::ProjArgs),
 }
 
+mod availability;
+mod bundle;
+mod devices;
+mod exec;
+mod meta;
+mod signing;
+mod sim_ctl;
+mod target;
+
 fn main() {
     // Handle different args from different calls:
     //
@@ -40,234 +62,627 @@ fn main() {
 
     // special case for runner to skip Cli::parse_from
     if args.get(1).map(|v| v == "runner") == Some(true) {
-        return runner::run(runner::Args { args });
+        return runner::run(runner::Args {
+            args,
+            terminate_existing: false,
+        });
     }
 
     match Cli::parse_from(args).cmd {
-        Cmd::Teams => teams::list(),
-        Cmd::Devices => device_ctl::list_devices(),
-        Cmd::Proj(args) => _ = xcode::proj(args),
-        _ => panic!("unknown command"),
+        Cmd::Teams(args) => signing::teams(args, &signing::identities(teams::certificates())),
+        Cmd::Doctor(args) => signing::doctor(args, &signing::identities(teams::certificates())),
+        Cmd::Devices(args) => devices::run(args),
+        Cmd::Availability(args) => availability::run(args),
+        Cmd::Run(args) => runner::run_product(args),
+        Cmd::Proj(args) => {
+            if let Err(err) = xcode::proj(args) {
+                eprintln!("error: {err}");
+                std::process::exit(1);
+            }
+        }
+        // handled before parsing
+        Cmd::Runner(_) => unreachable!(),
     }
 }
 
 mod runner {
     use std::{
-        env,
+        env, fmt, io,
         path::{Path, PathBuf},
         process,
     };
 
     use clap::Parser;
 
-    use crate::{device_ctl, xcode};
+    use crate::{
+        bundle, device_ctl,
+        exec::{self, Cmd, Exec, Exit},
+        sim_ctl, target, xcode,
+    };
 
     #[derive(Parser, Debug)]
     pub(crate) struct Args {
         /// Arguments for the runner
         #[arg()]
         pub(crate) args: Vec<String>,
+        /// `cargo box run --terminate-existing`
+        #[arg(skip)]
+        pub(crate) terminate_existing: bool,
     }
 
-    pub(crate) fn run(args: Args) {
-        assert!(args.args.len() > 2);
-        let binary = &args.args[2];
-        let path = Path::new(binary);
+    #[derive(Debug)]
+    pub(crate) enum Error {
+        Target(target::Error),
+        Exec(exec::Error),
+        Bundle(bundle::Error),
+        /// Invalid `[package.metadata.box]`, `.box` env or arguments
+        Config(String),
+        /// No `Cargo.toml` in cwd or next to `target/`
+        NoManifest,
+        Io {
+            path: PathBuf,
+            err: io::Error,
+        },
+        /// Device build of product or extension without provisioning profile
+        NoProfile(String),
+        NoDevice,
+        NoSimulator {
+            sdk: target::Sdk,
+            /// `SIMULATOR_ID`
+            wanted: Option<String>,
+        },
+    }
 
-        let err = "can't parse filename";
+    impl Error {
+        pub(crate) fn io(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+            let path = path.into();
+            move |err| Self::Io { path, err }
+        }
+    }
 
-        let Some(name) = path.file_name() else {
-            panic!("{err}");
-        };
+    impl From<target::Error> for Error {
+        fn from(err: target::Error) -> Self {
+            Self::Target(err)
+        }
+    }
 
-        let Some(name) = name.to_str() else {
-            panic!("{err}");
-        };
+    impl From<exec::Error> for Error {
+        fn from(err: exec::Error) -> Self {
+            Self::Exec(err)
+        }
+    }
 
-        let mut name = name.to_string();
+    impl From<bundle::Error> for Error {
+        fn from(err: bundle::Error) -> Self {
+            Self::Bundle(err)
+        }
+    }
 
-        let Some(parent) = path.parent() else {
-            panic!("{err}");
-        };
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Target(err) => err.fmt(f),
+                Self::Exec(err) => err.fmt(f),
+                Self::Bundle(err) => err.fmt(f),
+                Self::Config(err) => f.write_str(err),
+                Self::NoManifest => f.write_str("can't find Cargo.toml, run in workspace root"),
+                Self::Io { path, err } => write!(f, "{}: {err}", path.display()),
+                Self::NoProfile(name) => write!(
+                    f,
+                    "provisioning profile of `{name}` is required for device builds.
+Set BOX_PROVISIONING_PROFILE env or provisioning-profile in [package.metadata.box]
+or use BOX_BACKEND=xcode for automatic signing"
+                ),
+                Self::NoDevice => f.write_str(
+                    "DEVICE_ID env is required to run on device. Use `cargo box devices` to find it",
+                ),
+                Self::NoSimulator { sdk, wanted: None } => write!(
+                    f,
+                    "no available {} simulator. Install runtime in Xcode > Settings > Components",
+                    sdk.name()
+                ),
+                Self::NoSimulator {
+                    sdk,
+                    wanted: Some(id),
+                } => write!(
+                    f,
+                    "no available {} simulator `{id}`. Use `cargo box devices` to list simulators",
+                    sdk.name()
+                ),
+            }
+        }
+    }
 
-        let Some(folder) = parent.file_name() else {
-            panic!("{err}");
-        };
+    #[derive(clap::Args, Debug)]
+    pub(crate) struct RunArgs {
+        #[arg(long)]
+        pub(crate) bin: Option<String>,
+        #[arg(long)]
+        pub(crate) example: Option<String>,
+        #[arg(long, short)]
+        pub(crate) package: Option<String>,
+        /// Target triple, host by default
+        #[arg(long)]
+        pub(crate) target: Option<String>,
+        #[arg(long, conflicts_with = "profile")]
+        pub(crate) release: bool,
+        #[arg(long)]
+        pub(crate) profile: Option<String>,
+        /// Terminate running app on device before launch, `BOX_TERMINATE_EXISTING=1` for runner
+        #[arg(long)]
+        pub(crate) terminate_existing: bool,
+        /// Arguments for the product
+        #[arg(last = true)]
+        pub(crate) args: Vec<String>,
+    }
 
-        let Some(folder) = folder.to_str() else {
-            panic!("{err}");
-        };
-        let mut is_example = false;
-        let mut is_dep = false;
-        let mut is_binary = false;
-        let parent = match folder {
-            "examples" => {
-                // target/[optional-tripple/]profile|/examples/example
-                is_example = true;
-                parent.parent()
-            }
-            "deps" => {
-                // [fullpath/]target/[optional-tripple/]profile|/deps/test-or-bench
-                is_dep = true;
-                parent.parent()
+    /// Exits with product's exit code, so failed tests fail `cargo test`.
+    pub(crate) fn run(args: Args) {
+        exit(try_run(&mut exec::System, &args));
+    }
+
+    /// `cargo box run`, builds product and runs it as cargo runner would.
+    pub(crate) fn run_product(args: RunArgs) {
+        exit(try_run_product(&mut exec::System, args));
+    }
+
+    fn exit(res: Result<Exit, Error>) -> ! {
+        match res {
+            Ok(exit) => {
+                if let Exit::Signal(_) = exit {
+                    eprintln!("error: product terminated by {exit}");
+                }
+                process::exit(exit.code());
             }
-            "debug" => {
-                // target/[optional-tripple/]profile|/binary
-                // binary
-                is_binary = true;
-                Some(parent)
+            Err(err) => {
+                eprintln!("error: {err}");
+                process::exit(1);
             }
-            "release" => {
-                // target/[optional-tripple/]profile|/binary
-                // binary
-                is_binary = true;
-                Some(parent)
+        }
+    }
+
+    fn try_run_product(exec: &mut impl Exec, args: RunArgs) -> Result<Exit, Error> {
+        let binary = build_product(exec, &args, &target::Workspace::from_cargo()?)?;
+        let mut runner_args = vec![
+            "cargo-box".to_string(),
+            "runner".to_string(),
+            binary.display().to_string(),
+        ];
+        runner_args.extend(args.args);
+        try_run(
+            exec,
+            &Args {
+                args: runner_args,
+                terminate_existing: args.terminate_existing,
+            },
+        )
+    }
+
+    /// Builds product with `cargo build`, returns path to binary.
+    fn build_product(
+        exec: &mut impl Exec,
+        args: &RunArgs,
+        ws: &target::Workspace,
+    ) -> Result<PathBuf, Error> {
+        let (kind, flag, name) = match (&args.bin, &args.example) {
+            (Some(bin), None) => (target::Kind::Bin, "--bin", bin),
+            (None, Some(example)) => (target::Kind::Example, "--example", example),
+            _ => {
+                return Err(Error::Config(
+                    "exactly one of --bin or --example is required".to_string(),
+                ));
             }
-            _ => panic!("{err}"),
         };
-
-        let Some(parent) = parent else {
-            panic!("{err}");
+        let profile = match (&args.profile, args.release) {
+            (Some(profile), _) => profile.as_str(),
+            (None, true) => "release",
+            (None, false) => "dev",
         };
-        let Some(folder) = parent.file_name() else {
-            panic!("{err}");
+        let sdk = match &args.target {
+            Some(triple) => target::Sdk::from_triple(triple)
+                .ok_or_else(|| target::Error::UnsupportedTriple(triple.clone()))?,
+            None => target::Sdk::MacOsx,
         };
-        let Some(folder) = folder.to_str() else {
-            panic!("{err}");
+        let target = target::Target {
+            triple: args.target.clone(),
+            sdk,
+            profile: target::profile_dir(profile).to_string(),
+            config: target::Config::Debug,
+            kind,
+            name: name.clone(),
         };
+        let mut cmd = Cmd::new(target::cargo()).args(["build", flag, name]);
+        if let Some(package) = &args.package {
+            cmd = cmd.args(["--package", package]);
+        }
+        // `profile` is passed as is, `test` and `bench` are not the same as `dev` and `release`
+        cmd = cmd.args(["--profile", profile]);
+        if let Some(triple) = &args.target {
+            cmd = cmd.args(["--target", triple]);
+        }
+        exec.run(&cmd)?;
+        Ok(target.artifact(&ws.target_dir, kind, name, false))
+    }
 
-        let config = match folder {
-            "debug" => "Debug",
-            "release" => "Release",
-            _ => panic!("{err}"),
-        };
+    fn try_run(exec: &mut impl Exec, args: &Args) -> Result<Exit, Error> {
+        let binary = args.args.get(2).ok_or(target::Error::MissingBinary)?;
+        // cargo passes paths relative to cwd, we chdir to workspace root below
+        let binary = env::current_dir().map_err(Error::io("."))?.join(binary);
 
-        let Some(mut parent) = parent.parent() else {
-            panic!("{err}");
-        };
-        let Some(folder) = parent.file_name() else {
-            panic!("{err}");
-        };
+        let ws = target::Workspace::from_cargo()?;
+        // profiles are defined in workspace root manifest only
+        let man = cargo_toml::Manifest::from_path(ws.root.join("Cargo.toml")).ok();
+        let target = target::resolve(&binary, &ws.target_dir, man.as_ref().map(|m| &m.profile))?;
+        let name = &target.name;
+        let sdk = target.sdk;
+        let config = target.config;
 
-        let Some(folder) = folder.to_str() else {
-            panic!("{err}");
-        };
+        env::set_current_dir(&ws.root).map_err(Error::io(&ws.root))?;
 
-        let sdk = match folder {
-            "target" | "aarch64-apple-darwin" | "x86_64-apple-darwin" => "macos",
-            "aarch64-apple-ios" => "iphoneos",
-            "x86_64-apple-ios" | "aarch64-apple-ios-sim" => "iphonesimulator",
-            "aarch64-apple-tvos" => "appletvos",
-            "aarch64-apple-tvos-sim" => "appletvsimulator",
-            "arm64_32-apple-watchos" => "watchos",
-            "aarch64-apple-watchos" => "watchos",
-            "aarch64-apple-watchos-sim" => "watchsimulator",
-            "aarch64-apple-visionos" => "xros",
-            "aarch64-apple-visionos-sim" => "xrsimulator",
-            _ => panic!("{err}"),
+        let mut proj_args = xcode::ProjArgs {
+            target_dir: Some(ws.target_dir.clone()),
+            ..Default::default()
         };
 
-        let platform = match sdk {
-            "macos" => "macOS",
-            "iphoneos" => "iOS",
-            "tvos" => "tvOS",
-            "watchos" => "watchOS",
-            "xros" => "visionOS",
-            "iphoneosimulator" => "iOS Simulator",
-            "appletvsimulator" => "tvOS Simulator",
-            "watchsimulator" => "watchOS Simulator",
-            "xrsimulator" => "visionOS Simulator",
-            x => panic!("unknown platform {x}"),
-        };
-
-        if folder != "target" {
-            parent = parent.parent().unwrap();
+        let mut project = ws.target_dir.join("boxes");
+        match target.kind {
+            target::Kind::Bin => proj_args.bin = Some(name.clone()),
+            target::Kind::Example => {
+                proj_args.example = Some(name.clone());
+                project.push("examples");
+            }
+            target::Kind::Dep => {
+                proj_args.dep = Some(name.clone());
+                project.push("deps");
+            }
         }
 
-        let Some(folder) = parent.file_name() else {
-            panic!("{err}");
+        let mut build = project.clone();
+        build.push(format!("build-{name}"));
+
+        // the same place xcodebuild puts products to
+        let mut app = build.clone();
+        app.push("Build");
+        app.push("Products");
+        app.push(sdk.products_dir(config));
+        app.push(format!("{name}.app"));
+
+        let configured = xcode::configured_proj(&proj_args)?;
+        let bundle_id = if configured.is_some()
+            || bundle::Backend::from_env()? == bundle::Backend::Xcode
+        {
+            if configured.is_none() && !xcode::product(&proj_args)?.cfg.extensions.is_empty() {
+                return Err(Error::Config(
+                    "extensions are bundled by direct backend only, unset BOX_BACKEND".to_string(),
+                ));
+            }
+            let xcode_proj = match configured {
+                Some(proj) => proj,
+                None => xcode::proj(proj_args)?,
+            };
+
+            project.push(name);
+            project.push(name);
+
+            if xcode_proj.replace_binary {
+                // TODO: if is_binary, try replace with BOX_BIN_PATH instead of copy
+                std::fs::copy(&binary, &project).map_err(Error::io(&project))?;
+            }
+
+            xcode::build(
+                exec,
+                &xcode_proj,
+                sdk.destination(),
+                config.as_str(),
+                &build,
+            )?;
+            xcode_proj.bundle_id
+        } else {
+            bundle_app(exec, &proj_args, &target, &ws.target_dir, &binary, &app)?
         };
 
-        if folder == "target" {
-            parent = parent.parent().unwrap();
-        }
+        let mut dest = Dest::from_env();
+        dest.terminate_existing |= args.terminate_existing;
+        launch(exec, sdk, &app, name, &bundle_id, &dest, &args.args[3..])
+    }
 
-        if !parent.as_os_str().is_empty() {
-            env::set_current_dir(parent).unwrap();
-        }
+    /// Where to run non mac products.
+    #[derive(Debug, Default)]
+    struct Dest {
+        /// `DEVICE_ID`, see `cargo box devices`
+        device_id: Option<String>,
+        /// `SIMULATOR_ID`, udid or name of simulator
+        simulator_id: Option<String>,
+        /// `BOX_TERMINATE_EXISTING`, kill running app on device before launch
+        terminate_existing: bool,
+    }
 
-        if is_dep {
-            // /Users/yury/Projects/cidre/target/aarch64-apple-ios/release/deps/uuid-3968b503a26aa57f
-            if let Some((name_part, _hash)) = name.rsplit_once('-') {
-                name = name_part.to_string();
+    impl Dest {
+        fn from_env() -> Self {
+            Self {
+                device_id: env::var("DEVICE_ID").ok(),
+                simulator_id: env::var("SIMULATOR_ID").ok(),
+                terminate_existing: env::var("BOX_TERMINATE_EXISTING")
+                    .is_ok_and(|v| v == "1" || v == "true"),
             }
         }
+    }
+
+    /// Runs bundled product with test filters and flags cargo passed us.
+    fn launch(
+        exec: &mut impl Exec,
+        sdk: target::Sdk,
+        app: &Path,
+        name: &str,
+        bundle_id: &str,
+        dest: &Dest,
+        args: &[String],
+    ) -> Result<Exit, Error> {
+        if sdk.is_mac() {
+            let cmd = Cmd::new(app.join("Contents/MacOS").join(name)).args(args);
+            return exec
+                .status(&cmd)
+                .map_err(|err| exec::Error::spawn(&cmd, err).into());
+        }
+        if sdk.is_simulator() {
+            let list = sim_ctl::list(exec)?;
+            let wanted = dest.simulator_id.as_deref();
+            let Some(sim) = list.select(sdk, wanted) else {
+                return Err(Error::NoSimulator {
+                    sdk,
+                    wanted: wanted.map(str::to_string),
+                });
+            };
+            return Ok(sim_ctl::run_app(exec, sim, app, bundle_id, args)?);
+        }
+        let device_id = dest.device_id.as_deref().ok_or(Error::NoDevice)?;
+        device_ctl::install_app(exec, device_id, app)?;
+        Ok(device_ctl::run_app(
+            exec,
+            device_id,
+            bundle_id,
+            dest.terminate_existing,
+            args,
+        )?)
+    }
 
-        let mut proj_args = xcode::ProjArgs::default();
+    /// Assembles and signs `app` without xcode, returns bundle id.
+    fn bundle_app(
+        exec: &mut impl Exec,
+        proj_args: &xcode::ProjArgs,
+        target: &target::Target,
+        target_dir: &Path,
+        binary: &Path,
+        app: &Path,
+    ) -> Result<String, Error> {
+        let sdk = target.sdk;
+        let product = xcode::product(proj_args)?;
+        let bundle_id = product.bundle_id();
+        let version = product
+            .cfg
+            .version
+            .as_deref()
+            .or(product.version.as_deref())
+            .unwrap_or("1.0.0");
+        let identity =
+            env::var("BOX_CODESIGN_IDENTITY").unwrap_or_else(|_| "Apple Development".into());
+        let needs_profile = !sdk.is_mac() && !sdk.is_simulator();
+        let profile = product
+            .cfg
+            .provisioning_profile
+            .clone()
+            .or_else(|| env::var("BOX_PROVISIONING_PROFILE").ok())
+            .map(|p| product.root.join(p));
+        if profile.is_none() && needs_profile {
+            return Err(Error::NoProfile(product.name.clone()));
+        }
 
-        if is_example {
-            proj_args.example = Some(name.clone());
-        } else if is_binary {
-            proj_args.bin = Some(name.clone());
-        } else if is_dep {
-            proj_args.dep = Some(name.clone());
+        let mut built = Vec::with_capacity(product.cfg.extensions.len());
+        for (ext_name, ext) in &product.cfg.extensions {
+            let (is_example, product_name) = ext.product().map_err(Error::Config)?;
+            let (kind, flag) = if is_example {
+                (target::Kind::Example, "--example")
+            } else {
+                (target::Kind::Bin, "--bin")
+            };
+            let mut cmd = Cmd::new(target::cargo()).args(["build", flag, product_name]);
+            if let Some(package) = &product.package {
+                cmd = cmd.args(["--package", package]);
+            }
+            exec.run(&cmd.args(target.cargo_args()))?;
+
+            let cdylib = is_example && product.cdylib_examples.iter().any(|e| e == product_name);
+            let profile = ext
+                .provisioning_profile
+                .as_ref()
+                .map(|p| product.root.join(p));
+            if profile.is_none() && needs_profile {
+                return Err(Error::NoProfile(ext_name.clone()));
+            }
+            built.push(bundle::Appex {
+                name: ext_name,
+                bundle_id: ext.bundle_id(&bundle_id, ext_name),
+                binary: target.artifact(target_dir, kind, product_name, cdylib),
+                profile,
+                ext,
+            });
         }
 
-        let xcode_proj = xcode::proj(proj_args);
+        let spec = bundle::Spec {
+            name: &product.name,
+            bundle_id: &bundle_id,
+            version,
+            team_id: &product.dev_team_id,
+            identity: &identity,
+            sdk,
+            debug: target.config == target::Config::Debug,
+            root: &product.root,
+            profile: profile.as_deref(),
+            cfg: &product.cfg,
+            extensions: &built,
+        };
+        bundle::bundle(exec, &spec, binary, app)?;
+        Ok(bundle_id)
+    }
 
-        let mut project = PathBuf::from("./target/boxes");
+    #[cfg(test)]
+    mod tests {
+        use std::{io, path::Path};
 
-        if is_example {
-            project.push("examples");
-        }
-        if is_dep {
-            project.push("deps");
+        use super::{Dest, Error, RunArgs, build_product, launch};
+        use crate::{
+            exec::{Cmd, Exit, Output},
+            target::Sdk,
+        };
+
+        fn args(args: &[&str]) -> Vec<String> {
+            args.iter().map(|s| s.to_string()).collect()
         }
 
-        let mut target = project.clone();
-        target.push(format!("build-{name}"));
+        #[test]
+        fn mac_exit() {
+            let app = Path::new("/ws/target/boxes/build-box/Build/Products/Debug/box.app");
+            let dest = Dest::default();
+            let mut calls = Vec::new();
+            let mut exec = |cmd: &Cmd| {
+                calls.push(cmd.to_string());
+                Ok(Exit::Code(101).into())
+            };
+            let exit = launch(
+                &mut exec,
+                Sdk::MacOsx,
+                app,
+                "box",
+                "org.cidre.box",
+                &dest,
+                &args(&["--nocapture", "tests::mac"]),
+            )
+            .unwrap();
+            assert_eq!(exit, Exit::Code(101));
+            assert_eq!(
+                calls,
+                [format!(
+                    "{}/Contents/MacOS/box --nocapture tests::mac",
+                    app.display()
+                )]
+            );
 
-        project.push(&name);
-        project.push(&name);
+            let mut exec = |_: &Cmd| Ok(Exit::Signal(11).into());
+            let exit = launch(&mut exec, Sdk::MacCatalyst, app, "box", "", &dest, &[]).unwrap();
+            assert_eq!(exit.code(), 139);
 
-        if xcode_proj.replace_binary {
-            // TODO: if is_binary, try replace with BOX_BIN_PATH instead of copy
-            std::fs::copy(binary, &project).unwrap();
+            let mut exec = |_: &Cmd| Err(io::Error::from(io::ErrorKind::PermissionDenied));
+            let err = launch(&mut exec, Sdk::MacOsx, app, "box", "", &dest, &[]).unwrap_err();
+            assert!(err.to_string().starts_with("can't run `"), "{err}");
         }
 
-        xcode::build(&xcode_proj, platform, config, &target);
+        #[test]
+        fn build() {
+            let ws = crate::target::Workspace {
+                root: "/ws".into(),
+                target_dir: "/tmp/out".into(),
+            };
+            let mut args = RunArgs {
+                bin: None,
+                example: Some("au-host".to_string()),
+                package: Some("cidre".to_string()),
+                target: Some("aarch64-apple-ios".to_string()),
+                release: true,
+                profile: None,
+                terminate_existing: false,
+                args: Vec::new(),
+            };
+            let mut calls = Vec::new();
+            let mut exec = |cmd: &Cmd| {
+                calls.push(cmd.args.join(" ".as_ref()).to_string_lossy().into_owned());
+                io::Result::Ok(Output::from(Exit::Code(0)))
+            };
+            let binary = build_product(&mut exec, &args, &ws).unwrap();
+            assert_eq!(
+                binary,
+                Path::new("/tmp/out/aarch64-apple-ios/release/examples/au-host")
+            );
 
-        target.push("Build");
-        target.push("Products");
+            args.target = None;
+            args.release = false;
+            args.package = None;
+            let binary = build_product(&mut exec, &args, &ws).unwrap();
+            assert_eq!(binary, Path::new("/tmp/out/debug/examples/au-host"));
+
+            args.bin = Some("box".to_string());
+            assert!(matches!(
+                build_product(&mut exec, &args, &ws),
+                Err(Error::Config(_))
+            ));
+            assert_eq!(
+                calls,
+                [
+                    "build --example au-host --package cidre --profile release --target aarch64-apple-ios",
+                    "build --example au-host --profile dev",
+                ]
+            );
+        }
 
-        if sdk == "macos" {
-            target.push(config);
-        } else {
-            target.push(format!("{config}-{sdk}"));
-        }
-        target.push(format!("{name}.app"));
-
-        if sdk == "macos" {
-            target.push(format!("Contents/MacOS/{name}"));
-            process::Command::new(&target)
-                .args(&args.args[3..])
-                .stdout(process::Stdio::inherit()) // Inherit stdout from parent
-                .stderr(process::Stdio::inherit()) // Inherit stderr from parent
-                .status()
-                .unwrap();
-        } else {
-            let device_id = std::env::var("DEVICE_ID").unwrap();
+        #[test]
+        fn no_device() {
+            let mut exec = |_: &Cmd| -> io::Result<Output> { unreachable!() };
+            let app = Path::new("box.app");
+            let dest = Dest::default();
+            let err = launch(&mut exec, Sdk::IphoneOs, app, "box", "", &dest, &[]).unwrap_err();
+            assert!(matches!(err, Error::NoDevice));
+        }
 
-            device_ctl::install_app(&device_id, &target);
-            device_ctl::run_app(&device_id, &xcode_proj.bundle_id, &args.args[3..]);
+        #[test]
+        fn simulator() {
+            let mut calls = Vec::new();
+            let mut exec = |cmd: &Cmd| {
+                calls.push(cmd.args[1].to_string_lossy().into_owned());
+                let stdout = if cmd.args[1] == "list" {
+                    include_str!("../fixtures/simctl/list.json")
+                } else {
+                    ""
+                };
+                io::Result::Ok(Output {
+                    exit: Exit::Code(0),
+                    stdout: stdout.to_string(),
+                })
+            };
+            let app = Path::new("box.app");
+            let dest = Dest {
+                simulator_id: Some("Apple Vision Pro".to_string()),
+                ..Default::default()
+            };
+            let exit = launch(&mut exec, Sdk::XrSimulator, app, "box", "", &dest, &[]).unwrap();
+            assert_eq!(exit, Exit::Code(0));
+
+            let err =
+                launch(&mut exec, Sdk::AppleTvSimulator, app, "box", "", &dest, &[]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "no available appletvsimulator simulator `Apple Vision Pro`. Use `cargo box devices` to list simulators"
+            );
+            assert_eq!(
+                calls,
+                [
+                    "list",
+                    "bootstatus",
+                    "install",
+                    "get_app_container",
+                    "spawn",
+                    "shutdown",
+                    "list"
+                ]
+            );
         }
     }
 }
 
+#[cfg(target_vendor = "apple")]
 mod teams {
     use cidre::{arc, cf, sec};
 
-    pub(crate) fn list() {
+    /// DER of code signing certificates in keychains, see [`crate::signing::Identity`].
+    pub(crate) fn certificates() -> Vec<Vec<u8>> {
         let query = cf::DictionaryOf::with_keys_values(
             &[
                 sec::class_key(),
@@ -281,67 +696,26 @@ mod teams {
             ],
         );
 
-        let certs = sec::item_matching(&query).unwrap();
+        // errSecItemNotFound if there are no certificates
+        let Ok(certs) = sec::item_matching(&query) else {
+            return Vec::new();
+        };
 
         assert_eq!(certs.get_type_id(), cf::Array::type_id());
         let certs: arc::R<cf::ArrayOf<sec::Cert>> = unsafe { std::mem::transmute(certs) };
 
-        let mut filter_set = std::collections::HashSet::new();
-        let subject_key = sec::cert_oids::x509_v1_subject_name();
-        let org_name_label = sec::cert_oids::organization_name();
-        let unit_name_label = sec::cert_oids::organizational_unit_name();
-        let prop_value_key = sec::prop_keys::value();
-        let prop_label_key = sec::prop_keys::label();
-        let keys = cf::ArrayOf::from_slice(&[subject_key]);
-        for cert in certs.iter() {
-            let Ok(vals) = cert.values(&keys) else {
-                continue;
-            };
-            let Some(value) = vals.get(subject_key) else {
-                continue;
-            };
-            let Some(section) = value.get(prop_value_key) else {
-                continue;
-            };
-            assert_eq!(section.get_type_id(), cf::Array::type_id());
-
-            let section: &cf::ArrayOf<cf::DictionaryOf<cf::String, cf::Type>> =
-                unsafe { std::mem::transmute(section) };
-
-            let mut team_id = None;
-            let mut team_name = None;
-            for dict in section.iter() {
-                let Some(label) = dict.get(prop_label_key) else {
-                    continue;
-                };
-                let Some(value) = dict.get(prop_value_key) else {
-                    continue;
-                };
-
-                // todo: build safer api
-                if value.get_type_id() != cf::String::type_id() {
-                    continue;
-                }
-                let value: &cf::String = unsafe { std::mem::transmute(value) };
-
-                if label.equal(org_name_label) {
-                    team_name = Some(value);
-                } else if label.equal(unit_name_label) {
-                    team_id = Some(value);
-                }
-            }
+        certs
+            .iter()
+            .map(|cert| cert.data().as_slice().to_vec())
+            .collect()
+    }
+}
 
-            if let (Some(id), Some(name)) = (team_id, team_name) {
-                if filter_set.contains(id) {
-                    continue;
-                };
-                println!("{id}: {name}");
-                filter_set.insert(id);
-            }
-        }
-        if filter_set.is_empty() {
-            println!("no teams are found");
-        }
+/// Keychain is on apple hosts only, profiles are still inspected.
+#[cfg(not(target_vendor = "apple"))]
+mod teams {
+    pub(crate) fn certificates() -> Vec<Vec<u8>> {
+        Vec::new()
     }
 }
 
@@ -389,6 +763,10 @@ mod cargo {
             cwd_clone.pop();
             return Some(cwd_clone);
         }
+        // custom target dir, runner chdirs to workspace root
+        if cwd.join("Cargo.toml").exists() {
+            return Some(cwd);
+        }
         None
     }
 }
@@ -397,23 +775,32 @@ mod xcode {
     use std::{
         fs,
         path::{Path, PathBuf},
-        process,
     };
 
     use cargo_toml::{Manifest, Product};
 
-    use crate::cargo;
+    use crate::{
+        cargo,
+        exec::{self, Cmd, Exec},
+        meta,
+        runner::Error,
+    };
 
-    pub(crate) fn build(project: &Proj, platform: &str, conf: &str, target: &Path) {
-        process::Command::new("xcodebuild")
+    pub(crate) fn build(
+        exec: &mut impl Exec,
+        project: &Proj,
+        destination: &str,
+        conf: &str,
+        target: &Path,
+    ) -> Result<(), exec::Error> {
+        let cmd = Cmd::new("xcodebuild")
             .args(["-project".as_ref(), project.path.as_os_str()])
-            .args(["-destination", &format!("generic/platform={platform}")])
+            .args(["-destination", destination])
             .args(["-configuration", conf])
             .args(["-scheme", &project.scheme, "-quiet"])
-            .args(["-derivedDataPath".as_ref(), target.as_os_str()])
-            // .args(env_args)
-            .status()
-            .unwrap();
+            .args(["-derivedDataPath".as_ref(), target.as_os_str()]);
+        // .args(env_args)
+        exec.run(&cmd)
     }
 
     #[derive(clap::Args, Debug, Default)]
@@ -424,6 +811,9 @@ mod xcode {
         pub(crate) example: Option<String>,
         #[arg(long)]
         pub(crate) dep: Option<String>,
+        /// `target/` in workspace root by default
+        #[arg(long)]
+        pub(crate) target_dir: Option<PathBuf>,
     }
 
     #[derive(Debug)]
@@ -499,177 +889,437 @@ mod xcode {
         None
     }
 
-    pub(crate) fn proj(args: ProjArgs) -> Proj {
-        let (mut path, mans, _ws) = cargo::manifests().unwrap();
-
+    pub(crate) fn load_env() {
         _ = dotenv::from_filename(".box");
         // devs can add .box.local to git ignore and override their DEVICE_IDs and other env vars
         _ = dotenv::from_filename(".box.local");
+    }
 
-        if let Some(uppercase_name) = args.bin.as_deref().map(str::to_ascii_uppercase) {
-            // check binary replacement xcode project
+    /// Binary replacement xcode project configured with env vars
+    pub(crate) fn configured_proj(args: &ProjArgs) -> Result<Option<Proj>, Error> {
+        load_env();
 
-            // BOX_YOML_XCODE_PROJECT
-            // BOX_YOML_XCODE_SCHEME
-            // BOX_YOML_BUNDLE_ID
+        let Some(bin) = args.bin.as_deref() else {
+            return Ok(None);
+        };
+        let uppercase_name = bin.to_ascii_uppercase();
 
-            let project = std::env::var(format!("BOX_{uppercase_name}_XCODE_PROJECT"));
-            let scheme = std::env::var(format!("BOX_{uppercase_name}_XCODE_SCHEME"));
-            let bundle_id = std::env::var(format!("BOX_{uppercase_name}_BUNDLE_ID"));
+        // BOX_YOML_XCODE_PROJECT
+        // BOX_YOML_XCODE_SCHEME
+        // BOX_YOML_BUNDLE_ID
 
-            if let (Ok(project), Ok(scheme), Ok(bundle_id)) = (project, scheme, bundle_id) {
-                path.push(project);
+        let project = std::env::var(format!("BOX_{uppercase_name}_XCODE_PROJECT"));
+        let scheme = std::env::var(format!("BOX_{uppercase_name}_XCODE_SCHEME"));
+        let bundle_id = std::env::var(format!("BOX_{uppercase_name}_BUNDLE_ID"));
 
-                let proj = Proj {
-                    path,
-                    scheme,
-                    bundle_id,
-                    replace_binary: false,
-                };
+        let (Ok(project), Ok(scheme), Ok(bundle_id)) = (project, scheme, bundle_id) else {
+            return Ok(None);
+        };
+        let (mut path, _mans, _ws) = cargo::manifests().ok_or(Error::NoManifest)?;
+        path.push(project);
 
-                println!("found configured {proj:#?}");
+        let proj = Proj {
+            path,
+            scheme,
+            bundle_id,
+            replace_binary: false,
+        };
 
-                return proj;
-            }
+        println!("found configured {proj:#?}");
+
+        Ok(Some(proj))
+    }
+
+    /// Product with its `[package.metadata.box]` config and `.box` env.
+    #[derive(Debug)]
+    pub(crate) struct BoxProduct {
+        /// Workspace root
+        pub(crate) root: PathBuf,
+        /// `<target-dir>/boxes/[examples|deps]/name`
+        pub(crate) dir: PathBuf,
+        pub(crate) name: String,
+        /// Package version
+        pub(crate) version: Option<String>,
+        pub(crate) cfg: meta::Cfg,
+        pub(crate) org_id: String,
+        pub(crate) dev_team_id: String,
+        /// Package name for `cargo build --package`
+        pub(crate) package: Option<String>,
+        /// `[[example]]`s with `crate-type = ["cdylib"]`
+        pub(crate) cdylib_examples: Vec<String>,
+    }
+
+    impl BoxProduct {
+        pub(crate) fn bundle_id(&self) -> String {
+            self.cfg.bundle_id(&self.org_id, &self.name)
         }
+    }
 
-        let Ok(box_org_id) = std::env::var("BOX_ORG_ID") else {
-            panic!("BOX_ORG_ID env is required. You can add it .box file");
+    pub(crate) fn product(args: &ProjArgs) -> Result<BoxProduct, Error> {
+        load_env();
+
+        let (root, mans, _ws) = cargo::manifests().ok_or(Error::NoManifest)?;
+
+        let Ok(org_id) = std::env::var("BOX_ORG_ID") else {
+            return Err(Error::Config(
+                "BOX_ORG_ID env is required. You can add it .box file".to_string(),
+            ));
         };
         let Ok(dev_team_id) = std::env::var("DEVELOPMENT_TEAM") else {
-            panic!(
-                "DEVELOPMENT_TEAM env is required. You can add it .box file\n
-use cidre-box teams command to list available team ids"
-            );
+            return Err(Error::Config(
+                "DEVELOPMENT_TEAM env is required. You can add it .box file.
+Use `cargo box teams` to list available team ids"
+                    .to_string(),
+            ));
         };
 
-        let product = if args.dep.is_some() {
-            &Product {
-                name: args.dep.clone(),
-                ..Default::default()
-            }
+        let dep_product = Product {
+            name: args.dep.clone(),
+            ..Default::default()
+        };
+        let (man, product) = if let Some(dep) = args.dep.as_deref() {
+            // tests and benches are configured by their package
+            let man = mans.iter().find(|m| {
+                m.package
+                    .as_ref()
+                    .is_some_and(|p| p.name.replace('-', "_") == dep)
+            });
+            (man, &dep_product)
         } else {
-            let Some((_man, product)) = find_product(&mans, &args) else {
-                panic!("product not found");
+            let Some((man, product)) = find_product(&mans, args) else {
+                return Err(Error::Config("product not found".to_string()));
             };
-            product
+            (Some(man), product)
         };
 
-        path.push("target/boxes");
+        let mut dir = match &args.target_dir {
+            Some(target_dir) => root.join(target_dir),
+            None => root.join("target"),
+        };
+        dir.push("boxes");
         if args.example.is_some() {
-            path.push("examples")
+            dir.push("examples")
         }
         if args.dep.is_some() {
-            path.push("deps")
+            dir.push("deps")
         }
-        let product_name = product.name.as_ref().unwrap();
-        path.push(product_name);
+        let name = product.name.clone().unwrap();
+        dir.push(&name);
+
+        let cfg =
+            meta::Cfg::for_product(man, args.example.is_some(), &name).map_err(Error::Config)?;
+        let version = man
+            .and_then(|m| m.package.as_ref())
+            .and_then(|p| p.version.get().ok())
+            .map(|v| v.to_string());
+        let package = man.and_then(|m| m.package.as_ref()).map(|p| p.name.clone());
+        let cdylib_examples = man
+            .iter()
+            .flat_map(|m| m.example.iter())
+            .filter(|e| e.crate_type.iter().any(|t| t == "cdylib"))
+            .filter_map(|e| e.name.clone())
+            .collect();
+
+        Ok(BoxProduct {
+            root,
+            dir,
+            name,
+            version,
+            cfg,
+            org_id,
+            dev_team_id,
+            package,
+            cdylib_examples,
+        })
+    }
 
-        fs::create_dir_all(&path).unwrap();
+    pub(crate) fn proj(args: ProjArgs) -> Result<Proj, Error> {
+        if let Some(proj) = configured_proj(&args)? {
+            return Ok(proj);
+        }
+
+        let product = product(&args)?;
+        let cfg = &product.cfg;
+        let product_name = &product.name;
+        let mut path = product.dir.clone();
+
+        let create_dir = |path: &Path| fs::create_dir_all(path).map_err(Error::io(path));
+        let write =
+            |path: &Path, contents: &str| fs::write(path, contents).map_err(Error::io(path));
+
+        create_dir(&path)?;
         path.push("box.entitlements");
-        fs::write(&path, include_str!("../box/box.entitlements")).unwrap();
+        write(&path, &cfg.entitlements_plist())?;
+        path.pop();
+        path.push("Info.plist");
+        write(&path, &cfg.info_plist())?;
         path.pop();
         path.push("cfg.xcconfig");
-        fs::write(&path, include_str!("../box/cfg.xcconfig")).unwrap();
+        write(&path, include_str!("../box/cfg.xcconfig"))?;
         path.pop();
         path.push("box.xcconfig");
-        fs::write(
+        write(
             &path,
-            format!(
-                r#"
-PRODUCT_NAME = {product_name}
-BOX_ID = {product_name}
-DEVELOPMENT_TEAM = {dev_team_id}
-BOX_ORG_ID = {box_org_id}
-"#
-            ),
-        )
-        .unwrap();
+            &cfg.xcconfig(product_name, &product.dev_team_id, &product.org_id),
+        )?;
 
         path.pop();
 
         path.push("box.xcodeproj");
-        fs::create_dir_all(&path).unwrap();
+        create_dir(&path)?;
         path.push("project.pbxproj");
-        fs::write(&path, include_str!("../box/box.xcodeproj/project.pbxproj")).unwrap();
+        write(&path, include_str!("../box/box.xcodeproj/project.pbxproj"))?;
         path.pop();
         path.push("xcshareddata/xcschemes");
-        fs::create_dir_all(&path).unwrap();
+        create_dir(&path)?;
         path.push("box.xcscheme");
-        fs::write(
+        write(
             &path,
             include_str!("../box/box.xcodeproj/xcshareddata/xcschemes/box.xcscheme"),
-        )
-        .unwrap();
+        )?;
         path.pop();
         path.pop(); // xcshareddata
         path.pop(); // box.xcodeproj
 
-        Proj {
+        Ok(Proj {
             path,
             scheme: "box".to_owned(),
-            bundle_id: format!("{box_org_id}.{product_name}"),
+            bundle_id: product.bundle_id(),
             replace_binary: true,
-        }
+        })
     }
 }
 
 mod device_ctl {
-    use std::{env, fs, path::Path, process};
+    use std::{
+        env, fs,
+        path::Path,
+        process,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
 
-    fn run_cmd(args: &[&str]) -> String {
-        let json_output_path = env::temp_dir().join(format!("devicectl-{}.json", process::id()));
-        let mut child = process::Command::new("xcrun")
+    use crate::{
+        devices::{Process, Row},
+        exec::{self, Cmd, Exec, Exit},
+    };
+
+    /// Runs `xcrun devicectl` and returns its json output.
+    fn run_cmd(exec: &mut impl Exec, args: &[&str]) -> Result<String, exec::Error> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let json_output_path =
+            env::temp_dir().join(format!("devicectl-{}-{n}.json", process::id()));
+        let cmd = Cmd::new("xcrun")
             .args(["devicectl", "-q", "--json-output"])
             .arg(&json_output_path)
-            .args(args)
-            .spawn()
-            .unwrap();
-        child.wait().unwrap();
-        let buf = fs::read_to_string(&json_output_path).unwrap();
+            .args(args);
+        let exit = exec
+            .status(&cmd)
+            .map_err(|err| exec::Error::spawn(&cmd, err))?;
+        let buf = fs::read_to_string(&json_output_path);
         let _ = fs::remove_file(json_output_path);
-        buf
+        if !exit.success() {
+            return Err(exec::Error::Failed {
+                cmd: cmd.to_string(),
+                exit,
+                msg: buf.ok().as_deref().and_then(json::error_msg),
+            });
+        }
+        buf.map_err(|err| exec::Error::Output {
+            cmd: cmd.to_string(),
+            msg: err.to_string(),
+        })
     }
 
-    pub(crate) fn install_app(device_id: &str, bundle: &Path) {
-        run_cmd(&[
-            "device",
-            "install",
-            "app",
-            "-d",
-            device_id,
-            bundle.to_str().unwrap(),
-        ]);
+    fn parse<'a, T: serde::Deserialize<'a>>(buf: &'a str, args: &[&str]) -> Result<T, exec::Error> {
+        serde_json::from_str(buf).map_err(|err| exec::Error::Output {
+            cmd: format!("xcrun devicectl {}", args.join(" ")),
+            msg: err.to_string(),
+        })
     }
 
-    pub(crate) fn run_app(device_id: &str, id: &str, args: &[String]) {
-        let mut args_vec = vec![
-            "device",
-            "process",
-            "launch",
-            "--console",
-            "-d",
-            device_id,
-            "--terminate-existing",
-            id,
-        ];
+    pub(crate) fn install_app(
+        exec: &mut impl Exec,
+        device_id: &str,
+        bundle: &Path,
+    ) -> Result<(), exec::Error> {
+        run_cmd(
+            exec,
+            &[
+                "device",
+                "install",
+                "app",
+                "-d",
+                device_id,
+                &bundle.to_string_lossy(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Launches app with console attached and waits for it, returns app's exit.
+    pub(crate) fn run_app(
+        exec: &mut impl Exec,
+        device_id: &str,
+        id: &str,
+        terminate_existing: bool,
+        args: &[String],
+    ) -> Result<Exit, exec::Error> {
+        let mut args_vec = vec!["device", "process", "launch", "--console", "-d", device_id];
+        if terminate_existing {
+            args_vec.push("--terminate-existing");
+        }
+        args_vec.push(id);
+        if !args.is_empty() {
+            // app arguments like `--nocapture` are not devicectl options
+            args_vec.push("--");
+        }
         for s in args {
             args_vec.push(s);
         }
-        let buf = run_cmd(&args_vec);
-        let run = serde_json::from_str::<json::AppRun>(&buf).unwrap();
-        let code = match (run.result.termination.signal, run.result.termination.code) {
-            (Some(signal), None) => signal,
-            (None, Some(code)) => code,
-            _ => 0,
-        };
-        process::exit(code);
+        let buf = run_cmd(exec, &args_vec)?;
+        let run = parse::<json::AppRun>(&buf, &args_vec)?;
+        match run.result.termination {
+            json::AppRunTermination {
+                signal: Some(signal),
+                ..
+            } => Ok(Exit::Signal(signal)),
+            json::AppRunTermination {
+                code: Some(code), ..
+            } => Ok(Exit::Code(code)),
+            // success must be reported, otherwise failed tests would pass silently
+            _ => Err(exec::Error::Output {
+                cmd: format!("xcrun devicectl {}", args_vec.join(" ")),
+                msg: "no exit code or signal in termination result".to_string(),
+            }),
+        }
+    }
+
+    pub(crate) fn devices(exec: &mut impl Exec) -> Result<Vec<Row>, exec::Error> {
+        let args = ["list", "devices"];
+        let buf = run_cmd(exec, &args)?;
+        rows(&buf).map_err(|err| exec::Error::Output {
+            cmd: format!("xcrun devicectl {}", args.join(" ")),
+            msg: err.to_string(),
+        })
+    }
+
+    pub(crate) fn rows(buf: &str) -> Result<Vec<Row>, serde_json::Error> {
+        let list = serde_json::from_str::<json::DeviceList>(buf)?;
+        Ok(list
+            .result
+            .devices
+            .iter()
+            .map(|d| Row {
+                kind: "device",
+                name: d.props.name.to_string(),
+                os: match d.props.os_version {
+                    Some(v) => format!("{} {v}", d.hardware.platform),
+                    None => d.hardware.platform.to_string(),
+                },
+                id: d.id.to_string(),
+                udid: d.hardware.udid.to_string(),
+                state: d
+                    .connection
+                    .tunnel_state
+                    .unwrap_or(d.connection.pairing_state)
+                    .to_string(),
+            })
+            .collect())
+    }
+
+    fn copy(
+        exec: &mut impl Exec,
+        direction: &str,
+        device_id: &str,
+        bundle_id: &str,
+        src: &Path,
+        dst: &Path,
+    ) -> Result<(), exec::Error> {
+        run_cmd(
+            exec,
+            &[
+                "device",
+                "copy",
+                direction,
+                "-d",
+                device_id,
+                "--domain-type",
+                "appDataContainer",
+                "--domain-identifier",
+                bundle_id,
+                "--source",
+                &src.to_string_lossy(),
+                "--destination",
+                &dst.to_string_lossy(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Copies `local` file to `remote` path in app's data container.
+    pub(crate) fn copy_to(
+        exec: &mut impl Exec,
+        device_id: &str,
+        bundle_id: &str,
+        local: &Path,
+        remote: &Path,
+    ) -> Result<(), exec::Error> {
+        copy(exec, "to", device_id, bundle_id, local, remote)
+    }
+
+    /// Copies `remote` file in app's data container to `local` path.
+    pub(crate) fn copy_from(
+        exec: &mut impl Exec,
+        device_id: &str,
+        bundle_id: &str,
+        remote: &Path,
+        local: &Path,
+    ) -> Result<(), exec::Error> {
+        copy(exec, "from", device_id, bundle_id, remote, local)
+    }
+
+    pub(crate) fn processes(
+        exec: &mut impl Exec,
+        device_id: &str,
+    ) -> Result<Vec<Process>, exec::Error> {
+        let args = ["device", "info", "processes", "-d", device_id];
+        let buf = run_cmd(exec, &args)?;
+        let list = parse::<json::ProcessList>(&buf, &args)?;
+        Ok(list
+            .result
+            .running_processes
+            .into_iter()
+            .map(|p| Process {
+                pid: p.pid,
+                name: p
+                    .executable
+                    .as_deref()
+                    .map(|e| e.strip_prefix("file://").unwrap_or(e).to_string())
+                    .unwrap_or_default(),
+            })
+            .collect())
     }
 
-    pub(crate) fn list_devices() {
-        let buf = run_cmd(&["list", "devices"]);
-        let list = serde_json::from_str::<json::DeviceList>(&buf).unwrap();
-        println!("{:#?}", list.result.devices);
+    pub(crate) fn terminate(
+        exec: &mut impl Exec,
+        device_id: &str,
+        pid: u32,
+    ) -> Result<(), exec::Error> {
+        let pid = pid.to_string();
+        run_cmd(
+            exec,
+            &[
+                "device",
+                "process",
+                "terminate",
+                "-d",
+                device_id,
+                "--pid",
+                &pid,
+            ],
+        )?;
+        Ok(())
     }
 
     #[allow(unused)]
@@ -677,6 +1327,25 @@ mod device_ctl {
         use serde::Deserialize;
         use std::borrow::Cow;
 
+        /// `NSLocalizedDescription` of devicectl error
+        pub(crate) fn error_msg(buf: &str) -> Option<String> {
+            let value: serde_json::Value = serde_json::from_str(buf).ok()?;
+            let err = value.get("error")?;
+            let user_info = err.get("userInfo");
+            let desc = user_info
+                .and_then(|i| i.get("NSLocalizedDescription"))
+                .and_then(|d| d.get("string"))
+                .and_then(|s| s.as_str());
+            match desc {
+                Some(desc) => Some(desc.to_string()),
+                None => Some(format!(
+                    "{} {}",
+                    err.get("domain")?.as_str()?,
+                    err.get("code")?
+                )),
+            }
+        }
+
         #[derive(Deserialize, Debug)]
         pub(crate) struct AppRun {
             pub(crate) result: AppRunResult,
@@ -696,6 +1365,27 @@ mod device_ctl {
             pub(crate) code: Option<i32>,
         }
 
+        #[derive(Deserialize, Debug)]
+        pub(crate) struct ProcessList<'a> {
+            #[serde(borrow)]
+            pub(crate) result: ProcessListResult<'a>,
+        }
+
+        #[derive(Deserialize, Debug)]
+        #[serde(rename_all = "camelCase")]
+        pub(crate) struct ProcessListResult<'a> {
+            #[serde(borrow)]
+            pub(crate) running_processes: Vec<RunningProcess<'a>>,
+        }
+
+        #[derive(Deserialize, Debug)]
+        pub(crate) struct RunningProcess<'a> {
+            /// `file:///private/var/containers/Bundle/Application/<uuid>/box.app/box`
+            pub(crate) executable: Option<Cow<'a, str>>,
+            #[serde(rename = "processIdentifier")]
+            pub(crate) pid: u32,
+        }
+
         #[derive(Deserialize, Debug)]
         pub(crate) struct DeviceList<'a> {
             #[serde(borrow)]
@@ -731,6 +1421,8 @@ mod device_ctl {
         #[derive(Deserialize, Debug)]
         pub(crate) struct DeviceProps<'a> {
             pub(crate) name: Cow<'a, str>,
+            #[serde(rename = "osVersionNumber")]
+            pub(crate) os_version: Option<&'a str>,
             #[serde(rename = "developerModeStatus")]
             pub(crate) dev_mode_status: Option<&'a str>,
         }
@@ -747,6 +1439,146 @@ mod device_ctl {
         #[serde(rename_all = "camelCase")]
         pub(crate) struct ConnectionProps<'a> {
             pub(crate) pairing_state: &'a str,
+            /// `connected`, `disconnected` or `unavailable`
+            pub(crate) tunnel_state: Option<&'a str>,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::{fs, io, path::Path};
+
+        use super::{copy_from, copy_to, install_app, processes, run_app, terminate};
+        use crate::{
+            devices::Process,
+            exec::{self, Cmd, Exit, Output},
+        };
+
+        /// Fake devicectl which writes `json` to `--json-output` path.
+        fn devicectl<'a>(
+            calls: &'a mut Vec<String>,
+            exit: Exit,
+            json: &'static str,
+        ) -> impl FnMut(&Cmd) -> io::Result<Output> + 'a {
+            move |cmd: &Cmd| {
+                assert_eq!(cmd.program, "xcrun");
+                fs::write(&cmd.args[3], json)?;
+                calls.push(
+                    cmd.args[4..]
+                        .iter()
+                        .map(|a| a.to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                );
+                Ok(exit.into())
+            }
+        }
+
+        #[test]
+        fn exit_code() {
+            let json = r#"{"result":{"terminationResult":{"exitCode":101}}}"#;
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(0), json);
+            let args = ["--nocapture".to_string(), "tests::ios".to_string()];
+            let exit = run_app(&mut exec, "DEV", "org.cidre.box", true, &args).unwrap();
+            drop(exec);
+            assert_eq!(exit, Exit::Code(101));
+            assert_eq!(
+                calls,
+                [
+                    "device process launch --console -d DEV --terminate-existing org.cidre.box -- --nocapture tests::ios"
+                ]
+            );
+
+            let json = r#"{"result":{"terminationResult":{"terminatingSignal":6}}}"#;
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(0), json);
+            let exit = run_app(&mut exec, "DEV", "org.cidre.box", false, &[]).unwrap();
+            drop(exec);
+            assert_eq!(exit, Exit::Signal(6));
+            assert_eq!(
+                calls,
+                ["device process launch --console -d DEV org.cidre.box"]
+            );
+
+            let json = r#"{"result":{"terminationResult":{}}}"#;
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(0), json);
+            let err = run_app(&mut exec, "DEV", "org.cidre.box", false, &[]).unwrap_err();
+            assert!(matches!(err, exec::Error::Output { .. }), "{err}");
+        }
+
+        #[test]
+        fn files() {
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(0), "{}");
+            let (local, remote) = (Path::new("in.wav"), Path::new("Documents/in.wav"));
+            copy_to(&mut exec, "DEV", "org.cidre.box", local, remote).unwrap();
+            copy_from(&mut exec, "DEV", "org.cidre.box", remote, local).unwrap();
+            drop(exec);
+            assert_eq!(
+                calls,
+                [
+                    "device copy to -d DEV --domain-type appDataContainer --domain-identifier org.cidre.box --source in.wav --destination Documents/in.wav",
+                    "device copy from -d DEV --domain-type appDataContainer --domain-identifier org.cidre.box --source Documents/in.wav --destination in.wav",
+                ]
+            );
+        }
+
+        #[test]
+        fn process_control() {
+            let json = include_str!("../fixtures/devicectl/processes.json");
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(0), json);
+            let procs = processes(&mut exec, "DEV").unwrap();
+            terminate(&mut exec, "DEV", 4242).unwrap();
+            drop(exec);
+            assert_eq!(
+                procs,
+                [
+                    Process {
+                        pid: 1,
+                        name: "/sbin/launchd".to_string(),
+                    },
+                    Process {
+                        pid: 4242,
+                        name: "/private/var/containers/Bundle/Application/9C1D2E3F-4A5B-4C6D-8E7F-0A1B2C3D4E5F/box.app/box".to_string(),
+                    },
+                    Process {
+                        pid: 4250,
+                        name: String::new(),
+                    },
+                ]
+            );
+            assert_eq!(
+                calls,
+                [
+                    "device info processes -d DEV",
+                    "device process terminate -d DEV --pid 4242",
+                ]
+            );
+        }
+
+        #[test]
+        fn failures() {
+            let json = r#"{"error":{"code":1,"domain":"com.apple.dt.CoreDeviceError","userInfo":{"NSLocalizedDescription":{"string":"The device is locked."}}}}"#;
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(1), json);
+            let err = install_app(&mut exec, "DEV", Path::new("box.app")).unwrap_err();
+            assert!(
+                err.to_string()
+                    .ends_with("failed with exit code 1: The device is locked."),
+                "{err}"
+            );
+
+            let mut calls = Vec::new();
+            let mut exec = devicectl(&mut calls, Exit::Code(0), "{}");
+            let err = run_app(&mut exec, "DEV", "org.cidre.box", false, &[]).unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with("unexpected output of `xcrun devicectl device process launch"),
+                "{err}"
+            );
         }
     }
 }
@@ -0,0 +1,573 @@
+//! Per-product bundle configuration from `[package.metadata.box]`.
+//!
+//! ```toml
+//! [package.metadata.box]
+//! version = "1.2.0"
+//! device-families = ["iphone", "ipad", "mac"]
+//! deployment-targets = { ios = "17.0", macos = "14.0" }
+//!
+//! [package.metadata.box.entitlements]
+//! "com.apple.security.device.audio-input" = true
+//!
+//! [package.metadata.box.info]
+//! NSMicrophoneUsageDescription = "Recording audio"
+//!
+//! # overrides for `[[example]] name = "mic"`, same for `bin`
+//! [package.metadata.box.example.mic]
+//! bundle-id-suffix = "mic-test"
+//! display-name = "Mic"
+//! resources = ["examples/mic/assets"]
+//! frameworks = ["target/frameworks/Foo.framework"]
+//! provisioning-profile = "profiles/mic.mobileprovision"
+//!
+//! # app extension embedded into `au-host` bundle
+//! [package.metadata.box.example.au-host.extensions.au]
+//! example = "au-ext"
+//! ns-extension = { NSExtensionPointIdentifier = "com.apple.AudioUnit-UI" }
+//! ```
+//!
+//! Product tables override package values, `entitlements`, `info` and `extensions` maps and
+//! `resources` and `frameworks` lists are merged. Paths are relative to workspace root.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use cargo_toml::{Manifest, Value};
+use serde::Deserialize;
+
+/// Entitlements every box had before it was configurable.
+const DEFAULT_ENTITLEMENTS: &[&str] = &[
+    "com.apple.security.app-sandbox",
+    "com.apple.security.files.user-selected.read-only",
+];
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum DeviceFamily {
+    Iphone,
+    Ipad,
+    Tv,
+    Watch,
+    Mac,
+    Vision,
+}
+
+impl DeviceFamily {
+    /// `TARGETED_DEVICE_FAMILY` value
+    pub(crate) fn id(self) -> u8 {
+        match self {
+            Self::Iphone => 1,
+            Self::Ipad => 2,
+            Self::Tv => 3,
+            Self::Watch => 4,
+            Self::Mac => 6,
+            Self::Vision => 7,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Os {
+    Macos,
+    Ios,
+    Tvos,
+    Watchos,
+    Visionos,
+}
+
+impl Os {
+    pub(crate) fn deployment_target_key(self) -> &'static str {
+        match self {
+            Self::Macos => "MACOSX_DEPLOYMENT_TARGET",
+            Self::Ios => "IPHONEOS_DEPLOYMENT_TARGET",
+            Self::Tvos => "TVOS_DEPLOYMENT_TARGET",
+            Self::Watchos => "WATCHOS_DEPLOYMENT_TARGET",
+            Self::Visionos => "XROS_DEPLOYMENT_TARGET",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct Cfg {
+    /// Appended to `BOX_ORG_ID`, product name by default
+    pub(crate) bundle_id_suffix: Option<String>,
+    /// `CFBundleDisplayName`, product name by default
+    pub(crate) display_name: Option<String>,
+    /// `MARKETING_VERSION`
+    pub(crate) version: Option<String>,
+    pub(crate) entitlements: BTreeMap<String, Value>,
+    /// Extra Info.plist keys
+    pub(crate) info: BTreeMap<String, Value>,
+    pub(crate) device_families: Option<Vec<DeviceFamily>>,
+    pub(crate) deployment_targets: BTreeMap<Os, String>,
+    /// Files and folders copied to bundle resources
+    pub(crate) resources: Vec<String>,
+    /// Embedded and signed frameworks
+    pub(crate) frameworks: Vec<String>,
+    /// Embedded provisioning profile, `BOX_PROVISIONING_PROFILE` by default
+    pub(crate) provisioning_profile: Option<String>,
+    /// Extensions embedded into bundle by name
+    pub(crate) extensions: BTreeMap<String, Extension>,
+
+    /// Per `[[bin]]` overrides, only in package table
+    bin: BTreeMap<String, Cfg>,
+    /// Per `[[example]]` overrides, only in package table
+    example: BTreeMap<String, Cfg>,
+}
+
+impl Cfg {
+    /// Config of `[[bin]]` or `[[example]]` (`is_example`) product with `name`.
+    pub(crate) fn for_product(
+        man: Option<&Manifest>,
+        is_example: bool,
+        name: &str,
+    ) -> Result<Self, String> {
+        let Some(value) = man
+            .and_then(|m| m.package.as_ref())
+            .and_then(|p| p.metadata.as_ref())
+            .and_then(|m| m.get("box"))
+        else {
+            return Ok(Self::default());
+        };
+        let mut cfg: Self = value
+            .clone()
+            .try_into()
+            .map_err(|e| format!("invalid [package.metadata.box]: {e}"))?;
+
+        let bins = std::mem::take(&mut cfg.bin);
+        let examples = std::mem::take(&mut cfg.example);
+        for (table, products) in [("bin", &bins), ("example", &examples)] {
+            for (product_name, product) in products {
+                if !product.bin.is_empty() || !product.example.is_empty() {
+                    return Err(format!(
+                        "invalid [package.metadata.box.{table}.{product_name}]: product tables can't be nested"
+                    ));
+                }
+            }
+        }
+        let mut products = if is_example { examples } else { bins };
+        let cfg = match products.remove(name) {
+            Some(product) => cfg.merged(product),
+            None => cfg,
+        };
+        for (ext_name, ext) in &cfg.extensions {
+            ext.product()
+                .map_err(|e| format!("invalid box extension `{ext_name}`: {e}"))?;
+        }
+        Ok(cfg)
+    }
+
+    fn merged(mut self, product: Self) -> Self {
+        self.bundle_id_suffix = product.bundle_id_suffix.or(self.bundle_id_suffix);
+        self.display_name = product.display_name.or(self.display_name);
+        self.version = product.version.or(self.version);
+        self.device_families = product.device_families.or(self.device_families);
+        self.entitlements.extend(product.entitlements);
+        self.info.extend(product.info);
+        self.deployment_targets.extend(product.deployment_targets);
+        self.resources.extend(product.resources);
+        self.frameworks.extend(product.frameworks);
+        self.provisioning_profile = product.provisioning_profile.or(self.provisioning_profile);
+        self.extensions.extend(product.extensions);
+        self
+    }
+
+    pub(crate) fn bundle_id(&self, org_id: &str, product_name: &str) -> String {
+        let suffix = self.bundle_id_suffix.as_deref().unwrap_or(product_name);
+        format!("{org_id}.{suffix}")
+    }
+
+    /// `box.entitlements` contents, defaults can be turned off with `false`.
+    pub(crate) fn entitlements_plist(&self) -> String {
+        plist(&self.resolved_entitlements())
+    }
+
+    /// User entitlements on top of defaults.
+    pub(crate) fn resolved_entitlements(&self) -> BTreeMap<String, Value> {
+        with_defaults(&self.entitlements)
+    }
+
+    /// `Info.plist` merged by xcode with generated one.
+    pub(crate) fn info_plist(&self) -> String {
+        plist(&self.info)
+    }
+
+    /// `box.xcconfig` included by `cfg.xcconfig` from the template.
+    pub(crate) fn xcconfig(&self, product_name: &str, dev_team_id: &str, org_id: &str) -> String {
+        let box_id = self.bundle_id_suffix.as_deref().unwrap_or(product_name);
+        let mut res = format!(
+            r#"
+PRODUCT_NAME = {product_name}
+BOX_ID = {box_id}
+DEVELOPMENT_TEAM = {dev_team_id}
+BOX_ORG_ID = {org_id}
+INFOPLIST_FILE = Info.plist
+"#
+        );
+        if let Some(name) = &self.display_name {
+            _ = writeln!(res, "PRODUCT_DISPLAY_NAME = {name}");
+        }
+        if let Some(version) = &self.version {
+            _ = writeln!(res, "MARKETING_VERSION = {version}");
+        }
+        if let Some(families) = &self.device_families {
+            let mut ids: Vec<_> = families.iter().map(|f| f.id()).collect();
+            ids.sort();
+            ids.dedup();
+            let ids: Vec<_> = ids.iter().map(u8::to_string).collect();
+            _ = writeln!(res, "TARGETED_DEVICE_FAMILY = {}", ids.join(","));
+        }
+        for (os, version) in &self.deployment_targets {
+            _ = writeln!(res, "{} = {version}", os.deployment_target_key());
+        }
+        res
+    }
+}
+
+fn with_defaults(user: &BTreeMap<String, Value>) -> BTreeMap<String, Value> {
+    let mut entitlements: BTreeMap<String, Value> = DEFAULT_ENTITLEMENTS
+        .iter()
+        .map(|k| (k.to_string(), Value::Boolean(true)))
+        .collect();
+    entitlements.extend(user.clone());
+    entitlements
+}
+
+/// Where extension is embedded.
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ExtensionKind {
+    /// `.appex` in `PlugIns/`, widgets, audio units, notification services, broadcast uploads
+    #[default]
+    App,
+    /// ExtensionKit `.appex` in `Extensions/`
+    Kit,
+    /// `.systemextension` in `Library/SystemExtensions/`, camera and driver extensions
+    System,
+}
+
+/// `[package.metadata.box.<product>.extensions.<name>]`
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct Extension {
+    /// `[[bin]]` built as extension executable
+    pub(crate) bin: Option<String>,
+    /// `[[example]]` built as extension executable or `cdylib`
+    pub(crate) example: Option<String>,
+    pub(crate) kind: ExtensionKind,
+    /// Appended to host bundle id, extension name by default
+    pub(crate) bundle_id_suffix: Option<String>,
+    pub(crate) display_name: Option<String>,
+    /// `NSExtension` dictionary
+    pub(crate) ns_extension: Option<BTreeMap<String, Value>>,
+    pub(crate) entitlements: BTreeMap<String, Value>,
+    /// Extra Info.plist keys
+    pub(crate) info: BTreeMap<String, Value>,
+    pub(crate) provisioning_profile: Option<String>,
+}
+
+impl Extension {
+    /// `(is_example, name)` of product to build.
+    pub(crate) fn product(&self) -> Result<(bool, &str), String> {
+        match (&self.bin, &self.example) {
+            (Some(bin), None) => Ok((false, bin)),
+            (None, Some(example)) => Ok((true, example)),
+            _ => Err("exactly one of `bin` or `example` is required".to_string()),
+        }
+    }
+
+    pub(crate) fn bundle_id(&self, host_bundle_id: &str, name: &str) -> String {
+        let suffix = self.bundle_id_suffix.as_deref().unwrap_or(name);
+        format!("{host_bundle_id}.{suffix}")
+    }
+
+    /// User entitlements on top of the same defaults as apps, extensions are sandboxed too.
+    pub(crate) fn resolved_entitlements(&self) -> BTreeMap<String, Value> {
+        with_defaults(&self.entitlements)
+    }
+}
+
+pub(crate) fn plist(dict: &BTreeMap<String, Value>) -> String {
+    let mut res = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+"#,
+    );
+    write_dict(&mut res, dict.iter(), 0);
+    res.push_str("</plist>\n");
+    res
+}
+
+fn write_dict<'a>(
+    out: &mut String,
+    entries: impl Iterator<Item = (&'a String, &'a Value)>,
+    depth: usize,
+) {
+    let indent = "\t".repeat(depth);
+    _ = writeln!(out, "{indent}<dict>");
+    for (key, value) in entries {
+        _ = writeln!(out, "{indent}\t<key>{}</key>", escape(key));
+        write_value(out, value, depth + 1);
+    }
+    _ = writeln!(out, "{indent}</dict>");
+}
+
+fn write_value(out: &mut String, value: &Value, depth: usize) {
+    let indent = "\t".repeat(depth);
+    match value {
+        Value::String(s) => _ = writeln!(out, "{indent}<string>{}</string>", escape(s)),
+        Value::Integer(i) => _ = writeln!(out, "{indent}<integer>{i}</integer>"),
+        Value::Float(f) => _ = writeln!(out, "{indent}<real>{f}</real>"),
+        Value::Boolean(b) => _ = writeln!(out, "{indent}<{b}/>"),
+        Value::Datetime(d) => _ = writeln!(out, "{indent}<date>{d}</date>"),
+        Value::Array(arr) => {
+            _ = writeln!(out, "{indent}<array>");
+            for v in arr {
+                write_value(out, v, depth + 1);
+            }
+            _ = writeln!(out, "{indent}</array>");
+        }
+        Value::Table(t) => write_dict(out, t.iter(), depth),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use cargo_toml::Manifest;
+
+    use super::{Cfg, DeviceFamily, ExtensionKind};
+
+    const MANIFEST: &str = r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[[example]]
+name = "mic"
+
+[package.metadata.box]
+version = "1.2.0"
+device-families = ["iphone", "ipad"]
+deployment-targets = { ios = "17.0", macos = "14.0" }
+
+[package.metadata.box.entitlements]
+"com.apple.security.network.client" = true
+
+[package.metadata.box.info]
+NSCameraUsageDescription = "Camera & friends"
+
+[package.metadata.box.example.mic]
+bundle-id-suffix = "mic-test"
+display-name = "Mic"
+device-families = ["mac"]
+
+[package.metadata.box.example.mic.entitlements]
+"com.apple.security.device.audio-input" = true
+"com.apple.security.app-sandbox" = false
+
+[package.metadata.box.example.mic.info]
+NSMicrophoneUsageDescription = "Recording"
+UIBackgroundModes = ["audio"]
+"#;
+
+    fn manifest(toml: &str) -> Manifest {
+        Manifest::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn defaults() {
+        let man = manifest("[package]\nname = \"app\"\nversion = \"0.1.0\"\n");
+        let cfg = Cfg::for_product(Some(&man), false, "app").unwrap();
+        assert_eq!(cfg, Cfg::default());
+        assert_eq!(
+            cfg.entitlements_plist(),
+            include_str!("../box/box.entitlements")
+        );
+        assert_eq!(cfg.bundle_id("org.cidre", "app"), "org.cidre.app");
+        assert_eq!(
+            cfg.xcconfig("app", "TEAM", "org.cidre"),
+            "
+PRODUCT_NAME = app
+BOX_ID = app
+DEVELOPMENT_TEAM = TEAM
+BOX_ORG_ID = org.cidre
+INFOPLIST_FILE = Info.plist
+"
+        );
+
+        let cfg = Cfg::for_product(None, true, "app").unwrap();
+        assert_eq!(cfg, Cfg::default());
+    }
+
+    #[test]
+    fn package() {
+        let man = manifest(MANIFEST);
+        let cfg = Cfg::for_product(Some(&man), true, "other").unwrap();
+        assert_eq!(cfg.version.as_deref(), Some("1.2.0"));
+        assert_eq!(
+            cfg.device_families,
+            Some(vec![DeviceFamily::Iphone, DeviceFamily::Ipad])
+        );
+        assert_eq!(cfg.bundle_id("org.cidre", "other"), "org.cidre.other");
+
+        // examples overrides are not applied to bins
+        let cfg = Cfg::for_product(Some(&man), false, "mic").unwrap();
+        assert_eq!(cfg.display_name, None);
+    }
+
+    #[test]
+    fn product() {
+        let man = manifest(MANIFEST);
+        let cfg = Cfg::for_product(Some(&man), true, "mic").unwrap();
+        assert_eq!(cfg.bundle_id("org.cidre", "mic"), "org.cidre.mic-test");
+        assert_eq!(
+            cfg.xcconfig("mic", "TEAM", "org.cidre"),
+            "
+PRODUCT_NAME = mic
+BOX_ID = mic-test
+DEVELOPMENT_TEAM = TEAM
+BOX_ORG_ID = org.cidre
+INFOPLIST_FILE = Info.plist
+PRODUCT_DISPLAY_NAME = Mic
+MARKETING_VERSION = 1.2.0
+TARGETED_DEVICE_FAMILY = 6
+MACOSX_DEPLOYMENT_TARGET = 14.0
+IPHONEOS_DEPLOYMENT_TARGET = 17.0
+"
+        );
+        assert_eq!(
+            cfg.entitlements_plist(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>com.apple.security.app-sandbox</key>
+	<false/>
+	<key>com.apple.security.device.audio-input</key>
+	<true/>
+	<key>com.apple.security.files.user-selected.read-only</key>
+	<true/>
+	<key>com.apple.security.network.client</key>
+	<true/>
+</dict>
+</plist>
+"#
+        );
+        assert_eq!(
+            cfg.info_plist(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>NSCameraUsageDescription</key>
+	<string>Camera &amp; friends</string>
+	<key>NSMicrophoneUsageDescription</key>
+	<string>Recording</string>
+	<key>UIBackgroundModes</key>
+	<array>
+		<string>audio</string>
+	</array>
+</dict>
+</plist>
+"#
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let man = manifest(
+            r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[package.metadata.box]
+entitelments = {}
+"#,
+        );
+        let err = Cfg::for_product(Some(&man), false, "app").unwrap_err();
+        assert!(err.contains("entitelments"), "{err}");
+
+        let man = manifest(
+            r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[package.metadata.box.bin.app.example.nested]
+version = "1.0"
+"#,
+        );
+        let err = Cfg::for_product(Some(&man), false, "app").unwrap_err();
+        assert!(err.contains("[package.metadata.box.bin.app]"), "{err}");
+
+        let man = manifest(
+            r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[package.metadata.box.bin.app.extensions.widget]
+bin = "widget"
+example = "widget"
+"#,
+        );
+        let err = Cfg::for_product(Some(&man), false, "app").unwrap_err();
+        assert!(err.contains("box extension `widget`"), "{err}");
+    }
+
+    #[test]
+    fn extensions() {
+        let man = manifest(
+            r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[package.metadata.box.extensions.widget]
+bin = "widget"
+ns-extension = { NSExtensionPointIdentifier = "com.apple.widgetkit-extension" }
+
+[package.metadata.box.example.cam.extensions.cam-ext]
+example = "cam-ext"
+kind = "system"
+bundle-id-suffix = "camera"
+
+[package.metadata.box.example.cam.extensions.cam-ext.info]
+CMIOExtension = { CMIOExtensionMachServiceName = "$(TeamIdentifierPrefix)org.cidre.cam.camera" }
+"#,
+        );
+        let cfg = Cfg::for_product(Some(&man), true, "cam").unwrap();
+        assert_eq!(
+            cfg.extensions.keys().collect::<Vec<_>>(),
+            ["cam-ext", "widget"]
+        );
+        let widget = &cfg.extensions["widget"];
+        assert_eq!(widget.kind, ExtensionKind::App);
+        assert_eq!(widget.product(), Ok((false, "widget")));
+        assert_eq!(
+            widget.bundle_id("org.cidre.cam", "widget"),
+            "org.cidre.cam.widget"
+        );
+
+        let cam = &cfg.extensions["cam-ext"];
+        assert_eq!(cam.kind, ExtensionKind::System);
+        assert_eq!(cam.product(), Ok((true, "cam-ext")));
+        assert_eq!(
+            cam.bundle_id("org.cidre.cam", "cam-ext"),
+            "org.cidre.cam.camera"
+        );
+        assert!(cam.resolved_entitlements()["com.apple.security.app-sandbox"] == true.into());
+
+        let cfg = Cfg::for_product(Some(&man), false, "other").unwrap();
+        assert_eq!(cfg.extensions.len(), 1);
+    }
+}
@@ -0,0 +1,1481 @@
+//! Signing identities, provisioning profiles and `cargo box doctor`.
+//!
+//! Certificates and profiles are decoded from DER here, keychain and profile
+//! dirs are only read by callers, so inventory and checks are tested on any host.
+//! CMS signature of profiles is not verified, `codesign` and device do it anyway.
+
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use cargo_toml::Value;
+
+use crate::{bundle, devices, exec, meta, target::Sdk, xcode};
+
+/// Minimal DER reader, enough for X.509 names and CMS envelope.
+mod der {
+    pub(crate) const INTEGER: u8 = 0x02;
+    pub(crate) const OCTET_STRING: u8 = 0x04;
+    pub(crate) const OID: u8 = 0x06;
+    pub(crate) const UTC_TIME: u8 = 0x17;
+    pub(crate) const GENERALIZED_TIME: u8 = 0x18;
+    pub(crate) const SEQUENCE: u8 = 0x30;
+    pub(crate) const SET: u8 = 0x31;
+    /// Constructed `[0]`
+    pub(crate) const CONTEXT_0: u8 = 0xa0;
+
+    #[derive(Debug, Clone, Copy)]
+    pub(crate) struct Reader<'a> {
+        buf: &'a [u8],
+    }
+
+    impl<'a> Reader<'a> {
+        pub(crate) fn new(buf: &'a [u8]) -> Self {
+            Self { buf }
+        }
+
+        pub(crate) fn is_empty(&self) -> bool {
+            self.buf.is_empty()
+        }
+
+        pub(crate) fn peek(&self) -> Option<u8> {
+            self.buf.first().copied()
+        }
+
+        /// Tag and contents of the next element.
+        pub(crate) fn next(&mut self) -> Result<(u8, &'a [u8]), String> {
+            let [tag, len, rest @ ..] = self.buf else {
+                return Err("truncated der".to_string());
+            };
+            let (len, rest) = if len & 0x80 == 0 {
+                (*len as usize, rest)
+            } else {
+                // indefinite and huge lengths are not used by Apple
+                let n = (len & 0x7f) as usize;
+                if n == 0 || n > 4 || rest.len() < n {
+                    return Err("unsupported der length".to_string());
+                }
+                let len = rest[..n].iter().fold(0, |len, b| len << 8 | *b as usize);
+                (len, &rest[n..])
+            };
+            if rest.len() < len {
+                return Err("truncated der".to_string());
+            }
+            self.buf = &rest[len..];
+            Ok((*tag, &rest[..len]))
+        }
+
+        /// Contents of the next element which must be `tag`.
+        pub(crate) fn expect(&mut self, tag: u8) -> Result<&'a [u8], String> {
+            let (actual, contents) = self.next()?;
+            if actual != tag {
+                return Err(format!(
+                    "unexpected der tag {actual:#04x}, expected {tag:#04x}"
+                ));
+            }
+            Ok(contents)
+        }
+    }
+}
+
+/// Reader of XML property lists, `<data>` is kept as base64 string.
+mod xml {
+    use cargo_toml::Value;
+
+    enum Tag<'a> {
+        Open(&'a str),
+        Close(&'a str),
+        Empty(&'a str),
+    }
+
+    struct Parser<'a> {
+        s: &'a str,
+    }
+
+    pub(crate) fn parse(s: &str) -> Result<Value, String> {
+        let mut parser = Parser { s };
+        match parser.tag()? {
+            Tag::Open("plist") => {
+                let tag = parser.tag()?;
+                parser.value(tag)
+            }
+            _ => Err("plist is expected".to_string()),
+        }
+    }
+
+    impl<'a> Parser<'a> {
+        /// Next tag, prolog, doctype and comments are skipped.
+        fn tag(&mut self) -> Result<Tag<'a>, String> {
+            loop {
+                self.s = self.s.trim_start();
+                let end = if self.s.starts_with("<?") {
+                    self.s.find("?>").map(|i| i + 2)
+                } else if self.s.starts_with("<!--") {
+                    self.s.find("-->").map(|i| i + 3)
+                } else if self.s.starts_with("<!") {
+                    self.s.find('>').map(|i| i + 1)
+                } else {
+                    break;
+                };
+                self.s = &self.s[end.ok_or("unterminated tag")?..];
+            }
+            let Some(rest) = self.s.strip_prefix('<') else {
+                return Err(format!("tag is expected at `{}`", head(self.s)));
+            };
+            let end = rest.find('>').ok_or("unterminated tag")?;
+            let tag = &rest[..end];
+            self.s = &rest[end + 1..];
+            let name = |t: &'a str| t.split_whitespace().next().unwrap_or_default();
+            Ok(if let Some(t) = tag.strip_prefix('/') {
+                Tag::Close(name(t))
+            } else if let Some(t) = tag.strip_suffix('/') {
+                Tag::Empty(name(t))
+            } else {
+                Tag::Open(name(tag))
+            })
+        }
+
+        /// Unescaped text up to `</name>`.
+        fn text(&mut self, name: &str) -> Result<String, String> {
+            let close = format!("</{name}>");
+            let end = self
+                .s
+                .find(&close)
+                .ok_or_else(|| format!("`{close}` is expected"))?;
+            let text = unescape(&self.s[..end]);
+            self.s = &self.s[end + close.len()..];
+            Ok(text)
+        }
+
+        fn value(&mut self, tag: Tag<'a>) -> Result<Value, String> {
+            Ok(match tag {
+                Tag::Open("dict") => {
+                    let mut dict = Vec::new();
+                    loop {
+                        match self.tag()? {
+                            Tag::Close("dict") => break,
+                            Tag::Open("key") => {
+                                let key = self.text("key")?;
+                                let tag = self.tag()?;
+                                dict.push((key, self.value(tag)?));
+                            }
+                            _ => return Err("key is expected in dict".to_string()),
+                        }
+                    }
+                    Value::Table(dict.into_iter().collect())
+                }
+                Tag::Open("array") => {
+                    let mut arr = Vec::new();
+                    loop {
+                        match self.tag()? {
+                            Tag::Close("array") => break,
+                            tag => arr.push(self.value(tag)?),
+                        }
+                    }
+                    Value::Array(arr)
+                }
+                Tag::Empty("dict") => Value::Table(Default::default()),
+                Tag::Empty("array") => Value::Array(Vec::new()),
+                Tag::Empty("string") | Tag::Empty("data") => Value::String(String::new()),
+                Tag::Empty("true") => Value::Boolean(true),
+                Tag::Empty("false") => Value::Boolean(false),
+                Tag::Open("string") => Value::String(self.text("string")?),
+                Tag::Open("data") => {
+                    let data = self.text("data")?;
+                    Value::String(data.split_whitespace().collect())
+                }
+                Tag::Open("integer") => {
+                    let text = self.text("integer")?;
+                    Value::Integer(
+                        text.trim()
+                            .parse()
+                            .map_err(|_| format!("bad integer {text}"))?,
+                    )
+                }
+                Tag::Open("real") => {
+                    let text = self.text("real")?;
+                    Value::Float(
+                        text.trim()
+                            .parse()
+                            .map_err(|_| format!("bad real {text}"))?,
+                    )
+                }
+                Tag::Open("date") => {
+                    let text = self.text("date")?;
+                    text.trim()
+                        .parse()
+                        .map(Value::Datetime)
+                        .map_err(|_| format!("bad date {text}"))?
+                }
+                Tag::Open(name) | Tag::Empty(name) => {
+                    return Err(format!("unsupported plist element `{name}`"));
+                }
+                Tag::Close(name) => return Err(format!("unexpected `</{name}>`")),
+            })
+        }
+    }
+
+    fn head(s: &str) -> &str {
+        s.get(..20).unwrap_or(s)
+    }
+
+    fn unescape(s: &str) -> String {
+        let mut res = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some(i) = rest.find('&') {
+            res.push_str(&rest[..i]);
+            rest = &rest[i..];
+            let Some(end) = rest.find(';') else {
+                break;
+            };
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            match c {
+                Some(c) => {
+                    res.push(c);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    res.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        res.push_str(rest);
+        res
+    }
+}
+
+fn base64(s: &str) -> Result<Vec<u8>, String> {
+    let mut res = Vec::with_capacity(s.len() / 4 * 3);
+    let (mut acc, mut bits) = (0u32, 0);
+    for b in s.bytes().filter(|b| !b.is_ascii_whitespace() && *b != b'=') {
+        let v = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(format!("bad base64 byte {b:#04x}")),
+        };
+        acc = acc << 6 | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            res.push((acc >> bits) as u8);
+        }
+    }
+    Ok(res)
+}
+
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Seconds since unix epoch, days are counted as in proleptic gregorian calendar.
+fn unix_time(y: i64, m: i64, d: i64, hh: i64, mm: i64, ss: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    days * 86400 + hh * 3600 + mm * 60 + ss
+}
+
+/// `YYYY-MM-DD` of unix time.
+pub(crate) fn format_date(secs: i64) -> String {
+    let z = secs.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// Unix time of plist `YYYY-MM-DDTHH:MM:SSZ` or DER `YYYYMMDDHHMMSSZ` date.
+pub(crate) fn parse_date(s: &str) -> Option<i64> {
+    let digits: Vec<i64> = s
+        .strip_suffix('Z')?
+        .bytes()
+        .filter(|b| !matches!(b, b'-' | b':' | b'T'))
+        .map(|b| b.is_ascii_digit().then(|| (b - b'0') as i64))
+        .collect::<Option<_>>()?;
+    if digits.len() != 14 {
+        return None;
+    }
+    let n = |i: usize, len: usize| digits[i..i + len].iter().fold(0, |n, d| n * 10 + d);
+    Some(unix_time(
+        n(0, 4),
+        n(4, 2),
+        n(6, 2),
+        n(8, 2),
+        n(10, 2),
+        n(12, 2),
+    ))
+}
+
+fn asn1_time(tag: u8, contents: &[u8]) -> Result<i64, String> {
+    let s = std::str::from_utf8(contents).map_err(|err| err.to_string())?;
+    let time = match tag {
+        // two digit years are 1950..2049
+        der::UTC_TIME => {
+            let century = if s.get(..2) >= Some("50") { "19" } else { "20" };
+            parse_date(&format!("{century}{s}"))
+        }
+        der::GENERALIZED_TIME => parse_date(s),
+        _ => None,
+    };
+    time.ok_or_else(|| format!("unsupported certificate time {s}"))
+}
+
+/// Kind of signing certificate by its common name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IdentityKind {
+    /// `Apple Development`, legacy `iPhone Developer` and `Mac Developer`
+    Development,
+    /// `Apple Distribution`, `iPhone Distribution`, `3rd Party Mac Developer`
+    Distribution,
+    /// `Developer ID Application` and `Developer ID Installer`
+    DeveloperId,
+    Other,
+}
+
+impl IdentityKind {
+    pub(crate) fn of_name(name: &str) -> Self {
+        let prefix = name.split(':').next().unwrap_or_default();
+        match prefix {
+            "Apple Development" | "iPhone Developer" | "Mac Developer" => Self::Development,
+            "Apple Distribution" | "iPhone Distribution" | "Mac App Distribution" => {
+                Self::Distribution
+            }
+            p if p.starts_with("3rd Party Mac Developer") => Self::Distribution,
+            p if p.starts_with("Developer ID") => Self::DeveloperId,
+            _ => Self::Other,
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Distribution => "distribution",
+            Self::DeveloperId => "developer-id",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Code signing certificate, keychain has private key for it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Identity {
+    /// Common name, `Apple Development: Jane Doe (AB12CD34EF)`
+    pub(crate) name: String,
+    pub(crate) kind: IdentityKind,
+    /// Organizational unit
+    pub(crate) team_id: String,
+    /// Organization
+    pub(crate) team_name: String,
+    pub(crate) expires: i64,
+    /// Certificate DER, profiles list certificates they can be signed with
+    pub(crate) der: Vec<u8>,
+}
+
+impl Identity {
+    pub(crate) fn from_der(der: &[u8]) -> Result<Self, String> {
+        const CN: &[u8] = &[0x55, 0x04, 0x03];
+        const O: &[u8] = &[0x55, 0x04, 0x0a];
+        const OU: &[u8] = &[0x55, 0x04, 0x0b];
+
+        let mut cert = der::Reader::new(der::Reader::new(der).expect(der::SEQUENCE)?);
+        let mut tbs = der::Reader::new(cert.expect(der::SEQUENCE)?);
+        if tbs.peek() == Some(der::CONTEXT_0) {
+            // version
+            tbs.next()?;
+        }
+        tbs.expect(der::INTEGER)?;
+        // signature algorithm and issuer
+        tbs.expect(der::SEQUENCE)?;
+        tbs.expect(der::SEQUENCE)?;
+        let mut validity = der::Reader::new(tbs.expect(der::SEQUENCE)?);
+        validity.next()?;
+        let (tag, not_after) = validity.next()?;
+        let expires = asn1_time(tag, not_after)?;
+
+        let (mut name, mut team_id, mut team_name) = (None, None, None);
+        let mut subject = der::Reader::new(tbs.expect(der::SEQUENCE)?);
+        while !subject.is_empty() {
+            let mut rdn = der::Reader::new(subject.expect(der::SET)?);
+            while !rdn.is_empty() {
+                let mut attr = der::Reader::new(rdn.expect(der::SEQUENCE)?);
+                let oid = attr.expect(der::OID)?;
+                let (_, value) = attr.next()?;
+                let value = Some(String::from_utf8_lossy(value).into_owned());
+                match oid {
+                    CN => name = value,
+                    O => team_name = value,
+                    OU => team_id = value,
+                    _ => {}
+                }
+            }
+        }
+        let name = name.ok_or("certificate has no common name")?;
+        Ok(Self {
+            kind: IdentityKind::of_name(&name),
+            name,
+            team_id: team_id.unwrap_or_default(),
+            team_name: team_name.unwrap_or_default(),
+            expires,
+            der: der.to_vec(),
+        })
+    }
+
+    pub(crate) fn is_valid(&self, now: i64) -> bool {
+        self.expires > now
+    }
+}
+
+/// Identities of keychain certificates, unreadable ones are skipped.
+pub(crate) fn identities(certs: Vec<Vec<u8>>) -> Vec<Identity> {
+    certs
+        .iter()
+        .filter_map(|der| Identity::from_der(der).ok())
+        .collect()
+}
+
+/// Installed or configured `.mobileprovision` or `.provisionprofile`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Profile {
+    /// File profile is read from, empty for in memory profiles
+    pub(crate) path: PathBuf,
+    pub(crate) name: String,
+    pub(crate) uuid: String,
+    pub(crate) team_ids: Vec<String>,
+    pub(crate) team_name: String,
+    /// `iOS`, `xrOS`, `OSX`, ...
+    pub(crate) platforms: Vec<String>,
+    /// App id pattern, `TEAMID.org.cidre.*`
+    pub(crate) app_id: String,
+    pub(crate) entitlements: BTreeMap<String, Value>,
+    /// Hardware udids
+    pub(crate) devices: Vec<String>,
+    /// Enterprise and Developer ID profiles run anywhere
+    pub(crate) all_devices: bool,
+    pub(crate) created: i64,
+    pub(crate) expires: i64,
+    /// DER of certificates profile can be signed with
+    pub(crate) certificates: Vec<Vec<u8>>,
+}
+
+/// `SignedData` content of CMS `ContentInfo`.
+fn cms_content(buf: &[u8]) -> Result<&[u8], String> {
+    // 1.2.840.113549.1.7.2
+    const SIGNED_DATA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x02];
+
+    let mut info = der::Reader::new(der::Reader::new(buf).expect(der::SEQUENCE)?);
+    if info.expect(der::OID)? != SIGNED_DATA {
+        return Err("not a cms signed data".to_string());
+    }
+    let mut signed = der::Reader::new(info.expect(der::CONTEXT_0)?);
+    let mut signed = der::Reader::new(signed.expect(der::SEQUENCE)?);
+    // version and digest algorithms
+    signed.expect(der::INTEGER)?;
+    signed.expect(der::SET)?;
+    let mut encap = der::Reader::new(signed.expect(der::SEQUENCE)?);
+    encap.expect(der::OID)?;
+    let mut content = der::Reader::new(encap.expect(der::CONTEXT_0)?);
+    content.expect(der::OCTET_STRING)
+}
+
+impl Profile {
+    pub(crate) fn from_der(buf: &[u8]) -> Result<Self, String> {
+        let content = cms_content(buf)?;
+        let xml = std::str::from_utf8(content).map_err(|err| err.to_string())?;
+        Self::from_plist(xml)
+    }
+
+    pub(crate) fn from_plist(xml: &str) -> Result<Self, String> {
+        let Value::Table(dict) = xml::parse(xml)? else {
+            return Err("profile is not a dict".to_string());
+        };
+        let str = |key: &str| match dict.get(key) {
+            Some(Value::String(s)) => s.clone(),
+            _ => String::new(),
+        };
+        let strs = |key: &str| -> Vec<String> {
+            match dict.get(key) {
+                Some(Value::Array(arr)) => arr
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect(),
+                _ => Vec::new(),
+            }
+        };
+        let date = |key: &str| match dict.get(key) {
+            Some(Value::Datetime(d)) => parse_date(&d.to_string()),
+            _ => None,
+        };
+        let entitlements: BTreeMap<_, _> = match dict.get("Entitlements") {
+            Some(Value::Table(e)) => e.clone().into_iter().collect(),
+            _ => BTreeMap::new(),
+        };
+        let app_id = ["application-identifier", "com.apple.application-identifier"]
+            .iter()
+            .find_map(|k| entitlements.get(*k)?.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let certificates = strs("DeveloperCertificates")
+            .iter()
+            .map(|c| base64(c))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            path: PathBuf::new(),
+            name: str("Name"),
+            uuid: str("UUID"),
+            team_ids: strs("TeamIdentifier"),
+            team_name: str("TeamName"),
+            platforms: strs("Platform"),
+            app_id,
+            entitlements,
+            devices: strs("ProvisionedDevices"),
+            all_devices: dict.get("ProvisionsAllDevices") == Some(&Value::Boolean(true)),
+            created: date("CreationDate").unwrap_or_default(),
+            expires: date("ExpirationDate").ok_or("profile has no ExpirationDate")?,
+            certificates,
+        })
+    }
+
+    pub(crate) fn load(path: &Path) -> Result<Self, String> {
+        let buf = fs::read(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let mut profile =
+            Self::from_der(&buf).map_err(|err| format!("{}: {err}", path.display()))?;
+        profile.path = path.to_path_buf();
+        Ok(profile)
+    }
+
+    /// `development`, `ad-hoc`, `app-store`, `enterprise` or `developer-id`.
+    pub(crate) fn kind(&self) -> &'static str {
+        let get_task_allow = ["get-task-allow", "com.apple.security.get-task-allow"]
+            .iter()
+            .any(|k| self.entitlements.get(*k) == Some(&Value::Boolean(true)));
+        if get_task_allow {
+            "development"
+        } else if self.all_devices && self.platforms.iter().any(|p| p == "OSX") {
+            "developer-id"
+        } else if self.all_devices {
+            "enterprise"
+        } else if !self.devices.is_empty() {
+            "ad-hoc"
+        } else {
+            "app-store"
+        }
+    }
+
+    pub(crate) fn is_valid(&self, now: i64) -> bool {
+        self.expires > now
+    }
+
+    /// App id pattern covers `bundle_id` of `team_id`.
+    pub(crate) fn matches(&self, team_id: &str, bundle_id: &str) -> bool {
+        wildcard(&self.app_id, &format!("{team_id}.{bundle_id}"))
+    }
+
+    /// Profile grants `requested` value of entitlement `key`.
+    pub(crate) fn allows(&self, key: &str, requested: &Value) -> bool {
+        self.entitlements
+            .get(key)
+            .is_some_and(|granted| allowed(requested, granted))
+    }
+}
+
+/// Profile entitlements are patterns with `*` suffix.
+fn wildcard(pattern: &str, value: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => value.starts_with(prefix),
+        None => pattern == value,
+    }
+}
+
+fn allowed(requested: &Value, granted: &Value) -> bool {
+    match (requested, granted) {
+        (Value::Boolean(r), Value::Boolean(g)) => !r || *g,
+        (Value::String(r), Value::String(g)) => wildcard(g, r),
+        (Value::String(r), Value::Array(g)) => {
+            g.iter().any(|g| g.as_str().is_some_and(|g| wildcard(g, r)))
+        }
+        (Value::Array(r), _) => r.iter().all(|r| allowed(r, granted)),
+        (r, g) => r == g,
+    }
+}
+
+/// Where Xcode puts downloaded profiles, the first one is used before Xcode 16.
+fn profile_dirs() -> Vec<PathBuf> {
+    let Some(home) = env::var_os("HOME") else {
+        return Vec::new();
+    };
+    let home = PathBuf::from(home);
+    vec![
+        home.join("Library/MobileDevice/Provisioning Profiles"),
+        home.join("Library/Developer/Xcode/UserData/Provisioning Profiles"),
+    ]
+}
+
+/// Installed profiles sorted by name, unreadable ones are skipped.
+pub(crate) fn installed_profiles() -> Vec<Profile> {
+    let mut profiles: Vec<_> = profile_dirs()
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "mobileprovision" || ext == "provisionprofile")
+        })
+        .filter_map(|path| Profile::load(&path).ok())
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name).then(b.expires.cmp(&a.expires)));
+    profiles
+}
+
+fn expires(secs: i64, now: i64) -> String {
+    if secs > now {
+        format_date(secs)
+    } else {
+        format!("{} expired", format_date(secs))
+    }
+}
+
+/// Teams, identities and profiles tables of `cargo box teams`.
+pub(crate) fn inventory(identities: &[Identity], profiles: &[Profile], now: i64) -> String {
+    let mut teams: Vec<_> = identities
+        .iter()
+        .map(|i| [i.team_id.clone(), i.team_name.clone()])
+        .collect();
+    teams.sort();
+    teams.dedup();
+    let identities: Vec<_> = identities
+        .iter()
+        .map(|i| {
+            [
+                i.name.clone(),
+                i.kind.as_str().to_string(),
+                i.team_id.clone(),
+                expires(i.expires, now),
+            ]
+        })
+        .collect();
+    let profiles: Vec<_> = profiles
+        .iter()
+        .map(|p| {
+            [
+                p.name.clone(),
+                p.kind().to_string(),
+                p.app_id.clone(),
+                if p.all_devices {
+                    "all".to_string()
+                } else {
+                    p.devices.len().to_string()
+                },
+                expires(p.expires, now),
+                p.uuid.clone(),
+            ]
+        })
+        .collect();
+
+    let mut res = String::new();
+    if teams.is_empty() {
+        res.push_str("no teams are found\n");
+    } else {
+        res.push_str(&devices::columns(["TEAM", "NAME"], &teams));
+        res.push('\n');
+        res.push_str(&devices::columns(
+            ["IDENTITY", "TYPE", "TEAM", "EXPIRES"],
+            &identities,
+        ));
+    }
+    res.push('\n');
+    if profiles.is_empty() {
+        res.push_str("no provisioning profiles are found\n");
+    } else {
+        res.push_str(&devices::columns(
+            ["PROFILE", "TYPE", "APP ID", "DEVICES", "EXPIRES", "UUID"],
+            &profiles,
+        ));
+    }
+    res
+}
+
+pub(crate) fn profile_details(p: &Profile, now: i64) -> String {
+    let mut res = format!(
+        "\
+name:      {}
+uuid:      {}
+type:      {}
+team:      {} ({})
+app id:    {}
+platforms: {}
+created:   {}
+expires:   {}
+path:      {}
+",
+        p.name,
+        p.uuid,
+        p.kind(),
+        p.team_ids.join(", "),
+        p.team_name,
+        p.app_id,
+        p.platforms.join(", "),
+        format_date(p.created),
+        expires(p.expires, now),
+        p.path.display(),
+    );
+    if p.all_devices {
+        res.push_str("devices:   all\n");
+    } else {
+        res.push_str(&format!("devices:   {}\n", p.devices.len()));
+        for udid in &p.devices {
+            res.push_str(&format!("  {udid}\n"));
+        }
+    }
+    res.push_str("entitlements:\n");
+    res.push_str(&meta::plist(&p.entitlements));
+    res
+}
+
+#[derive(clap::Args, Debug, Default)]
+pub(crate) struct TeamsArgs {
+    /// Show provisioning profile by uuid, name or path
+    #[arg(long)]
+    pub(crate) profile: Option<String>,
+}
+
+pub(crate) fn teams(args: TeamsArgs, identities: &[Identity]) {
+    let now = now();
+    let Some(wanted) = args.profile else {
+        print!("{}", inventory(identities, &installed_profiles(), now));
+        return;
+    };
+    let profile = if Path::new(&wanted).is_file() {
+        Profile::load(Path::new(&wanted))
+    } else {
+        installed_profiles()
+            .into_iter()
+            .find(|p| p.uuid == wanted || p.name == wanted)
+            .ok_or_else(|| format!("profile `{wanted}` is not installed"))
+    };
+    match profile {
+        Ok(profile) => print!("{}", profile_details(&profile, now)),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Level {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// Findings of `cargo box doctor` and `cargo box availability`.
+#[derive(Debug, Default)]
+pub(crate) struct Report {
+    pub(crate) checks: Vec<(Level, String)>,
+}
+
+impl Report {
+    pub(crate) fn ok(&mut self, msg: impl Into<String>) {
+        self.checks.push((Level::Ok, msg.into()));
+    }
+
+    pub(crate) fn warning(&mut self, msg: impl Into<String>) {
+        self.checks.push((Level::Warning, msg.into()));
+    }
+
+    pub(crate) fn error(&mut self, msg: impl Into<String>) {
+        self.checks.push((Level::Error, msg.into()));
+    }
+
+    pub(crate) fn errors(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|(l, _)| *l == Level::Error)
+            .count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (level, msg) in &self.checks {
+            let level = match level {
+                Level::Ok => "ok",
+                Level::Warning => "warning",
+                Level::Error => "error",
+            };
+            writeln!(f, "{level:7}  {msg}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks `DEVELOPMENT_TEAM` and identity `codesign --sign <wanted>` resolves to.
+///
+/// codesign matches identities by name substring and fails on ambiguous matches.
+pub(crate) fn check_identity<'a>(
+    report: &mut Report,
+    team_id: Option<&str>,
+    wanted: &str,
+    identities: &'a [Identity],
+    now: i64,
+) -> Option<&'a Identity> {
+    match team_id {
+        Some(team_id) => match identities.iter().find(|i| i.team_id == team_id) {
+            Some(i) => report.ok(format!("team {team_id} ({})", i.team_name)),
+            None => report.error(format!(
+                "DEVELOPMENT_TEAM {team_id} has no identities in keychain, see `cargo box teams`"
+            )),
+        },
+        None => report.error("DEVELOPMENT_TEAM is not set, add it to .box, see `cargo box teams`"),
+    }
+
+    let matching: Vec<_> = identities
+        .iter()
+        .filter(|i| i.name.contains(wanted))
+        .collect();
+    let valid: Vec<_> = matching
+        .iter()
+        .filter(|i| i.is_valid(now))
+        .copied()
+        .collect();
+    let of_team = |i: &Identity| team_id.is_none_or(|t| i.team_id == t);
+    match valid.as_slice() {
+        [] => {
+            match matching.first() {
+                Some(i) => report.error(format!(
+                    "identity `{}` expired {}",
+                    i.name,
+                    format_date(i.expires)
+                )),
+                None => report.error(format!(
+                    "no signing identity matches `{wanted}`, set BOX_CODESIGN_IDENTITY"
+                )),
+            }
+            None
+        }
+        [identity] => {
+            if of_team(identity) {
+                report.ok(format!(
+                    "identity `{}` expires {}",
+                    identity.name,
+                    format_date(identity.expires)
+                ));
+            } else {
+                report.error(format!(
+                    "identity `{}` is of team {}, not DEVELOPMENT_TEAM",
+                    identity.name, identity.team_id
+                ));
+            }
+            Some(identity)
+        }
+        many => {
+            let names: Vec<_> = many.iter().map(|i| format!("`{}`", i.name)).collect();
+            report.error(format!(
+                "`{wanted}` is ambiguous, set BOX_CODESIGN_IDENTITY to one of {}",
+                names.join(", ")
+            ));
+            many.iter().copied().find(|i| of_team(i))
+        }
+    }
+}
+
+/// App or extension bundle runner signs.
+#[derive(Debug)]
+pub(crate) struct Signed<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) bundle_id: &'a str,
+    /// The same entitlements runner signs with, see [`bundle::entitlements`]
+    pub(crate) entitlements: BTreeMap<String, Value>,
+    /// Configured profile or reason it can't be read
+    pub(crate) profile: Option<Result<Profile, String>>,
+}
+
+/// Checks that `signed` can be signed and installed with its profile.
+pub(crate) fn check_bundle(
+    report: &mut Report,
+    signed: &Signed,
+    team_id: &str,
+    identity: Option<&Identity>,
+    device: Option<&str>,
+    installed: &[Profile],
+    now: i64,
+) {
+    let Signed {
+        name, bundle_id, ..
+    } = signed;
+    let profile = match &signed.profile {
+        Some(Ok(profile)) => profile,
+        Some(Err(err)) => return report.error(format!("`{name}` profile: {err}")),
+        None => {
+            let found = installed
+                .iter()
+                .find(|p| p.is_valid(now) && p.matches(team_id, bundle_id));
+            return report.error(match found {
+                Some(p) => format!(
+                    "`{name}` has no provisioning profile, installed `{}` matches: {}",
+                    p.name,
+                    p.path.display()
+                ),
+                None => format!(
+                    "`{name}` has no provisioning profile and no installed profile matches {team_id}.{bundle_id}"
+                ),
+            });
+        }
+    };
+
+    let mut errors = Vec::new();
+    let p = &profile.name;
+    if !profile.is_valid(now) {
+        errors.push(format!(
+            "profile `{p}` expired {}",
+            format_date(profile.expires)
+        ));
+    }
+    if !profile.team_ids.iter().any(|t| t == team_id) {
+        errors.push(format!(
+            "profile `{p}` is of team {}, not {team_id}",
+            profile.team_ids.join(", ")
+        ));
+    }
+    if !profile.matches(team_id, bundle_id) {
+        errors.push(format!(
+            "profile `{p}` app id {} doesn't match {team_id}.{bundle_id}",
+            profile.app_id
+        ));
+    }
+    if let Some(identity) = identity {
+        if !profile.certificates.contains(&identity.der) {
+            errors.push(format!(
+                "identity `{}` is not in profile `{p}`, regenerate profile",
+                identity.name
+            ));
+        }
+    }
+    for (key, value) in &signed.entitlements {
+        if !profile.allows(key, value) {
+            errors.push(match profile.entitlements.get(key) {
+                Some(granted) => {
+                    format!(
+                        "entitlement {key} = {value} is not allowed by profile `{p}` ({granted})"
+                    )
+                }
+                None => format!("entitlement {key} is not in profile `{p}`"),
+            });
+        }
+    }
+    if let Some(udid) = device {
+        if !profile.all_devices && !profile.devices.iter().any(|d| d == udid) {
+            errors.push(format!("device {udid} is not in profile `{p}`"));
+        }
+    }
+
+    if errors.is_empty() {
+        report.ok(format!(
+            "`{name}` ({bundle_id}) is signed with profile `{p}`, expires {}",
+            format_date(profile.expires)
+        ));
+    }
+    for err in errors {
+        report.error(format!("`{name}`: {err}"));
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct DoctorArgs {
+    #[arg(long, conflicts_with = "example")]
+    pub(crate) bin: Option<String>,
+
+    #[arg(long)]
+    pub(crate) example: Option<String>,
+
+    /// Target triple to check signing for
+    #[arg(long, default_value = "aarch64-apple-ios")]
+    pub(crate) target: String,
+
+    /// Check release build, debug builds need `get-task-allow`
+    #[arg(long)]
+    pub(crate) release: bool,
+}
+
+/// `cargo box doctor`, exits with 1 if product can't be signed.
+pub(crate) fn doctor(args: DoctorArgs, identities: &[Identity]) {
+    let report = diagnose(&args, identities, &installed_profiles(), now());
+    print!("{report}");
+    if report.errors() > 0 {
+        process::exit(1);
+    }
+}
+
+fn diagnose(args: &DoctorArgs, identities: &[Identity], installed: &[Profile], now: i64) -> Report {
+    xcode::load_env();
+    let mut report = Report::default();
+    let team_id = env::var("DEVELOPMENT_TEAM").ok();
+    let wanted = env::var("BOX_CODESIGN_IDENTITY").unwrap_or_else(|_| "Apple Development".into());
+    let identity = check_identity(&mut report, team_id.as_deref(), &wanted, identities, now);
+
+    let Some(sdk) = Sdk::from_triple(&args.target) else {
+        report.error(format!("{} is not an apple target", args.target));
+        return report;
+    };
+    if sdk.is_simulator() {
+        report.ok(format!("{} builds are not signed", sdk.name()));
+        return report;
+    }
+    if args.bin.is_none() && args.example.is_none() {
+        report.warning("pass --bin or --example to check bundle id, entitlements and profiles");
+        return report;
+    }
+    let Some(team_id) = team_id else {
+        return report;
+    };
+    if env::var("BOX_ORG_ID").is_err() {
+        report.error("BOX_ORG_ID is not set, add it to .box");
+        return report;
+    }
+
+    let proj_args = xcode::ProjArgs {
+        bin: args.bin.clone(),
+        example: args.example.clone(),
+        ..Default::default()
+    };
+    let product = match xcode::product(&proj_args) {
+        Ok(product) => product,
+        Err(err) => {
+            report.error(err.to_string());
+            return report;
+        }
+    };
+    let bundle_id = product.bundle_id();
+    let load = |path: &Option<String>| -> Option<Result<Profile, String>> {
+        path.as_ref().map(|p| Profile::load(&product.root.join(p)))
+    };
+    let profile = product
+        .cfg
+        .provisioning_profile
+        .clone()
+        .or_else(|| env::var("BOX_PROVISIONING_PROFILE").ok());
+
+    let appexes: Vec<_> = product
+        .cfg
+        .extensions
+        .iter()
+        .map(|(name, ext)| bundle::Appex {
+            name,
+            bundle_id: ext.bundle_id(&bundle_id, name),
+            binary: PathBuf::new(),
+            profile: None,
+            ext,
+        })
+        .collect();
+    let spec = bundle::Spec {
+        name: &product.name,
+        bundle_id: &bundle_id,
+        version: "1.0.0",
+        team_id: &team_id,
+        identity: &wanted,
+        sdk,
+        debug: !args.release,
+        root: &product.root,
+        profile: None,
+        cfg: &product.cfg,
+        extensions: &appexes,
+    };
+    let entitlements = |plist: Option<String>| match plist.as_deref().map(xml::parse) {
+        Some(Ok(Value::Table(dict))) => dict.into_iter().collect(),
+        _ => BTreeMap::new(),
+    };
+    let mut signed = vec![Signed {
+        name: &product.name,
+        bundle_id: &bundle_id,
+        entitlements: entitlements(bundle::entitlements(&spec)),
+        profile: load(&profile),
+    }];
+    for appex in &appexes {
+        signed.push(Signed {
+            name: appex.name,
+            bundle_id: &appex.bundle_id,
+            entitlements: entitlements(bundle::extension_entitlements(&spec, appex)),
+            profile: load(&appex.ext.provisioning_profile),
+        });
+    }
+
+    let device = if sdk.is_mac() {
+        None
+    } else {
+        device_udid(&mut report)
+    };
+    for signed in &signed {
+        if sdk.is_mac() && signed.profile.is_none() {
+            report.ok(format!(
+                "`{}` is signed without provisioning profile",
+                signed.name
+            ));
+            continue;
+        }
+        check_bundle(
+            &mut report,
+            signed,
+            &team_id,
+            identity,
+            device.as_deref(),
+            installed,
+            now,
+        );
+    }
+    report
+}
+
+/// Hardware udid of `DEVICE_ID`, profiles list devices by it.
+fn device_udid(report: &mut Report) -> Option<String> {
+    let id = env::var("DEVICE_ID").ok()?;
+    let rows = match crate::device_ctl::devices(&mut exec::System) {
+        Ok(rows) => rows,
+        Err(err) => {
+            report.warning(format!("DEVICE_ID is not checked: {err}"));
+            return None;
+        }
+    };
+    match devices::Dest::select(&rows, Some(&id)) {
+        Ok(devices::Dest::Device { udid, .. }) => Some(udid),
+        Ok(devices::Dest::Simulator { .. }) => None,
+        Err(err) => {
+            report.warning(format!("DEVICE_ID is not checked: {err}"));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use cargo_toml::Value;
+
+    use super::{
+        Identity, IdentityKind, Level, Profile, Report, Signed, asn1_time, base64, check_bundle,
+        check_identity, der, format_date, inventory, parse_date, xml,
+    };
+
+    const DEV: &[u8] = include_bytes!("../fixtures/signing/dev.cer");
+    const DIST: &[u8] = include_bytes!("../fixtures/signing/dist.cer");
+    const PROFILE: &[u8] = include_bytes!("../fixtures/signing/box.mobileprovision");
+
+    fn now() -> i64 {
+        parse_date("2026-01-01T00:00:00Z").unwrap()
+    }
+
+    fn identities() -> Vec<Identity> {
+        let ids = super::identities(vec![DEV.to_vec(), b"junk".to_vec(), DIST.to_vec()]);
+        assert_eq!(ids.len(), 2);
+        ids
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(parse_date("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_date("2000-03-01T00:00:01Z"), Some(951868801));
+        assert_eq!(parse_date("2035-12-31T23:59:59Z"), Some(2082758399));
+        assert_eq!(parse_date("2035-12-31"), None);
+        assert_eq!(format_date(951868801), "2000-03-01");
+        assert_eq!(format_date(2082758399), "2035-12-31");
+        assert_eq!(
+            asn1_time(der::GENERALIZED_TIME, b"20500101000000Z"),
+            Ok(parse_date("2050-01-01T00:00:00Z").unwrap())
+        );
+        assert_eq!(
+            asn1_time(der::UTC_TIME, b"491231235959Z").map(format_date),
+            Ok("2049-12-31".to_string())
+        );
+        assert_eq!(
+            asn1_time(der::UTC_TIME, b"500101000000Z").map(format_date),
+            Ok("1950-01-01".to_string())
+        );
+    }
+
+    #[test]
+    fn base64_decode() {
+        assert_eq!(base64("Y2lk cmU=").unwrap(), b"cidre");
+        assert_eq!(base64("Ym94").unwrap(), b"box");
+        assert!(base64("Ym9*").is_err());
+    }
+
+    #[test]
+    fn certificates() {
+        let [dev, dist] = identities().try_into().unwrap();
+        assert_eq!(dev.name, "Apple Development: Test Dev (DEV1234567)");
+        assert_eq!(dev.kind, IdentityKind::Development);
+        assert_eq!(dev.team_id, "TEAM123456");
+        assert_eq!(dev.team_name, "Cidre Test");
+        assert_eq!(format_date(dev.expires), "2035-12-31");
+        assert!(dev.is_valid(now()));
+
+        assert_eq!(dist.kind, IdentityKind::Distribution);
+        assert_eq!(format_date(dist.expires), "2024-01-01");
+        assert!(!dist.is_valid(now()));
+
+        assert_eq!(
+            IdentityKind::of_name("Developer ID Application: Cidre (TEAM123456)"),
+            IdentityKind::DeveloperId
+        );
+        assert_eq!(
+            IdentityKind::of_name("3rd Party Mac Developer Installer: Cidre (TEAM123456)"),
+            IdentityKind::Distribution
+        );
+        assert!(Identity::from_der(&DEV[..100]).is_err());
+    }
+
+    #[test]
+    fn profile() {
+        let p = Profile::from_der(PROFILE).unwrap();
+        assert_eq!(p.name, "Cidre Dev & Tests");
+        assert_eq!(p.uuid, "5D3A1C2B-7E8F-4A9B-8C1D-2E3F4A5B6C7D");
+        assert_eq!(p.team_ids, ["TEAM123456"]);
+        assert_eq!(p.platforms, ["iOS", "xrOS", "visionOS"]);
+        assert_eq!(p.app_id, "TEAM123456.org.cidre.*");
+        assert_eq!(p.devices, ["00008110-001A2B3C4D5E801E"]);
+        assert_eq!(p.kind(), "development");
+        assert_eq!(format_date(p.created), "2025-01-02");
+        assert_eq!(format_date(p.expires), "2035-01-02");
+        assert_eq!(p.certificates, [DEV]);
+
+        assert!(p.matches("TEAM123456", "org.cidre.box"));
+        assert!(!p.matches("TEAM123456", "com.example.box"));
+        assert!(!p.matches("OTHERTEAM1", "org.cidre.box"));
+
+        let groups = Value::Array(vec![Value::String("TEAM123456.box".into())]);
+        assert!(p.allows("keychain-access-groups", &groups));
+        assert!(!p.allows(
+            "keychain-access-groups",
+            &Value::Array(vec![Value::String("OTHER.box".into())])
+        ));
+        assert!(p.allows("get-task-allow", &Value::Boolean(false)));
+        assert!(!p.allows("com.apple.developer.healthkit", &Value::Boolean(true)));
+
+        assert!(Profile::from_der(DEV).is_err());
+    }
+
+    #[test]
+    fn plist() {
+        let value = xml::parse(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- comment -->
+<plist version="1.0">
+<dict>
+	<key>a &amp; b</key>
+	<string>&lt;&#65;&#x42;&gt;</string>
+	<key>empty</key>
+	<string/>
+	<key>nums</key>
+	<array><integer>-1</integer><real>0.5</real><true/><false/></array>
+	<key>nested</key>
+	<dict/>
+	<key>data</key>
+	<data>
+	Y2lk
+	cmU=
+	</data>
+</dict>
+</plist>"#,
+        )
+        .unwrap();
+        let Value::Table(dict) = value else {
+            panic!("dict is expected");
+        };
+        assert_eq!(dict["a & b"].as_str(), Some("<AB>"));
+        assert_eq!(dict["empty"].as_str(), Some(""));
+        assert_eq!(
+            dict["nums"],
+            Value::Array(vec![
+                Value::Integer(-1),
+                Value::Float(0.5),
+                Value::Boolean(true),
+                Value::Boolean(false)
+            ])
+        );
+        assert_eq!(dict["nested"], Value::Table(Default::default()));
+        assert_eq!(dict["data"].as_str(), Some("Y2lkcmU="));
+
+        assert!(xml::parse("<plist><dict><string>a</string></dict></plist>").is_err());
+        assert!(xml::parse("<plist><date>tomorrow</date></plist>").is_err());
+    }
+
+    #[test]
+    fn identity_checks() {
+        let ids = identities();
+        let mut report = Report::default();
+        let id = check_identity(
+            &mut report,
+            Some("TEAM123456"),
+            "Apple Development",
+            &ids,
+            now(),
+        );
+        assert_eq!(id, Some(&ids[0]));
+        assert_eq!(report.errors(), 0);
+        assert_eq!(
+            report.to_string(),
+            "\
+ok       team TEAM123456 (Cidre Test)
+ok       identity `Apple Development: Test Dev (DEV1234567)` expires 2035-12-31
+"
+        );
+
+        let mut report = Report::default();
+        assert!(check_identity(&mut report, None, "Apple Distribution", &ids, now()).is_none());
+        assert_eq!(
+            report.to_string(),
+            "\
+error    DEVELOPMENT_TEAM is not set, add it to .box, see `cargo box teams`
+error    identity `Apple Distribution: Cidre Test (TEAM123456)` expired 2024-01-01
+"
+        );
+
+        // codesign fails on ambiguous names
+        let mut other = ids[0].clone();
+        other.name = "Apple Development: Other Dev (DEV7654321)".to_string();
+        other.team_id = "OTHERTEAM1".to_string();
+        let ids = [other, ids[0].clone()];
+        let mut report = Report::default();
+        let id = check_identity(
+            &mut report,
+            Some("TEAM123456"),
+            "Apple Development",
+            &ids,
+            now(),
+        );
+        assert_eq!(id, Some(&ids[1]));
+        assert_eq!(report.errors(), 1);
+        assert!(report.checks[1].1.contains("is ambiguous"), "{report}");
+    }
+
+    #[test]
+    fn bundle_checks() {
+        let ids = identities();
+        let mut profile = Profile::from_der(PROFILE).unwrap();
+        profile.path = "/profiles/box.mobileprovision".into();
+        let mut entitlements = BTreeMap::new();
+        entitlements.insert(
+            "application-identifier".to_string(),
+            Value::String("TEAM123456.org.cidre.box".into()),
+        );
+        entitlements.insert("get-task-allow".to_string(), Value::Boolean(true));
+        let mut signed = Signed {
+            name: "box",
+            bundle_id: "org.cidre.box",
+            entitlements,
+            profile: Some(Ok(profile.clone())),
+        };
+        let device = Some("00008110-001A2B3C4D5E801E");
+
+        let mut report = Report::default();
+        check_bundle(
+            &mut report,
+            &signed,
+            "TEAM123456",
+            Some(&ids[0]),
+            device,
+            &[],
+            now(),
+        );
+        assert_eq!(
+            report.to_string(),
+            "ok       `box` (org.cidre.box) is signed with profile `Cidre Dev & Tests`, expires 2035-01-02\n"
+        );
+
+        signed.entitlements.insert(
+            "com.apple.developer.healthkit".to_string(),
+            Value::Boolean(true),
+        );
+        let mut report = Report::default();
+        check_bundle(
+            &mut report,
+            &signed,
+            "TEAM123456",
+            Some(&ids[1]),
+            Some("00008027-000C11223344002E"),
+            &[],
+            now(),
+        );
+        assert!(report.checks.iter().all(|(l, _)| *l == Level::Error));
+        assert_eq!(
+            report.to_string(),
+            "\
+error    `box`: identity `Apple Distribution: Cidre Test (TEAM123456)` is not in profile `Cidre Dev & Tests`, regenerate profile
+error    `box`: entitlement com.apple.developer.healthkit is not in profile `Cidre Dev & Tests`
+error    `box`: device 00008027-000C11223344002E is not in profile `Cidre Dev & Tests`
+"
+        );
+
+        signed.profile = None;
+        let mut report = Report::default();
+        check_bundle(
+            &mut report,
+            &signed,
+            "TEAM123456",
+            None,
+            None,
+            &[profile],
+            now(),
+        );
+        assert_eq!(
+            report.to_string(),
+            "error    `box` has no provisioning profile, installed `Cidre Dev & Tests` matches: /profiles/box.mobileprovision\n"
+        );
+    }
+
+    #[test]
+    fn teams_inventory() {
+        let profile = Profile::from_der(PROFILE).unwrap();
+        assert_eq!(
+            inventory(&identities(), &[profile], now()),
+            "\
+TEAM        NAME
+TEAM123456  Cidre Test
+
+IDENTITY                                     TYPE          TEAM        EXPIRES
+Apple Development: Test Dev (DEV1234567)     development   TEAM123456  2035-12-31
+Apple Distribution: Cidre Test (TEAM123456)  distribution  TEAM123456  2024-01-01 expired
+
+PROFILE            TYPE         APP ID                  DEVICES  EXPIRES     UUID
+Cidre Dev & Tests  development  TEAM123456.org.cidre.*  1        2035-01-02  5D3A1C2B-7E8F-4A9B-8C1D-2E3F4A5B6C7D
+"
+        );
+        assert_eq!(
+            inventory(&[], &[], now()),
+            "no teams are found\n\nno provisioning profiles are found\n"
+        );
+    }
+}
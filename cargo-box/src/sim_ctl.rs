@@ -0,0 +1,377 @@
+//! Simulators via `xcrun simctl`.
+//!
+//! Device is picked from `simctl list -j` by sdk platform and `SIMULATOR_ID`,
+//! booted if needed, app is installed, launched with console attached and
+//! simulator is shut down again if runner booted it.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    devices::{Process, Row},
+    exec::{self, Cmd, Exec, Exit},
+    target::Sdk,
+};
+
+#[derive(Deserialize, Debug, Default)]
+pub(crate) struct List {
+    pub(crate) runtimes: Vec<Runtime>,
+    /// Devices by runtime identifier
+    pub(crate) devices: BTreeMap<String, Vec<Device>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Runtime {
+    /// `com.apple.CoreSimulator.SimRuntime.iOS-18-0`
+    pub(crate) identifier: String,
+    /// `iOS 18.0`
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) is_available: bool,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Device {
+    pub(crate) udid: String,
+    pub(crate) name: String,
+    /// `Booted`, `Shutdown`, `Booting`, ...
+    pub(crate) state: String,
+    pub(crate) is_available: bool,
+}
+
+impl Device {
+    pub(crate) fn is_booted(&self) -> bool {
+        self.state == "Booted"
+    }
+}
+
+impl List {
+    pub(crate) fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Available runtimes of `sdk`, newest first.
+    fn runtimes(&self, sdk: Sdk) -> Vec<&Runtime> {
+        let Some(prefix) = runtime_prefix(sdk) else {
+            return Vec::new();
+        };
+        let mut res: Vec<_> = self
+            .runtimes
+            .iter()
+            .filter(|r| r.is_available && r.identifier.starts_with(prefix))
+            .collect();
+        res.sort_by_key(|r| std::cmp::Reverse(version(&r.version)));
+        res
+    }
+
+    /// `wanted` is udid or name, otherwise booted device is preferred
+    /// over the first device of the newest runtime.
+    pub(crate) fn select(&self, sdk: Sdk, wanted: Option<&str>) -> Option<&Device> {
+        let mut devices = self
+            .runtimes(sdk)
+            .into_iter()
+            .filter_map(|r| self.devices.get(&r.identifier))
+            .flatten()
+            .filter(|d| d.is_available);
+        if let Some(wanted) = wanted {
+            return devices.find(|d| d.udid == wanted || d.name == wanted);
+        }
+        let devices: Vec<_> = devices.collect();
+        devices
+            .iter()
+            .find(|d| d.is_booted())
+            .or(devices.first())
+            .copied()
+    }
+
+    pub(crate) fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for runtime in self.runtimes.iter().filter(|r| r.is_available) {
+            let Some(devices) = self.devices.get(&runtime.identifier) else {
+                continue;
+            };
+            for device in devices.iter().filter(|d| d.is_available) {
+                rows.push(Row {
+                    kind: "simulator",
+                    name: device.name.clone(),
+                    os: runtime.name.clone(),
+                    id: device.udid.clone(),
+                    udid: device.udid.clone(),
+                    state: device.state.to_lowercase(),
+                });
+            }
+        }
+        rows
+    }
+}
+
+fn runtime_prefix(sdk: Sdk) -> Option<&'static str> {
+    Some(match sdk {
+        Sdk::IphoneSimulator => "com.apple.CoreSimulator.SimRuntime.iOS-",
+        Sdk::AppleTvSimulator => "com.apple.CoreSimulator.SimRuntime.tvOS-",
+        Sdk::WatchSimulator => "com.apple.CoreSimulator.SimRuntime.watchOS-",
+        Sdk::XrSimulator => "com.apple.CoreSimulator.SimRuntime.xrOS-",
+        _ => return None,
+    })
+}
+
+fn version(v: &str) -> Vec<u32> {
+    v.split('.').map(|p| p.parse().unwrap_or(0)).collect()
+}
+
+fn simctl<'a>(args: impl IntoIterator<Item = &'a str>) -> Cmd {
+    Cmd::new("xcrun").arg("simctl").args(args)
+}
+
+pub(crate) fn list(exec: &mut impl Exec) -> Result<List, exec::Error> {
+    let cmd = simctl(["list", "-j", "runtimes", "devices"]);
+    let buf = exec.read(&cmd)?;
+    List::from_json(&buf).map_err(|err| exec::Error::Output {
+        cmd: cmd.to_string(),
+        msg: err.to_string(),
+    })
+}
+
+/// `log stream` in simulator or `log show` for the `last` interval.
+pub(crate) fn log_cmd(udid: &str, predicate: &str, last: Option<&str>) -> Cmd {
+    let cmd = simctl(["spawn", udid, "log"]);
+    match last {
+        Some(last) => cmd.args(["show", "--last", last, "--info", "--debug"]),
+        None => cmd.args(["stream", "--level", "debug"]),
+    }
+    .args(["--style", "compact", "--predicate", predicate])
+}
+
+/// Data container of installed app.
+pub(crate) fn app_container(
+    exec: &mut impl Exec,
+    udid: &str,
+    bundle_id: &str,
+) -> Result<PathBuf, exec::Error> {
+    let buf = exec.read(&simctl(["get_app_container", udid, bundle_id, "data"]))?;
+    Ok(PathBuf::from(buf.trim_end()))
+}
+
+pub(crate) fn processes(exec: &mut impl Exec, udid: &str) -> Result<Vec<Process>, exec::Error> {
+    let buf = exec.read(&simctl(["spawn", udid, "launchctl", "list"]))?;
+    Ok(launchctl_processes(&buf))
+}
+
+/// Running services of `launchctl list`, stopped ones have `-` pid.
+pub(crate) fn launchctl_processes(buf: &str) -> Vec<Process> {
+    buf.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut cols = line.split('\t');
+            let pid = cols.next()?.parse().ok()?;
+            let name = cols.nth(1)?.to_string();
+            Some(Process { pid, name })
+        })
+        .collect()
+}
+
+/// Installs and runs app on `sim`, returns exit of its executable.
+///
+/// `simctl launch --console-pty` exits with zero whatever app returns,
+/// so installed executable is started with `simctl spawn` which waits for it.
+pub(crate) fn run_app(
+    exec: &mut impl Exec,
+    sim: &Device,
+    app: &Path,
+    bundle_id: &str,
+    args: &[String],
+) -> Result<Exit, exec::Error> {
+    let udid = sim.udid.as_str();
+    let boot = !sim.is_booted();
+    if boot {
+        // boots and waits until device is ready
+        exec.run(&simctl(["bootstatus", udid, "-b"]))?;
+    }
+
+    let res = exec.run(&simctl(["install", udid]).arg(app)).and_then(|_| {
+        let buf = exec.read(&simctl(["get_app_container", udid, bundle_id, "app"]))?;
+        // xcode and direct backends name executable after the bundle
+        let name = app.file_stem().unwrap_or_default();
+        let cmd = simctl(["spawn", udid])
+            .arg(Path::new(buf.trim_end()).join(name))
+            .args(args);
+        exec.status(&cmd)
+            .map_err(|err| exec::Error::spawn(&cmd, err))
+    });
+
+    if boot {
+        let shutdown = exec.run(&simctl(["shutdown", udid]));
+        if res.is_ok() {
+            shutdown?;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, path::Path};
+
+    use super::{List, launchctl_processes, log_cmd, run_app};
+    use crate::{
+        exec::{Cmd, Exit, Output},
+        target::Sdk,
+    };
+
+    /// Installed bundle reported by `simctl get_app_container`.
+    const APP: &str = "/sim/Containers/Bundle/Application/0000/box.app";
+
+    fn list() -> List {
+        List::from_json(include_str!("../fixtures/simctl/list.json")).unwrap()
+    }
+
+    /// Fake simctl, `spawn`ed app exits with `exit`.
+    fn simctl(calls: &mut Vec<String>, exit: Exit) -> impl FnMut(&Cmd) -> io::Result<Output> + '_ {
+        move |cmd: &Cmd| {
+            calls.push(cmd.to_string());
+            Ok(match cmd.args[1].to_str() {
+                Some("spawn") => exit.into(),
+                Some("get_app_container") => Output {
+                    exit: Exit::Code(0),
+                    stdout: format!("{APP}\n"),
+                },
+                _ => Exit::Code(0).into(),
+            })
+        }
+    }
+
+    #[test]
+    fn select() {
+        let list = list();
+        // booted iOS 17.5 device wins over newer runtime
+        let sim = list.select(Sdk::IphoneSimulator, None).unwrap();
+        assert_eq!(sim.name, "iPhone 15 Pro");
+
+        let sim = list
+            .select(Sdk::IphoneSimulator, Some("iPad Pro 11-inch (M4)"))
+            .unwrap();
+        assert_eq!(sim.udid, "0F1E2D3C-4B5A-4968-8776-655443322110");
+        let sim = list
+            .select(
+                Sdk::IphoneSimulator,
+                Some("A1E2C3D4-B5F6-4789-8ABC-DEF012345678"),
+            )
+            .unwrap();
+        assert_eq!(sim.name, "iPhone 16 Pro");
+        assert!(
+            list.select(Sdk::IphoneSimulator, Some("Apple Vision Pro"))
+                .is_none()
+        );
+
+        let sim = list.select(Sdk::XrSimulator, None).unwrap();
+        assert_eq!(sim.name, "Apple Vision Pro");
+        // runtime is not available
+        assert!(list.select(Sdk::AppleTvSimulator, None).is_none());
+        assert!(list.select(Sdk::WatchSimulator, None).is_none());
+        assert!(list.select(Sdk::IphoneOs, None).is_none());
+    }
+
+    #[test]
+    fn processes() {
+        let procs = launchctl_processes(include_str!("../fixtures/simctl/launchctl-list.txt"));
+        let names: Vec<_> = procs.iter().map(|p| (p.pid, p.name.as_str())).collect();
+        assert_eq!(
+            names,
+            [
+                (61234, "UIKitApplication:org.cidre.box[8f2a][rb-legacy]"),
+                (60981, "com.apple.backboardd"),
+            ]
+        );
+    }
+
+    #[test]
+    fn logs() {
+        let predicate = r#"process == "box""#;
+        assert_eq!(
+            log_cmd("UDID", predicate, None).to_string(),
+            r#"xcrun simctl spawn UDID log stream --level debug --style compact --predicate process == "box""#
+        );
+        assert_eq!(
+            log_cmd("UDID", predicate, Some("1h")).to_string(),
+            r#"xcrun simctl spawn UDID log show --last 1h --info --debug --style compact --predicate process == "box""#
+        );
+    }
+
+    #[test]
+    fn newest_runtime() {
+        let mut list = list();
+        for devices in list.devices.values_mut() {
+            for d in devices {
+                d.state = "Shutdown".to_string();
+            }
+        }
+        let sim = list.select(Sdk::IphoneSimulator, None).unwrap();
+        assert_eq!(sim.name, "iPhone 16 Pro");
+    }
+
+    #[test]
+    fn boot_install_launch_shutdown() {
+        let list = list();
+        let sim = list
+            .select(Sdk::IphoneSimulator, Some("iPhone 16 Pro"))
+            .unwrap();
+        let mut calls = Vec::new();
+        let mut exec = simctl(&mut calls, Exit::Code(0));
+        let args = ["--nocapture".to_string()];
+        let exit = run_app(&mut exec, sim, Path::new("box.app"), "org.cidre.box", &args).unwrap();
+        drop(exec);
+        assert_eq!(exit, Exit::Code(0));
+        let udid = &sim.udid;
+        assert_eq!(
+            calls,
+            [
+                format!("xcrun simctl bootstatus {udid} -b"),
+                format!("xcrun simctl install {udid} box.app"),
+                format!("xcrun simctl get_app_container {udid} org.cidre.box app"),
+                format!("xcrun simctl spawn {udid} {APP}/box --nocapture"),
+                format!("xcrun simctl shutdown {udid}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_run() {
+        let list = list();
+        let sim = list.select(Sdk::IphoneSimulator, None).unwrap();
+        let mut calls = Vec::new();
+        // libtest exits with 101 when any test fails
+        let mut exec = simctl(&mut calls, Exit::Code(101));
+        let exit = run_app(&mut exec, sim, Path::new("box.app"), "org.cidre.box", &[]).unwrap();
+        drop(exec);
+        assert_eq!(exit, Exit::Code(101));
+        assert_eq!(
+            calls.last().unwrap(),
+            &format!("xcrun simctl spawn {} {APP}/box", sim.udid)
+        );
+    }
+
+    #[test]
+    fn booted_is_kept() {
+        let list = list();
+        let sim = list.select(Sdk::IphoneSimulator, None).unwrap();
+        let mut calls = Vec::new();
+        let mut exec = |cmd: &Cmd| {
+            calls.push(cmd.args[1].to_string_lossy().into_owned());
+            let exit = if cmd.args[1] == "install" {
+                Exit::Code(1)
+            } else {
+                Exit::Code(0)
+            };
+            io::Result::<Output>::Ok(exit.into())
+        };
+        let err = run_app(&mut exec, sim, Path::new("box.app"), "org.cidre.box", &[]).unwrap_err();
+        assert!(err.to_string().contains("simctl install"), "{err}");
+        assert_eq!(calls, ["install"]);
+    }
+}
@@ -0,0 +1,573 @@
+//! Runner target resolution.
+//!
+//! Cargo calls runner with a path to built binary only. Target dir and workspace root
+//! come from `cargo metadata`, triple, profile and product kind from the binary path
+//! relative to target dir:
+//!
+//! ```text
+//! <target-dir>/[<triple>/]<profile>/<bin>
+//! <target-dir>/[<triple>/]<profile>/examples/<example>
+//! <target-dir>/[<triple>/]<profile>/deps/<test-or-bench>-<hash>
+//! ```
+
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+    process,
+};
+
+use cargo_toml::Profiles;
+use serde::Deserialize;
+
+use crate::meta;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Sdk {
+    MacOsx,
+    /// `macosx` sdk with `ios-macabi` triples
+    MacCatalyst,
+    IphoneOs,
+    IphoneSimulator,
+    AppleTvOs,
+    AppleTvSimulator,
+    WatchOs,
+    WatchSimulator,
+    XrOs,
+    XrSimulator,
+}
+
+impl Sdk {
+    pub(crate) fn from_triple(triple: &str) -> Option<Self> {
+        Some(match triple {
+            "aarch64-apple-darwin"
+            | "arm64e-apple-darwin"
+            | "x86_64-apple-darwin"
+            | "x86_64h-apple-darwin" => Self::MacOsx,
+            "aarch64-apple-ios-macabi" | "x86_64-apple-ios-macabi" => Self::MacCatalyst,
+            "aarch64-apple-ios" | "arm64e-apple-ios" => Self::IphoneOs,
+            "aarch64-apple-ios-sim" | "x86_64-apple-ios" | "x86_64-apple-ios-sim" => {
+                Self::IphoneSimulator
+            }
+            "aarch64-apple-tvos" | "arm64e-apple-tvos" => Self::AppleTvOs,
+            "aarch64-apple-tvos-sim" | "x86_64-apple-tvos" => Self::AppleTvSimulator,
+            "aarch64-apple-watchos" | "arm64_32-apple-watchos" | "armv7k-apple-watchos" => {
+                Self::WatchOs
+            }
+            "aarch64-apple-watchos-sim" | "x86_64-apple-watchos-sim" => Self::WatchSimulator,
+            "aarch64-apple-visionos" => Self::XrOs,
+            "aarch64-apple-visionos-sim" => Self::XrSimulator,
+            _ => return None,
+        })
+    }
+
+    /// `xcodebuild -sdk` name
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::MacOsx | Self::MacCatalyst => "macosx",
+            Self::IphoneOs => "iphoneos",
+            Self::IphoneSimulator => "iphonesimulator",
+            Self::AppleTvOs => "appletvos",
+            Self::AppleTvSimulator => "appletvsimulator",
+            Self::WatchOs => "watchos",
+            Self::WatchSimulator => "watchsimulator",
+            Self::XrOs => "xros",
+            Self::XrSimulator => "xrsimulator",
+        }
+    }
+
+    /// `xcodebuild -destination` for any device of sdk
+    pub(crate) fn destination(self) -> &'static str {
+        match self {
+            Self::MacOsx => "generic/platform=macOS",
+            Self::MacCatalyst => "generic/platform=macOS,variant=Mac Catalyst",
+            Self::IphoneOs => "generic/platform=iOS",
+            Self::IphoneSimulator => "generic/platform=iOS Simulator",
+            Self::AppleTvOs => "generic/platform=tvOS",
+            Self::AppleTvSimulator => "generic/platform=tvOS Simulator",
+            Self::WatchOs => "generic/platform=watchOS",
+            Self::WatchSimulator => "generic/platform=watchOS Simulator",
+            Self::XrOs => "generic/platform=visionOS",
+            Self::XrSimulator => "generic/platform=visionOS Simulator",
+        }
+    }
+
+    /// Folder xcodebuild puts products to, `Build/Products/<folder>`
+    pub(crate) fn products_dir(self, config: Config) -> String {
+        let config = config.as_str();
+        match self {
+            Self::MacOsx => config.to_string(),
+            Self::MacCatalyst => format!("{config}-maccatalyst"),
+            sdk => format!("{config}-{}", sdk.name()),
+        }
+    }
+
+    /// `CFBundleSupportedPlatforms` value
+    pub(crate) fn platform(self) -> &'static str {
+        match self {
+            Self::MacOsx | Self::MacCatalyst => "MacOSX",
+            Self::IphoneOs => "iPhoneOS",
+            Self::IphoneSimulator => "iPhoneSimulator",
+            Self::AppleTvOs => "AppleTVOS",
+            Self::AppleTvSimulator => "AppleTVSimulator",
+            Self::WatchOs => "WatchOS",
+            Self::WatchSimulator => "WatchSimulator",
+            Self::XrOs => "XROS",
+            Self::XrSimulator => "XRSimulator",
+        }
+    }
+
+    /// Runs on this mac with `Contents/...` bundle layout
+    pub(crate) fn is_mac(self) -> bool {
+        matches!(self, Self::MacOsx | Self::MacCatalyst)
+    }
+
+    pub(crate) fn is_simulator(self) -> bool {
+        matches!(
+            self,
+            Self::IphoneSimulator
+                | Self::AppleTvSimulator
+                | Self::WatchSimulator
+                | Self::XrSimulator
+        )
+    }
+
+    pub(crate) fn os(self) -> meta::Os {
+        match self {
+            Self::MacOsx | Self::MacCatalyst => meta::Os::Macos,
+            Self::IphoneOs | Self::IphoneSimulator => meta::Os::Ios,
+            Self::AppleTvOs | Self::AppleTvSimulator => meta::Os::Tvos,
+            Self::WatchOs | Self::WatchSimulator => meta::Os::Watchos,
+            Self::XrOs | Self::XrSimulator => meta::Os::Visionos,
+        }
+    }
+
+    /// `UIDeviceFamily` values supported by sdk
+    pub(crate) fn device_families(self) -> &'static [meta::DeviceFamily] {
+        use meta::DeviceFamily::*;
+        match self {
+            Self::MacOsx => &[],
+            Self::MacCatalyst => &[Ipad, Mac],
+            Self::IphoneOs | Self::IphoneSimulator => &[Iphone, Ipad],
+            Self::AppleTvOs | Self::AppleTvSimulator => &[Tv],
+            Self::WatchOs | Self::WatchSimulator => &[Watch],
+            Self::XrOs | Self::XrSimulator => &[Vision],
+        }
+    }
+}
+
+/// Xcode build configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Config {
+    Debug,
+    Release,
+}
+
+impl Config {
+    /// Configuration of cargo profile folder, custom profiles follow `inherits`.
+    pub(crate) fn of_profile(profile: &str, profiles: Option<&Profiles>) -> Self {
+        let mut name = profile;
+        // inherits chain can't be longer than number of custom profiles
+        let max = profiles.map_or(0, |p| p.custom.len());
+        for _ in 0..=max {
+            match name {
+                "debug" | "dev" | "test" => return Self::Debug,
+                "release" | "bench" => return Self::Release,
+                _ => {}
+            }
+            let inherits = profiles
+                .and_then(|p| p.custom.get(name))
+                .and_then(|p| p.inherits.as_deref());
+            match inherits {
+                Some(parent) => name = parent,
+                None => break,
+            }
+        }
+        Self::Release
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Debug => "Debug",
+            Self::Release => "Release",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Bin,
+    Example,
+    /// Test or bench
+    Dep,
+}
+
+/// Product cargo asked to run.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Target {
+    /// `None` for host builds
+    pub(crate) triple: Option<String>,
+    pub(crate) sdk: Sdk,
+    /// Profile folder, `debug` for `dev` and `test`, `release` for `bench`
+    pub(crate) profile: String,
+    pub(crate) config: Config,
+    pub(crate) kind: Kind,
+    /// Product name, hash is stripped from deps
+    pub(crate) name: String,
+}
+
+impl Target {
+    /// Cargo profile of profile folder
+    pub(crate) fn profile_name(&self) -> &str {
+        match self.profile.as_str() {
+            "debug" => "dev",
+            profile => profile,
+        }
+    }
+
+    /// `cargo build` args to build other products with the same triple and profile.
+    pub(crate) fn cargo_args(&self) -> Vec<String> {
+        let mut args = vec!["--profile".to_string(), self.profile_name().to_string()];
+        if let Some(triple) = &self.triple {
+            args.extend(["--target".to_string(), triple.clone()]);
+        }
+        args
+    }
+
+    /// Path of product built with the same triple and profile, `cdylib`s are `lib<name>.dylib`.
+    pub(crate) fn artifact(
+        &self,
+        target_dir: &Path,
+        kind: Kind,
+        name: &str,
+        cdylib: bool,
+    ) -> PathBuf {
+        let mut path = target_dir.to_path_buf();
+        if let Some(triple) = &self.triple {
+            path.push(triple);
+        }
+        path.push(&self.profile);
+        match kind {
+            Kind::Bin => {}
+            Kind::Example => path.push("examples"),
+            Kind::Dep => path.push("deps"),
+        }
+        if cdylib {
+            path.push(format!("lib{}.dylib", name.replace('-', "_")));
+        } else {
+            path.push(name);
+        }
+        path
+    }
+}
+
+/// Folder of cargo profile in target dir
+pub(crate) fn profile_dir(profile: &str) -> &str {
+    match profile {
+        "dev" | "test" => "debug",
+        "release" | "bench" => "release",
+        profile => profile,
+    }
+}
+
+/// `cargo` which runs us, so toolchain overrides are kept.
+pub(crate) fn cargo() -> std::ffi::OsString {
+    env::var_os("CARGO").unwrap_or_else(|| "cargo".into())
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Error {
+    MissingBinary,
+    OutsideTargetDir {
+        binary: PathBuf,
+        target_dir: PathBuf,
+    },
+    UnexpectedLayout(PathBuf),
+    UnsupportedTriple(String),
+    Metadata(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingBinary => f.write_str("runner expects a path to binary"),
+            Self::OutsideTargetDir { binary, target_dir } => write!(
+                f,
+                "{} is not in target dir {}",
+                binary.display(),
+                target_dir.display()
+            ),
+            Self::UnexpectedLayout(path) => write!(
+                f,
+                "can't find profile and product in {}, expected [<triple>/]<profile>/[examples|deps/]<name>",
+                path.display()
+            ),
+            Self::UnsupportedTriple(triple) => write!(f, "unsupported target `{triple}`"),
+            Self::Metadata(err) => write!(f, "cargo metadata failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Resolves `binary` built in `target_dir`.
+///
+/// Host builds have no triple folder and are always macOS, runner is used on macs only.
+pub(crate) fn resolve(
+    binary: &Path,
+    target_dir: &Path,
+    profiles: Option<&Profiles>,
+) -> Result<Target, Error> {
+    let rel = binary
+        .strip_prefix(target_dir)
+        .map_err(|_| Error::OutsideTargetDir {
+            binary: binary.to_path_buf(),
+            target_dir: target_dir.to_path_buf(),
+        })?;
+    let layout = || Error::UnexpectedLayout(rel.to_path_buf());
+    let comps = rel
+        .iter()
+        .map(|c| c.to_str().ok_or_else(layout))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let is_kind = |s: &str| s == "examples" || s == "deps";
+    let (triple, profile, kind, name) = match comps.as_slice() {
+        [profile, name] => (None, *profile, None, *name),
+        [profile, kind, name] if is_kind(kind) => (None, *profile, Some(*kind), *name),
+        [triple, profile, name] => (Some(*triple), *profile, None, *name),
+        [triple, profile, kind, name] if is_kind(kind) => {
+            (Some(*triple), *profile, Some(*kind), *name)
+        }
+        _ => return Err(layout()),
+    };
+
+    let sdk = match triple {
+        None => Sdk::MacOsx,
+        Some(triple) => {
+            Sdk::from_triple(triple).ok_or_else(|| Error::UnsupportedTriple(triple.to_string()))?
+        }
+    };
+
+    let kind = match kind {
+        None => Kind::Bin,
+        Some("examples") => Kind::Example,
+        Some(_) => Kind::Dep,
+    };
+
+    let name = match kind {
+        // uuid-3968b503a26aa57f
+        Kind::Dep => name.rsplit_once('-').map_or(name, |(name, _hash)| name),
+        Kind::Bin | Kind::Example => name,
+    };
+
+    Ok(Target {
+        triple: triple.map(str::to_string),
+        sdk,
+        profile: profile.to_string(),
+        config: Config::of_profile(profile, profiles),
+        kind,
+        name: name.to_string(),
+    })
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub(crate) struct Workspace {
+    #[serde(rename = "workspace_root")]
+    pub(crate) root: PathBuf,
+    #[serde(rename = "target_directory")]
+    pub(crate) target_dir: PathBuf,
+}
+
+impl Workspace {
+    /// `cargo metadata` of the package cargo runs us for.
+    ///
+    /// Cargo passes `CARGO` and `CARGO_MANIFEST_DIR` to runners, so the same
+    /// cargo resolves the same `CARGO_TARGET_DIR`, `build.target-dir` and workspace.
+    pub(crate) fn from_cargo() -> Result<Self, Error> {
+        let mut cmd = process::Command::new(cargo());
+        cmd.args(["metadata", "--format-version", "1", "--no-deps"]);
+        if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            cmd.current_dir(dir);
+        }
+        let output = cmd
+            .stderr(process::Stdio::piped())
+            .output()
+            .map_err(|err| Error::Metadata(err.to_string()))?;
+        if !output.status.success() {
+            let err = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Metadata(err.trim().to_string()));
+        }
+        Self::from_json(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    pub(crate) fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|err| Error::Metadata(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use cargo_toml::Manifest;
+
+    use super::{Config, Error, Kind, Sdk, Target, Workspace, profile_dir, resolve};
+
+    const TARGET_DIR: &str = "/ws/target";
+
+    fn target(binary: &str) -> Result<Target, Error> {
+        resolve(Path::new(binary), Path::new(TARGET_DIR), None)
+    }
+
+    #[test]
+    fn host() {
+        assert_eq!(
+            target("/ws/target/debug/box").unwrap(),
+            Target {
+                triple: None,
+                sdk: Sdk::MacOsx,
+                profile: "debug".to_string(),
+                config: Config::Debug,
+                kind: Kind::Bin,
+                name: "box".to_string(),
+            }
+        );
+        let t = target("/ws/target/release/examples/mic").unwrap();
+        assert_eq!((t.kind, t.config), (Kind::Example, Config::Release));
+        assert_eq!(t.name, "mic");
+    }
+
+    #[test]
+    fn triples() {
+        let t = target("/ws/target/aarch64-apple-ios/release/deps/uuid-3968b503a26aa57f").unwrap();
+        assert_eq!(t.triple.as_deref(), Some("aarch64-apple-ios"));
+        assert_eq!((t.sdk, t.kind), (Sdk::IphoneOs, Kind::Dep));
+        assert_eq!(t.name, "uuid");
+
+        let t = target("/ws/target/aarch64-apple-ios-macabi/debug/app").unwrap();
+        assert_eq!((t.sdk, t.kind), (Sdk::MacCatalyst, Kind::Bin));
+        assert!(t.sdk.is_mac());
+        assert_eq!(t.sdk.products_dir(t.config), "Debug-maccatalyst");
+
+        let t = target("/ws/target/x86_64-apple-ios-sim/debug/examples/mic").unwrap();
+        assert_eq!((t.sdk, t.kind), (Sdk::IphoneSimulator, Kind::Example));
+        assert_eq!(t.sdk.products_dir(t.config), "Debug-iphonesimulator");
+
+        assert_eq!(
+            target("/ws/target/x86_64-unknown-linux-gnu/debug/app"),
+            Err(Error::UnsupportedTriple(
+                "x86_64-unknown-linux-gnu".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn sdks() {
+        for (triple, name, sim) in [
+            ("aarch64-apple-darwin", "macosx", false),
+            ("x86_64-apple-ios-macabi", "macosx", false),
+            ("aarch64-apple-ios", "iphoneos", false),
+            ("x86_64-apple-ios", "iphonesimulator", true),
+            ("aarch64-apple-tvos", "appletvos", false),
+            ("aarch64-apple-tvos-sim", "appletvsimulator", true),
+            ("arm64_32-apple-watchos", "watchos", false),
+            ("aarch64-apple-watchos-sim", "watchsimulator", true),
+            ("aarch64-apple-visionos", "xros", false),
+            ("aarch64-apple-visionos-sim", "xrsimulator", true),
+        ] {
+            let sdk = Sdk::from_triple(triple).unwrap();
+            assert_eq!(sdk.name(), name, "{triple}");
+            assert_eq!(sdk.is_simulator(), sim, "{triple}");
+            assert!(sdk.destination().starts_with("generic/platform="));
+        }
+    }
+
+    #[test]
+    fn artifacts() {
+        let t = target("/ws/target/aarch64-apple-ios/debug/examples/au-host").unwrap();
+        assert_eq!(
+            t.cargo_args(),
+            ["--profile", "dev", "--target", "aarch64-apple-ios"]
+        );
+        let target_dir = Path::new(TARGET_DIR);
+        assert_eq!(
+            t.artifact(target_dir, Kind::Example, "au-ext", true),
+            Path::new("/ws/target/aarch64-apple-ios/debug/examples/libau_ext.dylib")
+        );
+        assert_eq!(
+            t.artifact(target_dir, Kind::Bin, "widget", false),
+            Path::new("/ws/target/aarch64-apple-ios/debug/widget")
+        );
+
+        let t = target("/ws/target/release-lto/app").unwrap();
+        assert_eq!(t.cargo_args(), ["--profile", "release-lto"]);
+        assert_eq!(profile_dir("dev"), "debug");
+        assert_eq!(profile_dir("bench"), "release");
+        assert_eq!(profile_dir("release-lto"), "release-lto");
+    }
+
+    #[test]
+    fn custom_profiles() {
+        let man = Manifest::from_str(
+            r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[profile.release-lto]
+inherits = "release"
+lto = true
+
+[profile.profiling]
+inherits = "dev"
+
+[profile.profiling-opt]
+inherits = "profiling"
+opt-level = 1
+"#,
+        )
+        .unwrap();
+        let profiles = Some(&man.profile);
+        let binary = Path::new("/tmp/out/aarch64-apple-ios/profiling-opt/app");
+        let t = resolve(binary, Path::new("/tmp/out"), profiles).unwrap();
+        assert_eq!(
+            (t.profile.as_str(), t.config),
+            ("profiling-opt", Config::Debug)
+        );
+
+        assert_eq!(Config::of_profile("release-lto", profiles), Config::Release);
+        assert_eq!(Config::of_profile("unknown", profiles), Config::Release);
+        assert_eq!(Config::of_profile("debug", None), Config::Debug);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            target("/other/debug/app"),
+            Err(Error::OutsideTargetDir { .. })
+        ));
+        assert_eq!(
+            target("/ws/target/aarch64-apple-ios/debug/build/app/out"),
+            Err(Error::UnexpectedLayout(
+                "aarch64-apple-ios/debug/build/app/out".into()
+            ))
+        );
+        assert!(matches!(
+            target("/ws/target/app"),
+            Err(Error::UnexpectedLayout(_))
+        ));
+    }
+
+    #[test]
+    fn metadata() {
+        let ws = Workspace::from_json(
+            r#"{"packages":[],"target_directory":"/tmp/out","workspace_root":"/ws","version":1}"#,
+        )
+        .unwrap();
+        assert_eq!(ws.root, Path::new("/ws"));
+        assert_eq!(ws.target_dir, Path::new("/tmp/out"));
+        assert!(matches!(
+            Workspace::from_json("{}"),
+            Err(Error::Metadata(_))
+        ));
+    }
+}
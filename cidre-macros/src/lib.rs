@@ -2,7 +2,7 @@
 ///
 use std::{borrow::Cow, str::FromStr};
 
-use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 enum Attr {
     Optional,
@@ -58,7 +58,7 @@ impl Attr {
                                 return None;
                             };
                             let sel = a.stream().to_string().replace([' ', '\n'], "");
-                            Some(Attr::MsgSend(sel))
+                            Some(Attr::MsgSend(strip_opts(sel)))
                         }
                         "available" => {
                             let Some(TokenTree::Group(a)) = iter.next() else {
@@ -228,16 +228,14 @@ pub fn protocol(args: TokenStream, ts: TokenStream) -> TokenStream {
                         Cow::Borrowed("None")
                     } else {
                         ext = "extern \"C\" ";
-                        fn_args_str = fn_args_str.replacen("(& self", "(&self", 1);
-                        fn_args_str =
-                            fn_args_str.replacen("(&self", "(&self, _cmd: Option<&objc::Sel>", 1);
-
-                        fn_args_str = fn_args_str.replacen("(& mut self", "(&mut self", 1);
-                        fn_args_str = fn_args_str.replacen(
-                            "(&mut self",
-                            "(&mut self, _cmd: Option<&objc::Sel>",
-                            1,
-                        );
+                        let mut parts: Vec<String> = args_split(&args)
+                            .into_iter()
+                            .map(|arg| TokenStream::from_iter(arg).to_string())
+                            .collect();
+                        if parts.first().is_some_and(|p| p.ends_with("self")) {
+                            parts.insert(1, "_cmd: Option<&objc::Sel>".to_string());
+                            fn_args_str = format!("({})", parts.join(", "));
+                        }
                         Cow::Owned(format!(
                             "unsafe {{ objc::sel_reg_name(c\"{sel}\".as_ptr()) }}"
                         ))
@@ -276,7 +274,12 @@ pub fn protocol(args: TokenStream, ts: TokenStream) -> TokenStream {
                                     "
                         )
                     } else {
-                        fn_names.push((fn_name.clone(), gen_rar_version));
+                        let argc = if lifetimes_only(&generics) {
+                            Some(args_count(&args) + !sel.is_empty() as usize)
+                        } else {
+                            None
+                        };
+                        fn_names.push((fn_name.clone(), gen_rar_version, argc));
                         if gen_rar_version {
                             let ret = ret.replacen("arc :: R <", "arc :: Rar <", 1);
                             format!(
@@ -329,7 +332,16 @@ pub fn protocol(args: TokenStream, ts: TokenStream) -> TokenStream {
                             has_optionals = true;
                             is_optional = true;
                         }
-                        Some(Attr::MsgSend(s)) => sel = s,
+                        Some(Attr::MsgSend(s)) => {
+                            if let Some(span) = throws_span(g.stream()) {
+                                return error(
+                                    span,
+                                    "`throws` is not supported in #[objc::protocol], \
+                                     declare throwing method in inherent impl",
+                                );
+                            }
+                            sel = s
+                        }
                         Some(_) => continue,
                         None => continue,
                     }
@@ -365,7 +377,7 @@ pub fn protocol(args: TokenStream, ts: TokenStream) -> TokenStream {
     let code = format!(
         "
 
-{pre} {obj_trait_name}: {trait_name} {{
+{pre} {obj_trait_name}: {trait_name} + objc::Obj {{
     {fns}
     {add_methods}
     {add_protocol}
@@ -379,23 +391,138 @@ pub fn protocol(args: TokenStream, ts: TokenStream) -> TokenStream {
     original_trait
 }
 
-fn add_methods_fn(fns: &[(String, bool)]) -> String {
+/// Splits fn args group into top level arguments.
+fn args_split(args: &Group) -> Vec<Vec<TokenTree>> {
+    let mut res = vec![];
+    let mut arg = vec![];
+    let mut depth = 0;
+    let mut arrow = false;
+    for tt in args.stream() {
+        if let TokenTree::Punct(ref p) = tt {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !arrow => depth -= 1,
+                ',' if depth == 0 => {
+                    res.push(std::mem::take(&mut arg));
+                    arrow = false;
+                    continue;
+                }
+                _ => {}
+            }
+            arrow = p.as_char() == '-';
+        } else {
+            arrow = false;
+        }
+        arg.push(tt);
+    }
+    if !arg.is_empty() {
+        res.push(arg);
+    }
+    res
+}
+
+/// Generics without type params, so fn item can be coerced to fn pointer.
+fn lifetimes_only(generics: &[TokenTree]) -> bool {
+    let mut lifetime = false;
+    for tt in generics {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '\'' => lifetime = true,
+            TokenTree::Ident(_) if !lifetime => return false,
+            _ => lifetime = false,
+        }
+    }
+    true
+}
+
+fn args_count(args: &Group) -> usize {
+    args_split(args).len()
+}
+
+/// Generates `super_{fn_name}` calling implementation of superclass.
+///
+/// Skips `_cmd`, selector is taken from `sel_{fn_name}`.
+fn super_fn(fn_name: &str, generics: &str, args: &Group, ret: &[TokenTree]) -> Option<String> {
+    let args = args_split(args);
+    if args.len() < 2 {
+        return None;
+    }
+    let receiver = TokenStream::from_iter(args[0].clone()).to_string();
+    let receiver = receiver.replace("& mut", "&mut").replace("& self", "&self");
+    let mut fn_args = vec![receiver];
+    let mut imp_args = vec![
+        "*const std::ffi::c_void".to_string(),
+        "&objc::Sel".to_string(),
+    ];
+    let mut call_args = vec!["receiver".to_string(), "sel".to_string()];
+    for (i, arg) in args[2..].iter().enumerate() {
+        let pos = arg
+            .iter()
+            .position(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ':'))?;
+        let ty = TokenStream::from_iter(arg[pos + 1..].iter().cloned()).to_string();
+        fn_args.push(format!("a{i}: {ty}"));
+        imp_args.push("_".to_string());
+        call_args.push(format!("a{i}"));
+    }
+    let ret = TokenStream::from_iter(ret.iter().cloned()).to_string();
+    let imp_ret = if ret.is_empty() { "" } else { "-> _" };
+    let fn_args = fn_args.join(", ");
+    let imp_args = imp_args.join(", ");
+    let call_args = call_args.join(", ");
+    Some(format!(
+        "
+    #[allow(dead_code)]
+    fn super_{fn_name}{generics}({fn_args}) {ret} {{
+        unsafe {{
+            let sup = objc::Super {{
+                receiver: self as *const Self as *mut objc::Id,
+                super_class: Self::super_cls(),
+            }};
+            let sel = Self::sel_{fn_name}();
+            let (receiver, imp) = objc::super_imp(&sup, sel);
+            let imp: extern \"C\" fn({imp_args}) {imp_ret} = std::mem::transmute(imp);
+            imp({call_args})
+        }}
+    }}
+        "
+    ))
+}
+
+fn add_methods_fn(fns: &[(String, bool, Option<usize>)]) -> String {
     let mut res = "
     fn cls_add_methods<O: objc::Obj>(cls: &objc::Class<O>) {
         let cls: &objc::Class<objc::Id> = unsafe { std::mem::transmute(cls) };
         "
     .to_string();
-    for (f, ar) in fns {
+    for (f, ar, argc) in fns {
         let suffix = if *ar { "_ar" } else { "" };
-        let add = format!(
-            "
+        let add = if let Some(argc) = argc {
+            let args = vec!["_"; *argc].join(", ");
+            format!(
+                "
+        let sel = Self::sel_{f}();
+        unsafe {{
+            let imp: extern \"C\" fn({args}) -> _ = Self::impl_{f}{suffix};
+            let types = {{
+                use objc::{{MethodTypesAny as _, MethodTypesEncode as _}};
+                objc::MethodTypes::of(imp).types()
+            }};
+            let imp: extern \"C\" fn() = std::mem::transmute(imp as *const u8);
+            let types = types.as_ref().map_or(std::ptr::null(), |t| t.as_ptr().cast());
+            objc::class_addMethod(cls, sel, imp, types);
+        }}
+            ",
+            )
+        } else {
+            format!(
+                "
         let sel = Self::sel_{f}();
         unsafe {{
             let imp: extern \"C\" fn() = std::mem::transmute(Self::impl_{f}{suffix} as *const u8);
             objc::class_addMethod(cls, sel, imp, std::ptr::null());
         }}
             ",
-        );
+            )
+        };
         res.push_str(&add);
     }
     res.push_str("\n}");
@@ -407,10 +534,13 @@ pub fn add_methods(_args: TokenStream, tr_impl: TokenStream) -> TokenStream {
 
     let iter = tr_impl.into_iter();
     let mut fns = vec![];
+    let mut super_fns = vec![];
+    let mut self_ty = vec![];
+    let mut after_for = false;
 
     for tt in iter {
         match tt {
-            TokenTree::Group(g) => {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => {
                 let mut body = g.stream().into_iter();
                 while let Some(tt) = body.next() {
                     match tt {
@@ -419,11 +549,36 @@ pub fn add_methods(_args: TokenStream, tr_impl: TokenStream) -> TokenStream {
                                 panic!("expected function name");
                             };
                             let f = f.to_string().replacen("impl_", "", 1);
-                            if let Some(f) = f.strip_suffix("_ar") {
-                                fns.push((f.to_string(), true));
-                            } else {
-                                fns.push((f, false));
+                            let (f, ar) = match f.strip_suffix("_ar") {
+                                Some(f) => (f.to_string(), true),
+                                None => (f, false),
+                            };
+                            let mut generics = vec![];
+                            let args = loop {
+                                match body.next() {
+                                    Some(TokenTree::Group(args)) => break Some(args),
+                                    Some(tt) => generics.push(tt),
+                                    None => break None,
+                                }
+                            };
+                            let mut argc = None;
+                            if let Some(args) = args.filter(|_| lifetimes_only(&generics)) {
+                                argc = Some(args_count(&args));
+                                let mut ret = vec![];
+                                for tt in body.by_ref() {
+                                    match tt {
+                                        TokenTree::Group(ref g)
+                                            if g.delimiter() == Delimiter::Brace =>
+                                        {
+                                            break
+                                        }
+                                        _ => ret.push(tt),
+                                    }
+                                }
+                                let generics = TokenStream::from_iter(generics).to_string();
+                                super_fns.extend(super_fn(&f, &generics, &args, &ret));
                             }
+                            fns.push((f, ar, argc));
                         }
                         _ => continue,
                     }
@@ -434,34 +589,429 @@ pub fn add_methods(_args: TokenStream, tr_impl: TokenStream) -> TokenStream {
                 let g = Group::new(g.delimiter(), stream);
                 tokens.push(TokenTree::Group(g));
             }
-            _ => tokens.push(tt),
+            TokenTree::Ident(ref i) if i.to_string() == "for" => {
+                after_for = true;
+                tokens.push(tt);
+            }
+            _ => {
+                if after_for {
+                    self_ty.push(tt.clone());
+                }
+                tokens.push(tt)
+            }
         }
     }
 
-    // println!("fns {fns:?}");
+    let self_ty = TokenStream::from_iter(self_ty).to_string();
+    // generic impls are not supported
+    if !super_fns.is_empty() && !self_ty.contains('<') {
+        let super_fns = super_fns.join("\n");
+        let code = format!("impl {self_ty} {{ {super_fns} }}");
+        tokens.extend(code.parse::<TokenStream>().unwrap());
+    }
 
     TokenStream::from_iter(tokens)
 }
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Dispatch {
+    /// `objc_msgSend$sel` stubs generated by Apple linker
+    SelStubs,
+    /// `objc_msgSend`, `objc_msgSend_stret`, `objc_msgSend_fpret` with registered selector
+    MsgSend,
+    /// GNUstep libobjc2 `objc_msg_lookup` returning `IMP`
+    MsgLookup,
+}
+
 #[proc_macro_attribute]
 pub fn msg_send_debug(sel: TokenStream, func: TokenStream) -> TokenStream {
-    let x86_64 = false;
-    gen_msg_send(sel, func, x86_64, true)
+    gen_msg_send(sel, func, Dispatch::SelStubs, true)
 }
 
 #[proc_macro_attribute]
 pub fn msg_send(sel: TokenStream, func: TokenStream) -> TokenStream {
-    let x86_64 = false;
-    gen_msg_send(sel, func, x86_64, false)
+    gen_msg_send(sel, func, Dispatch::SelStubs, false)
 }
 
 #[proc_macro_attribute]
 pub fn msg_send_x86_64(sel: TokenStream, func: TokenStream) -> TokenStream {
-    let x86_64 = true;
-    gen_msg_send(sel, func, x86_64, false)
+    gen_msg_send(sel, func, Dispatch::MsgSend, false)
+}
+
+#[proc_macro_attribute]
+pub fn msg_send_gnustep(sel: TokenStream, func: TokenStream) -> TokenStream {
+    gen_msg_send(sel, func, Dispatch::MsgLookup, false)
+}
+
+#[proc_macro_attribute]
+pub fn msg_send_gnustep_debug(sel: TokenStream, func: TokenStream) -> TokenStream {
+    gen_msg_send(sel, func, Dispatch::MsgLookup, true)
+}
+
+#[derive(Default)]
+struct MsgSendOpts {
+    /// generate `try_*` version catching exceptions
+    throws: bool,
+    /// generate `KEY_PATH_*` constant for getter
+    kvo: bool,
+}
+
+/// `sel, throws, kvo` -> `sel`
+///
+/// Options are validated by `msg_send` itself.
+fn strip_opts(sel: String) -> String {
+    sel.split(',').next().unwrap_or_default().to_string()
+}
+
+/// Span of `throws` option in `msg_send(sel, throws)` attribute stream.
+fn throws_span(attr: TokenStream) -> Option<Span> {
+    attr.into_iter().find_map(|tt| match tt {
+        TokenTree::Group(g) => g.stream().into_iter().find_map(|tt| match tt {
+            TokenTree::Ident(i) if i.to_string() == "throws" => Some(i.span()),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
+/// `compile_error!` pointing at `span`.
+fn error(span: Span, msg: &str) -> TokenStream {
+    let mut lit = Literal::string(msg);
+    lit.set_span(span);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(lit).into());
+    args.set_span(span);
+    TokenStream::from_iter([
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct({
+            let mut p = Punct::new('!', Spacing::Alone);
+            p.set_span(span);
+            p
+        }),
+        TokenTree::Group(args),
+        TokenTree::Punct({
+            let mut p = Punct::new(';', Spacing::Alone);
+            p.set_span(span);
+            p
+        }),
+    ])
+}
+
+/// `sel:with:, throws, kvo` -> (`sel:with:`, opts)
+fn parse_sel(sel: TokenStream) -> Result<(String, MsgSendOpts), TokenStream> {
+    let mut iter = sel.into_iter();
+    let mut name = String::new();
+    for tt in iter.by_ref() {
+        match tt {
+            TokenTree::Punct(ref p) if p.as_char() == ',' => break,
+            TokenTree::Ident(ref i) => name.push_str(&i.to_string()),
+            TokenTree::Punct(ref p) if p.as_char() == ':' => name.push(':'),
+            tt => return Err(error(tt.span(), "expected selector")),
+        }
+    }
+    if name.is_empty() {
+        return Err(error(Span::call_site(), "expected selector"));
+    }
+    let mut opts = MsgSendOpts::default();
+    for tt in iter {
+        match tt {
+            TokenTree::Punct(ref p) if p.as_char() == ',' => {}
+            TokenTree::Ident(ref i) if i.to_string() == "throws" => opts.throws = true,
+            TokenTree::Ident(ref i) if i.to_string() == "kvo" => opts.kvo = true,
+            tt => {
+                return Err(error(
+                    tt.span(),
+                    &format!("unknown msg_send option `{tt}`, expected `throws` or `kvo`"),
+                ))
+            }
+        }
+    }
+    Ok((name, opts))
+}
+
+/// Argument of `msg_send` function.
+struct FnArg {
+    name: String,
+    ty: Vec<TokenTree>,
+}
+
+/// Receiver and arguments of `msg_send` function.
+struct FnArgs {
+    /// `Self`, `& Self`, `& 'a mut Self`. `None` for class methods.
+    receiver: Option<Vec<TokenTree>>,
+    args: Vec<FnArg>,
 }
 
-fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool) -> TokenStream {
-    let sel = sel.to_string().replace([' ', '\n'], "");
+impl FnArgs {
+    fn parse(args: &Group) -> Result<Self, TokenStream> {
+        let mut res = Self {
+            receiver: None,
+            args: Vec::new(),
+        };
+        for (i, arg) in args_split(args).into_iter().enumerate() {
+            let is_self =
+                |tt: &TokenTree| matches!(tt, TokenTree::Ident(i) if i.to_string() == "self");
+            if arg.iter().any(is_self) {
+                let mut receiver = arg;
+                if matches!(&receiver[0], TokenTree::Ident(i) if i.to_string() == "mut") {
+                    receiver.remove(0);
+                }
+                if i != 0 || !is_self(receiver.last().unwrap()) {
+                    return Err(error(
+                        receiver[0].span(),
+                        "expected `self`, `&self` or `&mut self`",
+                    ));
+                }
+                // self -> Self
+                let last = receiver.len() - 1;
+                receiver[last] = TokenTree::Ident(Ident::new("Self", receiver[last].span()));
+                res.receiver = Some(receiver);
+                continue;
+            }
+            let mut iter = arg.iter().peekable();
+            if matches!(iter.peek(), Some(TokenTree::Ident(i)) if i.to_string() == "mut") {
+                iter.next();
+            }
+            let (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(colon))) =
+                (iter.next(), iter.next())
+            else {
+                return Err(error(arg[0].span(), "expected `name: Type` argument"));
+            };
+            let name = name.to_string();
+            if colon.as_char() != ':' || name == "_" {
+                return Err(error(arg[0].span(), "expected `name: Type` argument"));
+            }
+            let ty: Vec<TokenTree> = iter.cloned().collect();
+            if ty.is_empty() {
+                return Err(error(colon.span(), "expected argument type"));
+            }
+            res.args.push(FnArg { name, ty });
+        }
+        Ok(res)
+    }
+
+    fn is_class(&self) -> bool {
+        self.receiver.is_none()
+    }
+
+    fn vars(&self) -> Vec<String> {
+        self.args.iter().map(|a| a.name.clone()).collect()
+    }
+
+    /// `id: & Self` or `cls: *const c_void`
+    fn receiver_param(&self) -> String {
+        match &self.receiver {
+            Some(receiver) => format!("id: {}", TokenStream::from_iter(receiver.clone())),
+            None => "cls: *const std::ffi::c_void".to_string(),
+        }
+    }
+
+    fn params(&self) -> String {
+        self.args
+            .iter()
+            .map(|a| format!("{}: {}", a.name, TokenStream::from_iter(a.ty.clone())))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+const INTEGERS: &[&str] = &[
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "isize", "usize",
+];
+
+/// Why `ty` can't be passed to or returned from `objc_msgSend`.
+fn ffi_unsafe(ty: &[TokenTree]) -> Option<(Span, String)> {
+    let first = ty.first()?;
+    let span = first.span();
+    let mut ty_str = String::new();
+    for (i, tt) in ty.iter().enumerate() {
+        if i > 0
+            && matches!(
+                (&ty[i - 1], tt),
+                (
+                    TokenTree::Ident(_),
+                    TokenTree::Ident(_) | TokenTree::Group(_)
+                )
+            )
+        {
+            ty_str.push(' ');
+        }
+        ty_str.push_str(&tt.to_string());
+    }
+    match first {
+        TokenTree::Punct(p) if p.as_char() == '&' => {
+            let mut iter = ty[1..].iter().peekable();
+            // lifetime
+            if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '\'') {
+                iter.next();
+                iter.next();
+            }
+            if matches!(iter.peek(), Some(TokenTree::Ident(i)) if i.to_string() == "mut") {
+                iter.next();
+            }
+            match iter.next() {
+                Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Bracket => Some((
+                    span,
+                    format!("`{ty_str}` is a fat pointer, pass pointer and length instead"),
+                )),
+                Some(TokenTree::Ident(i)) if i.to_string() == "str" => Some((
+                    span,
+                    format!("`{ty_str}` is a fat pointer, use `&ns::String` or `*const c_char`"),
+                )),
+                Some(TokenTree::Ident(i)) if i.to_string() == "dyn" => Some((
+                    span,
+                    format!("`{ty_str}` is a fat pointer, trait objects are not FFI-safe"),
+                )),
+                _ => None,
+            }
+        }
+        TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => {
+            if g.stream().is_empty() {
+                None
+            } else {
+                Some((span, format!("tuple `{ty_str}` has no C representation")))
+            }
+        }
+        TokenTree::Group(g) if g.delimiter() == Delimiter::Bracket => Some((
+            span,
+            format!("array `{ty_str}` can't be passed by value, wrap it in `#[repr(C)]` struct"),
+        )),
+        TokenTree::Ident(i) if i.to_string() == "impl" || i.to_string() == "dyn" => {
+            Some((span, format!("`{ty_str}` is not FFI-safe")))
+        }
+        TokenTree::Ident(_) => {
+            let path: String = ty
+                .iter()
+                .take_while(|tt| !matches!(tt, TokenTree::Punct(p) if p.as_char() == '<'))
+                .map(|tt| tt.to_string())
+                .collect();
+            match path.as_str() {
+                "char" | "i128" | "u128" => {
+                    Some((span, format!("`{ty_str}` has no stable C representation")))
+                }
+                "String" | "std::string::String" | "Vec" | "std::vec::Vec" | "str" => Some((
+                    span,
+                    format!(
+                        "`{ty_str}` is not FFI-safe, Objective-C objects are passed by reference"
+                    ),
+                )),
+                "Option" | "std::option::Option" => {
+                    // `std :: option :: Option < T >`
+                    let inner = ty.get(path.matches("::").count() * 3 + 2)?;
+                    let no_niche = match inner {
+                        TokenTree::Ident(inner) => {
+                            let inner = inner.to_string();
+                            inner == "bool"
+                                || inner == "char"
+                                || inner == "f32"
+                                || inner == "f64"
+                                || INTEGERS.contains(&inner.as_str())
+                        }
+                        TokenTree::Group(_) => true,
+                        _ => false,
+                    };
+                    if no_niche {
+                        let reason = "only `Option` of references and pointers is FFI-safe";
+                        Some((
+                            span,
+                            format!("`{ty_str}` has no C representation, {reason}"),
+                        ))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `#[cfg(...)]` attributes of function
+fn cfg_attrs(meta: &[TokenTree]) -> String {
+    let mut res = String::new();
+    for w in meta.windows(2) {
+        let (TokenTree::Punct(p), TokenTree::Group(g)) = (&w[0], &w[1]) else {
+            continue;
+        };
+        if p.as_char() != '#' || g.delimiter() != Delimiter::Bracket {
+            continue;
+        }
+        if let Some(TokenTree::Ident(i)) = g.stream().into_iter().next() {
+            if i.to_string() == "cfg" {
+                res.push_str(&format!("#{g}\n"));
+            }
+        }
+    }
+    res
+}
+
+/// Visibility among fn attributes and qualifiers: `pub`, `pub(crate)` or empty.
+fn vis(meta: &[TokenTree]) -> String {
+    let Some(pos) = meta
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == "pub"))
+    else {
+        return String::new();
+    };
+    match meta.get(pos + 1) {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => format!("pub{g}"),
+        _ => "pub".to_string(),
+    }
+}
+
+/// Fn generics with version of `api::Available` proof: `<T>` -> `<T, const V: u32>`.
+fn proof_generics(gen: &str) -> String {
+    match gen.trim().strip_suffix('>') {
+        Some(gen) => format!("{}, const V: u32>", gen.trim_end().trim_end_matches(',')),
+        None => "<const V: u32>".to_string(),
+    }
+}
+
+/// Fn args with `api::Available` proof at the end.
+fn proof_args(args: &Group, av: &str) -> String {
+    let args = args.stream().to_string();
+    let args = args.trim().trim_end_matches(',');
+    if args.is_empty() {
+        format!("({av}: crate::api::Available<V>)")
+    } else {
+        format!("({args}, {av}: crate::api::Available<V>)")
+    }
+}
+
+/// KVO key of getter selector: `isReady` -> `ready`.
+fn kvo_key(sel: &str) -> String {
+    let Some(rest) = sel.strip_prefix("is") else {
+        return sel.to_string();
+    };
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_uppercase() => {
+            let mut key = c.to_ascii_lowercase().to_string();
+            key.push_str(chars.as_str());
+            key
+        }
+        _ => sel.to_string(),
+    }
+}
+
+fn gen_msg_send(
+    sel: TokenStream,
+    func: TokenStream,
+    dispatch: Dispatch,
+    debug: bool,
+) -> TokenStream {
+    try_gen_msg_send(sel, func, dispatch, debug).unwrap_or_else(|err| err)
+}
+
+fn try_gen_msg_send(
+    sel: TokenStream,
+    func: TokenStream,
+    dispatch: Dispatch,
+    debug: bool,
+) -> Result<TokenStream, TokenStream> {
+    // both `objc_msgSend` and `objc_msg_lookup` take registered selector
+    let x86_64 = dispatch != Dispatch::SelStubs;
+    let (sel, opts) = parse_sel(sel)?;
+    let throws = opts.throws;
     let sel_args_count = sel.matches(':').count();
 
     let mut iter = func.into_iter();
@@ -502,7 +1052,9 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
                             meta.push(TokenTree::Group(g));
                             continue;
                         }
-                        Some(Attr::MsgSend(_)) => panic!("only one msg_send is allowed"),
+                        Some(Attr::MsgSend(_)) => {
+                            return Err(error(g.span(), "only one msg_send is allowed"));
+                        }
                         None => {}
                     }
                 }
@@ -521,11 +1073,11 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
         meta.push(tt);
     }
 
-    let Some(TokenTree::Ident(fn_name)) = iter.next() else {
-        panic!("expected function name");
+    let Some(TokenTree::Ident(fn_ident)) = iter.next() else {
+        return Err(error(Span::call_site(), "expected function name"));
     };
 
-    let fn_name = fn_name.to_string();
+    let fn_name = fn_ident.to_string();
     let mut generics = Vec::new();
     let doc_alias = if fn_name != sel {
         format!("#[doc(alias = \"{sel}\")]")
@@ -534,23 +1086,41 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
     };
 
     let args = loop {
-        let Some(tt) = iter.next() else {
-            panic!("need more tokens");
-        };
-        match tt {
-            TokenTree::Group(args) => break args,
-            _ => generics.push(tt),
+        match iter.next() {
+            Some(TokenTree::Group(args)) if args.delimiter() == Delimiter::Parenthesis => {
+                break args;
+            }
+            Some(tt) => generics.push(tt),
+            None => return Err(error(fn_ident.span(), "expected function arguments")),
         }
     };
 
     let gen = TokenStream::from_iter(generics).to_string();
 
-    let mut ret = TokenStream::from_iter(iter).to_string();
-    assert_eq!(ret.pop().expect(";"), ';');
-    let ret_full = ret.to_string();
-    if let Some((a, _)) = ret.split_once("where") {
-        ret = a.to_string();
+    let mut rest: Vec<TokenTree> = iter.collect();
+    match rest.pop() {
+        Some(TokenTree::Punct(p)) if p.as_char() == ';' => {}
+        Some(tt) => return Err(error(tt.span(), "msg_send function should have no body")),
+        None => return Err(error(args.span(), "expected `;`")),
     }
+    let where_pos = rest
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == "where"))
+        .unwrap_or(rest.len());
+    let where_ = TokenStream::from_iter(rest[where_pos..].iter().cloned()).to_string();
+    let ret_tokens = &rest[..where_pos];
+    // `-> T`
+    let ret_ty = match ret_tokens {
+        [] => &[][..],
+        [TokenTree::Punct(a), TokenTree::Punct(b), ty @ ..]
+            if a.as_char() == '-' && b.as_char() == '>' && !ty.is_empty() =>
+        {
+            ty
+        }
+        [tt, ..] => return Err(error(tt.span(), "expected `-> Type`, `where` or `;`")),
+    };
+    let ret = TokenStream::from_iter(ret_tokens.iter().cloned()).to_string();
+    let ret_full = TokenStream::from_iter(rest.iter().cloned()).to_string();
     let option = ret_full.contains("-> Option <");
     if debug {
         println!("{option}: {ret_full}");
@@ -562,72 +1132,87 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
         println!("option: {option}, gen_rar_version {gen_rar_version} ret: {ret}");
     }
 
-    let msg_send_fn = if x86_64 {
-        choose_msg_send_fn(&ret_full)
-    } else {
-        ""
-    };
-
-    let fn_args = args.to_string();
-
-    let (class, vars) = fn_args_from_stream(args.stream());
+    let fn_args = FnArgs::parse(&args)?;
+    let class = fn_args.is_class();
+    let vars = fn_args.vars();
     let fn_args_count = vars.len();
 
-    assert_eq!(
-        sel_args_count, fn_args_count,
-        "selector and function args don't match {vars:?}"
-    );
+    if sel_args_count != fn_args_count {
+        return Err(error(
+            args.span(),
+            &format!(
+                "selector `{sel}` takes {sel_args_count} argument(s) but function has {fn_args_count}"
+            ),
+        ));
+    }
 
-    let (mut fn_args, mut call_args) = if x86_64 {
-        let fn_args = fn_args.replacen('(', "(id:", 1).replacen(
-            "self",
-            "Self, imp: *const std::ffi::c_void",
-            1,
-        );
+    for arg in fn_args.args.iter() {
+        if let Some((span, reason)) = ffi_unsafe(&arg.ty) {
+            return Err(error(
+                span,
+                &format!(
+                    "argument `{}` can't be passed to objc_msgSend: {reason}",
+                    arg.name
+                ),
+            ));
+        }
+    }
+    if let Some((span, reason)) = ffi_unsafe(ret_ty) {
+        return Err(error(
+            span,
+            &format!("can't be returned from objc_msgSend: {reason}"),
+        ));
+    }
+
+    let receiver = if class { "Self::cls_ptr()" } else { "self" };
+    let params = fn_args.params();
+    let (fn_args, call_args) = if x86_64 {
         (
-            fn_args,
-            format!("sig(self, x86_64_sel, {})", vars.join(", ")),
+            format!(
+                "({}, imp: *const std::ffi::c_void, {params})",
+                fn_args.receiver_param()
+            ),
+            format!("sig({receiver}, x86_64_sel, {})", vars.join(", ")),
         )
     } else if fn_args_count == 0 {
-        let fn_args = fn_args
-            .replacen("( &", "(id: &", 1)
-            .replacen("self", "Self", 1);
-        (fn_args, "sig(self)".to_string())
+        (
+            format!("({})", fn_args.receiver_param()),
+            format!("sig({receiver})"),
+        )
     } else {
-        let fn_args = fn_args
-            .replacen('(', "(id:", 1)
-            .replace("self", "Self, imp: *const std::ffi::c_void");
         (
-            fn_args,
-            format!("sig(self, std::ptr::null(), {})", vars.join(", ")),
+            format!(
+                "({}, imp: *const std::ffi::c_void, {params})",
+                fn_args.receiver_param()
+            ),
+            format!("sig({receiver}, std::ptr::null(), {})", vars.join(", ")),
         )
     };
-
-    if class {
-        if x86_64 {
-            fn_args = fn_args.replacen(
-                "(id:",
-                "(cls: *const std::ffi::c_void, imp: *const std::ffi::c_void, ",
-                1,
-            );
-            call_args = call_args.replacen("sig(self", "sig(Self::cls_ptr()", 1);
-        } else if fn_args_count == 0 {
-            fn_args = fn_args.replacen('(', "(cls: *const std::ffi::c_void", 1);
-            call_args = call_args.replacen("sig(self", "sig(Self::cls_ptr()", 1);
-        } else {
-            fn_args = fn_args.replacen(
-                "(id:",
-                "(cls: *const std::ffi::c_void, imp: *const std::ffi::c_void, ",
-                1,
-            );
-            call_args = call_args.replacen("sig(self", "sig(Self::cls_ptr()", 1);
-        }
-    }
-
     let available = versions.available_cfg();
     let unavailable = versions.unavailable_cfg();
 
+    let throws_ret = if throws {
+        if !unsafe_already {
+            return Err(error(
+                fn_ident.span(),
+                &format!("throwing `{fn_name}` should be unsafe"),
+            ));
+        }
+        let t = ret.trim().strip_prefix("->").map(str::trim).unwrap_or("()");
+        format!("-> objc::TryResult<{t}> {where_}")
+    } else {
+        String::new()
+    };
+
     let mut flow = String::new();
+    let cfgs = cfg_attrs(&meta);
+    let vis = vis(&meta);
+    let safe_pre = TokenStream::from_iter(
+        meta.iter()
+            .filter(|tt| !matches!(tt, TokenTree::Ident(i) if i.to_string() == "unsafe"))
+            .cloned(),
+    )
+    .to_string();
     let pre = TokenStream::from_iter(meta).to_string();
     let self_ = if class { "Self::" } else { "self." };
     let vars = vars.join(", ");
@@ -646,6 +1231,34 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
     if gen_rar_version {
         impl_fn_name.push_str("_ar");
     }
+    let (msg_send_decl, lookup_decl, fn_ptr) = if dispatch == Dispatch::MsgLookup {
+        let receiver = if class { "Self::cls_ptr()" } else { "self" };
+        (
+            String::new(),
+            "fn objc_msg_lookup(receiver: *const std::ffi::c_void, sel: *const std::ffi::c_void) -> *const std::ffi::c_void;",
+            format!("objc_msg_lookup(std::mem::transmute_copy(&{receiver}), x86_64_sel)"),
+        )
+    } else {
+        // `sig` return type, `arc::R<T>` would require `T: 'static` here
+        let ret_ty = impl_ret.trim().strip_prefix("->").map_or("()", str::trim);
+        (
+            "extern \"C\" {
+            #[link_name = \"objc_msgSend\"]
+            fn msg_send();
+            #[link_name = \"objc_msgSend_stret\"]
+            fn msg_send_stret();
+        }"
+            .to_string(),
+            "",
+            format!(
+                "if objc::msg_send_stret::<{ret_ty}>() {{
+                msg_send_stret as *const std::ffi::c_void
+            }} else {{
+                msg_send as *const std::ffi::c_void
+            }}"
+            ),
+        )
+    };
     if x86_64 {
         flow.push_str(&format!(
             "
@@ -653,17 +1266,15 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
     {doc_alias}
     #[inline]
     {pre} fn {impl_fn_name}{gen}{args}{impl_ret_full} {{
-        extern \"C\" {{
-            #[link_name = \"{msg_send_fn}\"]
-            fn msg_send();
-        }}
+        {msg_send_decl}
         extern \"C-unwind\" {{
             fn sel_registerName(name: *const i8) -> *const std::ffi::c_void;
+            {lookup_decl}
         }}
 
         unsafe {{
             let x86_64_sel = sel_registerName(c\"{sel}\".as_ptr());
-            let fn_ptr = msg_send as *const std::ffi::c_void;
+            let fn_ptr = {fn_ptr};
             let sig: extern \"C\" fn{fn_args} {impl_ret} = std::mem::transmute(fn_ptr);
 
             {call_args}
@@ -696,18 +1307,15 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
     {doc_alias}
     #[inline]
     {pre} {unsafe_str} fn {impl_fn_name}{gen}{args}{impl_ret_full} {{
-        extern \"C\" {{
-            #[link_name = \"{msg_send_fn}\"]
-            fn msg_send();
-
-        }}
+        {msg_send_decl}
         extern \"C-unwind\" {{
             fn sel_registerName(name: *const i8) -> *const std::ffi::c_void;
+            {lookup_decl}
         }}
 
         unsafe {{
             let x86_64_sel = sel_registerName(c\"{sel}\".as_ptr());
-            let fn_ptr = msg_send as *const std::ffi::c_void;
+            let fn_ptr = {fn_ptr};
             let sig: extern \"C\" fn{fn_args} {impl_ret} = std::mem::transmute(fn_ptr);
 
             {call_args}
@@ -838,62 +1446,120 @@ fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool)
             }
         }
     }
-    if debug {
-        println!("{flow}");
+    // safe variant for callers with proof, skipped for trait and private fns
+    if versions.any() && !unsafe_already && !vis.is_empty() {
+        let gen = proof_generics(&gen);
+        let args = proof_args(&args, "_av");
+        let check = versions.proof_check();
+        for cfg in [versions.available_cfg(), versions.unavailable_cfg()] {
+            flow.push_str(&format!(
+                "
+
+    {cfg}
+    {cfgs}
+    /// `{fn_name}` with proof from `api::if_available!`
+    #[allow(unused_unsafe)]
+    #[inline]
+    {vis} fn {fn_name}_available{gen}{args}{ret_full} {{
+        {check}
+        unsafe {{ {self_}{fn_name}({vars}) }}
+    }}
+                "
+            ));
+        }
     }
+    if opts.kvo {
+        if class || fn_args_count != 0 {
+            return Err(error(
+                fn_ident.span(),
+                &format!("kvo `{fn_name}` should be instance getter"),
+            ));
+        }
+        let t = TokenStream::from_iter(ret_ty.iter().cloned()).to_string();
+        let t = if t.is_empty() { "()" } else { &t };
+        if t.contains('\'') || t.contains('&') {
+            return Err(error(
+                fn_ident.span(),
+                &format!("kvo `{fn_name}` should return owned value"),
+            ));
+        }
+        let key = kvo_key(&sel);
+        let cfgs = &cfgs;
+        let const_name = format!(
+            "KEY_PATH_{}",
+            fn_name
+                .strip_suffix("_throws")
+                .unwrap_or(&fn_name)
+                .to_uppercase()
+        );
+        flow.push_str(&format!(
+            "
 
-    flow.parse().unwrap()
-}
+    {cfgs}
+    /// Typed key path of `{key}`
+    pub const {const_name}: objc::KeyPath<Self, {t}> = unsafe {{ objc::KeyPath::new(c\"{key}\") }};
+            "
+        ));
+    }
 
-fn fn_args_from_stream(stream: TokenStream) -> (bool, Vec<String>) {
-    if stream.is_empty() {
-        return (true, Vec::new());
+    if throws {
+        let try_fn_name = try_fn_name(&fn_name);
+        flow.push_str(&format!(
+            "
+
+    {available}
+    {doc_alias}
+    #[inline]
+    {safe_pre} fn {try_fn_name}{gen}{args} {throws_ret} {{
+        objc::try_catch_retained(|| unsafe {{ {self_}{fn_name}({vars}) }})
+    }}
+            "
+        ));
+        if versions.any() {
+            flow.push_str(&format!(
+                "
+
+    {unavailable}
+    {doc_alias}
+    /// Check availability with selector `Self::sel_{fn_name}()`
+    #[inline]
+    {pre} fn {try_fn_name}{gen}{args} {throws_ret} {{
+        objc::try_catch_retained(|| unsafe {{ {self_}{fn_name}({vars}) }})
+    }}
+                "
+            ));
+        }
     }
-    let mut res = Vec::new();
-    let mut pos = 0;
-    let mut self_arg = false;
-    let mut skip_ident = false;
-    let mut lifetime = false;
-    let mut nesting = 0;
-    for s in stream.into_iter() {
-        match s {
-            TokenTree::Group(_) => {}
-            TokenTree::Ident(ref i) => {
-                if lifetime {
-                    lifetime = false;
-                    continue;
-                }
-                if !skip_ident {
-                    let str = i.to_string();
-                    if str == "mut" {
-                        continue;
-                    }
-                    if pos == 0 && str == "self" {
-                        self_arg = true;
-                        continue;
-                    }
-                    res.push(str);
-                    skip_ident = true;
-                }
-            }
-            // #[objc::msg_send_debug(objectForKey:)]
-            // pub fn get<'a>(&'a self, key: &K) -> Option<&'a V>;
-            TokenTree::Punct(p) => match p.as_char() {
-                '<' => nesting += 1,
-                '>' => nesting -= 1,
-                '\'' => lifetime = true,
-                // '&' => skip_ident = true,
-                ',' if nesting == 0 => {
-                    pos += 1;
-                    skip_ident = false;
-                }
-                ':' => skip_ident = true,
-                _ => {}
-            },
-            TokenTree::Literal(ref _l) => {}
+
+    if versions.any() {
+        let names = if throws {
+            vec![fn_name.clone(), try_fn_name(&fn_name)]
+        } else {
+            vec![fn_name.clone()]
+        };
+        for name in names {
+            let row = Row::new(
+                fn_ident.clone(),
+                &name,
+                !class,
+                fn_args_count,
+                Fallback::Unsafe,
+                &versions,
+            );
+            row.record();
         }
     }
-    (!self_arg, res)
+
+    if debug {
+        println!("{flow}");
+    }
+
+    Ok(flow.parse().unwrap())
+}
+
+/// `try_*` fn generated for `x_throws` or `x`.
+fn try_fn_name(fn_name: &str) -> String {
+    format!("try_{}", fn_name.strip_suffix("_throws").unwrap_or(fn_name))
 }
 
 #[proc_macro_attribute]
@@ -1049,6 +1715,33 @@ impl Version {
     }
 }
 
+/// What unavailable variant of API does.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Fallback {
+    /// Returns `None`
+    Option,
+    /// Weak function is unwrapped, panics
+    Panic,
+    /// Caller should check availability
+    Unsafe,
+    /// `define_weak_cls`, `cls()` returns `None`
+    Class,
+    /// Declaration without body is compiled out
+    Cfg,
+}
+
+impl Fallback {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Option => "option",
+            Self::Panic => "panic",
+            Self::Unsafe => "unsafe",
+            Self::Class => "class",
+            Self::Cfg => "cfg",
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 struct Versions {
     macos: Option<Version>,
@@ -1230,6 +1923,46 @@ impl Versions {
         }
     }
 
+    /// `macos = 14.0, ios = 17.0` list as in `#[api::available(..)]`
+    fn list(&self) -> String {
+        let mut vec = Vec::with_capacity(6);
+        let platforms = [
+            ("macos", self.macos),
+            ("ios", self.ios),
+            ("tvos", self.tvos),
+            ("watchos", self.watchos),
+            ("visionos", self.visionos),
+            ("maccatalyst", self.maccatalyst),
+        ];
+        for (os, v) in platforms {
+            if let Some(v) = v {
+                vec.push(format!("{os} = {}.{}", v.0, v.1));
+            }
+        }
+        vec.join(", ")
+    }
+
+    /// Versions appended to `define_cls!(CLS)` args, so the class gets
+    /// `cls_available(av)` and friends
+    fn cls_args(&self, args: &Group) -> TokenTree {
+        let list = self.list();
+        if list.is_empty() {
+            return TokenTree::Group(args.clone());
+        }
+        let stream = TokenStream::from_str(&format!("{}, {list}", args.stream())).unwrap();
+        let mut group = Group::new(args.delimiter(), stream);
+        group.set_span(args.span());
+        TokenTree::Group(group)
+    }
+
+    /// Compile time check that proof version `V` covers these versions.
+    fn proof_check(&self) -> String {
+        format!(
+            "const {{ assert!(V >= crate::api::platform_version!({}), \"proof is older than API availability\") }};",
+            self.list()
+        )
+    }
+
     fn from_stream(versions: TokenStream) -> Self {
         let mut iter = versions.into_iter();
         let mut versions = Self::default();
@@ -1292,13 +2025,37 @@ impl Versions {
 
 #[proc_macro_attribute]
 pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
-    let versions = Versions::from_stream(versions);
+    let (res, row) = available(&Versions::from_stream(versions), body);
+    if let Some(row) = row {
+        row.record();
+    }
+    res
+}
+
+/// Availability table row `#[api::available(..)]` records for `item`, for tests:
+///
+/// `api_available_row!((macos = 14.0) pub fn foo() { .. })`
+#[doc(hidden)]
+#[proc_macro]
+pub fn api_available_row(input: TokenStream) -> TokenStream {
+    let mut iter = input.into_iter();
+    let Some(TokenTree::Group(versions)) = iter.next() else {
+        panic!("expecting (versions) item");
+    };
+    let versions = Versions::from_stream(versions.stream());
+    let (_, row) = available(&versions, TokenStream::from_iter(iter));
+    let row = row.map(|r| r.api).unwrap_or_default();
+    TokenTree::Literal(Literal::string(&row)).into()
+}
+
+/// Available and unavailable variants of `body` and its availability table row.
+fn available(versions: &Versions, body: TokenStream) -> (TokenStream, Option<Row>) {
     let available = versions.available_cfg_ts();
     let available_doc = versions.available_doc_ts();
     let unavailable = versions.unavailable_cfg_ts();
     let unavailable_doc = versions.unavailable_doc_ts();
     if available.is_empty() {
-        return body;
+        return (body, None);
     }
 
     let mut no_args = false;
@@ -1312,7 +2069,9 @@ pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
     let mut maybe_res: Vec<TokenTree> = Vec::new();
     let mut fn_index = 0usize;
     let mut unsafe_already = false;
+    let mut fallback = Fallback::Unsafe;
 
+    let item: Vec<TokenTree> = body.clone().into_iter().collect();
     let mut body_iter = body.into_iter();
 
     while let Some(t) = body_iter.next() {
@@ -1352,20 +2111,34 @@ pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
                     unsafe_already = true;
                 }
                 "define_cls" => {
+                    fallback = Fallback::Class;
                     let token = TokenTree::Ident(Ident::new("define_weak_cls", t.span()));
                     maybe_res.push(token);
                     res.push(t.clone());
                     while let Some(t) = body_iter.next() {
+                        let t = match t {
+                            TokenTree::Group(ref g) if g.delimiter() == Delimiter::Parenthesis => {
+                                versions.cls_args(g)
+                            }
+                            t => t,
+                        };
                         maybe_res.push(t.clone());
                         res.push(t);
                     }
                     break;
                 }
                 "define_cls_init" => {
+                    fallback = Fallback::Class;
                     let token = TokenTree::Ident(Ident::new("define_weak_cls_init", t.span()));
                     maybe_res.push(token);
                     res.push(t.clone());
                     while let Some(t) = body_iter.next() {
+                        let t = match t {
+                            TokenTree::Group(ref g) if g.delimiter() == Delimiter::Parenthesis => {
+                                versions.cls_args(g)
+                            }
+                            t => t,
+                        };
                         maybe_res.push(t.clone());
                         res.push(t);
                     }
@@ -1386,9 +2159,16 @@ pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
             }
             // function body {}
             if g.delimiter() == Delimiter::Brace {
-                if no_args && try_replace_return(&mut maybe_res) {}
+                if no_args && try_replace_return(&mut maybe_res) {
+                    fallback = Fallback::Option;
+                }
                 let mut make_result_optional = false;
                 if try_replace_fn(&mut maybe_res, &mut make_result_optional) {
+                    fallback = if make_result_optional {
+                        Fallback::Option
+                    } else {
+                        Fallback::Panic
+                    };
                     if make_result_optional {
                         let mut i = maybe_res.len() - 2;
 
@@ -1423,11 +2203,280 @@ pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
 
         res.push(t);
     }
+    if no_body {
+        // unavailable item is compiled out
+        fallback = Fallback::Cfg;
+    }
     if !no_body {
+        let proof_variant = match maybe_res.last() {
+            Some(TokenTree::Group(body)) if fallback != Fallback::Class => {
+                proof_variant(versions, fallback, &item, body)
+            }
+            _ => TokenStream::new(),
+        };
         res.extend(maybe_res);
+        res.extend(proof_variant);
+    }
+
+    let row = match item_sig(&item) {
+        Some((ident, name, receiver, args)) => {
+            Some(Row::new(ident, &name, receiver, args, fallback, versions))
+        }
+        None => None,
+    };
+    (TokenStream::from_iter(res), row)
+}
+
+/// Declaration ident, name, whether it takes `self` and count of other args
+/// of `fn` or `define_cls!` item.
+fn item_sig(item: &[TokenTree]) -> Option<(Ident, String, bool, usize)> {
+    let mut iter = item.iter();
+    while let Some(tt) = iter.next() {
+        let TokenTree::Ident(i) = tt else {
+            continue;
+        };
+        match i.to_string().as_str() {
+            "define_cls" | "define_cls_init" => return Some((i.clone(), "cls".into(), false, 0)),
+            "fn" => {}
+            _ => continue,
+        }
+        let Some(TokenTree::Ident(name)) = iter.next() else {
+            return None;
+        };
+        let args = iter.find_map(|tt| match tt {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => Some(args_split(g)),
+            _ => None,
+        })?;
+        let receiver = args.first().is_some_and(|arg| {
+            arg.iter()
+                .any(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == "self"))
+        });
+        let count = args.len() - receiver as usize;
+        return Some((name.clone(), name.to_string(), receiver, count));
     }
+    None
+}
 
-    TokenStream::from_iter(res)
+/// Row of availability table `cargo box availability` reads instead of expanding
+/// cidre, one file per annotated API in `$OUT_DIR/availability`.
+///
+/// Tab separated file, line and ident of declaration, then name, `self` or `static`,
+/// count of other args, fallback and versions:
+///
+/// `src/sc/stream.rs 90 add_recording_output_throws try_add_recording_output self 1 unsafe macos = 15.0`
+///
+/// Files are named by hash of declaration, so rebuilds overwrite them and rows
+/// of moved or removed APIs are told apart by ident missing on their line.
+struct Row {
+    item: Ident,
+    name: String,
+    /// Row without declaration
+    api: String,
+}
+
+impl Row {
+    fn new(
+        item: Ident,
+        name: &str,
+        receiver: bool,
+        args: usize,
+        fallback: Fallback,
+        versions: &Versions,
+    ) -> Self {
+        let receiver = if receiver { "self" } else { "static" };
+        let api = format!(
+            "{name}\t{receiver}\t{args}\t{}\t{}",
+            fallback.as_str(),
+            versions.list()
+        );
+        Self {
+            item,
+            name: name.to_string(),
+            api,
+        }
+    }
+
+    /// Writes row while cidre is built, other crates and tests don't have table.
+    fn record(&self) {
+        use std::{
+            env, fs,
+            hash::{BuildHasher, BuildHasherDefault, DefaultHasher},
+            path::Path,
+        };
+
+        if env::var("CARGO_PKG_NAME").as_deref() != Ok("cidre") {
+            return;
+        }
+        let (Some(out_dir), Some(file)) = (env::var_os("OUT_DIR"), self.item.span().local_file())
+        else {
+            return;
+        };
+        // relative paths are relative to rustc working dir
+        let file = env::current_dir().map_or(file.clone(), |cwd| cwd.join(&file));
+        let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+        let file = file.strip_prefix(&manifest_dir).unwrap_or(&file);
+        let decl = format!(
+            "{}\t{}\t{}",
+            file.display(),
+            self.item.span().line(),
+            self.item
+        );
+        let hash = BuildHasherDefault::<DefaultHasher>::default().hash_one((&decl, &self.name));
+        let dir = Path::new(&out_dir).join("availability");
+        // lib and its tests are built in parallel, both write the same contents
+        let _ = fs::create_dir_all(&dir);
+        let _ = fs::write(
+            dir.join(format!("{hash:016x}.tsv")),
+            format!("{decl}\t{}\n", self.api),
+        );
+    }
+}
+
+/// `{name}_available` variants of fn with body, safe with proof from `api::if_available!`
+/// and returning what available variant returns.
+///
+/// `unavailable_body` is body of unavailable variant after fallback replacements.
+fn proof_variant(
+    versions: &Versions,
+    fallback: Fallback,
+    item: &[TokenTree],
+    unavailable_body: &Group,
+) -> TokenStream {
+    let Some(fn_pos) = item
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == "fn"))
+    else {
+        return TokenStream::new();
+    };
+    let meta = &item[..fn_pos];
+    let unsafe_already = meta
+        .iter()
+        .any(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == "unsafe"));
+    // skipped for trait and private fns
+    if vis(meta).is_empty() || unsafe_already {
+        return TokenStream::new();
+    }
+    // `pub async` and friends without attributes
+    let mut quals = Vec::new();
+    let mut meta_iter = meta.iter();
+    while let Some(tt) = meta_iter.next() {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '#' => {
+                meta_iter.next();
+            }
+            tt => quals.push(tt.clone()),
+        }
+    }
+    let quals = TokenStream::from_iter(quals).to_string();
+    let Some(TokenTree::Ident(name)) = item.get(fn_pos + 1) else {
+        return TokenStream::new();
+    };
+    let rest = &item[fn_pos + 2..];
+    let Some(args_pos) = rest.iter().position(
+        |tt| matches!(tt, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis),
+    ) else {
+        return TokenStream::new();
+    };
+    let TokenTree::Group(args) = &rest[args_pos] else {
+        return TokenStream::new();
+    };
+    let [ret @ .., TokenTree::Group(body)] = &rest[args_pos + 1..] else {
+        return TokenStream::new();
+    };
+    // body branching on cfg handles availability itself, its `_available` variant is hand-written
+    if !cfg_attrs(&body.stream().into_iter().collect::<Vec<_>>()).is_empty() {
+        return TokenStream::new();
+    }
+    let gen = TokenStream::from_iter(rest[..args_pos].iter().cloned()).to_string();
+    let ret = TokenStream::from_iter(ret.iter().cloned()).to_string();
+
+    // unavailable `Self::alloc()` of weak class becomes `Self::alloc_available(av)`
+    let alloc = with_alloc_available(body);
+    let panics = "/// # Panics
+    /// If symbol is missing despite the proof, e.g. one made with `Available::new_unchecked`";
+    let (unavailable_av, available_body, unavailable_body, panics) = match (fallback, alloc) {
+        (Fallback::Unsafe, _) => (
+            "_av",
+            format!("unsafe {body}"),
+            format!("unsafe {body}"),
+            "",
+        ),
+        (Fallback::Option, Some(alloc)) => ("av", body.to_string(), format!("unsafe {alloc}"), ""),
+        (Fallback::Option, None) => (
+            "_av",
+            body.to_string(),
+            format!(
+                "match {unavailable_body} {{
+            Some(res) => res,
+            None => panic!(\"`{name}` is missing despite the proof\"),
+        }}"
+            ),
+            panics,
+        ),
+        (Fallback::Panic, _) => (
+            "_av",
+            body.to_string(),
+            unavailable_body.to_string(),
+            panics,
+        ),
+        _ => return TokenStream::new(),
+    };
+
+    let cfgs = cfg_attrs(meta);
+    let gen = proof_generics(&gen);
+    let check = versions.proof_check();
+    let variant = |cfg: String, av: &str, body: String, panics: &str| {
+        let args = proof_args(args, av);
+        format!(
+            "
+    {cfg}
+    {cfgs}
+    /// `{name}` with proof from `api::if_available!`
+    {panics}
+    #[allow(unused_unsafe)]
+    #[inline]
+    {quals} fn {name}_available{gen}{args} {ret} {{
+        {check}
+        {body}
+    }}
+            "
+        )
+    };
+    let mut res = variant(versions.available_cfg(), "_av", available_body, "");
+    res.push_str(&variant(
+        versions.unavailable_cfg(),
+        unavailable_av,
+        unavailable_body,
+        panics,
+    ));
+    TokenStream::from_str(&res).unwrap()
+}
+
+/// `{ Self::alloc().init() }` -> `{ Self::alloc_available(av).init() }`
+fn with_alloc_available(body: &Group) -> Option<Group> {
+    let mut tokens: Vec<TokenTree> = body.stream().into_iter().collect();
+    let [TokenTree::Ident(s), TokenTree::Punct(c1), TokenTree::Punct(c2), TokenTree::Ident(alloc), TokenTree::Group(args), ..] =
+        &tokens[..]
+    else {
+        return None;
+    };
+    if s.to_string() != "Self"
+        || c1.as_char() != ':'
+        || c2.as_char() != ':'
+        || alloc.to_string() != "alloc"
+        || !args.stream().is_empty()
+    {
+        return None;
+    }
+    let alloc_span = alloc.span();
+    let args_span = args.span();
+    tokens[3] = TokenTree::Ident(Ident::new("alloc_available", alloc_span));
+    let mut av = Group::new(Delimiter::Parenthesis, TokenStream::from_str("av").unwrap());
+    av.set_span(args_span);
+    tokens[4] = TokenTree::Group(av);
+    let mut group = Group::new(Delimiter::Brace, TokenStream::from_iter(tokens));
+    group.set_span(body.span());
+    Some(group)
 }
 
 fn try_replace_fn(tokens: &mut Vec<TokenTree>, make_result_optional: &mut bool) -> bool {
@@ -1631,76 +2680,6 @@ fn upper_case(str: &str) -> String {
     String::from_utf8(res).unwrap()
 }
 
-fn choose_msg_send_fn(ret: &str) -> &'static str {
-    const MSG_SEND: &'static str = "objc_msgSend";
-    const MSG_SEND_FPRET: &'static str = "objc_msgSend_fpret";
-    const MSG_SEND_STRET: &'static str = "objc_msgSend_stret";
-
-    if ret.is_empty()
-        || ret.starts_with("-> arc :: ")
-        || ret.starts_with("-> Option <")
-        || ret.starts_with("-> * const ")
-        || ret.starts_with("-> * mut ")
-        || ret.starts_with("-> & ")
-    {
-        return MSG_SEND;
-    }
-
-    match ret {
-        "-> bool"
-        | "-> i8"
-        | "-> u8"
-        | "-> i16"
-        | "-> u16"
-        | "-> f32"
-        | "-> u32"
-        | "-> i32"
-        | "-> u64"
-        | "-> i64"
-        | "-> isize"
-        | "-> usize"
-        | "-> ns :: Integer"
-        | "-> ns :: UInteger"
-        | "-> std :: ffi :: c_int"
-        | "-> mps :: DType"
-        | "-> crate :: mtl :: ResId"
-        | "-> mach :: Port"
-        | "-> os :: Type"
-        | "-> vn :: Confidence"
-        | "-> vn :: AspectRatio"
-        | "-> vn :: Degrees"
-        | "-> sys :: Pid" => MSG_SEND,
-
-        // known structs
-        "-> ns :: Rect"
-        | "-> cg :: Rect"
-        | "-> cm :: Time"
-        | "-> cm :: TimeRange"
-        | "-> cg :: AffineTransform"
-        | "-> cg :: Size"
-        | "-> cg :: Point"
-        | "-> ns :: Size"
-        | "-> ns :: Point"
-        | "-> cm :: RotationRate"
-        | "-> cm :: Acceleration"
-        | "-> ca :: Transform3d"
-        | "-> mtl :: Size" => MSG_SEND_STRET,
-
-        // known floats
-        "-> f64"
-        | "-> cg :: Float"
-        | "-> ns :: Float"
-        | "-> ns :: Range"
-        | "-> cf :: TimeInterval"
-        | "-> ns :: TimeInterval" => MSG_SEND_FPRET,
-        _x => {
-            // TODO: do actual structure size check
-            // println!("unknown {x}");
-            MSG_SEND
-        }
-    }
-}
-
 // fn is_upper_case(str: &str) -> bool {
 //     let bytes = str.as_bytes();
 //     for ch in bytes {
@@ -0,0 +1,38 @@
+use cidre_macros::api_available_row as row;
+
+#[test]
+fn fallbacks() {
+    assert_eq!(
+        row!((macos = 15.0) pub fn with_cfg(cfg: &Cfg) -> Option<arc::R<Self>> {
+            Self::alloc().init_with_cfg(cfg)
+        }),
+        "with_cfg\tstatic\t1\toption\tmacos = 15.0"
+    );
+    assert_eq!(
+        row!((macos = 14.0, maccatalyst = 18.2) pub fn bounding_rect() -> &'static Self {
+            unsafe { SCStreamFrameInfoBoundingRect }
+        }),
+        "bounding_rect\tstatic\t0\toption\tmacos = 14.0, maccatalyst = 18.2"
+    );
+    assert_eq!(
+        row!((macos = 15.0, ios = 18.0) pub fn converter_new(src: &Asbd, opts: Opts<'_, u8>) -> os::Result<Converter> {
+            unsafe { AudioConverterNewWithOptions(src, opts) }
+        }),
+        "converter_new\tstatic\t2\tpanic\tmacos = 15.0, ios = 18.0"
+    );
+    assert_eq!(
+        row!((macos = 26.0) pub fn len(&self) -> usize {
+            self.duration_len()
+        }),
+        "len\tself\t0\tunsafe\tmacos = 26.0"
+    );
+    assert_eq!(
+        row!((macos = 14.0, ios = 17.0) crate::define_cls!(SC_STREAM);),
+        "cls\tstatic\t0\tclass\tmacos = 14.0, ios = 17.0"
+    );
+    assert_eq!(
+        row!((macos = 15.0) #[objc::msg_send(addRecordingOutput:error:)] pub fn add(&self, output: &Output, err: *mut Error);),
+        "add\tself\t2\tcfg\tmacos = 15.0"
+    );
+    assert_eq!(row!(() pub fn len(&self) -> usize { 0 }), "");
+}
@@ -0,0 +1,7 @@
+#[test]
+fn msg_send() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/msg_send_pass.rs");
+    t.pass("tests/ui/msg_send_kvo_pass.rs");
+    t.compile_fail("tests/ui/msg_send_*_err.rs");
+}
@@ -0,0 +1,63 @@
+use cidre_macros::msg_send;
+
+mod objc {
+    use std::{ffi::CStr, marker::PhantomData};
+
+    pub const fn msg_send_stret<R>() -> bool {
+        std::mem::size_of::<R>() > 16
+    }
+
+    pub type TryResult<R = ()> = Result<R, ()>;
+
+    pub fn try_catch_retained<F, R>(f: F) -> TryResult<R>
+    where
+        F: FnOnce() -> R,
+    {
+        Ok(f())
+    }
+
+    pub struct KeyPath<O, T>(&'static CStr, PhantomData<fn(&O) -> T>);
+
+    impl<O, T> KeyPath<O, T> {
+        pub const fn new(path: &'static CStr) -> Self {
+            Self(path, PhantomData)
+        }
+
+        pub fn as_c_str(&self) -> &'static CStr {
+            self.0
+        }
+    }
+}
+
+pub struct Player;
+
+impl Player {
+    fn cls_ptr() -> *const std::ffi::c_void {
+        std::ptr::null()
+    }
+
+    #[msg_send(status, kvo)]
+    pub fn status(&self) -> isize;
+
+    #[msg_send(isMuted, kvo)]
+    pub fn is_muted(&self) -> bool;
+
+    #[msg_send(isolated, kvo)]
+    pub fn isolated(&self) -> bool;
+
+    #[msg_send(is3D, kvo)]
+    pub fn is_3d(&self) -> bool;
+
+    #[msg_send( rate , kvo , throws )]
+    pub unsafe fn rate_throws(&self) -> f32;
+}
+
+fn main() {
+    let _: objc::KeyPath<Player, isize> = Player::KEY_PATH_STATUS;
+    assert_eq!(Player::KEY_PATH_STATUS.as_c_str(), c"status");
+    assert_eq!(Player::KEY_PATH_IS_MUTED.as_c_str(), c"muted");
+    assert_eq!(Player::KEY_PATH_ISOLATED.as_c_str(), c"isolated");
+    assert_eq!(Player::KEY_PATH_IS_3D.as_c_str(), c"is3D");
+    assert_eq!(Player::KEY_PATH_RATE.as_c_str(), c"rate");
+    let _: fn(&Player) -> objc::TryResult<f32> = Player::try_rate;
+}
@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct Player;
+
+impl Player {
+    #[msg_send(setRate:, kvo)]
+    pub fn set_rate(&mut self, val: f32);
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct Array;
+
+impl Array {
+    #[msg_send(count, trows)]
+    pub fn len(&self) -> usize;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct Array;
+
+impl Array {
+    #[msg_send(indexOfObject:)]
+    pub fn index_of(&self, val: &Array) -> Option<usize>;
+}
+
+fn main() {}
@@ -0,0 +1,81 @@
+use cidre_macros::{msg_send, msg_send_gnustep, msg_send_x86_64};
+
+mod objc {
+    pub const fn msg_send_stret<R>() -> bool {
+        std::mem::size_of::<R>() > 16
+    }
+}
+
+#[repr(C)]
+pub struct Size {
+    pub width: f64,
+    pub height: f64,
+}
+
+pub struct Array<T>(std::marker::PhantomData<T>);
+
+impl<T> Array<T> {
+    fn cls_ptr() -> *const std::ffi::c_void {
+        std::ptr::null()
+    }
+
+    #[msg_send(count)]
+    pub fn len(&self) -> usize;
+
+    #[msg_send(size)]
+    pub fn size(&self) -> Size;
+
+    #[msg_send(objectAtIndex:)]
+    pub fn get<'a>(&'a self, index: usize) -> &'a T;
+
+    #[msg_send(firstObject)]
+    pub fn first(&self) -> Option<&T>;
+
+    #[msg_send(removeAllObjects)]
+    pub fn clear(&mut self);
+
+    #[msg_send(getObjects:range:)]
+    pub unsafe fn get_objects(&self, objects: *mut Option<&T>, range: Size);
+
+    #[msg_send(indexOfObject:inRange:)]
+    pub fn index_of<U>(&self, mut object: Option<&U>, range: Size) -> usize
+    where
+        U: Sized;
+
+    #[msg_send(setDelegate:)]
+    pub fn set_delegate<D>(&mut self, val: Option<&D>);
+
+    #[msg_send(array)]
+    pub fn new_array() -> Option<&'static Self>;
+}
+
+#[repr(C)]
+pub struct Rect {
+    pub origin: Size,
+    pub size: Size,
+}
+
+pub struct View;
+
+impl View {
+    fn cls_ptr() -> *const std::ffi::c_void {
+        std::ptr::null()
+    }
+
+    #[msg_send_x86_64(frame)]
+    pub fn frame(&self) -> Rect;
+
+    #[msg_send_x86_64(setFrame:)]
+    pub fn set_frame(&mut self, val: Rect);
+
+    #[msg_send_x86_64(bounds)]
+    pub fn bounds(&self) -> Size;
+
+    #[msg_send_gnustep(superview)]
+    pub fn superview(&self) -> Option<&View>;
+
+    #[msg_send_gnustep(layerClass)]
+    pub fn layer_cls() -> *const std::ffi::c_void;
+}
+
+fn main() {}
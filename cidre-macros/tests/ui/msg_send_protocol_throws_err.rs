@@ -0,0 +1,9 @@
+use cidre_macros::protocol;
+
+#[protocol(NSFileManagerDelegate)]
+pub trait FileManagerDelegate {
+    #[objc::msg_send(fileManager:shouldRemoveItemAtPath:, throws)]
+    fn should_remove_item(&mut self, manager: &Self, path: &Self) -> bool;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct Data;
+
+impl Data {
+    #[msg_send(appendBytes:)]
+    pub fn append(&mut self, bytes: &'static mut [u8]);
+}
+
+fn main() {}
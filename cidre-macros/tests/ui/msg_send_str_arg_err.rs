@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct Label;
+
+impl Label {
+    #[msg_send(setText:)]
+    pub fn set_text(&mut self, val: &str);
+}
+
+fn main() {}
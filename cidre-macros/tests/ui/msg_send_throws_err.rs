@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct Array;
+
+impl Array {
+    #[msg_send(objectAtIndex:, throws)]
+    pub fn get_throws(&self, index: usize) -> &Array;
+}
+
+fn main() {}
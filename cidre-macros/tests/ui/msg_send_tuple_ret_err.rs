@@ -0,0 +1,10 @@
+use cidre_macros::msg_send;
+
+pub struct View;
+
+impl View {
+    #[msg_send(origin)]
+    pub fn origin(&self) -> (f64, f64);
+}
+
+fn main() {}
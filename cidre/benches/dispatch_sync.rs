@@ -6,7 +6,10 @@ use criterion::{Criterion, criterion_group, criterion_main};
 extern "C-unwind" fn work(_ctx: *mut u8) {}
 
 pub fn criterion_benchmark(c: &mut Criterion) {
+    #[cfg(target_vendor = "apple")]
     let queue = dispatch::Queue::serial_with_ar_pool();
+    #[cfg(not(target_vendor = "apple"))]
+    let queue = dispatch::Queue::new();
 
     c.bench_function("dispatch::queue.barrier_sync_f", |b| {
         b.iter(|| queue.barrier_sync_f(std::ptr::null_mut(), work))
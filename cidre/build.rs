@@ -1,6 +1,6 @@
 use std::{env, fs::read_to_string, path::PathBuf, process::Command, str::FromStr, thread};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Version {
     major: u32,
     minor: u32,
@@ -43,6 +43,17 @@ impl Version {
         Some(res)
     }
 
+    /// `14.0` or `14` from `MACOSX_DEPLOYMENT_TARGET` like vars
+    fn with_dotted_str(str: &str) -> Option<Self> {
+        let mut iter = str.trim().split('.');
+        let major = str::parse(iter.next()?).ok()?;
+        let minor = match iter.next() {
+            Some(s) => str::parse(s).ok()?,
+            None => 0,
+        };
+        Some(Self { major, minor })
+    }
+
     fn to_string(&self) -> String {
         format!("{}.{}", self.major, self.minor)
     }
@@ -278,6 +289,61 @@ fn parse_deployment_targets() -> DeploymentTargets {
     res
 }
 
+/// Warns if enabled `macos_x_x` like feature is newer than deployment target.
+///
+/// APIs of such features are linked strongly and binary fails to load
+/// on older OS versions, they should be used via `api::version!` checks instead.
+fn check_deployment_target(sdk: &str, deployment_targets: &DeploymentTargets) {
+    let (var, feature, platform) = match sdk {
+        "macosx" => (
+            "MACOSX_DEPLOYMENT_TARGET",
+            deployment_targets.macos,
+            "macos",
+        ),
+        "iphoneos" | "iphonesimulator" => {
+            ("IPHONEOS_DEPLOYMENT_TARGET", deployment_targets.ios, "ios")
+        }
+        "maccatalyst" => (
+            "IPHONEOS_DEPLOYMENT_TARGET",
+            deployment_targets.ios,
+            "maccatalyst",
+        ),
+        "appletvos" | "appletvsimulator" => {
+            ("TVOS_DEPLOYMENT_TARGET", deployment_targets.tvos, "tvos")
+        }
+        "watchos" | "watchsimulator" => (
+            "WATCHOS_DEPLOYMENT_TARGET",
+            deployment_targets.watchos,
+            "watchos",
+        ),
+        "xros" | "xrsimulator" => (
+            "XROS_DEPLOYMENT_TARGET",
+            deployment_targets.visionos,
+            "visionos",
+        ),
+        _ => return,
+    };
+    println!("cargo:rerun-if-env-changed={var}");
+    let (Some(feature), Ok(target)) = (feature, env::var(var)) else {
+        return;
+    };
+    let Some(target) = Version::with_dotted_str(&target) else {
+        println!("cargo:warning={var}={target} is not a version");
+        return;
+    };
+    if feature > target {
+        println!(
+            "cargo:warning=feature {platform}_{}_{} is newer than {var}={}, \
+             its APIs are not weak linked. Use {platform}_{}_{} feature and `api::version!` checks",
+            feature.major,
+            feature.minor,
+            target.to_string(),
+            target.major,
+            target.minor,
+        );
+    }
+}
+
 fn clang_link_search_path() -> String {
     // Use the CC env var if set (e.g. by Nix), otherwise fall back to
     // the cc crate's compiler detection.
@@ -308,11 +374,107 @@ fn clang_link_search_path() -> String {
     panic!("{compiler} is missing search paths");
 }
 
+fn gnustep_config(arg: &str) -> Option<String> {
+    let output = Command::new("gnustep-config").arg(arg).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+/// GNUstep libobjc2 and gnustep-base for non-Apple targets.
+fn build_gnustep() {
+    if !has_feature("objc") {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let mut build = cc::Build::new();
+    if env::var_os("CC").is_none() {
+        // gcc doesn't support libobjc2 ABI
+        build.compiler("clang");
+    }
+    build.file(format!("{manifest_dir}/pomace/gnustep/gnustep.m"));
+    build.flag("-fobjc-runtime=gnustep-2.0");
+    build.flag("-fobjc-exceptions");
+    build.flag("-fobjc-arc");
+    build.flag("-fblocks");
+    if let Some(flags) = gnustep_config("--objc-flags") {
+        for flag in flags.split_whitespace() {
+            if flag.starts_with("-I") || flag.starts_with("-D") {
+                build.flag(flag);
+            }
+        }
+    }
+    build.compile("gnustep");
+
+    if let Some(libs) = gnustep_config("--base-libs") {
+        for path in libs.split_whitespace().filter_map(|l| l.strip_prefix("-L")) {
+            println!("cargo:rustc-link-search={path}");
+        }
+    }
+    println!("cargo:rustc-link-lib=objc");
+    println!("cargo:rustc-link-lib=gnustep-base");
+    println!("cargo:rerun-if-changed=./pomace/gnustep/");
+}
+
+/// LLVM compiler-rt BlocksRuntime for non-Apple targets.
+///
+/// `blocks.c` is clang `-fblocks` counterpart of blocks ABI tests.
+fn build_blocks() {
+    if !has_feature("blocks") {
+        return;
+    }
+
+    println!("cargo:rustc-link-lib=BlocksRuntime");
+
+    // C side of `blocks::abi_tests`, only test binary links it
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let mut build = cc::Build::new();
+    if env::var_os("CC").is_none() {
+        // gcc doesn't support blocks
+        build.compiler("clang");
+    }
+    build.file(format!("{manifest_dir}/pomace/blocks/blocks.c"));
+    build.flag("-fblocks");
+    build.cargo_metadata(false);
+    if build.try_compile("cidre_blocks_tests").is_ok() {
+        let out_dir = env::var("OUT_DIR").unwrap();
+        println!("cargo:rustc-link-search=native={out_dir}");
+    }
+    println!("cargo:rerun-if-changed=./pomace/blocks/");
+}
+
+/// CoreFoundation of swift-corelibs-foundation for non-Apple targets.
+///
+/// `CIDRE_CF_LIB_DIR` points to directory with the library and `CIDRE_CF_LIB`
+/// overrides its name (Swift toolchains ship CF inside `Foundation`).
+fn link_cf() {
+    if !has_feature("cf") {
+        return;
+    }
+
+    println!("cargo:rerun-if-env-changed=CIDRE_CF_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=CIDRE_CF_LIB");
+    if let Ok(dir) = env::var("CIDRE_CF_LIB_DIR") {
+        println!("cargo:rustc-link-search={dir}");
+    }
+    let lib = env::var("CIDRE_CF_LIB").unwrap_or_else(|_| "CoreFoundation".to_string());
+    println!("cargo:rustc-link-lib={lib}");
+}
+
 fn main() {
     if std::env::var("DOCS_RS").is_ok() {
         return;
     }
 
+    if env::var("CARGO_CFG_TARGET_VENDOR").as_deref() != Ok("apple") {
+        build_gnustep();
+        build_blocks();
+        link_cf();
+        return;
+    }
+
     let deployment_targets = parse_deployment_targets();
 
     let sdk = match env::var("TARGET").unwrap().as_ref() {
@@ -344,6 +506,8 @@ fn main() {
         x => panic!("unknown sdk {x}"),
     };
 
+    check_deployment_target(sdk, &deployment_targets);
+
     println!("cargo:rustc-link-lib={}", clang_rt);
     println!("cargo:rustc-link-search={}", clang_link_search_path());
 
@@ -20,13 +20,14 @@ async fn main() {
     );
 
     let mut output =
-        av::AssetReaderTrackOutput::with_track(&tracks.get(0).unwrap(), Some(&options)).unwrap();
+        av::AssetReaderTrackOutput::with_track(&tracks.try_get(0).unwrap(), Some(&options))
+            .unwrap();
     // let mut output = av::AssetReaderTrackOutput::with_track(&tracks[0], None).unwrap();
     output.set_always_copies_sample_data(false);
 
     let mut reader = av::AssetReader::with_asset(&asset).unwrap();
-    reader.add_output(&output).unwrap();
-    let true = reader.start_reading().expect("Failed to start reading") else {
+    reader.try_add_output(&output).unwrap();
+    let true = reader.try_start_reading().expect("Failed to start reading") else {
         println!("error: {:?}", reader.error());
         println!("status: {:?}", reader.status());
         return;
@@ -106,7 +107,7 @@ async fn main() {
             // }
             if let Some(results) = features.results() {
                 if !results.is_empty() {
-                    let res = results.get(0).unwrap();
+                    let res = results.try_get(0).unwrap();
                     feature_prints.push(res.vec_f32());
 
                     // if let Some(prev) = prev_frame_featurs.as_ref() {
@@ -138,7 +138,7 @@ mod macos {
                     let duration = segment_report
                         .unwrap()
                         .track_reports()
-                        .get(0)
+                        .try_get(0)
                         .unwrap()
                         .duration()
                         .as_secs();
@@ -192,7 +192,7 @@ mod macos {
         input.set_expects_media_data_in_real_time(true);
 
         let mut writer = av::AssetWriter::with_content_type(ut::Type::mpeg4movie()).unwrap();
-        writer.add_input(&input).unwrap();
+        writer.try_add_input(&input).unwrap();
         writer.set_delegate(Some(delegate.as_ref()));
         // writer.set_output_file_type_profile(Some(av::FileTypeProfile::mpeg4_cmaf_compliant()));
         writer.set_output_file_type_profile(Some(av::FileTypeProfile::mpeg4_apple_hls()));
@@ -204,7 +204,7 @@ mod macos {
         let queue = dispatch::Queue::serial_with_ar_pool();
 
         let content = sc::ShareableContent::current().await.expect("content");
-        let display = content.displays().get(0).unwrap();
+        let display = content.displays().try_get(0).unwrap();
         let mut cfg = sc::StreamCfg::new();
         cfg.set_minimum_frame_interval(cm::Time::new(1, FPS));
         cfg.set_width(display.width() as usize * 2);
@@ -62,10 +62,10 @@ async fn reader_and_output(
         .unwrap();
 
     let mut track_output =
-        av::AssetReaderTrackOutput::with_track(&tracks.get(0).unwrap(), None).unwrap();
+        av::AssetReaderTrackOutput::with_track(&tracks.try_get(0).unwrap(), None).unwrap();
     track_output.set_always_copies_sample_data(false);
 
-    asset_reader.add_output(&track_output).unwrap();
+    asset_reader.try_add_output(&track_output).unwrap();
     (asset_reader, track_output)
 }
 
@@ -87,9 +87,9 @@ fn writer_and_input(
 
     let mut writer = av::AssetWriter::with_url_and_file_type(dst.as_ns(), file_type).unwrap();
 
-    assert!(reader.start_reading().expect("failed to start reading"));
+    assert!(reader.try_start_reading().expect("failed to start reading"));
     let buf = output
-        .next_sample_buf()
+        .try_next_sample_buf()
         .expect("Failed to copy next sample buf")
         .unwrap();
     let fd = buf.format_desc().unwrap();
@@ -133,7 +133,7 @@ fn writer_and_input(
     )
     .unwrap();
 
-    writer.add_input(&input).unwrap();
+    writer.try_add_input(&input).unwrap();
 
     (writer, input, buf)
 }
@@ -158,7 +158,7 @@ fn write(
     let mut block = blocks::EscBlock::new0(move || {
         while inp.is_ready_for_more_media_data() {
             unsafe { inp.append_sample_buf_throws(&buf) };
-            let Ok(Some(b)) = out.next_sample_buf() else {
+            let Ok(Some(b)) = out.try_next_sample_buf() else {
                 inp.mark_as_finished();
                 sem.signal();
                 break;
@@ -168,7 +168,7 @@ fn write(
     });
 
     input
-        .request_media_data_when_ready_on_queue(&queue, &mut block)
+        .try_request_media_data_when_ready_on_queue(&queue, &mut block)
         .unwrap();
 
     sema.wait_forever();
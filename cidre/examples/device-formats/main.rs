@@ -47,7 +47,7 @@ fn main() {
     let session = av::CaptureSession::new();
     if session.can_set_session_preset(av::CaptureSessionPreset::_1920x1080()) {
         session
-            .set_session_preset(av::CaptureSessionPreset::_1920x1080())
+            .try_set_session_preset(av::CaptureSessionPreset::_1920x1080())
             .expect("failed to set preseet");
     }
     session.as_type_ref().show();
@@ -280,7 +280,7 @@ async fn main() {
 
     let queue = dispatch::Queue::serial_with_ar_pool();
     let content = sc::ShareableContent::current().await.expect("content");
-    let ref display = content.displays().get(0).unwrap();
+    let ref display = content.displays().try_get(0).unwrap();
     let mut cfg = sc::StreamCfg::new();
     cfg.set_minimum_frame_interval(cm::Time::new(1, FPS));
     cfg.set_width(display.width() as usize * 2);
@@ -60,7 +60,7 @@ mod macos {
         synth.set_delegate(Some(delegate.as_ref()));
 
         // Tell the synthesizer to speak the utterance.
-        synth.speak_utterance(&utterance).unwrap();
+        synth.try_speak_utterance(&utterance).unwrap();
 
         ns::App::shared().run();
     }
@@ -10,6 +10,9 @@
 NS_ASSUME_NONNULL_BEGIN
 
 Class AU_AUDIO_UNIT;
+Class AU_AUDIO_UNIT_BUS;
+Class AU_AUDIO_UNIT_BUS_ARRAY;
+Class AU_PARAMETER_TREE;
 
 __attribute__((constructor))
 static void at_initializer(void)
@@ -19,6 +22,9 @@ static void at_initializer(void)
         initialized = 1;
 
         AU_AUDIO_UNIT = [AUAudioUnit class];
+        AU_AUDIO_UNIT_BUS = [AUAudioUnitBus class];
+        AU_AUDIO_UNIT_BUS_ARRAY = [AUAudioUnitBusArray class];
+        AU_PARAMETER_TREE = [AUParameterTree class];
     }
     
 }
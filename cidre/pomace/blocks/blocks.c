@@ -0,0 +1,63 @@
+//
+//  blocks.c
+//  blocks
+//
+//  clang -fblocks side of blocks ABI tests on non-Apple targets.
+//  Blocks are copied, called and released here the same way
+//  libdispatch and Foundation do it.
+//
+
+#include <Block.h>
+#include <pthread.h>
+#include <stddef.h>
+
+int cidre_blocks_call_i32(int (^block)(int), int a) {
+    return block(a);
+}
+
+static void (^stored)(void);
+
+void cidre_blocks_store(void (^block)(void)) {
+    stored = Block_copy(block);
+}
+
+void cidre_blocks_call_stored(void) {
+    stored();
+}
+
+void cidre_blocks_release_stored(void) {
+    Block_release(stored);
+    stored = NULL;
+}
+
+int (^cidre_blocks_adder(int n))(int) {
+    return Block_copy(^(int a) { return a + n; });
+}
+
+static void *call_on_thread(void *block) {
+    ((void (^)(void))block)();
+    Block_release(block);
+    return NULL;
+}
+
+int cidre_blocks_call_on_thread(void (^block)(void)) {
+    pthread_t thread;
+    void *copy = (void *)Block_copy(block);
+    if (pthread_create(&thread, NULL, call_on_thread, copy) != 0) {
+        Block_release(copy);
+        return -1;
+    }
+    return pthread_join(thread, NULL);
+}
+
+void cidre_blocks_complete0(void (^done)(void)) {
+    done();
+}
+
+void cidre_blocks_complete1(void (^done)(long), long value) {
+    done(value);
+}
+
+void cidre_blocks_complete2(void (^done)(void *, void *), void *value, void *error) {
+    done(value, error);
+}
@@ -0,0 +1,73 @@
+//
+//  gnustep.m
+//  gnustep
+//
+//  Runtime glue for GNUstep libobjc2 and gnustep-base.
+//  Mirrors symbols of ns.h used by objc module.
+//
+
+#import <Foundation/Foundation.h>
+
+NS_ASSUME_NONNULL_BEGIN
+
+void cidre_raise_exception(NSString *message) {
+    [NSException raise:NSGenericException format:@"%@", message];
+}
+
+id _Nullable cidre_try_catch(void (*during)(void *), void * context ) {
+    @try {
+        during(context);
+        return nil;
+    } @catch (id e) {
+        return e;
+    }
+}
+
+void cidre_log(NSString * str) {
+    NSLog(@"%@", str);
+}
+
+Class NS_OBJECT;
+Class NS_STRING;
+Class NS_MUTABLE_STRING;
+Class NS_NUMBER;
+Class NS_VALUE;
+Class NS_NULL;
+Class NS_DATA;
+Class NS_MUTABLE_DATA;
+Class NS_ARRAY;
+Class NS_MUTABLE_ARRAY;
+Class NS_DICTIONARY;
+Class NS_MUTABLE_DICTIONARY;
+Class NS_SET;
+Class NS_MUTABLE_SET;
+Class NS_ERROR;
+Class NS_EXCEPTION;
+
+__attribute__((constructor))
+static void gnustep_initializer(void)
+{
+    static int initialized = 0;
+    if (!initialized) {
+        initialized = 1;
+
+        NS_OBJECT = [NSObject class];
+        NS_STRING = [NSString class];
+        NS_MUTABLE_STRING = [NSMutableString class];
+        NS_NUMBER = [NSNumber class];
+        NS_VALUE = [NSValue class];
+        NS_NULL = [NSNull class];
+        NS_DATA = [NSData class];
+        NS_MUTABLE_DATA = [NSMutableData class];
+        NS_ARRAY = [NSArray class];
+        NS_MUTABLE_ARRAY = [NSMutableArray class];
+        NS_DICTIONARY = [NSDictionary class];
+        NS_MUTABLE_DICTIONARY = [NSMutableDictionary class];
+        NS_SET = [NSSet class];
+        NS_MUTABLE_SET = [NSMutableSet class];
+        NS_ERROR = [NSError class];
+        NS_EXCEPTION = [NSException class];
+    }
+}
+
+NS_ASSUME_NONNULL_END
@@ -60,6 +60,7 @@ Class NS_URL_SESSION_WEB_SOCKET_MESSAGE;
 Class NS_UUID;
 Class NS_VALUE;
 Class NS_ERROR;
+Class NS_EXCEPTION;
 Class NS_NULL;
 Class NS_INDEX_PATH;
 Class NS_INDEX_SET;
@@ -167,6 +168,7 @@ static void common_initializer(void)
         NS_UUID = [NSUUID class];
         NS_VALUE = [NSValue class];
         NS_ERROR = [NSError class];
+        NS_EXCEPTION = [NSException class];
         NS_NULL = [NSNull class];
         NS_INDEX_PATH = [NSIndexPath class];
         NS_INDEX_SET = [NSIndexSet class];
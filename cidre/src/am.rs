@@ -10,5 +10,8 @@ pub mod service_connection;
 pub use service_connection::InvalidSocketError;
 pub use service_connection::ServiceConnection;
 
-#[link(name = "MobileDevice", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "MobileDevice", kind = "framework")
+)]
 unsafe extern "C" {}
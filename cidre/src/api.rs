@@ -322,18 +322,231 @@ macro_rules! version {
         $( || $crate::api::version!(visionos = $visionos_ver))?
     };
 }
+
+/// Version packed as `major << 16 | minor`.
+///
+/// `u32::MAX` is a version no OS reaches.
+#[inline]
+pub const fn ver(major: u32, minor: u32) -> u32 {
+    (major << 16) | minor
+}
+
+/// Name of the platform as in `#[api::available(..)]`, empty on non apple targets.
+pub const PLATFORM: &str = if cfg!(all(target_os = "ios", target_abi = "macabi")) {
+    "maccatalyst"
+} else if cfg!(target_os = "macos") {
+    "macos"
+} else if cfg!(target_os = "ios") {
+    "ios"
+} else if cfg!(target_os = "tvos") {
+    "tvos"
+} else if cfg!(target_os = "watchos") {
+    "watchos"
+} else if cfg!(target_os = "visionos") {
+    "visionos"
+} else {
+    ""
+};
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn parse_ver(str: &str) -> u32 {
+    let bytes = str.as_bytes();
+    let (mut major, mut minor, mut dot) = (0u32, 0u32, false);
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'.' if !dot => dot = true,
+            b @ b'0'..=b'9' if dot => minor = minor * 10 + (b - b'0') as u32,
+            b @ b'0'..=b'9' => major = major * 10 + (b - b'0') as u32,
+            _ => panic!("version should look like 14.4"),
+        }
+        i += 1;
+    }
+    ver(major, minor)
+}
+
+/// Picks version of the current platform from `(platform, version)` pairs
+/// produced by `api::platform_version!`.
+///
+/// Mac Catalyst falls back to ios version. Unlisted platform gets `u32::MAX`.
+#[doc(hidden)]
+pub const fn platform_ver(versions: &[(&str, &str)]) -> u32 {
+    let mut res = u32::MAX;
+    let mut i = 0;
+    while i < versions.len() {
+        let (platform, ver) = versions[i];
+        if !(str_eq(platform, "macos")
+            || str_eq(platform, "ios")
+            || str_eq(platform, "maccatalyst")
+            || str_eq(platform, "tvos")
+            || str_eq(platform, "watchos")
+            || str_eq(platform, "visionos"))
+        {
+            panic!("unknown platform");
+        }
+        if str_eq(platform, PLATFORM) {
+            return parse_ver(ver);
+        }
+        if str_eq(platform, "ios") && str_eq(PLATFORM, "maccatalyst") {
+            res = parse_ver(ver);
+        }
+        i += 1;
+    }
+    res
+}
+
+/// Checks at runtime that OS is at least `ver` (see [`ver`]).
+#[inline]
+pub fn os_at_least(ver: u32) -> bool {
+    if ver == u32::MAX {
+        return false;
+    }
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "tvos",
+        target_os = "watchos",
+        target_os = "visionos"
+    ))]
+    return OsVersion {
+        major: (ver >> 16) as _,
+        minor: (ver & 0xffff) as _,
+        patch: 0,
+    }
+    .at_least();
+    #[cfg(not(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "tvos",
+        target_os = "watchos",
+        target_os = "visionos"
+    )))]
+    false
+}
+
+/// Zero-sized proof that the OS is at least `V` (see [`ver`]).
+///
+/// Obtained with `api::if_available!` and accepted by weak APIs
+/// instead of returning `Option`. Public `#[api::available]` fns get
+/// `{name}_available(.., av)` variants which are safe with the proof,
+/// fns branching on deployment features in their bodies write them by hand:
+///
+/// ```no_run
+/// use cidre::{api, mtl4};
+///
+/// api::if_available!(macos = 26.0, ios = 26.0 => |av| {
+///     let desc = mtl4::CmdAllocatorDesc::new_available(av);
+/// } else {
+///     // fallback
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Available<const V: u32>(());
+
+impl<const V: u32> Available<V> {
+    /// Proof if the OS is at least `V`.
+    #[inline]
+    pub fn check() -> Option<Self> {
+        if os_at_least(V) { Some(Self(())) } else { None }
+    }
+
+    /// # Safety
+    ///
+    /// The OS should be at least `V`.
+    #[inline]
+    pub const unsafe fn new_unchecked() -> Self {
+        Self(())
+    }
+
+    /// Proof for older version. Fails to compile if `W` is newer than `V`.
+    #[inline]
+    pub const fn downgrade<const W: u32>(self) -> Available<W> {
+        const { assert!(W <= V, "proof is for older version") };
+        Available(())
+    }
+
+    pub const fn version(&self) -> u32 {
+        V
+    }
+}
+
+/// Version of the current platform as [`ver`] or `u32::MAX` if not listed.
+///
+/// ```
+/// use cidre::api;
+///
+/// const V: u32 = api::platform_version!(macos = 14.4, ios = 17.4);
+/// ```
+#[macro_export]
+macro_rules! platform_version {
+    ($($platform:ident = $ver:literal),+ $(,)?) => {
+        $crate::api::platform_ver(&[$((stringify!($platform), stringify!($ver))),+])
+    };
+}
+
+/// Runs block with [`Available`] proof if the OS is at least the version
+/// listed for the current platform.
+///
+/// ```
+/// use cidre::api;
+///
+/// let new = api::if_available!(macos = 14.4, ios = 17.4 => |av| {
+///     av.version() > 0
+/// } else {
+///     false
+/// });
+/// # let _ = new;
+///
+/// api::if_available!(macos = 14.4 => {
+///     println!("macos 14.4+");
+/// });
+/// ```
+#[macro_export]
+macro_rules! if_available {
+    ($($platform:ident = $ver:literal),+ $(,)? => |$av:pat_param| $then:block $(else $else:block)?) => {
+        match $crate::api::Available::<{ $crate::api::platform_version!($($platform = $ver),+) }>::check() {
+            Some($av) => $then,
+            None => { $($else)? }
+        }
+    };
+    ($($platform:ident = $ver:literal),+ $(,)? => $then:block $(else $else:block)?) => {
+        $crate::api::if_available!($($platform = $ver),+ => |_| $then $(else $else)?)
+    };
+}
+
 pub use cidre_macros::api_available as available;
 pub use cidre_macros::api_weak as weak;
+pub use if_available;
+pub use platform_version;
 pub use version;
 
 #[cfg(test)]
 mod tests {
-    use crate::{api, ns};
+    use crate::api;
+    #[cfg(feature = "ns")]
+    use crate::ns;
 
+    #[cfg(feature = "ns")]
     static NOT_FOUND: api::DlSym<ns::String> = api::DlSym::new(c"not_a_symbol");
+    #[cfg(feature = "ns")]
     static SHOULD_BE_FOUND: api::DlSym<ns::String> =
         api::DlSym::new(c"NSInvocationOperationVoidResultException");
 
+    #[cfg(feature = "ns")]
     #[test]
     fn basics() {
         assert!(NOT_FOUND.get_var().is_none());
@@ -362,4 +575,64 @@ mod tests {
         assert!(!api::version!(visionos = 1.0));
         assert!(!api::version!(macos = 40.0));
     }
+
+    #[test]
+    fn platform_version() {
+        assert_eq!(api::ver(14, 4), 0xe_0004);
+        const V: u32 = api::platform_version!(macos = 14.4, ios = 17.4, visionos = 2);
+        #[cfg(target_os = "macos")]
+        assert_eq!(V, api::ver(14, 4));
+        #[cfg(target_os = "ios")]
+        assert_eq!(V, api::ver(17, 4));
+        #[cfg(target_os = "visionos")]
+        assert_eq!(V, api::ver(2, 0));
+        #[cfg(not(target_vendor = "apple"))]
+        assert_eq!(V, u32::MAX);
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        assert_eq!(api::platform_version!(visionos = 1.0), u32::MAX);
+    }
+
+    #[cfg(target_os = "macos")]
+    struct Answer;
+
+    #[cfg(target_os = "macos")]
+    impl Answer {
+        #[api::available(macos = 10.15)]
+        pub fn get(&self) -> usize {
+            42
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn proof_variant() {
+        let answer =
+            api::if_available!(macos = 10.15 => |av| { Answer.get_available(av) } else { 0 });
+        assert_eq!(answer, 42);
+    }
+
+    #[test]
+    fn if_available() {
+        assert_eq!(
+            std::mem::size_of::<api::Available<{ api::ver(14, 0) }>>(),
+            0
+        );
+
+        let v = api::if_available!(macos = 11.0, ios = 14.0, tvos = 14.0, watchos = 7.0, visionos = 1.0 => |av| {
+            let older: api::Available<{ api::ver(10, 15) }> = av.downgrade();
+            older.version()
+        } else {
+            0
+        });
+        #[cfg(target_vendor = "apple")]
+        assert!(v > 0);
+        #[cfg(not(target_vendor = "apple"))]
+        assert_eq!(v, 0);
+
+        let mut ran = false;
+        api::if_available!(macos = 99.0, ios = 99.0 => {
+            ran = true;
+        });
+        assert!(!ran);
+    }
 }
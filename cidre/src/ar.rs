@@ -69,7 +69,7 @@ pub use tracked_raycast::TrackedRaycast;
 pub mod error;
 pub use error::Code as ErrorCode;
 
-#[link(name = "ARKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "ARKit", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "ar", kind = "static")]
@@ -78,7 +78,7 @@ impl Cfg {
     /// Supported video formats for this configuration on the current device.
     ///
     /// The first element is the default session output format.
-    #[objc::msg_send(supportedVideoFormats)]
+    #[objc::msg_send(supportedVideoFormats, throws)]
     #[objc::available(ios = 12.0)]
     pub unsafe fn supported_video_formats_throws() -> arc::R<ns::Array<ar::VideoFormat>>;
 
@@ -343,7 +343,7 @@ impl<T: objc::Obj> std::borrow::BorrowMut<T> for R<T> {
     }
 }
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_vendor = "apple", target_arch = "aarch64"))]
 #[cfg(feature = "objc")]
 #[inline(always)]
 pub fn rar_retain_option<T: objc::Obj>(id: Option<Rar<T>>) -> Option<R<T>> {
@@ -401,7 +401,7 @@ pub fn rar_claim_value<T: objc::Obj>(obj: Option<&T>) -> Option<R<T>> {
     }
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_vendor = "apple", target_arch = "x86_64"))]
 #[cfg(feature = "objc")]
 #[inline(always)]
 pub fn rar_retain_option<T: objc::Obj>(id: Option<Rar<T>>) -> Option<R<T>> {
@@ -410,7 +410,7 @@ pub fn rar_retain_option<T: objc::Obj>(id: Option<Rar<T>>) -> Option<R<T>> {
 }
 
 #[cfg(feature = "objc")]
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_vendor = "apple", target_arch = "aarch64"))]
 #[inline]
 pub fn rar_retain<T: objc::Obj>(id: Rar<T>) -> R<T> {
     #[cfg(any(
@@ -451,7 +451,7 @@ pub fn rar_retain<T: objc::Obj>(id: Rar<T>) -> R<T> {
 }
 
 #[cfg(feature = "objc")]
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_vendor = "apple", target_arch = "x86_64"))]
 #[inline]
 pub fn rar_retain<T: objc::Obj>(id: Rar<T>) -> R<T> {
     // asm!("mov rax, rdi");
@@ -459,6 +459,30 @@ pub fn rar_retain<T: objc::Obj>(id: Rar<T>) -> R<T> {
     unsafe { std::mem::transmute(objc::objc_retain(std::mem::transmute(id))) }
 }
 
+/// libobjc2 tracks autoreleased return values in thread local storage,
+/// so no marker is needed.
+#[cfg(feature = "objc")]
+#[cfg(not(target_vendor = "apple"))]
+#[inline(always)]
+pub fn rar_retain_option<T: objc::Obj>(id: Option<Rar<T>>) -> Option<R<T>> {
+    unsafe {
+        std::mem::transmute(objc::objc_retainAutoreleasedReturnValue(
+            std::mem::transmute(id),
+        ))
+    }
+}
+
+#[cfg(feature = "objc")]
+#[cfg(not(target_vendor = "apple"))]
+#[inline]
+pub fn rar_retain<T: objc::Obj>(id: Rar<T>) -> R<T> {
+    unsafe {
+        std::mem::transmute(objc::objc_retainAutoreleasedReturnValue(
+            std::mem::transmute(id),
+        ))
+    }
+}
+
 #[cfg(all(test, feature = "objc"))]
 mod tests {
     use crate::{arc, objc};
@@ -37,7 +37,10 @@ pub use extended_audio_file::ExtAudioFilePropId;
 pub use extended_audio_file::ExtAudioFileRef;
 pub use extended_audio_file::err as ext_audio_file_err;
 
-#[link(name = "AudioToolbox", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "AudioToolbox", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "at", kind = "static")]
@@ -64,7 +64,7 @@ impl InstantiationOpts {
 
 /// A structure describing the unique and identifying IDs of an audio component
 #[doc(alias = "AudioComponentDescription")]
-#[derive(Default)]
+#[derive(Default, Clone, Copy)]
 #[repr(C)]
 pub struct Desc {
     /// A 4-char code identifying the generic type of an audio component.
@@ -28,6 +28,45 @@ pub use audio_unit::AudioUnitBusArray;
 pub use audio_unit::AudioUnitBusType;
 pub use audio_unit::AudioUnitStatus;
 
+mod parameter_tree;
+#[cfg(feature = "blocks")]
+pub use parameter_tree::ImplementorValueObserver;
+#[cfg(feature = "blocks")]
+pub use parameter_tree::ImplementorValueProvider;
+pub use parameter_tree::Parameter;
+pub use parameter_tree::ParameterGroup;
+pub use parameter_tree::ParameterNode;
+pub use parameter_tree::ParameterTree;
+
+#[cfg(feature = "blocks")]
+mod audio_unit_implementation;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::Def as KernelDef;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::EVENT_SAMPLE_TIME_IMMEDIATE;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::EventSampleTime;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::InternalRenderBlock;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::KernelAudioUnit;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::RenderEventHeader;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::RenderEventType;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::RenderEvents;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::RenderMidiEvent;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::RenderParamEvent;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::RenderPullInputBlock;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::Subclass as AudioUnitSubclass;
+#[cfg(feature = "blocks")]
+pub use audio_unit_implementation::SubclassImpl as AudioUnitSubclassImpl;
+
 mod multi_channel_mixer;
 pub use multi_channel_mixer::MultiChannelMixer;
 
@@ -44,9 +83,7 @@ pub use properties::ExternalBuf;
 pub use properties::FrequencyResponseBin;
 pub use properties::MeterClipping;
 pub use properties::OfflinePreflight;
-pub use properties::ParamFlags;
 pub use properties::ParamInfo;
-pub use properties::ParamUnit;
 pub use properties::Preset;
 pub use properties::RenderCbStruct;
 pub use properties::ScheduledFileRegion;
@@ -64,6 +101,34 @@ pub use properties::preset_key;
 pub use properties::sample_rate_converter_complexity;
 pub use properties::voice_io_other_audio_ducking_level;
 
+use crate::cat::audio::graph;
+pub use graph::Connection as GraphConnection;
+pub use graph::Graph;
+pub use graph::Node;
+pub use graph::NodeId;
+pub use graph::Pull as GraphPull;
+pub use graph::Source as GraphSource;
+pub use graph::bufs_count as graph_bufs_count;
+pub use graph::bufs_mut as graph_bufs_mut;
+pub use graph::err as graph_err;
+
+use crate::cat::audio::kernel;
+pub use kernel::Ctx as KernelCtx;
+pub use kernel::Kernel;
+pub use kernel::ParamAddr;
+pub use kernel::ParamDesc;
+pub use kernel::ParamFlags;
+pub use kernel::ParamModel;
+pub use kernel::ParamNode;
+pub use kernel::ParamUnit;
+pub use kernel::ParamValues;
+pub use kernel::PullInput;
+pub use kernel::RenderEvent;
+pub use kernel::Renderer as KernelRenderer;
+
+mod offline;
+pub use offline::UnitNode;
+
 mod parameters;
 pub use parameters::NBandEQFilterType;
 #[cfg(target_os = "macos")]
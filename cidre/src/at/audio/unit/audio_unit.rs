@@ -1,6 +1,6 @@
 #[cfg(feature = "blocks")]
 use crate::blocks;
-use crate::{arc, at::audio, av, define_cls, define_obj_type, ns, objc, os};
+use crate::{arc, at::au, at::audio, av, define_cls, define_obj_type, ns, objc, os};
 
 pub type AudioUnitStatus = os::Status;
 
@@ -113,12 +113,28 @@ impl AudioUnit {
     #[objc::msg_send(reset)]
     pub fn reset(&mut self);
 
+    #[objc::msg_send(maximumFramesToRender)]
+    pub fn max_frames_to_render(&self) -> u32;
+
+    /// The maximum number of frames which the audio unit will render at once.
+    ///
+    /// Must be set by the host before render resources are allocated.
+    #[objc::msg_send(setMaximumFramesToRender:)]
+    pub fn set_max_frames_to_render(&mut self, val: u32);
+
     #[objc::msg_send(inputBusses)]
     pub fn input_busses(&self) -> arc::R<AudioUnitBusArray>;
 
     #[objc::msg_send(outputBusses)]
     pub fn output_busses(&self) -> arc::R<AudioUnitBusArray>;
 
+    #[objc::msg_send(parameterTree)]
+    pub fn param_tree(&self) -> Option<arc::R<au::ParameterTree>>;
+
+    /// Subclasses set their tree during initialization.
+    #[objc::msg_send(setParameterTree:)]
+    pub fn set_param_tree(&mut self, val: Option<&au::ParameterTree>);
+
     //...
 
     #[objc::msg_send(fullState)]
@@ -132,7 +148,27 @@ define_obj_type!(
 
 impl ns::FastEnum<AudioUnitBus> for AudioUnitBusArray {}
 
+impl arc::A<AudioUnitBusArray> {
+    #[objc::msg_send(initWithAudioUnit:busType:busses:)]
+    pub fn init_with_audio_unit_busses(
+        self,
+        owner: &AudioUnit,
+        bus_type: AudioUnitBusType,
+        busses: &ns::Array<AudioUnitBus>,
+    ) -> arc::R<AudioUnitBusArray>;
+}
+
 impl AudioUnitBusArray {
+    define_cls!(AU_AUDIO_UNIT_BUS_ARRAY);
+
+    pub fn with_busses(
+        owner: &AudioUnit,
+        bus_type: AudioUnitBusType,
+        busses: &ns::Array<AudioUnitBus>,
+    ) -> arc::R<Self> {
+        Self::alloc().init_with_audio_unit_busses(owner, bus_type, busses)
+    }
+
     #[objc::msg_send(lount)]
     pub fn count(&self) -> usize;
 
@@ -141,11 +177,12 @@ impl AudioUnitBusArray {
         self.count()
     }
 
-    #[objc::msg_send(objectAtIndexedSubscript:)]
+    #[objc::msg_send(objectAtIndexedSubscript:, throws)]
     pub unsafe fn get_throws(&self, index: usize) -> arc::R<AudioUnitBus>;
 
+    #[deprecated(note = "use `try_get`")]
     pub fn get<'ear>(&self, index: usize) -> ns::ExResult<'ear, arc::R<AudioUnitBus>> {
-        ns::try_catch(|| unsafe { self.get_throws(index) })
+        self.try_get(index).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(isCountChangeable)]
@@ -170,7 +207,23 @@ define_obj_type!(
     pub AudioUnitBus(ns::Id)
 );
 
+impl arc::A<AudioUnitBus> {
+    #[objc::msg_send(initWithFormat:error:)]
+    pub unsafe fn init_with_format_err<'ear>(
+        self,
+        format: &av::AudioFormat,
+        err: *mut Option<&'ear ns::Error>,
+    ) -> Option<arc::R<AudioUnitBus>>;
+}
+
 impl AudioUnitBus {
+    define_cls!(AU_AUDIO_UNIT_BUS);
+
+    /// Creates a bus of an audio unit implemented in this process.
+    pub fn with_format<'ear>(format: &av::AudioFormat) -> ns::Result<'ear, arc::R<Self>> {
+        ns::if_none(|err| unsafe { Self::alloc().init_with_format_err(format, err) })
+    }
+
     /// The audio format and channel layout of audio being transferred on the bus.
     ///
     /// Bridged to the v2 property kAudioUnitProperty_StreamFormat.
@@ -264,4 +317,6 @@ pub enum AudioUnitBusType {
 
 unsafe extern "C" {
     static AU_AUDIO_UNIT: &'static objc::Class<AudioUnit>;
+    static AU_AUDIO_UNIT_BUS: &'static objc::Class<AudioUnitBus>;
+    static AU_AUDIO_UNIT_BUS_ARRAY: &'static objc::Class<AudioUnitBusArray>;
 }
@@ -0,0 +1,652 @@
+use std::{
+    cell::UnsafeCell,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    arc,
+    at::{au, audio},
+    av, blocks, define_obj_type, ns, objc, os,
+};
+
+use crate::cat::audio::kernel::{self, Kernel, ParamNode, RenderEvent, Renderer};
+
+#[doc(alias = "AUEventSampleTime")]
+pub type EventSampleTime = i64;
+
+/// A special value of AUEventSampleTime indicating "immediately."
+///
+/// Events with sample times from `IMMEDIATE` up to `IMMEDIATE + u32::MAX`
+/// are relative to the start of the render cycle.
+#[doc(alias = "AUEventSampleTimeImmediate")]
+pub const EVENT_SAMPLE_TIME_IMMEDIATE: EventSampleTime = 0xffffffff00000000u64 as i64;
+
+#[doc(alias = "AURenderEventType")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct RenderEventType(pub u8);
+
+impl RenderEventType {
+    #[doc(alias = "AURenderEventParameter")]
+    pub const PARAM: Self = Self(1);
+
+    #[doc(alias = "AURenderEventParameterRamp")]
+    pub const PARAM_RAMP: Self = Self(2);
+
+    #[doc(alias = "AURenderEventMIDI")]
+    pub const MIDI: Self = Self(8);
+
+    #[doc(alias = "AURenderEventMIDISysEx")]
+    pub const MIDI_SYS_EX: Self = Self(9);
+
+    #[doc(alias = "AURenderEventMIDIEventList")]
+    pub const MIDI_EVENT_LIST: Self = Self(10);
+}
+
+/// Common header of realtime render events.
+#[doc(alias = "AURenderEventHeader")]
+#[doc(alias = "AURenderEvent")]
+#[derive(Debug)]
+#[repr(C)]
+pub struct RenderEventHeader {
+    pub next: *const RenderEventHeader,
+    pub event_sample_time: EventSampleTime,
+    pub event_type: RenderEventType,
+    pub reserved: u8,
+}
+
+#[doc(alias = "AUParameterEvent")]
+#[derive(Debug)]
+#[repr(C)]
+pub struct RenderParamEvent {
+    pub next: *const RenderEventHeader,
+    pub event_sample_time: EventSampleTime,
+    pub event_type: RenderEventType,
+    pub reserved: [u8; 3],
+    pub ramp_duration_sample_frames: u32,
+    pub param_addr: kernel::ParamAddr,
+    pub value: f32,
+}
+
+#[doc(alias = "AUMIDIEvent")]
+#[derive(Debug)]
+#[repr(C)]
+pub struct RenderMidiEvent {
+    pub next: *const RenderEventHeader,
+    pub event_sample_time: EventSampleTime,
+    pub event_type: RenderEventType,
+    pub reserved: u8,
+    pub len: u16,
+    pub cable: u8,
+    pub data: [u8; 3],
+}
+
+impl RenderEventHeader {
+    /// Offset of the event in frames from the start of the cycle at `sample_time`.
+    pub fn offset(&self, sample_time: f64) -> u32 {
+        let t = self.event_sample_time;
+        let offset = if (EVENT_SAMPLE_TIME_IMMEDIATE..0).contains(&t) {
+            t - EVENT_SAMPLE_TIME_IMMEDIATE
+        } else {
+            t - sample_time as i64
+        };
+        offset.clamp(0, u32::MAX as i64) as u32
+    }
+}
+
+/// Iterates linked list of realtime events as [`RenderEvent`]s.
+///
+/// SysEx and MIDI event list events are skipped.
+pub struct RenderEvents<'a> {
+    head: Option<&'a RenderEventHeader>,
+    sample_time: f64,
+}
+
+impl RenderEvents<'_> {
+    /// # Safety
+    ///
+    /// `head` must be null or point to a valid event list.
+    pub unsafe fn new(head: *const RenderEventHeader, sample_time: f64) -> Self {
+        Self {
+            head: unsafe { head.as_ref() },
+            sample_time,
+        }
+    }
+}
+
+impl Iterator for RenderEvents<'_> {
+    type Item = RenderEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = self.head?;
+            self.head = unsafe { event.next.as_ref() };
+            let offset = event.offset(self.sample_time);
+            match event.event_type {
+                RenderEventType::PARAM | RenderEventType::PARAM_RAMP => {
+                    let e = unsafe { &*(event as *const _ as *const RenderParamEvent) };
+                    return Some(RenderEvent::Param {
+                        offset,
+                        address: e.param_addr,
+                        value: e.value,
+                        ramp: e.ramp_duration_sample_frames,
+                    });
+                }
+                RenderEventType::MIDI => {
+                    let e = unsafe { &*(event as *const _ as *const RenderMidiEvent) };
+                    return Some(RenderEvent::Midi {
+                        offset,
+                        cable: e.cable,
+                        len: e.len.min(3) as u8,
+                        data: e.data,
+                    });
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+#[doc(alias = "AURenderPullInputBlock")]
+pub type RenderPullInputBlock = blocks::EscBlock<
+    fn(
+        *mut au::RenderActionFlags,
+        *const audio::TimeStamp,
+        u32,
+        isize,
+        *mut audio::BufList,
+    ) -> os::Status,
+>;
+
+/// Block to render the audio unit.
+///
+/// Called on realtime thread by the host through renderBlock.
+#[doc(alias = "AUInternalRenderBlock")]
+pub type InternalRenderBlock = blocks::SendBlock<
+    fn(
+        *mut au::RenderActionFlags,
+        *const audio::TimeStamp,
+        u32,
+        isize,
+        *mut audio::BufList,
+        *const RenderEventHeader,
+        Option<&mut RenderPullInputBlock>,
+    ) -> os::Status,
+>;
+
+/// Methods of AUAudioUnit subclasses override.
+#[objc::protocol(AUAudioUnitImplementation)]
+pub trait Subclass: objc::Obj {
+    #[objc::optional]
+    #[objc::msg_send(initWithComponentDescription:options:error:)]
+    fn init_with_comp_desc_opts_err<'a, 'ear>(
+        &'a mut self,
+        desc: audio::ComponentDesc,
+        opts: audio::ComponentInstantiationOpts,
+        err: *mut Option<&'ear ns::Error>,
+    ) -> Option<&'a mut Self>;
+
+    #[objc::optional]
+    #[objc::msg_send(inputBusses)]
+    fn input_busses(&self) -> Option<&au::AudioUnitBusArray>;
+
+    #[objc::optional]
+    #[objc::msg_send(outputBusses)]
+    fn output_busses(&self) -> Option<&au::AudioUnitBusArray>;
+
+    #[objc::optional]
+    #[objc::msg_send(internalRenderBlock)]
+    fn internal_render_block(&self) -> Option<&InternalRenderBlock>;
+
+    #[objc::optional]
+    #[objc::msg_send(allocateRenderResourcesAndReturnError:)]
+    fn allocate_render_resources_err<'ear>(&mut self, err: *mut Option<&'ear ns::Error>) -> bool;
+
+    #[objc::optional]
+    #[objc::msg_send(deallocateRenderResources)]
+    fn deallocate_render_resources(&mut self);
+
+    #[objc::optional]
+    #[objc::msg_send(reset)]
+    fn reset(&mut self);
+}
+
+impl au::AudioUnit {
+    /// Registers an AUAudioUnit subclass, making it available in this process
+    /// with the given component description.
+    #[doc(alias = "AudioComponentRegister")]
+    #[objc::msg_send(registerSubclass:asComponentDescription:name:version:)]
+    pub fn register_subclass(
+        cls: &objc::Class<au::AudioUnit>,
+        desc: audio::ComponentDesc,
+        name: &ns::String,
+        version: u32,
+    );
+}
+
+/// Audio unit implemented in Rust.
+///
+/// Component type and buses layout of the registered AUAudioUnit subclass.
+/// Instances are created by the host like any other audio unit, each of them
+/// gets its own kernel and parameter values.
+pub struct Def {
+    pub desc: audio::ComponentDesc,
+    /// "Manufacturer: Name" as shown by hosts.
+    pub name: String,
+    pub version: u32,
+    pub params: Vec<ParamNode>,
+    pub inputs: usize,
+    pub outputs: usize,
+    pub sample_rate: f64,
+    pub channels: u32,
+    kernel: Box<dyn Fn() -> Box<dyn Kernel> + Send + Sync>,
+}
+
+impl Def {
+    /// Effect with one stereo input and output by default.
+    pub fn new<K: Kernel>(
+        desc: audio::ComponentDesc,
+        name: impl Into<String>,
+        kernel: impl Fn() -> K + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            desc,
+            name: name.into(),
+            version: 1,
+            params: Vec::new(),
+            inputs: 1,
+            outputs: 1,
+            sample_rate: 44_100.0,
+            channels: 2,
+            kernel: Box::new(move || Box::new(kernel()) as Box<dyn Kernel>),
+        }
+    }
+
+    /// Registers [`KernelAudioUnit`] for the description.
+    ///
+    /// Fails with [`au::component_err::DUPLICATE_DESCRIPTION`] if the description
+    /// is already taken by another Rust audio unit.
+    #[doc(alias = "AudioComponentRegister")]
+    pub fn register(self) -> os::Result<&'static objc::Class<KernelAudioUnit>> {
+        kernel::ParamModel::new(self.params.iter().cloned())?;
+        if self.outputs == 0 {
+            return Err(au::err::INVALID_ELEMENT);
+        }
+        let mut defs = DEFS.lock().unwrap();
+        if defs.iter().any(|def| same_desc(&def.desc, &self.desc)) {
+            return Err(au::component_err::DUPLICATE_DESCRIPTION);
+        }
+        let cls = KernelAudioUnit::cls();
+        let name = ns::String::with_str(&self.name);
+        let (desc, version) = (self.desc, self.version);
+        defs.push(Arc::new(self));
+        drop(defs);
+        au::AudioUnit::register_subclass(
+            unsafe { std::mem::transmute::<&objc::Class<KernelAudioUnit>, _>(cls) },
+            desc,
+            &name,
+            version,
+        );
+        Ok(cls)
+    }
+}
+
+fn same_desc(a: &audio::ComponentDesc, b: &audio::ComponentDesc) -> bool {
+    (a.type_, a.sub_type, a.manufacturer) == (b.type_, b.sub_type, b.manufacturer)
+}
+
+static DEFS: Mutex<Vec<Arc<Def>>> = Mutex::new(Vec::new());
+
+/// Forwards to boxed kernel created by [`Def`].
+struct Dsp(Box<dyn Kernel>);
+
+impl Kernel for Dsp {
+    fn allocate(&mut self, asbd: &audio::StreamBasicDesc, max_frames: u32) -> os::Result {
+        self.0.allocate(asbd, max_frames)
+    }
+
+    fn deallocate(&mut self) {
+        self.0.deallocate()
+    }
+
+    fn reset(&mut self) {
+        self.0.reset()
+    }
+
+    fn param_changed(&mut self, address: kernel::ParamAddr, value: f32) {
+        self.0.param_changed(address, value)
+    }
+
+    fn midi(&mut self, cable: u8, data: &[u8]) {
+        self.0.midi(cable, data)
+    }
+
+    fn process(&mut self, ctx: &mut kernel::Ctx) -> os::Result {
+        self.0.process(ctx)
+    }
+}
+
+/// Renderer shared by the audio unit and its render block.
+///
+/// Parameter values are atomics of [`kernel::ParamValues`], the rest of
+/// renderer is only reachable through [`Shared::renderer`].
+struct Shared(UnsafeCell<Renderer<Dsp>>);
+
+unsafe impl Sync for Shared {}
+
+impl Shared {
+    /// # Safety
+    ///
+    /// Caller must be the only user of the renderer while the result is alive.
+    /// Render block calls it on the single render thread of the host.
+    /// Other callers are allocation, deallocation and reset of render
+    /// resources, during which the host doesn't render.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn renderer(&self) -> &mut Renderer<Dsp> {
+        unsafe { &mut *self.0.get() }
+    }
+}
+
+struct State {
+    shared: Arc<Shared>,
+    inputs: arc::R<au::AudioUnitBusArray>,
+    outputs: arc::R<au::AudioUnitBusArray>,
+    render_block: arc::R<InternalRenderBlock>,
+}
+
+define_obj_type!(
+    /// AUAudioUnit subclass rendering with a Rust [`Kernel`].
+    ///
+    /// Registered for every description with [`Def::register`].
+    pub KernelAudioUnit(au::AudioUnit) + SubclassImpl, State, CIDRE_KERNEL_AUDIO_UNIT
+);
+
+impl Subclass for KernelAudioUnit {}
+
+impl KernelAudioUnit {
+    /// State is set by `initWithComponentDescription:options:error:`.
+    fn state(&self) -> Option<&State> {
+        self.ivar().get()
+    }
+
+    fn def(&self) -> Option<Arc<Def>> {
+        let desc = self.component_desc();
+        let defs = DEFS.lock().unwrap();
+        defs.iter().find(|def| same_desc(&def.desc, &desc)).cloned()
+    }
+
+    fn setup(&mut self, def: &Def) -> os::Result {
+        let tree = kernel::ParamModel::new(def.params.iter().cloned())?;
+        let format =
+            av::AudioFormat::standard_with_sample_rate_and_channels(def.sample_rate, def.channels)
+                .ok_or(au::err::FORMAT_NOT_SUPPORTED)?;
+        let busses = |n: usize| -> os::Result<arc::R<ns::Array<au::AudioUnitBus>>> {
+            let mut res = Vec::with_capacity(n);
+            for _ in 0..n {
+                let bus = au::AudioUnitBus::with_format(&format)
+                    .map_err(|_| au::err::FORMAT_NOT_SUPPORTED)?;
+                res.push(bus);
+            }
+            Ok(ns::Array::from_slice_retained(&res))
+        };
+        let inputs = busses(def.inputs)?;
+        let inputs = au::AudioUnitBusArray::with_busses(self, au::AudioUnitBusType::Input, &inputs);
+        let outputs = busses(def.outputs)?;
+        let outputs =
+            au::AudioUnitBusArray::with_busses(self, au::AudioUnitBusType::Ouptut, &outputs);
+
+        let mut params = au::ParameterTree::with_model(&tree);
+        params.bind_values(tree.values().clone());
+        self.set_param_tree(Some(&params));
+
+        let renderer = Renderer::new(Dsp((def.kernel)()), tree.values().clone());
+        let shared = Arc::new(Shared(UnsafeCell::new(renderer)));
+        let render_block = render_block(shared.clone());
+        self.set_inner(State {
+            shared,
+            inputs,
+            outputs,
+            render_block,
+        });
+        Ok(())
+    }
+
+    fn allocate(&self) -> os::Result {
+        let Some(state) = self.state() else {
+            return Err(au::err::UNINITIALIZED);
+        };
+        let output = state
+            .outputs
+            .try_get(0)
+            .map_err(|_| au::err::INVALID_ELEMENT)?
+            .format();
+        for i in 0..state.inputs.len() {
+            let input = state
+                .inputs
+                .try_get(i)
+                .map_err(|_| au::err::INVALID_ELEMENT)?;
+            if input.format().absd() != output.absd() {
+                return Err(au::err::FORMAT_NOT_SUPPORTED);
+            }
+        }
+        let max_frames = self.max_frames_to_render();
+        unsafe { state.shared.renderer() }.allocate(output.absd(), max_frames)
+    }
+}
+
+fn render_block(shared: Arc<Shared>) -> arc::R<InternalRenderBlock> {
+    InternalRenderBlock::new7(
+        move |_flags: *mut au::RenderActionFlags,
+              ts: *const audio::TimeStamp,
+              frames: u32,
+              bus: isize,
+              output: *mut audio::BufList,
+              events: *const RenderEventHeader,
+              pull: Option<&mut RenderPullInputBlock>| {
+            let renderer = unsafe { shared.renderer() };
+            let (ts, output) = unsafe { (&*ts, &mut *output) };
+            let events = unsafe { RenderEvents::new(events, ts.sample_time) };
+            let res = match pull {
+                Some(pull) => {
+                    let mut pull =
+                        |bus, ts: &audio::TimeStamp, frames, list: &mut audio::BufList| {
+                            let mut flags = au::RenderActionFlags::default();
+                            pull.call(&mut flags, ts, frames, bus, list).result()
+                        };
+                    renderer.render(ts, frames, bus, output, events, Some(&mut pull))
+                }
+                None => renderer.render(ts, frames, bus, output, events, None),
+            };
+            match res {
+                Ok(()) => os::Status::NO_ERR,
+                Err(e) => e.into(),
+            }
+        },
+    )
+}
+
+fn fail<T>(err: *mut Option<&ns::Error>, e: os::Error) -> Option<T> {
+    if let Some(err) = unsafe { err.as_mut() } {
+        let e: arc::R<ns::Error> = e.into();
+        *err = Some(e.autoreleased());
+    }
+    None
+}
+
+#[objc::add_methods]
+impl SubclassImpl for KernelAudioUnit {
+    extern "C" fn impl_init_with_comp_desc_opts_err<'a, 'ear>(
+        &'a mut self,
+        _cmd: Option<&objc::Sel>,
+        desc: audio::ComponentDesc,
+        opts: audio::ComponentInstantiationOpts,
+        err: *mut Option<&'ear ns::Error>,
+    ) -> Option<&'a mut Self> {
+        let this = self.super_init_with_comp_desc_opts_err(desc, opts, err)?;
+        let res = match this.def() {
+            Some(def) => this.setup(&def),
+            None => Err(au::component_err::UNSUPPORTED_TYPE),
+        };
+        match res {
+            Ok(()) => Some(this),
+            Err(e) => {
+                let _release: arc::R<Self> = unsafe { std::mem::transmute(this) };
+                fail(err, e)
+            }
+        }
+    }
+
+    extern "C" fn impl_input_busses(
+        &self,
+        _cmd: Option<&objc::Sel>,
+    ) -> Option<&au::AudioUnitBusArray> {
+        self.state().map(|s| s.inputs.as_ref())
+    }
+
+    extern "C" fn impl_output_busses(
+        &self,
+        _cmd: Option<&objc::Sel>,
+    ) -> Option<&au::AudioUnitBusArray> {
+        self.state().map(|s| s.outputs.as_ref())
+    }
+
+    extern "C" fn impl_internal_render_block(
+        &self,
+        _cmd: Option<&objc::Sel>,
+    ) -> Option<&InternalRenderBlock> {
+        self.state().map(|s| s.render_block.as_ref())
+    }
+
+    extern "C" fn impl_allocate_render_resources_err<'ear>(
+        &mut self,
+        _cmd: Option<&objc::Sel>,
+        err: *mut Option<&'ear ns::Error>,
+    ) -> bool {
+        if !self.super_allocate_render_resources_err(err) {
+            return false;
+        }
+        match self.allocate() {
+            Ok(()) => true,
+            Err(e) => {
+                self.deallocate_render_resources();
+                fail::<()>(err, e);
+                false
+            }
+        }
+    }
+
+    extern "C" fn impl_deallocate_render_resources(&mut self, _cmd: Option<&objc::Sel>) {
+        if let Some(state) = self.state() {
+            unsafe { state.shared.renderer() }.deallocate();
+        }
+        self.super_deallocate_render_resources();
+    }
+
+    extern "C" fn impl_reset(&mut self, _cmd: Option<&objc::Sel>) {
+        if let Some(state) = self.state() {
+            unsafe { state.shared.renderer() }.reset();
+        }
+        self.super_reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        at::{au, audio},
+        ns,
+    };
+
+    use super::{EVENT_SAMPLE_TIME_IMMEDIATE, RenderEventHeader, RenderEventType, RenderEvents};
+
+    #[test]
+    fn events() {
+        let midi = super::RenderMidiEvent {
+            next: std::ptr::null(),
+            event_sample_time: EVENT_SAMPLE_TIME_IMMEDIATE + 3,
+            event_type: RenderEventType::MIDI,
+            reserved: 0,
+            len: 3,
+            cable: 0,
+            data: [0x90, 60, 100],
+        };
+        let sys_ex = RenderEventHeader {
+            next: &midi as *const _ as _,
+            event_sample_time: 1000,
+            event_type: RenderEventType::MIDI_SYS_EX,
+            reserved: 0,
+        };
+        let param = super::RenderParamEvent {
+            next: &sys_ex,
+            event_sample_time: 1010,
+            event_type: RenderEventType::PARAM_RAMP,
+            reserved: [0; 3],
+            ramp_duration_sample_frames: 64,
+            param_addr: 7,
+            value: 0.5,
+        };
+        let events: Vec<_> =
+            unsafe { RenderEvents::new(&param as *const _ as _, 1000.0) }.collect();
+        assert_eq!(
+            events,
+            [
+                au::RenderEvent::Param {
+                    offset: 10,
+                    address: 7,
+                    value: 0.5,
+                    ramp: 64
+                },
+                au::RenderEvent::Midi {
+                    offset: 3,
+                    cable: 0,
+                    len: 3,
+                    data: [0x90, 60, 100]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn basics() {
+        let desc = audio::ComponentDesc {
+            type_: u32::from_be_bytes(*b"aufx"),
+            sub_type: u32::from_be_bytes(*b"gain"),
+            manufacturer: u32::from_be_bytes(*b"Cdre"),
+            flags: 0,
+            flags_mask: 0,
+        };
+        let mut def = au::KernelDef::new(desc, "cidre: Gain", || {
+            |ctx: &mut au::KernelCtx| {
+                ctx.pull_input_in_place(0)?;
+                let gain = ctx.param(0);
+                for i in 0..ctx.channels() {
+                    ctx.channel_mut(i)
+                        .unwrap()
+                        .iter_mut()
+                        .for_each(|s| *s *= gain);
+                }
+                Ok(())
+            }
+        });
+        def.params
+            .push(au::ParamDesc::new("gain", "Gain", 0, 0.0..=2.0, 1.0).into());
+        let cls = def.register().unwrap();
+        assert!(std::ptr::eq(cls, au::KernelAudioUnit::cls()));
+
+        let opts = audio::ComponentInstantiationOpts::LOAD_IN_PROCESS;
+        let unit = ns::if_none(|err| unsafe {
+            au::AudioUnit::alloc().init_with_comp_desc_opts_err(desc, opts, err)
+        })
+        .unwrap();
+        assert_eq!(unit.input_busses().len(), 1);
+        assert_eq!(unit.output_busses().len(), 1);
+        let tree = unit.param_tree().unwrap();
+        assert_eq!(tree.param_with_addr(0).unwrap().value(), 1.0);
+
+        let dup = au::KernelDef::new(desc, "cidre: Gain", || |_: &mut au::KernelCtx| Ok(()));
+        assert_eq!(
+            dup.register().unwrap_err(),
+            au::component_err::DUPLICATE_DESCRIPTION
+        );
+    }
+}
@@ -1207,6 +1207,14 @@ impl UnitRef<UninitializedState> {
         }
     }
 
+    /// Same as [`Self::initialize`], but gives the unit back on error.
+    pub fn try_initialize(mut self) -> Result<UnitRef<InitializedState>, (os::Error, Self)> {
+        match unsafe { AudioUnitInitialize(&mut self.0).result() } {
+            Ok(()) => Ok(unsafe { std::mem::transmute(self) }),
+            Err(err) => Err((err, self)),
+        }
+    }
+
     pub fn set_offline_render(&mut self, val: bool) -> os::Result {
         self.0.set_offline_render(val)
     }
@@ -1367,6 +1375,14 @@ impl UnitRef<InitializedState> {
         })
     }
 
+    /// Same as [`Self::unintialize`], but gives the unit back on error.
+    pub fn try_uninitialize(mut self) -> Result<UnitRef<UninitializedState>, (os::Error, Self)> {
+        match unsafe { AudioUnitUninitialize(&mut self.0).result() } {
+            Ok(()) => Ok(unsafe { std::mem::transmute(self) }),
+            Err(err) => Err((err, self)),
+        }
+    }
+
     pub fn render<const N: usize>(
         &mut self,
         timestamp: &audio::TimeStamp,
@@ -117,6 +117,12 @@ impl FormatConverter<InitializedState> {
     }
 }
 
+impl From<FormatConverter<UninitializedState>> for au::UnitNode {
+    fn from(val: FormatConverter<UninitializedState>) -> Self {
+        au::UnitNode::converter(val.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::ffi::c_void;
@@ -339,6 +339,12 @@ impl MultiChannelMixer<InitializedState> {
     }
 }
 
+impl From<MultiChannelMixer<UninitializedState>> for au::UnitNode {
+    fn from(val: MultiChannelMixer<UninitializedState>) -> Self {
+        au::UnitNode::converter(val.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -0,0 +1,274 @@
+use crate::{
+    at::{
+        self, au,
+        au::{Graph, GraphPull, Node, Scope, Unit, UnitRef},
+        audio,
+        audio::component::{InitializedState, UninitializedState},
+    },
+    os,
+};
+
+enum UnitState {
+    Uninitialized(UnitRef<UninitializedState>),
+    Initialized(UnitRef<InitializedState>),
+    Invalid,
+}
+
+/// Pull of the graph that is active during `AudioUnitRender`.
+struct PullCtx {
+    pull: Option<std::ptr::NonNull<GraphPull<'static>>>,
+}
+
+/// Audio unit as a node of offline [`Graph`].
+///
+/// Inputs of the unit are wired to the graph with input callbacks,
+/// so the unit pulls its sources while rendering.
+pub struct UnitNode {
+    state: UnitState,
+    ctx: Box<PullCtx>,
+    converts: bool,
+}
+
+impl UnitNode {
+    pub fn new(unit: UnitRef<UninitializedState>) -> Self {
+        Self {
+            state: UnitState::Uninitialized(unit),
+            ctx: Box::new(PullCtx { pull: None }),
+            converts: false,
+        }
+    }
+
+    /// Node of unit which inputs may have different formats from its output.
+    pub fn converter(unit: UnitRef<UninitializedState>) -> Self {
+        Self {
+            converts: true,
+            ..Self::new(unit)
+        }
+    }
+
+    pub fn unit(&self) -> &Unit {
+        match &self.state {
+            UnitState::Uninitialized(u) => u.unit(),
+            UnitState::Initialized(u) => u.unit(),
+            UnitState::Invalid => unreachable!(),
+        }
+    }
+
+    pub fn unit_mut(&mut self) -> &mut Unit {
+        match &mut self.state {
+            UnitState::Uninitialized(u) => u.unit_mut(),
+            UnitState::Initialized(u) => u.unit_mut(),
+            UnitState::Invalid => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        matches!(self.state, UnitState::Initialized(_))
+    }
+
+    /// Unit in uninitialized state, it stays in the node on error.
+    fn uninitialized(&mut self) -> os::Result<&mut UnitRef<UninitializedState>> {
+        if let UnitState::Initialized(_) = self.state {
+            let UnitState::Initialized(unit) =
+                std::mem::replace(&mut self.state, UnitState::Invalid)
+            else {
+                unreachable!()
+            };
+            self.state = match unit.try_uninitialize() {
+                Ok(unit) => UnitState::Uninitialized(unit),
+                Err((err, unit)) => {
+                    self.state = UnitState::Initialized(unit);
+                    return Err(err);
+                }
+            };
+        }
+        match &mut self.state {
+            UnitState::Uninitialized(unit) => Ok(unit),
+            _ => Err(au::err::UNINITIALIZED),
+        }
+    }
+
+    /// Initializes configured unit, it stays in the node on error.
+    fn initialize(&mut self) -> os::Result {
+        let UnitState::Uninitialized(unit) = std::mem::replace(&mut self.state, UnitState::Invalid)
+        else {
+            unreachable!()
+        };
+        self.state = match unit.try_initialize() {
+            Ok(unit) => UnitState::Initialized(unit),
+            Err((err, unit)) => {
+                self.state = UnitState::Uninitialized(unit);
+                return Err(err);
+            }
+        };
+        Ok(())
+    }
+}
+
+impl From<UnitRef<UninitializedState>> for UnitNode {
+    fn from(val: UnitRef<UninitializedState>) -> Self {
+        Self::new(val)
+    }
+}
+
+extern "C-unwind" fn input_cb(
+    ref_con: *mut PullCtx,
+    _io_action_flags: &mut au::RenderActionFlags,
+    ts: &at::AudioTimeStamp,
+    bus: u32,
+    frames: u32,
+    io_data: *mut at::AudioBufList,
+) -> os::Status {
+    let ctx = unsafe { &mut *ref_con };
+    let Some(mut pull) = ctx.pull else {
+        return au::err::NO_CONNECTION.status();
+    };
+    if io_data.is_null() {
+        return au::err::NO_CONNECTION.status();
+    }
+    let pull = unsafe { pull.as_mut() };
+    match pull(bus, ts, frames, unsafe { &mut *io_data }) {
+        Ok(()) => os::Status::NO_ERR,
+        Err(e) => e.status(),
+    }
+}
+
+impl Node for UnitNode {
+    fn input_buses(&self) -> u32 {
+        self.unit().element_count(Scope::INPUT).unwrap_or(0)
+    }
+
+    fn output_buses(&self) -> u32 {
+        self.unit().element_count(Scope::OUTPUT).unwrap_or(0)
+    }
+
+    fn input_format(&self, bus: u32) -> os::Result<audio::StreamBasicDesc> {
+        self.unit().stream_format(Scope::INPUT, bus)
+    }
+
+    fn set_input_format(&mut self, bus: u32, val: &audio::StreamBasicDesc) -> os::Result {
+        if self.is_initialized() {
+            return Err(au::err::CANNOT_DO_IN_CURRENT_CONTEXT);
+        }
+        self.unit_mut().set_stream_format(Scope::INPUT, bus, val)
+    }
+
+    fn output_format(&self, bus: u32) -> os::Result<audio::StreamBasicDesc> {
+        self.unit().stream_format(Scope::OUTPUT, bus)
+    }
+
+    fn converts_format(&self) -> bool {
+        self.converts
+    }
+
+    fn prepare(&mut self, max_frames: u32) -> os::Result {
+        let inputs = self.input_buses();
+        let ctx: *const PullCtx = self.ctx.as_ref();
+        let unit = self.uninitialized()?;
+        unit.set_max_frames_per_slice(max_frames)?;
+        // not every unit supports offline render, it is just a hint
+        let _ = unit.set_offline_render(true);
+        for bus in 0..inputs {
+            unit.set_input_cb(bus, input_cb, ctx)?;
+        }
+        self.initialize()
+    }
+
+    fn render(
+        &mut self,
+        ts: &audio::TimeStamp,
+        bus: u32,
+        frames: u32,
+        buf_list: &mut audio::BufList,
+        pull: &mut GraphPull,
+    ) -> os::Result {
+        let UnitState::Initialized(unit) = &mut self.state else {
+            return Err(au::err::UNINITIALIZED);
+        };
+        let pull = std::ptr::NonNull::from(pull);
+        // erased lifetime is only used during this render call
+        self.ctx.pull = Some(unsafe {
+            std::mem::transmute::<
+                std::ptr::NonNull<GraphPull<'_>>,
+                std::ptr::NonNull<GraphPull<'static>>,
+            >(pull)
+        });
+        let res = unit.render(ts, bus, frames, buf_list);
+        self.ctx.pull = None;
+        res
+    }
+}
+
+impl Graph<'_> {
+    /// Renders `frames` of the output node and writes them to `file`.
+    ///
+    /// Client data format of `file` must match output format of the graph.
+    pub fn render_to_file(&mut self, file: &mut at::ExtAudioFile, frames: u64) -> os::Result {
+        if !self.is_prepared() {
+            self.prepare()?;
+        }
+        self.render_slices(frames, |_sample_time, n, buf_list| file.write(n, buf_list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::at::{au, audio};
+
+    #[test]
+    fn basics() {
+        let asbd = audio::StreamBasicDesc::common_f32(48_000.0, 2, false);
+        let mut graph = au::Graph::new();
+        graph.set_max_frames_per_slice(512);
+
+        let src = graph.add_node(au::GraphSource::new(asbd, |ts, frames, buf_list| {
+            let bufs = unsafe { au::graph_bufs_mut(buf_list) };
+            for buf in bufs {
+                let data = unsafe {
+                    std::slice::from_raw_parts_mut(buf.data as *mut f32, frames as usize)
+                };
+                for (i, s) in data.iter_mut().enumerate() {
+                    *s = ((ts.sample_time + i as f64) * 0.01).sin() as f32;
+                }
+            }
+            Ok(())
+        }));
+
+        let mut conv = au::FormatConverter::new_apple().unwrap();
+        conv.set_output_stream_format(&audio::StreamBasicDesc::common_f32(44_100.0, 2, false))
+            .unwrap();
+        let conv = graph.add_node(au::UnitNode::from(conv));
+
+        let mut mixer = au::MultiChannelMixer::new_apple().unwrap();
+        mixer
+            .unit_mut()
+            .set_element_count(au::Scope::INPUT, 1)
+            .unwrap();
+        mixer
+            .set_output_stream_format(&audio::StreamBasicDesc::common_f32(44_100.0, 2, false))
+            .unwrap();
+        let mixer = graph.add_node(au::UnitNode::from(mixer));
+
+        graph.connect(src, 0, conv, 0).unwrap();
+        graph.connect(conv, 0, mixer, 0).unwrap();
+        graph.set_output(mixer).unwrap();
+        graph.prepare().unwrap();
+
+        let mut frames = 0;
+        graph
+            .render_slices(4096, |_, n, buf_list| {
+                assert_eq!(buf_list.number_buffers, 2);
+                frames += n;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(frames, 4096);
+        assert_eq!(graph.sample_time(), 4096.0);
+
+        let err = graph
+            .connect(src, 0, mixer, 0)
+            .expect_err("output bus is already connected");
+        assert_eq!(err, au::graph_err::FAN_OUT);
+    }
+}
@@ -0,0 +1,211 @@
+#[cfg(feature = "blocks")]
+use crate::blocks;
+use crate::{arc, at::au, define_cls, define_obj_type, ns, objc};
+
+use crate::cat::audio::kernel;
+
+#[doc(alias = "AUValue")]
+pub type Value = f32;
+
+#[doc(alias = "AUImplementorValueObserver")]
+#[cfg(feature = "blocks")]
+pub type ImplementorValueObserver = blocks::SyncBlock<fn(&Parameter, Value)>;
+
+#[doc(alias = "AUImplementorValueProvider")]
+#[cfg(feature = "blocks")]
+pub type ImplementorValueProvider = blocks::SyncBlock<fn(&Parameter) -> Value>;
+
+define_obj_type!(
+    /// A node in an audio unit's tree of parameters.
+    #[doc(alias = "AUParameterNode")]
+    pub ParameterNode(ns::Id)
+);
+
+impl ParameterNode {
+    /// A non-localized, permanent name for a parameter or group.
+    #[objc::msg_send(identifier)]
+    pub fn identifier(&self) -> arc::R<ns::String>;
+
+    /// Generated by concatenating the identifiers of a node's parents with its own.
+    #[objc::msg_send(keyPath)]
+    pub fn key_path(&self) -> arc::R<ns::String>;
+
+    #[objc::msg_send(displayName)]
+    pub fn display_name(&self) -> arc::R<ns::String>;
+
+    #[cfg(feature = "blocks")]
+    #[objc::msg_send(setImplementorValueObserver:)]
+    pub fn set_implementor_value_observer(&mut self, val: Option<&mut ImplementorValueObserver>);
+
+    #[cfg(feature = "blocks")]
+    #[objc::msg_send(setImplementorValueProvider:)]
+    pub fn set_implementor_value_provider(&mut self, val: Option<&mut ImplementorValueProvider>);
+}
+
+define_obj_type!(
+    #[doc(alias = "AUParameterGroup")]
+    pub ParameterGroup(ParameterNode)
+);
+
+impl ParameterGroup {
+    #[objc::msg_send(children)]
+    pub fn children(&self) -> arc::R<ns::Array<ParameterNode>>;
+
+    #[objc::msg_send(allParameters)]
+    pub fn all_params(&self) -> arc::R<ns::Array<Parameter>>;
+}
+
+define_obj_type!(
+    #[doc(alias = "AUParameterTree")]
+    pub ParameterTree(ParameterGroup)
+);
+
+impl ParameterTree {
+    define_cls!(AU_PARAMETER_TREE);
+
+    #[objc::msg_send(parameterWithAddress:)]
+    pub fn param_with_addr(&self, address: kernel::ParamAddr) -> Option<arc::R<Parameter>>;
+
+    #[objc::msg_send(createParameterWithIdentifier:name:address:min:max:unit:unitName:flags:valueStrings:dependentParameters:)]
+    pub fn create_param(
+        identifier: &ns::String,
+        name: &ns::String,
+        address: kernel::ParamAddr,
+        min: Value,
+        max: Value,
+        unit: au::ParamUnit,
+        unit_name: Option<&ns::String>,
+        flags: au::ParamFlags,
+        value_strings: Option<&ns::Array<ns::String>>,
+        dependent_params: Option<&ns::Array<ns::Number>>,
+    ) -> arc::R<Parameter>;
+
+    #[objc::msg_send(createGroupWithIdentifier:name:children:)]
+    pub fn create_group(
+        identifier: &ns::String,
+        name: &ns::String,
+        children: &ns::Array<ParameterNode>,
+    ) -> arc::R<ParameterGroup>;
+
+    #[objc::msg_send(createTreeWithChildren:)]
+    pub fn create_tree(children: &ns::Array<ParameterNode>) -> arc::R<Self>;
+
+    /// Mirrors Rust parameter tree model.
+    ///
+    /// Values are initialized from current values of the model.
+    pub fn with_model(model: &kernel::ParamModel) -> arc::R<Self> {
+        let values = model.values();
+        let children = Self::nodes(model.nodes(), values);
+        Self::create_tree(&ns::Array::from_slice_retained(&children))
+    }
+
+    fn nodes(
+        nodes: &[kernel::ParamNode],
+        values: &kernel::ParamValues,
+    ) -> Vec<arc::R<ParameterNode>> {
+        nodes
+            .iter()
+            .map(|node| match node {
+                kernel::ParamNode::Param(p) => {
+                    let mut param = Self::create_param(
+                        &ns::String::with_str(&p.identifier),
+                        &ns::String::with_str(&p.name),
+                        p.address,
+                        p.min,
+                        p.max,
+                        p.unit,
+                        None,
+                        p.flags,
+                        None,
+                        None,
+                    );
+                    param.set_value(values.get(p.address).unwrap_or(p.default));
+                    unsafe { std::mem::transmute(param) }
+                }
+                kernel::ParamNode::Group {
+                    identifier,
+                    name,
+                    children,
+                } => {
+                    let children = Self::nodes(children, values);
+                    let group = Self::create_group(
+                        &ns::String::with_str(identifier),
+                        &ns::String::with_str(name),
+                        &ns::Array::from_slice_retained(&children),
+                    );
+                    unsafe { std::mem::transmute(group) }
+                }
+            })
+            .collect()
+    }
+
+    /// Routes host and UI reads and writes of the tree to the model values.
+    #[cfg(feature = "blocks")]
+    pub fn bind_values(&mut self, values: std::sync::Arc<kernel::ParamValues>) {
+        let observed = values.clone();
+        let mut observer = ImplementorValueObserver::new2(move |param: &Parameter, val| {
+            let _ = observed.set(param.address(), val);
+        });
+        let mut provider = ImplementorValueProvider::new1(move |param: &Parameter| {
+            values.get(param.address()).unwrap_or_default()
+        });
+        self.set_implementor_value_observer(Some(&mut observer));
+        self.set_implementor_value_provider(Some(&mut provider));
+    }
+}
+
+define_obj_type!(
+    #[doc(alias = "AUParameter")]
+    pub Parameter(ParameterNode)
+);
+
+impl Parameter {
+    #[objc::msg_send(minValue)]
+    pub fn min_value(&self) -> Value;
+
+    #[objc::msg_send(maxValue)]
+    pub fn max_value(&self) -> Value;
+
+    #[objc::msg_send(unit)]
+    pub fn unit(&self) -> au::ParamUnit;
+
+    #[objc::msg_send(flags)]
+    pub fn flags(&self) -> au::ParamFlags;
+
+    #[objc::msg_send(address)]
+    pub fn address(&self) -> kernel::ParamAddr;
+
+    #[objc::msg_send(value)]
+    pub fn value(&self) -> Value;
+
+    #[objc::msg_send(setValue:)]
+    pub fn set_value(&mut self, val: Value);
+}
+
+unsafe extern "C" {
+    static AU_PARAMETER_TREE: &'static objc::Class<ParameterTree>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::at::au;
+
+    #[test]
+    fn basics() {
+        let model = au::ParamModel::new([
+            au::ParamDesc::new("gain", "Gain", 0, 0.0..=2.0, 1.0).into(),
+            au::ParamNode::group(
+                "fx",
+                "Effect",
+                [au::ParamDesc::new("mix", "Mix", 7, 0.0..=1.0, 0.5).into()],
+            ),
+        ])
+        .unwrap();
+        let tree = au::ParameterTree::with_model(&model);
+        assert_eq!(tree.all_params().len(), 2);
+        let mix = tree.param_with_addr(7).unwrap();
+        assert_eq!(mix.key_path().to_string(), "fx.mix");
+        assert_eq!(mix.value(), 0.5);
+        assert_eq!(mix.max_value(), 1.0);
+    }
+}
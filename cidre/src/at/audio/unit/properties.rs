@@ -1,6 +1,10 @@
 use std::ffi::c_void;
 
-use crate::{arc, at, at::au, cf, define_opts, os};
+use crate::{
+    arc, at,
+    at::au::{self, ParamFlags, ParamUnit},
+    cf, define_opts, os,
+};
 
 impl au::Scope {
     /// The context for audio unit characteristics that apply to the audio unit as a
@@ -51,186 +55,6 @@ impl au::Element {
     pub const INPUT: Self = Self(1);
 }
 
-define_opts!(
-    #[doc(alias = "AudioUnitParameterOptions")]
-    pub ParamFlags(u32)
-);
-
-impl ParamFlags {
-    #[doc(alias = "kAudioUnitParameterFlag_CFNameRelease")]
-    pub const CF_NAME_RELEASE: Self = Self(1 << 4);
-
-    #[doc(alias = "kAudioUnitParameterFlag_OmitFromPresets")]
-    pub const OMIT_FROM_PRESETS: Self = Self(1 << 13);
-
-    #[doc(alias = "kAudioUnitParameterFlag_PlotHistory")]
-    pub const PLOT_HISTORY: Self = Self(1 << 14);
-
-    #[doc(alias = "kAudioUnitParameterFlag_MeterReadOnly")]
-    pub const METER_READ_ONLY: Self = Self(1 << 15);
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplayMask")]
-    pub const DISPLAY_MASK: Self = Self((7 << 16) | (1 << 22));
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplaySquareRoot")]
-    pub const DISPLAY_SQUARE_ROOT: Self = Self(1 << 16);
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplaySquared")]
-    pub const DISPLAY_SQUARED: Self = Self(2 << 16);
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplayCubed")]
-    pub const DISPLAY_CUBED: Self = Self(3 << 16);
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplayCubeRoot")]
-    pub const DISPLAY_CUBE_ROOT: Self = Self(4 << 16);
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplayExponential")]
-    pub const DISPLAY_EXPONENTIAL: Self = Self(4 << 16);
-
-    #[doc(alias = "kAudioUnitParameterFlag_HasClump")]
-    pub const HAS_CLUMP: Self = Self(1 << 20);
-
-    #[doc(alias = "kAudioUnitParameterFlag_ValuesHaveStrings")]
-    pub const VALUES_HAVE_STRINGS: Self = Self(1 << 21);
-
-    #[doc(alias = "kAudioUnitParameterFlag_DisplayLogarithmic")]
-    pub const DISPLAY_LOGARITHMIC: Self = Self(1 << 22);
-
-    /// This flag provides a hint to a host that this parameter should be controlled through the
-    /// highest resolution if the host has limitations on the control resolution of parameter
-    /// values. Generally this means that controlling this parameter with a single MIDI Control
-    /// message (i.e. 128 values) is too course a grain for that parameter, and a finer control
-    /// resolution should be used if possible. If this flag is not set, then a host can assume that
-    /// a 7-bit control quantization is acceptable. Ideally, parameters should be controlled in the
-    /// fullest resolution that they are published with.
-    #[doc(alias = "kAudioUnitParameterFlag_IsHighResolution")]
-    pub const IS_HIGH_RESOLUTION: Self = Self(1 << 23);
-
-    /// Changing the parameter in real-time will cause a glitch or otherwise undesirable effect.
-    #[doc(alias = "kAudioUnitParameterFlag_NonRealTime")]
-    pub const NON_REAL_TIME: Self = Self(1 << 24);
-
-    /// If set, the parameter can be ramped.
-    #[doc(alias = "kAudioUnitParameterFlag_CanRamp")]
-    pub const CAN_RAMP: Self = Self(1 << 25);
-
-    /// If set, the parameter is obscure (hint to UI to only display in expert mode).
-    #[doc(alias = "kAudioUnitParameterFlag_ExpertMode")]
-    pub const EXPERT_MODE: Self = Self(1 << 26);
-
-    /// In the original ParameterInfo a C string only was specified. With MacOS 10.2 and later, the
-    /// last four bytes of this string are reserved for a CFStringRef, which gives the ability to
-    /// used Unicode encoding, necessary for providing a name in languages using non-ASCII
-    /// characters. If this flag bit is set, the CFStringRef is valid.
-    #[doc(alias = "kAudioUnitParameterFlag_HasCFNameString")]
-    pub const HAS_CF_NAME_STRING: Self = Self(1 << 27);
-
-    /// If set, changing this parameter may change any number of others in the AudioUnit.
-    #[doc(alias = "kAudioUnitParameterFlag_IsGlobalMeta")]
-    pub const IS_GLOBAL_META: Self = Self(1 << 28);
-
-    /// If set, changing this parameter may change others in the same element as the current
-    /// parameter.
-    #[doc(alias = "kAudioUnitParameterFlag_IsElementMeta")]
-    pub const IS_ELEMENT_META: Self = Self(1 << 29);
-
-    #[doc(alias = "kAudioUnitParameterFlag_IsReadable")]
-    pub const IS_READABLE: Self = Self(1 << 30);
-
-    #[doc(alias = "kAudioUnitParameterFlag_IsWritable")]
-    pub const IS_WRITABLE: Self = Self(1 << 30);
-}
-
-#[doc(alias = "AudioUnitParameterUnit")]
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-#[repr(u32)]
-pub enum ParamUnit {
-    /// untyped value generally between 0.0 and 1.0
-    Generic = 0,
-
-    /// takes an integer value (good for menu selections)
-    Indexed = 1,
-
-    /// 0.0 means false, non-zero means true
-    Boolean = 2,
-
-    /// usually from 0 -> 100, sometimes -50 -> +50
-    Percent = 3,
-
-    /// absolute or relative time
-    Seconds = 4,
-
-    /// one sample frame equals (1.0/sampleRate) seconds
-    SampleFrames = 5,
-
-    /// -180 to 180 degrees
-    Phase = 6,
-
-    /// rate multiplier, for playback speed, etc. (e.g. 2.0 == twice as fast)
-    Rate = 7,
-
-    /// absolute frequency/pitch in cycles/second
-    Hertz = 8,
-
-    /// unit of relative pitch
-    Cents = 9,
-
-    /// useful for coarse detuning
-    RelativeSemiTones = 10,
-
-    /// absolute pitch as defined in the MIDI spec (exact freq may depend on tuning table)
-    MidiNoteNumber = 11,
-
-    /// a generic MIDI controller value from 0 -> 127
-    MidiController = 12,
-
-    /// logarithmic relative gain
-    Decibels = 13,
-
-    /// linear relative gain
-    LinearGain = 14,
-
-    /// -180 to 180 degrees, similar to phase but more general (good for 3D coord system)
-    Degrees = 15,
-
-    /// 0 -> 100, crossfade mix two sources according to sqrt(x) and sqrt(1.0 - x)
-    EqualPowerCrossfade = 16,
-
-    /// 0.0 -> 1.0, pow(x, 3.0) -> linear gain to simulate a reasonable mixer channel fader response
-    MixerFaderCurve1 = 17,
-
-    /// standard left to right mixer pan
-    Pan = 18,
-
-    /// distance measured in meters
-    Meters = 19,
-
-    /// absolute frequency measurement :
-    ///if f is freq in hertz then absoluteCents = 1200 * log2(f / 440) + 6900
-    AbsoluteCents = 20,
-
-    /// octaves in relative pitch where a value of 1 is equal to 1200 cents
-    Octaves = 21,
-
-    /// beats per minute, ie tempo
-    Bpm = 22,
-
-    /// time relative to tempo, i.e., 1.0 at 120 BPM would equal 1/2 a second
-    Beats = 23,
-
-    /// parameter is expressed in milliseconds
-    Milliseconds = 24,
-
-    /// for compression, expansion ratio, etc.
-    Ratio = 25,
-
-    /// this is the parameter unit type for parameters that present a custom unit name
-    CustomUnit = 26,
-
-    /// a generic MIDI 2.0 controller value with 32-bit range
-    Midi2Controller = 27,
-}
-
 #[doc(alias = "AudioUnitParameterInfo")]
 #[derive(Debug)]
 #[repr(C)]
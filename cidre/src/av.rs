@@ -313,7 +313,10 @@ pub use kit::InputPickerInteractionDelegate;
 #[cfg(all(feature = "av_kit", target_os = "ios"))]
 pub use kit::InputPickerInteractionDelegateImpl;
 
-#[link(name = "AVFoundation", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "AVFoundation", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "av", kind = "static")]
@@ -40,12 +40,13 @@ impl Reader {
         ns::if_none(|err| unsafe { Self::alloc().init_with_assert_err(asset, err) })
     }
 
-    #[objc::msg_send(addOutput:)]
+    #[objc::msg_send(addOutput:, throws)]
     pub unsafe fn add_output_throws(&mut self, output: &av::AssetReaderOutput);
 
+    #[deprecated(note = "use `try_add_output`")]
     #[inline]
     pub fn add_output<'ear>(&mut self, output: &av::AssetReaderOutput) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.add_output_throws(output) })
+        self.try_add_output(output).map_err(|e| &*e.autoreleased())
     }
 
     /// Prepares the receiver for reading sample buffers from the asset.
@@ -54,11 +55,12 @@ impl Reader {
     /// If this method returns `false`, clients can determine the nature of the failure by checking the value of the status and error properties.
     ///
     /// This method throws an exception if reading has already started (`status` has progressed beyond AVAssetReaderStatusUnknown).
-    #[objc::msg_send(startReading)]
+    #[objc::msg_send(startReading, throws)]
     pub unsafe fn start_reading_throws(&mut self) -> bool;
 
+    #[deprecated(note = "use `try_start_reading`")]
     pub fn start_reading<'ear>(&mut self) -> ns::ExResult<'ear, bool> {
-        ns::try_catch(|| unsafe { self.start_reading_throws() })
+        self.try_start_reading().map_err(|e| &*e.autoreleased())
     }
 
     /// Cancels any background work and prevents the receiver's outputs from reading more samples.
@@ -88,12 +90,14 @@ impl Reader {
     ///
     /// Throws an Objective-C exception if reading has already started.
     #[cfg(feature = "cm")]
-    #[objc::msg_send(setTimeRange:)]
+    #[objc::msg_send(setTimeRange:, throws)]
     pub unsafe fn set_time_range_throws(&mut self, value: cm::TimeRange);
 
+    #[deprecated(note = "use `try_set_time_range`")]
     #[cfg(feature = "cm")]
     pub fn set_time_range<'ear>(&mut self, value: cm::TimeRange) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_time_range_throws(value) })
+        self.try_set_time_range(value)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(outputs)]
@@ -79,19 +79,20 @@ impl ReaderOutput {
     /// This method throws an exception if this output is not added to an instance of av::AssetReader
     /// (using add_output()) and start_reading() is not called on that asset reader.
     #[cfg(feature = "cm")]
-    #[objc::msg_send(copyNextSampleBuffer)]
+    #[objc::msg_send(copyNextSampleBuffer, throws)]
     pub unsafe fn next_sample_buf_throws(&mut self) -> Option<arc::Retained<cm::SampleBuf>>;
 
+    #[deprecated(note = "use `try_next_sample_buf`")]
     #[cfg(feature = "cm")]
     #[inline]
     pub fn next_sample_buf<'ear>(&mut self) -> ns::ExResult<'ear, Option<arc::R<cm::SampleBuf>>> {
-        ns::try_catch(|| unsafe { self.next_sample_buf_throws() })
+        self.try_next_sample_buf().map_err(|e| &*e.autoreleased())
     }
 }
 
 impl arc::A<ReaderTrackOutput> {
-    #[objc::msg_send(initWithTrack:outputSettings:)]
-    pub fn init_with_track_throws(
+    #[objc::msg_send(initWithTrack:outputSettings:, throws)]
+    pub unsafe fn init_with_track_throws(
         self,
         track: &av::asset::Track,
         output_settings: Option<&ns::Dictionary<ns::String, ns::Id>>,
@@ -138,18 +139,18 @@ impl ReaderTrackOutput {
     ///     a pixel format with an alpha component such as kCVPixelFormatType_4444AYpCbCr16 or kCVPixelFormatType_64ARGB.
     ///     To test whether your source contains an alpha channel check that the track's format description has kCMFormatDescriptionExtension_Depth
     ///     and that its value is 32.
-    pub fn with_track_throws(
+    pub unsafe fn with_track_throws(
         track: &av::asset::Track,
         output_settings: Option<&ns::Dictionary<ns::String, ns::Id>>,
     ) -> arc::R<Self> {
-        Self::alloc().init_with_track_throws(track, output_settings)
+        unsafe { Self::alloc().init_with_track_throws(track, output_settings) }
     }
 
-    pub fn with_track<'ear>(
+    pub fn with_track(
         track: &av::asset::Track,
         output_settings: Option<&ns::Dictionary<ns::String, ns::Id>>,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| Self::with_track_throws(track, output_settings))
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_track(track, output_settings)
     }
 
     #[objc::msg_send(resetForReadingTimeRanges:)]
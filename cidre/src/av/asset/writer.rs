@@ -35,7 +35,7 @@ impl arc::A<Writer> {
         error: *mut Option<&'ear ns::Error>,
     ) -> Option<arc::R<Writer>>;
 
-    #[objc::msg_send(initWithContentType:)]
+    #[objc::msg_send(initWithContentType:, throws)]
     pub unsafe fn init_with_content_type_throws(
         self,
         output_content_type: &ut::Type,
@@ -54,11 +54,12 @@ impl Writer {
     #[objc::msg_send(canAddInput:)]
     pub fn can_add_input(&self, input: &WriterInput) -> bool;
 
-    #[objc::msg_send(addInput:)]
+    #[objc::msg_send(addInput:, throws)]
     pub unsafe fn add_input_throws(&mut self, input: &WriterInput);
 
+    #[deprecated(note = "use `try_add_input`")]
     pub fn add_input<'ear>(&mut self, input: &WriterInput) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.add_input_throws(input) })
+        self.try_add_input(input).map_err(|e| &*e.autoreleased())
     }
 
     /// Prepares the receiver for accepting input and for writing its output to its output file.
@@ -75,12 +76,14 @@ impl Writer {
     ///
     /// Throws an Objective-C exception if no session was started first.
     #[cfg(feature = "cm")]
-    #[objc::msg_send(endSessionAtSourceTime:)]
+    #[objc::msg_send(endSessionAtSourceTime:, throws)]
     pub unsafe fn end_session_at_src_time_throws(&mut self, end_time: cm::Time);
 
+    #[deprecated(note = "use `try_end_session_at_src_time`")]
     #[cfg(feature = "cm")]
     pub fn end_session_at_src_time<'ear>(&mut self, end_time: cm::Time) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.end_session_at_src_time_throws(end_time) })
+        self.try_end_session_at_src_time(end_time)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(finishWriting)]
@@ -112,12 +115,8 @@ impl Writer {
         ns::if_none(|err| Self::alloc().init_with_url_file_type_err(url, file_type, err))
     }
 
-    pub fn with_content_type<'ear>(
-        output_content_type: &ut::Type,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_content_type_throws(output_content_type)
-        })
+    pub fn with_content_type(output_content_type: &ut::Type) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_content_type(output_content_type)
     }
 }
 
@@ -162,13 +161,14 @@ impl Writer {
 
     /// This method throws an exception if the delegate method to output segment data is not implemented,
     /// or if the value of the preferredOutputSegmentInterval property is not kCMTimeIndefinite.
-    #[objc::msg_send(flushSegment)]
+    #[objc::msg_send(flushSegment, throws)]
     pub unsafe fn flush_segment_throws(&mut self);
 
     /// Closes the current segment and outputs it to the -assetWriter:didOutputSegmentData:segmentType:segmentReport:
     /// or -assetWriter:didOutputSegmentData:segmentType: delegate method.
+    #[deprecated(note = "use `try_flush_segment`")]
     pub fn flush_segment<'ear>(&mut self) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.flush_segment_throws() })
+        self.try_flush_segment().map_err(|e| &*e.autoreleased())
     }
 }
 
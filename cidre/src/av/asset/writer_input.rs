@@ -9,7 +9,7 @@ use crate::{blocks, dispatch};
 define_obj_type!(pub WriterInput(ns::Id));
 
 impl arc::A<WriterInput> {
-    #[objc::msg_send(initWithMediaType:outputSettings:)]
+    #[objc::msg_send(initWithMediaType:outputSettings:, throws)]
     pub unsafe fn init_media_type_output_settings_throws(
         self,
         media_type: &MediaType,
@@ -17,7 +17,7 @@ impl arc::A<WriterInput> {
     ) -> arc::R<WriterInput>;
 
     #[cfg(feature = "cm")]
-    #[objc::msg_send(initWithMediaType:outputSettings:sourceFormatHint:)]
+    #[objc::msg_send(initWithMediaType:outputSettings:sourceFormatHint:, throws)]
     pub unsafe fn with_media_type_output_settings_source_format_hint_throws(
         self,
         media_type: &MediaType,
@@ -36,13 +36,11 @@ impl WriterInput {
         unsafe { Self::alloc().init_media_type_output_settings_throws(media_type, output_settings) }
     }
 
-    pub fn with_media_type_and_output_settings<'ear>(
+    pub fn with_media_type_and_output_settings(
         media_type: &MediaType,
         output_settings: Option<&ns::Dictionary<ns::String, ns::Id>>,
-    ) -> ns::ExResult<'ear, arc::R<WriterInput>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_media_type_output_settings_throws(media_type, output_settings)
-        })
+    ) -> objc::TryResult<arc::R<WriterInput>> {
+        Self::alloc().try_init_media_type_output_settings(media_type, output_settings)
     }
 
     #[cfg(feature = "cm")]
@@ -61,18 +59,16 @@ impl WriterInput {
     }
 
     #[cfg(feature = "cm")]
-    pub fn with_media_type_output_settings_source_format_hint<'ear>(
+    pub fn with_media_type_output_settings_source_format_hint(
         media_type: &MediaType,
         output_settings: Option<&ns::Dictionary<ns::String, ns::Id>>,
         source_format_hint: Option<&cm::FormatDesc>,
-    ) -> ns::ExResult<'ear, arc::R<WriterInput>> {
-        ns::try_catch(|| unsafe {
-            Self::with_media_type_output_settings_source_format_hint_throws(
-                media_type,
-                output_settings,
-                source_format_hint,
-            )
-        })
+    ) -> objc::TryResult<arc::R<WriterInput>> {
+        Self::alloc().try_with_media_type_output_settings_source_format_hint(
+            media_type,
+            output_settings,
+            source_format_hint,
+        )
     }
 
     #[cfg(feature = "cm")]
@@ -93,10 +89,8 @@ impl WriterInput {
         unsafe { Self::with_media_type_output_settings_throws(media_type, None) }
     }
 
-    pub fn with_media_type<'ear>(
-        media_type: &MediaType,
-    ) -> ns::ExResult<'ear, arc::R<WriterInput>> {
-        ns::try_catch(|| unsafe { Self::with_media_type_throws(media_type) })
+    pub fn with_media_type(media_type: &MediaType) -> objc::TryResult<arc::R<WriterInput>> {
+        objc::try_catch_retained(|| unsafe { Self::with_media_type_throws(media_type) })
     }
 
     #[objc::msg_send(mediaType)]
@@ -132,31 +126,33 @@ impl WriterInput {
     ///
     /// This method throws an exception if the sample buffer's media type does not match the asset writer input's media type.
     #[cfg(feature = "cm")]
-    #[objc::msg_send(appendSampleBuffer:)]
+    #[objc::msg_send(appendSampleBuffer:, throws)]
     pub unsafe fn append_sample_buf_throws(&mut self, buffer: &cm::SampleBuf) -> bool;
 
+    #[deprecated(note = "use `try_append_sample_buf`")]
     #[cfg(feature = "cm")]
     pub fn append_sample_buf<'ear>(&mut self, buffer: &cm::SampleBuf) -> ns::ExResult<'ear, bool> {
-        ns::try_catch(|| unsafe { self.append_sample_buf_throws(buffer) })
+        self.try_append_sample_buf(buffer)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(all(feature = "blocks", feature = "dispatch"))]
-    #[objc::msg_send(requestMediaDataWhenReadyOnQueue:usingBlock:)]
+    #[objc::msg_send(requestMediaDataWhenReadyOnQueue:usingBlock:, throws)]
     pub unsafe fn request_media_data_when_ready_on_queue_throws(
         &self,
         queue: &dispatch::Queue,
         block: &mut blocks::CompletionBlock,
     );
 
+    #[deprecated(note = "use `try_request_media_data_when_ready_on_queue`")]
     #[cfg(all(feature = "blocks", feature = "dispatch"))]
     pub fn request_media_data_when_ready_on_queue<'ear>(
         &self,
         queue: &dispatch::Queue,
         block: &mut blocks::CompletionBlock,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.request_media_data_when_ready_on_queue_throws(queue, block)
-        })
+        self.try_request_media_data_when_ready_on_queue(queue, block)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -166,7 +162,7 @@ define_obj_type!(
 );
 
 impl arc::A<WriterInputPixelBufAdaptor> {
-    #[objc::msg_send(initWithAssetWriterInput:sourcePixelBufferAttributes:)]
+    #[objc::msg_send(initWithAssetWriterInput:sourcePixelBufferAttributes:, throws)]
     pub unsafe fn init_with_asset_writer_input_throws(
         self,
         input: &WriterInput,
@@ -177,13 +173,11 @@ impl arc::A<WriterInputPixelBufAdaptor> {
 impl WriterInputPixelBufAdaptor {
     define_cls!(AV_ASSET_WRITER_INPUT_PIXEL_BUFFER_ADAPTOR);
 
-    pub fn with_input_writer<'ear>(
+    pub fn with_input_writer(
         input: &WriterInput,
         src_pixel_buf_attrs: Option<ns::Dictionary<ns::String, ns::Id>>,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_asset_writer_input_throws(input, src_pixel_buf_attrs)
-        })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_asset_writer_input(input, src_pixel_buf_attrs)
     }
 
     /// The asset writer input to which the receiver should append pixel buffers.
@@ -200,20 +194,22 @@ impl WriterInputPixelBufAdaptor {
 
     /// This method throws an exception if the presentation time is is non-numeric (see cm::Time::is_numeric()) or if "ready_for_more_media_data" is false.
     #[cfg(feature = "cm")]
-    #[objc::msg_send(appendPixelBuffer:withPresentationTime:)]
+    #[objc::msg_send(appendPixelBuffer:withPresentationTime:, throws)]
     pub unsafe fn append_pixel_buf_with_pts_throws(
         &mut self,
         buf: &cv::PixelBuf,
         pts: cm::Time,
     ) -> bool;
 
+    #[deprecated(note = "use `try_append_pixel_buf_with_pts`")]
     #[cfg(feature = "cm")]
     pub fn append_pixel_buf_with_pts<'ear>(
         &mut self,
         buf: &cv::PixelBuf,
         pts: cm::Time,
     ) -> ns::ExResult<'ear, bool> {
-        ns::try_catch(|| unsafe { self.append_pixel_buf_with_pts_throws(buf, pts) })
+        self.try_append_pixel_buf_with_pts(buf, pts)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -167,5 +167,5 @@ pub mod application;
 pub use application::App;
 pub use application::RecordPermission as AppRecordPermission;
 
-#[link(name = "AVFAudio", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "AVFAudio", kind = "framework"))]
 unsafe extern "C" {}
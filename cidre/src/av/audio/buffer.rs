@@ -82,11 +82,13 @@ impl PcmBuf {
     #[objc::msg_send(frameLength)]
     pub fn frame_len(&self) -> FrameCount;
 
-    #[objc::msg_send(setFrameLength:)]
-    pub fn set_frame_len_throws<'ear>(&mut self, value: FrameCount);
+    #[objc::msg_send(setFrameLength:, throws)]
+    pub unsafe fn set_frame_len_throws<'ear>(&mut self, value: FrameCount);
 
+    #[deprecated(note = "use `try_set_frame_len`")]
     pub fn set_frame_len<'ear>(&mut self, value: FrameCount) -> ns::ExResult<'ear> {
-        ns::try_catch(|| self.set_frame_len_throws(value))
+        self.try_set_frame_len(value)
+            .map_err(|e| &*e.autoreleased())
     }
 
     /// The buffer's number of interleaved channels.
@@ -290,7 +292,7 @@ mod tests {
                 .unwrap();
         let cap = 1024;
         let mut buf = av::AudioPcmBuf::with_format(&format, cap).unwrap();
-        buf.set_frame_len(cap).unwrap();
+        buf.try_set_frame_len(cap).unwrap();
         let data = buf.data_f32();
         let (n, cap) = if format.is_interleaved() {
             (1, channel_count * cap)
@@ -312,8 +314,8 @@ mod tests {
                 .unwrap();
         let cap = 1024;
         let mut buf = av::AudioPcmBuf::with_format(&format, cap).unwrap();
-        let _err = buf.set_frame_len(1025).expect_err("Should fail");
-        buf.set_frame_len(1024)
+        let _err = buf.try_set_frame_len(1025).expect_err("Should fail");
+        buf.try_set_frame_len(1024)
             .expect("Failed to set max cap frame len");
     }
 }
@@ -346,7 +346,7 @@ mod tests {
 
         let mut pcm_buf = av::audio::PcmBuf::with_format(&format, 1024).unwrap();
 
-        pcm_buf.set_frame_len(1024).unwrap();
+        pcm_buf.try_set_frame_len(1024).unwrap();
 
         engine.start().expect("Failed to start engine");
         assert!(engine.is_running());
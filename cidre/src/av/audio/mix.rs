@@ -103,7 +103,7 @@ impl InputParamsMut {
     #[objc::msg_send(setAudioTapProcessor:)]
     pub fn set_tap(&mut self, val: Option<&mt::AudioProcessingTap>);
 
-    #[objc::msg_send(setVolumeRampFromStartVolume:toEndVolume:timeRange:)]
+    #[objc::msg_send(setVolumeRampFromStartVolume:toEndVolume:timeRange:, throws)]
     pub unsafe fn set_volume_ramp_throws(
         &mut self,
         start_volume: f32,
@@ -111,22 +111,24 @@ impl InputParamsMut {
         time_range: cm::TimeRange,
     );
 
+    #[deprecated(note = "use `try_set_volume_ramp`")]
     pub fn set_volume_ramp<'ear>(
         &mut self,
         start_volume: f32,
         end_volume: f32,
         time_range: cm::TimeRange,
     ) -> ns::ExResult<'ear> {
-        unsafe {
-            ns::try_catch(|| self.set_volume_ramp_throws(start_volume, end_volume, time_range))
-        }
+        self.try_set_volume_ramp(start_volume, end_volume, time_range)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(setVolume:atTime:)]
+    #[objc::msg_send(setVolume:atTime:, throws)]
     pub unsafe fn set_volume_at_time_throws(&mut self, volume: f32, time: cm::Time);
 
+    #[deprecated(note = "use `try_set_volume_at_time`")]
     pub fn set_volume_at_time<'ear>(&mut self, volume: f32, time: cm::Time) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_volume_at_time_throws(volume, time)) }
+        self.try_set_volume_at_time(volume, time)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
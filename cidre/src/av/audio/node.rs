@@ -58,7 +58,7 @@ impl Node {
 
     /// NOTE: `remove_tap_on_bus` if you have already installed tap
     #[cfg(feature = "blocks")]
-    #[objc::msg_send(installTapOnBus:bufferSize:format:block:)]
+    #[objc::msg_send(installTapOnBus:bufferSize:format:block:, throws)]
     pub unsafe fn install_tap_on_bus_block_throws(
         &mut self,
         bus: av::AudioNodeBus,
@@ -82,23 +82,23 @@ impl Node {
 
     #[cfg(feature = "blocks")]
     #[inline]
-    pub fn install_tap_on_bus<'ear>(
+    pub fn install_tap_on_bus(
         &mut self,
         bus: av::AudioNodeBus,
         buffer_size: av::AudioFrameCount,
         format: Option<&av::AudioFormat>,
         tap_block: impl FnMut(&av::AudioPcmBuf, &av::AudioTime) + 'static,
-    ) -> ns::ExResult<'ear> {
+    ) -> objc::TryResult {
         let mut tap_block = AudioNodeTapBlock::<blocks::Esc>::new2(tap_block);
-        ns::try_catch(|| unsafe {
-            self.install_tap_on_bus_block_throws(bus, buffer_size, format, &mut tap_block)
-        })
+        self.try_install_tap_on_bus_block(bus, buffer_size, format, &mut tap_block)
     }
 
-    #[objc::msg_send(removeTapOnBus:)]
+    #[objc::msg_send(removeTapOnBus:, throws)]
     pub unsafe fn remove_tap_on_bus_throws(&mut self, bus: av::AudioNodeBus);
 
+    #[deprecated(note = "use `try_remove_tap_on_bus`")]
     pub fn remove_tap_on_bus<'ear>(&mut self, bus: av::AudioNodeBus) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.remove_tap_on_bus_throws(bus) })
+        self.try_remove_tap_on_bus(bus)
+            .map_err(|e| &*e.autoreleased())
     }
 }
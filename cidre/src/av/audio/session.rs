@@ -93,7 +93,7 @@ impl Session {
         ns::if_false(|err| unsafe { self.set_category_mode_opts_err(val, mode, options, err) })
     }
 
-    #[objc::msg_send(setCategory:mode:routeSharingPolicy:options:error:)]
+    #[objc::msg_send(setCategory:mode:routeSharingPolicy:options:error:, throws)]
     pub unsafe fn set_category_mode_policy_opts_err_throws<'ear>(
         &mut self,
         val: &Category,
@@ -284,11 +284,13 @@ impl Synthesizer {
 
     /// Enqueing the same [`av::SpeechUtterance`] that is already enqueued or is speaking will
     /// raise an exception
-    #[objc::msg_send(speakUtterance:)]
+    #[objc::msg_send(speakUtterance:, throws)]
     pub unsafe fn speak_utterance_throws(&mut self, utterance: &Utterance);
 
+    #[deprecated(note = "use `try_speak_utterance`")]
     pub fn speak_utterance<'ear>(&mut self, utterance: &Utterance) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.speak_utterance_throws(utterance) })
+        self.try_speak_utterance(utterance)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(stopSpeakingAtBoundary:)]
@@ -395,7 +397,7 @@ mod tests {
 
         assert!(av::SpeechSynthesisVoice::voices().len() > 0);
 
-        let v1 = &av::SpeechSynthesisVoice::voices().get(0).unwrap();
+        let v1 = &av::SpeechSynthesisVoice::voices().try_get(0).unwrap();
 
         let _v = av::SpeechSynthesisVoice::with_id(&v1.id()).unwrap();
         let _v = av::SpeechSynthesisVoice::with_lang(Some(&v1.lang().unwrap())).unwrap();
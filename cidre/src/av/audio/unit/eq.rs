@@ -116,7 +116,7 @@ mod tests {
         let equ = audio::UnitEq::with_bands(10);
 
         let bands = equ.bands();
-        bands.get(0).unwrap().set_gain(10.0);
+        bands.try_get(0).unwrap().set_gain(10.0);
         assert_eq!(bands.len(), 10);
         assert_eq!(equ.global_gain(), 0.0);
     }
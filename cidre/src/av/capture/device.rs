@@ -193,41 +193,17 @@ impl Device {
     #[objc::msg_send(setActiveFormat:)]
     pub unsafe fn set_active_format(&mut self, val: &Format);
 
-    #[objc::msg_send(setAutoVideoFrameRateEnabled:)]
+    #[objc::msg_send(setAutoVideoFrameRateEnabled:, throws)]
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub unsafe fn set_auto_video_frame_rate_enabled_throws(&mut self, val: bool);
 
-    #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub unsafe fn set_auto_video_frame_rate_enabled<'ear>(
-        &mut self,
-        val: bool,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_auto_video_frame_rate_enabled_throws(val) })
-    }
-
     #[cfg(feature = "cm")]
-    #[objc::msg_send(setActiveVideoMinFrameDuration:)]
+    #[objc::msg_send(setActiveVideoMinFrameDuration:, throws)]
     pub unsafe fn set_active_video_min_frame_duration_throws(&mut self, val: cm::Time);
 
     #[cfg(feature = "cm")]
-    pub unsafe fn set_active_video_min_frame_duration<'ear>(
-        &mut self,
-        val: cm::Time,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_active_video_min_frame_duration_throws(val) })
-    }
-
-    #[cfg(feature = "cm")]
-    #[objc::msg_send(setActiveVideoMaxFrameDuration:)]
+    #[objc::msg_send(setActiveVideoMaxFrameDuration:, throws)]
     pub unsafe fn set_active_video_max_frame_duration_throws(&mut self, val: cm::Time);
-
-    #[cfg(feature = "cm")]
-    pub unsafe fn set_active_video_max_frame_duration<'ear>(
-        &mut self,
-        val: cm::Time,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_active_video_max_frame_duration_throws(val) })
-    }
 }
 
 #[doc(alias = "AVCaptureFlashMode")]
@@ -283,7 +259,7 @@ impl Device {
     #[objc::msg_send(torchMode)]
     pub fn torch_mode(&self) -> TorchMode;
 
-    #[objc::msg_send(setTorchMode:)]
+    #[objc::msg_send(setTorchMode:, throws)]
     unsafe fn set_torch_mode_throws(&self, val: TorchMode);
 
     #[objc::msg_send(setTorchModeOnWithLevel:error:)]
@@ -300,8 +276,8 @@ impl<'a> ConfigLockGuard<'a> {
         unsafe { self.device.set_torch_mode_throws(val) }
     }
 
-    pub fn set_torch_mode<'ear>(&mut self, val: TorchMode) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_torch_mode_throws(val) })
+    pub fn set_torch_mode(&mut self, val: TorchMode) -> objc::TryResult {
+        self.device.try_set_torch_mode(val)
     }
 
     pub unsafe fn set_torch_mode_on_with_level_err<'ear>(
@@ -389,7 +365,7 @@ impl Device {
     #[objc::msg_send(automaticallyAdjustsVideoHDREnabled)]
     pub fn automatically_adjusts_video_hdr_enabled(&self) -> bool;
 
-    #[objc::msg_send(setAutomaticallyAdjustsVideoHDREnabled:)]
+    #[objc::msg_send(setAutomaticallyAdjustsVideoHDREnabled:, throws)]
     pub unsafe fn set_automatically_adjusts_video_hdr_enabled_throws(&mut self, val: bool);
 
     #[objc::msg_send(isVideoHDREnabled)]
@@ -407,23 +383,18 @@ impl Device {
     /// a new active format or [`av::CaptureSession`] sessionPreset. If you are setting either the active
     /// format or the [`av::CaptureSession`]'s sessionPreset AND this property, you should bracket these operations
     /// with [session beginConfiguration] and [session commitConfiguration] to minimize reconfiguration time.
-    #[objc::msg_send(setVideoHDREnabled:)]
+    #[objc::msg_send(setVideoHDREnabled:, throws)]
     pub unsafe fn set_video_hdr_enabled_throws(&mut self, val: bool);
 }
 
 impl<'a> ConfigLockGuard<'a> {
-    pub fn set_video_hdr_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.device.set_video_hdr_enabled_throws(val) })
+    pub fn set_video_hdr_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_video_hdr_enabled(val)
     }
 
-    pub fn set_automatically_adjusts_video_hdr_enabled<'ear>(
-        &mut self,
-        val: bool,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.device
-                .set_automatically_adjusts_video_hdr_enabled_throws(val)
-        })
+    pub fn set_automatically_adjusts_video_hdr_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device
+            .try_set_automatically_adjusts_video_hdr_enabled(val)
     }
 }
 
@@ -559,7 +530,7 @@ impl Device {
     /// '::center_stage_enabled' is 'false' on the av::CaptureDevice class.
     /// 'set_center_stage_rect_of_interest_throws' throws an 'ns::ExceptionName::invalid_argument()'
     /// if the provided rect of interest goes outside the normalized (0-1) coordinate space.
-    #[objc::msg_send(setCenterStageRectOfInterest:)]
+    #[objc::msg_send(setCenterStageRectOfInterest:, throws)]
     pub unsafe fn set_center_stage_rect_of_interest_throws(&mut self, val: cg::Rect);
 }
 
@@ -633,7 +604,7 @@ impl Device {
     /// video preview will temporarily freeze. -setActiveColorSpace: throws an NSGenericException
     /// if called without first obtaining exclusive access to the receiver
     /// using -lockForConfiguration:.
-    #[objc::msg_send(setActiveColorSpace:)]
+    #[objc::msg_send(setActiveColorSpace:, throws)]
     pub unsafe fn set_active_color_space_throws(&mut self, val: ColorSpace);
 }
 
@@ -647,28 +618,22 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn set_auto_video_frame_rate_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        unsafe { self.device.set_auto_video_frame_rate_enabled(val) }
+    pub fn set_auto_video_frame_rate_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_auto_video_frame_rate_enabled(val)
     }
 
     #[cfg(feature = "cm")]
-    pub fn set_active_video_min_frame_duration<'ear>(
-        &mut self,
-        val: cm::Time,
-    ) -> ns::ExResult<'ear> {
-        unsafe { self.device.set_active_video_min_frame_duration(val) }
+    pub fn set_active_video_min_frame_duration(&mut self, val: cm::Time) -> objc::TryResult {
+        self.device.try_set_active_video_min_frame_duration(val)
     }
 
     #[cfg(feature = "cm")]
-    pub fn set_active_video_max_frame_duration<'ear>(
-        &mut self,
-        val: cm::Time,
-    ) -> ns::ExResult<'ear> {
-        unsafe { self.device.set_active_video_max_frame_duration(val) }
+    pub fn set_active_video_max_frame_duration(&mut self, val: cm::Time) -> objc::TryResult {
+        self.device.try_set_active_video_max_frame_duration(val)
     }
 
-    pub fn set_center_stage_rect_of_interest<'ear>(&mut self, val: cg::Rect) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.device.set_center_stage_rect_of_interest_throws(val) })
+    pub fn set_center_stage_rect_of_interest(&mut self, val: cg::Rect) -> objc::TryResult {
+        self.device.try_set_center_stage_rect_of_interest(val)
     }
 
     /// Will throw uncatchable NSInvalidArgumentsException if ColorSpace is not supported.
@@ -684,8 +649,8 @@ impl<'a> ConfigLockGuard<'a> {
         unsafe { self.device.set_focus_mode_throws(mode) }
     }
 
-    pub fn set_focus_mode<'ear>(&mut self, mode: FocusMode) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_focus_mode_throws(mode) })
+    pub fn set_focus_mode(&mut self, mode: FocusMode) -> objc::TryResult {
+        self.device.try_set_focus_mode(mode)
     }
 
     #[inline]
@@ -701,8 +666,8 @@ impl<'a> ConfigLockGuard<'a> {
     /// Clients can observe automatic changes to the receiver's 'focusPointOfInterest' by key value observing this property.
     /// Note that setting focusPointOfInterest alone does not initiate a focus operation. After setting
     /// 'set_focus_point_of_interest', call 'set_focus_mode()' to apply the new point of interest.
-    pub fn set_focus_point_of_interest<'ear>(&mut self, val: cg::Point) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_focus_point_of_interest_throws(val) })
+    pub fn set_focus_point_of_interest(&mut self, val: cg::Point) -> objc::TryResult {
+        self.device.try_set_focus_point_of_interest(val)
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -715,11 +680,11 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_auto_focus_range_restriction<'ear>(
+    pub fn set_auto_focus_range_restriction(
         &mut self,
         val: AutoFocusRangeRestriction,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_auto_focus_range_restriction_throws(val) })
+    ) -> objc::TryResult {
+        self.device.try_set_auto_focus_range_restriction(val)
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -729,8 +694,8 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_smooth_auto_focus_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_smooth_auto_focus_enabled_throws(val) })
+    pub fn set_smooth_auto_focus_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_smooth_auto_focus_enabled(val)
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -746,13 +711,12 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_automatically_adjusts_face_driven_auto_focus_enabled<'ear>(
+    pub fn set_automatically_adjusts_face_driven_auto_focus_enabled(
         &mut self,
         val: bool,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.set_automatically_adjusts_face_driven_auto_focus_enabled_throws(val)
-        })
+    ) -> objc::TryResult {
+        self.device
+            .try_set_automatically_adjusts_face_driven_auto_focus_enabled(val)
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -762,8 +726,8 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_face_driven_auto_focus_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_face_driven_auto_focus_enabled_throws(val) })
+    pub fn set_face_driven_auto_focus_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_face_driven_auto_focus_enabled(val)
     }
 
     #[cfg(all(feature = "blocks", any(target_os = "tvos", target_os = "ios")))]
@@ -775,11 +739,10 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(all(feature = "blocks", any(target_os = "tvos", target_os = "ios")))]
-    pub fn set_focus_mode_locked_with_lens_pos_no_ch<'ear>(
-        &mut self,
-        val: f32,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_focus_mode_locked_with_lens_pos_no_ch_throws(val) })
+    pub fn set_focus_mode_locked_with_lens_pos_no_ch(&mut self, val: f32) -> objc::TryResult {
+        objc::try_catch_retained(|| unsafe {
+            self.set_focus_mode_locked_with_lens_pos_no_ch_throws(val)
+        })
     }
 
     #[cfg(all(
@@ -803,15 +766,13 @@ impl<'a> ConfigLockGuard<'a> {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    pub fn set_focus_mode_locked_with_lens_pos_with_ch<'ear>(
+    pub fn set_focus_mode_locked_with_lens_pos_with_ch(
         &mut self,
         val: f32,
         block: &mut blocks::EscBlock<fn(sync_time: cm::Time)>,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.device
-                .set_focus_mode_locked_with_lens_pos_ch_throws(val, Some(block))
-        })
+    ) -> objc::TryResult {
+        self.device
+            .try_set_focus_mode_locked_with_lens_pos_ch(val, Some(block))
     }
 
     #[cfg(all(
@@ -836,14 +797,11 @@ impl<'a> ConfigLockGuard<'a> {
     pub async fn set_focus_mode_locked_with_lens_pos(
         &mut self,
         val: f32,
-    ) -> Result<cm::Time, arc::R<ns::Exception>> {
+    ) -> objc::TryResult<cm::Time> {
         let (future, mut block) = blocks::comp1();
-        let res = ns::try_catch(move || unsafe {
+        objc::try_catch_retained(move || unsafe {
             self.set_focus_mode_locked_with_lens_pos_with_ch_throws(val, block.as_esc_mut())
-        });
-        if let Err(err) = res {
-            return Err(err.retained());
-        }
+        })?;
         Ok(future.await)
     }
 }
@@ -952,7 +910,7 @@ impl Device {
     #[objc::msg_send(focusMode)]
     pub fn focus_mode(&self) -> FocusMode;
 
-    #[objc::msg_send(setFocusMode:)]
+    #[objc::msg_send(setFocusMode:, throws)]
     unsafe fn set_focus_mode_throws(&mut self, mode: FocusMode);
 
     #[objc::msg_send(isFocusPointOfInterestSupported)]
@@ -961,7 +919,7 @@ impl Device {
     #[objc::msg_send(focusPointOfInterest)]
     pub fn focus_point_of_intereset(&self) -> cg::Point;
 
-    #[objc::msg_send(setFocusPointOfInterest:)]
+    #[objc::msg_send(setFocusPointOfInterest:, throws)]
     unsafe fn set_focus_point_of_interest_throws(&mut self, val: cg::Point);
 
     /// The value of this property is a bool indicating whether the receiver's camera focus
@@ -980,7 +938,7 @@ impl Device {
     pub fn auto_focus_range_restriction(&self) -> AutoFocusRangeRestriction;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setAutoFocusRangeRestriction:)]
+    #[objc::msg_send(setAutoFocusRangeRestriction:, throws)]
     unsafe fn set_auto_focus_range_restriction_throws(&mut self, val: AutoFocusRangeRestriction);
 
     /// Indicates whether the receiver supports smooth autofocus.
@@ -993,7 +951,7 @@ impl Device {
     pub fn is_smooth_auto_focus_enabled(&self) -> bool;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setSmoothAutoFocusEnabled:)]
+    #[objc::msg_send(setSmoothAutoFocusEnabled:, throws)]
     unsafe fn set_smooth_auto_focus_enabled_throws(&mut self, val: bool);
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -1001,7 +959,7 @@ impl Device {
     pub fn automatically_adjusts_face_driven_auto_focus_enabled(&self) -> bool;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setAutomaticallyAdjustsFaceDrivenAutoFocusEnabled:)]
+    #[objc::msg_send(setAutomaticallyAdjustsFaceDrivenAutoFocusEnabled:, throws)]
     unsafe fn set_automatically_adjusts_face_driven_auto_focus_enabled_throws(&mut self, val: bool);
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -1009,16 +967,16 @@ impl Device {
     pub fn is_face_driven_auto_focus_enabled(&self) -> bool;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setFaceDrivenAutoFocusEnabled:)]
+    #[objc::msg_send(setFaceDrivenAutoFocusEnabled:, throws)]
     unsafe fn set_face_driven_auto_focus_enabled_throws(&mut self, val: bool);
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(lensPosition)]
+    #[objc::msg_send(lensPosition, kvo)]
     pub fn lens_pos(&self) -> f32;
 
     // #[cfg(any(target_os = "tvos", target_os = "ios"))]
     #[cfg(all(feature = "blocks", feature = "cm"))]
-    #[objc::msg_send(setFocusModeLockedWithLensPosition:completionHandler:)]
+    #[objc::msg_send(setFocusModeLockedWithLensPosition:completionHandler:, throws)]
     unsafe fn set_focus_mode_locked_with_lens_pos_ch_throws(
         &mut self,
         val: f32,
@@ -1072,7 +1030,7 @@ impl Device {
     #[objc::msg_send(exposureMode)]
     pub fn exposure_mode(&self) -> ExposureMode;
 
-    #[objc::msg_send(setExposureMode:)]
+    #[objc::msg_send(setExposureMode:, throws)]
     unsafe fn set_exposure_mode_throws(&mut self, val: ExposureMode);
 
     #[objc::msg_send(isExposurePointOfInterestSupported)]
@@ -1081,7 +1039,7 @@ impl Device {
     #[objc::msg_send(exposurePointOfInterest)]
     pub fn exposure_point_of_interest(&self) -> cg::Point;
 
-    #[objc::msg_send(setExposurePointOfInterest:)]
+    #[objc::msg_send(setExposurePointOfInterest:, throws)]
     unsafe fn set_exposure_point_of_interest_throws(&mut self, val: cg::Point);
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -1089,7 +1047,7 @@ impl Device {
     pub fn automatically_adjusts_face_driven_auto_exposure_enabled(&self) -> bool;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setAutomaticallyAdjustsFaceDrivenAutoExposureEnabled:)]
+    #[objc::msg_send(setAutomaticallyAdjustsFaceDrivenAutoExposureEnabled:, throws)]
     unsafe fn set_automatically_adjusts_face_driven_auto_exposure_enabled_throws(
         &mut self,
         val: bool,
@@ -1100,7 +1058,7 @@ impl Device {
     pub fn is_face_driven_auto_exposure_enabled(&self) -> bool;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setFaceDrivenAutoExposureEnabled:)]
+    #[objc::msg_send(setFaceDrivenAutoExposureEnabled:, throws)]
     unsafe fn set_face_driven_auto_exposure_enabled_throws(&mut self, val: bool);
 
     #[cfg(all(feature = "cm", any(target_os = "tvos", target_os = "ios")))]
@@ -1108,7 +1066,7 @@ impl Device {
     pub fn active_max_exposure_duration(&self) -> cm::Time;
 
     #[cfg(all(feature = "cm", any(target_os = "tvos", target_os = "ios")))]
-    #[objc::msg_send(setActiveMaxExposureDuration:)]
+    #[objc::msg_send(setActiveMaxExposureDuration:, throws)]
     unsafe fn set_active_max_exposure_duration_throws(&mut self, val: cm::Time);
 
     #[objc::msg_send(isAdjustingExposure)]
@@ -1131,8 +1089,8 @@ impl Device {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    #[objc::msg_send(setExposureModeCustomWithDuration:ISO:completionHandler:)]
-    pub fn set_exposure_mode_custom_with_duration_and_iso_throws(
+    #[objc::msg_send(setExposureModeCustomWithDuration:ISO:completionHandler:, throws)]
+    pub unsafe fn set_exposure_mode_custom_with_duration_and_iso_throws(
         &mut self,
         duration: cm::Time,
         iso: f32,
@@ -1161,7 +1119,7 @@ impl Device {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    #[objc::msg_send(setExposureTargetBias:completionHandler:)]
+    #[objc::msg_send(setExposureTargetBias:completionHandler:, throws)]
     unsafe fn set_exposure_target_bias_throws(
         &mut self,
         bias: f32,
@@ -1175,16 +1133,16 @@ impl<'a> ConfigLockGuard<'a> {
         unsafe { self.device.set_exposure_mode_throws(val) }
     }
 
-    pub fn set_exposure_mode<'ear>(&mut self, val: ExposureMode) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_exposure_mode_throws(val) })
+    pub fn set_exposure_mode(&mut self, val: ExposureMode) -> objc::TryResult {
+        self.device.try_set_exposure_mode(val)
     }
 
     pub unsafe fn set_exposure_point_of_interest_throws(&mut self, val: cg::Point) {
         unsafe { self.device.set_exposure_point_of_interest_throws(val) }
     }
 
-    pub fn set_exposure_point_of_interest<'ear>(&mut self, val: cg::Point) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_exposure_point_of_interest_throws(val) })
+    pub fn set_exposure_point_of_interest(&mut self, val: cg::Point) -> objc::TryResult {
+        self.device.try_set_exposure_point_of_interest(val)
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -1199,13 +1157,12 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_automatically_adjusts_face_driven_auto_exposure_enabled<'ear>(
+    pub fn set_automatically_adjusts_face_driven_auto_exposure_enabled(
         &mut self,
         val: bool,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.set_automatically_adjusts_face_driven_auto_exposure_enabled_throws(val)
-        })
+    ) -> objc::TryResult {
+        self.device
+            .try_set_automatically_adjusts_face_driven_auto_exposure_enabled(val)
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
@@ -1217,8 +1174,8 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_face_driven_auto_exposure_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_face_driven_auto_exposure_enabled_throws(val) })
+    pub fn set_face_driven_auto_exposure_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_face_driven_auto_exposure_enabled(val)
     }
 
     #[cfg(all(feature = "cm", any(target_os = "tvos", target_os = "ios")))]
@@ -1227,8 +1184,8 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(all(feature = "cm", any(target_os = "tvos", target_os = "ios")))]
-    pub fn set_active_max_exposure_duration<'ear>(&mut self, val: cm::Time) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_active_max_exposure_duration_throws(val) })
+    pub fn set_active_max_exposure_duration(&mut self, val: cm::Time) -> objc::TryResult {
+        self.device.try_set_active_max_exposure_duration(val)
     }
 
     #[cfg(all(
@@ -1250,12 +1207,12 @@ impl<'a> ConfigLockGuard<'a> {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    pub fn set_exposure_mode_custom_with_duration_and_iso_no_ch<'ear>(
+    pub fn set_exposure_mode_custom_with_duration_and_iso_no_ch(
         &mut self,
         duration: cm::Time,
         iso: f32,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
+    ) -> objc::TryResult {
+        objc::try_catch_retained(|| unsafe {
             self.set_exposure_mode_custom_with_duration_and_iso_no_ch_throws(duration, iso)
         })
     }
@@ -1280,16 +1237,14 @@ impl<'a> ConfigLockGuard<'a> {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    pub fn set_exposure_mode_custom_with_duration_and_iso_with_ch<'ear>(
+    pub fn set_exposure_mode_custom_with_duration_and_iso_with_ch(
         &mut self,
         duration: cm::Time,
         iso: f32,
         block: &mut blocks::EscBlock<fn(sync_time: cm::Time)>,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| {
-            self.device
-                .set_exposure_mode_custom_with_duration_and_iso_throws(duration, iso, Some(block))
-        })
+    ) -> objc::TryResult {
+        self.device
+            .try_set_exposure_mode_custom_with_duration_and_iso(duration, iso, Some(block))
     }
 
     #[cfg(all(
@@ -1322,18 +1277,15 @@ impl<'a> ConfigLockGuard<'a> {
         &mut self,
         duration: cm::Time,
         iso: f32,
-    ) -> Result<cm::Time, arc::R<ns::Exception>> {
+    ) -> objc::TryResult<cm::Time> {
         let (future, mut block) = blocks::comp1();
-        let res = ns::try_catch(move || unsafe {
+        objc::try_catch_retained(move || unsafe {
             self.set_exposure_mode_custom_with_duration_and_iso_with_ch_throws(
                 duration,
                 iso,
                 block.as_esc_mut(),
             )
-        });
-        if let Err(err) = res {
-            return Err(err.retained());
-        }
+        })?;
         Ok(future.await)
     }
 
@@ -1363,20 +1315,17 @@ impl<'a> ConfigLockGuard<'a> {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    pub fn set_exposure_target_bias_with_ch<'ear>(
+    pub fn set_exposure_target_bias_with_ch(
         &mut self,
         bias: f32,
         block: &mut blocks::EscBlock<fn(sync_time: cm::Time)>,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.device
-                .set_exposure_target_bias_throws(bias, Some(block))
-        })
+    ) -> objc::TryResult {
+        self.device.try_set_exposure_target_bias(bias, Some(block))
     }
 
     #[cfg(all(feature = "blocks", any(target_os = "tvos", target_os = "ios")))]
-    pub fn set_exposure_target_bias_no_ch<'ear>(&mut self, bias: f32) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.device.set_exposure_target_bias_throws(bias, None) })
+    pub fn set_exposure_target_bias_no_ch(&mut self, bias: f32) -> objc::TryResult {
+        self.device.try_set_exposure_target_bias(bias, None)
     }
 
     #[cfg(all(
@@ -1395,17 +1344,11 @@ impl<'a> ConfigLockGuard<'a> {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    pub async fn set_exposure_target_bias(
-        &mut self,
-        bias: f32,
-    ) -> Result<cm::Time, arc::R<ns::Exception>> {
+    pub async fn set_exposure_target_bias(&mut self, bias: f32) -> objc::TryResult<cm::Time> {
         let (future, mut block) = blocks::comp1();
-        let res = ns::try_catch(move || unsafe {
+        objc::try_catch_retained(move || unsafe {
             self.set_exposure_target_bias_with_ch_throws(bias, block.as_esc_mut())
-        });
-        if let Err(err) = res {
-            return Err(err.retained());
-        }
+        })?;
         Ok(future.await)
     }
 }
@@ -1417,8 +1360,8 @@ impl Device {
     pub fn is_global_tone_mapping_enabled(&self) -> bool;
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    #[objc::msg_send(setGlobalToneMappingEnabled:)]
-    pub fn set_global_tone_mapping_enabled_throws(&mut self, val: bool);
+    #[objc::msg_send(setGlobalToneMappingEnabled:, throws)]
+    pub unsafe fn set_global_tone_mapping_enabled_throws(&mut self, val: bool);
 }
 
 /// AVCaptureDeviceToneMapping
@@ -1430,8 +1373,8 @@ impl<'a> ConfigLockGuard<'a> {
     }
 
     #[cfg(any(target_os = "tvos", target_os = "ios"))]
-    pub fn set_global_tone_mapping_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_global_tone_mapping_enabled_throws(val) })
+    pub fn set_global_tone_mapping_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_global_tone_mapping_enabled(val)
     }
 }
 
@@ -1486,7 +1429,7 @@ impl Device {
     #[objc::msg_send(whiteBalanceMode)]
     pub fn wb_mode(&self) -> WbMode;
 
-    #[objc::msg_send(setWhiteBalanceMode:)]
+    #[objc::msg_send(setWhiteBalanceMode:, throws)]
     unsafe fn set_wb_mode_throws(&mut self, val: WbMode);
 
     #[objc::msg_send(isAdjustingWhiteBalance)]
@@ -1510,7 +1453,7 @@ impl Device {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    #[objc::msg_send(setWhiteBalanceModeLockedWithDeviceWhiteBalanceGains:completionHandler:)]
+    #[objc::msg_send(setWhiteBalanceModeLockedWithDeviceWhiteBalanceGains:completionHandler:, throws)]
     unsafe fn set_wb_mode_locked_with_device_wb_gains_throws(
         &mut self,
         gains: WbGains,
@@ -1538,8 +1481,8 @@ impl<'a> ConfigLockGuard<'a> {
         unsafe { self.device.set_wb_mode_throws(val) }
     }
 
-    pub fn set_wb_mode<'ear>(&mut self, val: WbMode) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_wb_mode_throws(val) })
+    pub fn set_wb_mode(&mut self, val: WbMode) -> objc::TryResult {
+        self.device.try_set_wb_mode(val)
     }
 
     #[cfg(all(
@@ -1571,22 +1514,22 @@ impl<'a> ConfigLockGuard<'a> {
         feature = "cm",
         any(target_os = "tvos", target_os = "ios")
     ))]
-    pub fn set_wb_mode_locked_with_device_wb_gains_with_ch<'ear>(
+    pub fn set_wb_mode_locked_with_device_wb_gains_with_ch(
         &mut self,
         gains: WbGains,
         block: &mut blocks::EscBlock<fn(sync_time: cm::Time)>,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
+    ) -> objc::TryResult {
+        objc::try_catch_retained(|| unsafe {
             self.set_wb_mode_locked_with_device_wb_gains_with_ch_throws(gains, block)
         })
     }
 
     #[cfg(all(feature = "blocks", any(target_os = "tvos", target_os = "ios")))]
-    pub fn set_wb_mode_locked_with_device_wb_gains_no_ch<'ear>(
+    pub fn set_wb_mode_locked_with_device_wb_gains_no_ch(
         &mut self,
         gains: WbGains,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
+    ) -> objc::TryResult {
+        objc::try_catch_retained(|| unsafe {
             self.set_wb_mode_locked_with_device_wb_gains_no_ch_throws(gains)
         })
     }
@@ -1615,14 +1558,11 @@ impl<'a> ConfigLockGuard<'a> {
     pub async fn set_wb_mode_locked_with_device_wb_gains(
         &mut self,
         gains: WbGains,
-    ) -> Result<cm::Time, arc::R<ns::Exception>> {
+    ) -> objc::TryResult<cm::Time> {
         let (future, mut block) = blocks::comp1();
-        let res = ns::try_catch(move || unsafe {
+        objc::try_catch_retained(move || unsafe {
             self.set_wb_mode_locked_with_device_wb_gains_with_ch_throws(gains, block.as_esc_mut())
-        });
-        if let Err(err) = res {
-            return Err(err.retained());
-        }
+        })?;
         Ok(future.await)
     }
 }
@@ -1633,7 +1573,7 @@ impl Device {
     #[objc::msg_send(isSubjectAreaChangeMonitoringEnabled)]
     pub fn is_subject_area_change_monitoring_enabled(&self) -> bool;
 
-    #[objc::msg_send(setSubjectAreaChangeMonitoringEnabled:)]
+    #[objc::msg_send(setSubjectAreaChangeMonitoringEnabled:, throws)]
     unsafe fn set_subject_area_change_monitoring_enabled_throws(&mut self, val: bool);
 }
 
@@ -1657,13 +1597,13 @@ impl Device {
     #[objc::msg_send(isLowLightBoostEnabled)]
     pub fn is_low_light_boost_enabled(&self) -> bool;
 
-    #[objc::msg_send(setLowLightBoostEnabled:)]
+    #[objc::msg_send(setLowLightBoostEnabled:, throws)]
     unsafe fn set_low_light_boost_enabled_throws(&mut self, val: bool);
 
     #[objc::msg_send(automaticallyEnablesLowLightBoostWhenAvailable)]
     pub fn automatically_enables_low_light_boost_when_available(&self) -> bool;
 
-    #[objc::msg_send(setAutomaticallyEnablesLowLightBoostWhenAvailable:)]
+    #[objc::msg_send(setAutomaticallyEnablesLowLightBoostWhenAvailable:, throws)]
     unsafe fn set_automatically_enables_low_light_boost_when_available_throws(&mut self, val: bool);
 }
 
@@ -1674,8 +1614,8 @@ impl<'a> ConfigLockGuard<'a> {
         unsafe { self.device.set_low_light_boost_enabled_throws(val) }
     }
 
-    pub fn set_low_light_boost_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_low_light_boost_enabled_throws(val) })
+    pub fn set_low_light_boost_enabled(&mut self, val: bool) -> objc::TryResult {
+        self.device.try_set_low_light_boost_enabled(val)
     }
 
     pub unsafe fn set_automatically_enables_low_light_boost_when_available_throws(
@@ -1688,13 +1628,12 @@ impl<'a> ConfigLockGuard<'a> {
         }
     }
 
-    pub fn set_automatically_enables_low_light_boost_when_available<'ear>(
+    pub fn set_automatically_enables_low_light_boost_when_available(
         &mut self,
         val: bool,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.set_automatically_enables_low_light_boost_when_available_throws(val)
-        })
+    ) -> objc::TryResult {
+        self.device
+            .try_set_automatically_enables_low_light_boost_when_available(val)
     }
 }
 
@@ -1703,16 +1642,16 @@ impl Device {
     #[objc::msg_send(videoZoomFactor)]
     pub fn video_zoom_factor(&self) -> cg::Float;
 
-    #[objc::msg_send(setVideoZoomFactor:)]
+    #[objc::msg_send(setVideoZoomFactor:, throws)]
     unsafe fn set_video_zoom_factor_throws(&mut self, val: cg::Float);
 
     #[objc::msg_send(isRampingVideoZoom)]
     pub fn is_ramping_video_zoom(&self) -> bool;
 
-    #[objc::msg_send(rampToVideoZoomFactor:withRate:)]
+    #[objc::msg_send(rampToVideoZoomFactor:withRate:, throws)]
     pub unsafe fn ramp_to_video_zoom_factor_throws(&mut self, factor: cg::Float, rate: f32);
 
-    #[objc::msg_send(cancelVideoZoomRamp)]
+    #[objc::msg_send(cancelVideoZoomRamp, throws)]
     pub unsafe fn cancel_video_zoom_ramp_throws(&mut self);
 
     #[objc::msg_send(displayVideoZoomFactorMultiplier)]
@@ -1916,19 +1855,20 @@ pub enum AuthorizationStatus {
 
 /// AVCaptureDeviceAuthorization
 impl Device {
-    #[objc::msg_send(authorizationStatusForMediaType:)]
+    #[objc::msg_send(authorizationStatusForMediaType:, throws)]
     pub unsafe fn authorization_status_for_media_type_throws(
         media_type: &av::MediaType,
     ) -> AuthorizationStatus;
 
+    #[deprecated(note = "use `try_authorization_status_for_media_type`")]
     pub fn authorization_status_for_media_type<'ear>(
         media_type: &av::MediaType,
     ) -> ns::ExResult<'ear, AuthorizationStatus> {
-        ns::try_catch(|| unsafe { Self::authorization_status_for_media_type_throws(media_type) })
+        Self::try_authorization_status_for_media_type(media_type).map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(feature = "blocks")]
-    #[objc::msg_send(requestAccessForMediaType:completionHandler:)]
+    #[objc::msg_send(requestAccessForMediaType:completionHandler:, throws)]
     unsafe fn _request_access_for_media_type_ch_throws(
         media_type: &av::MediaType,
         block: &mut blocks::SendBlock<fn(bool)>,
@@ -1943,11 +1883,11 @@ impl Device {
     }
 
     #[cfg(feature = "blocks")]
-    pub fn request_access_for_media_type_ch<'ear>(
+    pub fn request_access_for_media_type_ch(
         media_type: &av::MediaType,
         block: &mut blocks::SendBlock<fn(bool)>,
-    ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
+    ) -> objc::TryResult {
+        objc::try_catch_retained(|| unsafe {
             Self::request_access_for_media_type_ch_throws(media_type, block)
         })
     }
@@ -1955,12 +1895,10 @@ impl Device {
     #[cfg(feature = "async")]
     pub async fn request_access_for_media_type(
         media_type: &av::MediaType,
-    ) -> Result<bool, arc::R<ns::Exception>> {
+    ) -> objc::TryResult<bool> {
         let (future, mut block) = blocks::comp1();
-        match Self::request_access_for_media_type_ch(media_type, &mut block) {
-            Ok(_) => Ok(future.await),
-            Err(e) => Err(e.retained()),
-        }
+        Self::request_access_for_media_type_ch(media_type, &mut block)?;
+        Ok(future.await)
     }
 }
 
@@ -2279,7 +2217,7 @@ impl Format {
 
 /// CameraLensSmudgeDetection
 impl Device {
-    #[objc::msg_send(setCameraLensSmudgeDetectionEnabled:detectionInterval:)]
+    #[objc::msg_send(setCameraLensSmudgeDetectionEnabled:detectionInterval:, throws)]
     #[api::available(macos = 26.0, ios = 26.0, maccatalyst = 26.0, tvos = 26.0)]
     pub unsafe fn set_cam_lens_smudge_detection_enabled_throws(
         &mut self,
@@ -2313,16 +2251,14 @@ pub enum CamLensSmudgeDetectionStatus {
 /// CameraLensSmudgeDetection
 impl<'a> ConfigLockGuard<'a> {
     #[api::available(macos = 26.0, ios = 26.0, maccatalyst = 26.0, tvos = 26.0)]
-    pub fn set_cam_lens_smudge_detection_enabled<'ear>(
+    pub fn set_cam_lens_smudge_detection_enabled(
         &mut self,
         val: bool,
         detection_interval: cm::Time,
-    ) -> ns::ExResult<'ear> {
+    ) -> objc::TryResult {
         unsafe {
-            ns::try_catch(|| {
-                self.device
-                    .set_cam_lens_smudge_detection_enabled_throws(val, detection_interval)
-            })
+            self.device
+                .try_set_cam_lens_smudge_detection_enabled(val, detection_interval)
         }
     }
 }
@@ -2401,8 +2337,6 @@ impl DiscoverySession {
     pub fn supported_multi_cam_device_sets(&self) -> arc::R<ns::Array<ns::Set<Device>>>;
 }
 
-impl ns::KvObserverRegistration for DiscoverySession {}
-
 define_obj_type!(
     /// Allows clients to monitor rotations of a given [`av::CaptureDevice`] instance
     /// and be provided the video rotation angle that should be applied for
@@ -2624,11 +2558,12 @@ mod tests {
         let res = av::CaptureDevice::request_access_for_media_type(av::MediaType::text()).await;
         assert!(res.is_err());
 
-        let status = av::CaptureDevice::authorization_status_for_media_type(av::MediaType::video())
-            .expect("Failed on valid media type");
+        let status =
+            av::CaptureDevice::try_authorization_status_for_media_type(av::MediaType::video())
+                .expect("Failed on valid media type");
         assert_eq!(status, av::AuthorizationStatus::Authorized);
 
-        let res = av::CaptureDevice::authorization_status_for_media_type(av::MediaType::text());
+        let res = av::CaptureDevice::try_authorization_status_for_media_type(av::MediaType::text());
         assert!(res.is_err());
     }
 
@@ -9,7 +9,7 @@ define_obj_type!(
 );
 
 impl arc::A<IndexPicker> {
-    #[objc::msg_send(initWithLocalizedTitle:symbolName:numberOfIndexes:)]
+    #[objc::msg_send(initWithLocalizedTitle:symbolName:numberOfIndexes:, throws)]
     pub unsafe fn init_with_indexes_n_throws(
         self,
         localized_title: &ns::String,
@@ -17,18 +17,18 @@ impl arc::A<IndexPicker> {
         n: isize,
     ) -> arc::R<IndexPicker>;
 
+    #[deprecated(note = "use `try_init_with_indexes_n`")]
     pub fn init_with_indexes_n<'ear>(
         self,
         localized_title: &ns::String,
         symbol_name: &ns::String,
         n: isize,
     ) -> ns::ExResult<'ear, arc::R<IndexPicker>> {
-        ns::try_catch(|| unsafe {
-            self.init_with_indexes_n_throws(localized_title, symbol_name, n)
-        })
+        self.try_init_with_indexes_n(localized_title, symbol_name, n)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(initWithLocalizedTitle:symbolName:localizedIndexTitles:)]
+    #[objc::msg_send(initWithLocalizedTitle:symbolName:localizedIndexTitles:, throws)]
     pub unsafe fn init_with_indexes_titles_throws(
         self,
         localized_title: &ns::String,
@@ -36,15 +36,15 @@ impl arc::A<IndexPicker> {
         titles: &ns::Array<ns::String>,
     ) -> arc::R<IndexPicker>;
 
+    #[deprecated(note = "use `try_init_with_indexes_titles`")]
     pub fn init_with_titles<'ear>(
         self,
         localized_title: &ns::String,
         symbol_name: &ns::String,
         titles: &ns::Array<ns::String>,
     ) -> ns::ExResult<'ear, arc::R<IndexPicker>> {
-        ns::try_catch(|| unsafe {
-            self.init_with_indexes_titles_throws(localized_title, symbol_name, titles)
-        })
+        self.try_init_with_indexes_titles(localized_title, symbol_name, titles)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -53,34 +53,36 @@ impl IndexPicker {
     crate::define_cls!(AV_CAPTURE_INDEX_PICKER);
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_indexes_n<'ear>(
+    pub fn with_indexes_n(
         localized_title: &ns::String,
         symbol_name: &ns::String,
         n: isize,
-    ) -> ns::ExResult<'ear, arc::R<IndexPicker>> {
-        Self::alloc().init_with_indexes_n(localized_title, symbol_name, n)
+    ) -> objc::TryResult<arc::R<IndexPicker>> {
+        Self::alloc().try_init_with_indexes_n(localized_title, symbol_name, n)
     }
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_titles<'ear>(
+    pub fn with_titles(
         localized_title: &ns::String,
         symbol_name: &ns::String,
         titles: &ns::Array<ns::String>,
-    ) -> ns::ExResult<'ear, arc::R<IndexPicker>> {
-        Self::alloc().init_with_titles(localized_title, symbol_name, titles)
+    ) -> objc::TryResult<arc::R<IndexPicker>> {
+        Self::alloc().try_init_with_indexes_titles(localized_title, symbol_name, titles)
     }
 
     #[objc::msg_send(selectedIndex)]
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub fn selected_index(&self) -> isize;
 
-    #[objc::msg_send(setSelectedIndex:)]
+    #[objc::msg_send(setSelectedIndex:, throws)]
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub unsafe fn set_selected_index_throws(&mut self, val: isize);
 
+    #[deprecated(note = "use `try_set_selected_index`")]
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub fn set_selected_index<'ear>(&mut self, val: isize) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_selected_index_throws(val) })
+        self.try_set_selected_index(val)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(localizedTitle)]
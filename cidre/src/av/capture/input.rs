@@ -147,16 +147,18 @@ impl DeviceInput {
     #[objc::available(macos = 26.0, ios = 26.0, maccatalyst = 26.0, tvos = 26.0)]
     pub fn simulated_aperture(&self) -> f32;
 
-    #[objc::msg_send(setSimulatedAperture:)]
+    #[objc::msg_send(setSimulatedAperture:, throws)]
     #[objc::available(macos = 26.0, ios = 26.0, maccatalyst = 26.0, tvos = 26.0)]
     pub unsafe fn set_simulated_aperture_throws(&mut self, val: f32);
 
     /// Shallow depth of field simulated aperture.
     ///
     /// When capturing a Cinematic Video, use this property to control the amount of blur in the simulated depth of field effect.
+    #[deprecated(note = "use `try_set_simulated_aperture`")]
     #[objc::available(macos = 26.0, ios = 26.0, maccatalyst = 26.0, tvos = 26.0)]
     pub fn set_simultated_aperture<'ear>(&mut self, val: f32) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_simulated_aperture_throws(val)) }
+        objc::try_catch_retained(|| unsafe { self.set_simulated_aperture_throws(val) })
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -49,13 +49,15 @@ impl Output {
     #[objc::available(macos = 26.0, maccatalyst = 26.0, ios = 26.0, tvos = 26.0)]
     pub fn is_deferred_start_enabled(&self) -> bool;
 
-    #[objc::msg_send(setDeferredStartEnabled:)]
+    #[objc::msg_send(setDeferredStartEnabled:, throws)]
     #[objc::available(macos = 26.0, maccatalyst = 26.0, ios = 26.0, tvos = 26.0)]
     pub unsafe fn set_deferred_start_enabled_throws(&mut self, val: bool);
 
+    #[deprecated(note = "use `try_set_deferred_start_enabled`")]
     #[objc::available(macos = 26.0, maccatalyst = 26.0, ios = 26.0, tvos = 26.0)]
     pub fn set_deferred_start_enabled<'ear>(&mut self, val: bool) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_deferred_start_enabled_throws(val)) }
+        objc::try_catch_retained(|| unsafe { self.set_deferred_start_enabled_throws(val) })
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
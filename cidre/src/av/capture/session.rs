@@ -69,11 +69,13 @@ impl Session {
     #[objc::msg_send(sessionPreset)]
     pub fn session_preset(&self) -> &av::CaptureSessionPreset;
 
-    #[objc::msg_send(setSessionPreset:)]
+    #[objc::msg_send(setSessionPreset:, throws)]
     pub unsafe fn set_session_preset_throws(&self, val: &av::CaptureSessionPreset);
 
+    #[deprecated(note = "use `try_set_session_preset`")]
     pub fn set_session_preset<'ear>(&self, val: &av::CaptureSessionPreset) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_session_preset_throws(val) })
+        self.try_set_session_preset(val)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(inputs)]
@@ -190,13 +192,14 @@ impl Session {
     #[objc::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub fn can_add_control(&self, val: &av::CaptureControl) -> bool;
 
-    #[objc::msg_send(addControl:)]
+    #[objc::msg_send(addControl:, throws)]
     #[objc::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub unsafe fn add_control_throws(&mut self, val: &av::CaptureControl);
 
+    #[deprecated(note = "use `try_add_control`")]
     #[objc::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
     pub fn add_control<'ear>(&mut self, val: &av::CaptureControl) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.add_control_throws(val) })
+        self.try_add_control(val).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(removeControl:)]
@@ -500,11 +503,13 @@ impl Connection {
     #[objc::msg_send(videoRotationAngle)]
     pub fn video_rotation_angle(&self) -> cg::Float;
 
-    #[objc::msg_send(setVideoRotationAngle:)]
+    #[objc::msg_send(setVideoRotationAngle:, throws)]
     pub unsafe fn set_video_rotation_angle_throws(&mut self, val: cg::Float);
 
+    #[deprecated(note = "use `try_set_video_rotation_angle`")]
     pub fn set_video_rotation_angle<'ear>(&mut self, val: cg::Float) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_video_rotation_angle_throws(val) })
+        self.try_set_video_rotation_angle(val)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(target_os = "macos")]
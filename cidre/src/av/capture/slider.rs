@@ -9,7 +9,7 @@ define_obj_type!(
 );
 
 impl arc::A<Slider> {
-    #[objc::msg_send(initWithLocalizedTitle:symbolName:minValue:maxValue:)]
+    #[objc::msg_send(initWithLocalizedTitle:symbolName:minValue:maxValue:, throws)]
     pub unsafe fn init_with_range_throws(
         self,
         localized_title: &ns::String,
@@ -18,6 +18,7 @@ impl arc::A<Slider> {
         max_val: f32,
     ) -> arc::R<Slider>;
 
+    #[deprecated(note = "use `try_init_with_range`")]
     pub fn init_with_range<'ear>(
         self,
         localized_title: &ns::String,
@@ -25,12 +26,11 @@ impl arc::A<Slider> {
         min_val: f32,
         max_val: f32,
     ) -> ns::ExResult<'ear, arc::R<Slider>> {
-        ns::try_catch(|| unsafe {
-            self.init_with_range_throws(localized_title, symbol_name, min_val, max_val)
-        })
+        self.try_init_with_range(localized_title, symbol_name, min_val, max_val)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(initWithLocalizedTitle:symbolName:minValue:maxValue:step:)]
+    #[objc::msg_send(initWithLocalizedTitle:symbolName:minValue:maxValue:step:, throws)]
     pub unsafe fn init_with_range_step_throws(
         self,
         localized_title: &ns::String,
@@ -40,6 +40,7 @@ impl arc::A<Slider> {
         step: f32,
     ) -> arc::R<Slider>;
 
+    #[deprecated(note = "use `try_init_with_range_step`")]
     pub fn init_with_range_step<'ear>(
         self,
         localized_title: &ns::String,
@@ -48,12 +49,11 @@ impl arc::A<Slider> {
         max_val: f32,
         step: f32,
     ) -> ns::ExResult<'ear, arc::R<Slider>> {
-        ns::try_catch(|| unsafe {
-            self.init_with_range_step_throws(localized_title, symbol_name, min_val, max_val, step)
-        })
+        self.try_init_with_range_step(localized_title, symbol_name, min_val, max_val, step)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(initWithLocalizedTitle:symbolName:values:)]
+    #[objc::msg_send(initWithLocalizedTitle:symbolName:values:, throws)]
     pub unsafe fn init_with_vals_throws(
         self,
         localized_title: &ns::String,
@@ -61,13 +61,15 @@ impl arc::A<Slider> {
         vals: &ns::Array<ns::Number>,
     ) -> arc::R<Slider>;
 
+    #[deprecated(note = "use `try_init_with_vals`")]
     pub fn init_with_vals<'ear>(
         self,
         localized_title: &ns::String,
         symbol_name: &ns::String,
         vals: &ns::Array<ns::Number>,
     ) -> ns::ExResult<'ear, arc::R<Slider>> {
-        ns::try_catch(|| unsafe { self.init_with_vals_throws(localized_title, symbol_name, vals) })
+        self.try_init_with_vals(localized_title, symbol_name, vals)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -76,48 +78,50 @@ impl Slider {
     crate::define_cls!(AV_CAPTURE_SLIDER);
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_range<'ear>(
+    pub fn with_range(
         localized_title: &ns::String,
         symbol_name: &ns::String,
         min_val: f32,
         max_val: f32,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        Self::alloc().init_with_range(localized_title, symbol_name, min_val, max_val)
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_range(localized_title, symbol_name, min_val, max_val)
     }
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_range_step<'ear>(
+    pub fn with_range_step(
         localized_title: &ns::String,
         symbol_name: &ns::String,
         min_val: f32,
         max_val: f32,
         step: f32,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        Self::alloc().init_with_range_step(localized_title, symbol_name, min_val, max_val, step)
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_range_step(localized_title, symbol_name, min_val, max_val, step)
     }
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_vals<'ear>(
+    pub fn with_vals(
         localized_title: &ns::String,
         symbol_name: &ns::String,
         vals: &ns::Array<ns::Number>,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        Self::alloc().init_with_vals(localized_title, symbol_name, vals)
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_vals(localized_title, symbol_name, vals)
     }
 
-    #[objc::msg_send(value)]
+    #[objc::msg_send(value, throws)]
     pub unsafe fn value_throws(&self) -> f32;
 
     /// The current value of the slider.
+    #[deprecated(note = "use `try_value`")]
     pub fn value<'ear>(&self) -> ns::ExResult<'ear, f32> {
-        ns::try_catch(|| unsafe { self.value_throws() })
+        self.try_value().map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(setValue:)]
+    #[objc::msg_send(setValue:, throws)]
     pub unsafe fn set_value_throws(&mut self, val: f32);
 
+    #[deprecated(note = "use `try_set_value`")]
     pub fn set_value<'ear>(&mut self, val: f32) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_value_throws(val) })
+        self.try_set_value(val).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(localizedValueFormat)]
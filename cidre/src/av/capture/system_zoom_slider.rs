@@ -11,34 +11,38 @@ define_obj_type!(
 );
 
 impl arc::A<SysZoomSlider> {
-    #[objc::msg_send(initWithDevice:)]
+    #[objc::msg_send(initWithDevice:, throws)]
     pub unsafe fn init_with_device_throws(
         self,
         device: &av::CaptureDevice,
     ) -> arc::R<SysZoomSlider>;
 
+    #[deprecated(note = "use `try_init_with_device`")]
     pub fn init_with_device<'ear>(
         self,
         device: &av::CaptureDevice,
     ) -> ns::ExResult<'ear, arc::R<SysZoomSlider>> {
-        ns::try_catch(|| unsafe { self.init_with_device_throws(device) })
+        self.try_init_with_device(device)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(feature = "blocks")]
-    #[objc::msg_send(initWithDevice:action:)]
+    #[objc::msg_send(initWithDevice:action:, throws)]
     pub unsafe fn init_with_device_action_throws(
         self,
         device: &av::CaptureDevice,
         action: &mut blocks::EscBlock<fn(video_zoom_factor: cg::Float)>,
     ) -> arc::R<SysZoomSlider>;
 
+    #[deprecated(note = "use `try_init_with_device_action`")]
     #[cfg(feature = "blocks")]
     pub fn init_with_device_action<'ear>(
         self,
         device: &av::CaptureDevice,
         action: &mut blocks::EscBlock<fn(video_zoom_factor: cg::Float)>,
     ) -> ns::ExResult<'ear, arc::R<SysZoomSlider>> {
-        ns::try_catch(|| unsafe { self.init_with_device_action_throws(device, action) })
+        self.try_init_with_device_action(device, action)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -47,17 +51,17 @@ impl SysZoomSlider {
     crate::define_cls!(AV_CAPTURE_SYSTEM_ZOOM_SLIDER);
 
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_device<'ear>(device: &av::CaptureDevice) -> ns::ExResult<'ear, arc::R<Self>> {
-        Self::alloc().init_with_device(device)
+    pub fn with_device(device: &av::CaptureDevice) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_device(device)
     }
 
     #[cfg(feature = "blocks")]
     #[api::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
-    pub fn with_device_action<'ear>(
+    pub fn with_device_action(
         device: &av::CaptureDevice,
         action: &mut blocks::EscBlock<fn(video_zoom_factor: cg::Float)>,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        Self::alloc().init_with_device_action(device, action)
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_device_action(device, action)
     }
 }
 
@@ -62,14 +62,16 @@ impl VideoDataOutput {
     pub fn delivers_preview_sized_output_bufs(&self) -> bool;
 
     /// Throws unless automatically_configures_output_buf_dims has been set to false.
-    #[objc::msg_send(setDeliversPreviewSizedOutputBuffers:)]
+    #[objc::msg_send(setDeliversPreviewSizedOutputBuffers:, throws)]
     pub unsafe fn set_delivers_preview_sized_output_bufs_throws(&mut self, value: bool);
 
+    #[deprecated(note = "use `try_set_delivers_preview_sized_output_bufs`")]
     pub fn set_delivers_preview_sized_output_bufs<'ear>(
         &mut self,
         value: bool,
     ) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_delivers_preview_sized_output_bufs_throws(value)) }
+        self.try_set_delivers_preview_sized_output_bufs(value)
+            .map_err(|e| &*e.autoreleased())
     }
 
     /// Indicates whether the receiver should prepare the cellular radio for imminent network activity.
@@ -136,17 +138,19 @@ impl VideoDataOutput {
     #[objc::msg_send(videoSettings)]
     pub fn video_settings(&self) -> Option<arc::R<ns::Dictionary<ns::String, ns::Id>>>;
 
-    #[objc::msg_send(setVideoSettings:)]
+    #[objc::msg_send(setVideoSettings:, throws)]
     pub unsafe fn set_video_settings_throws(
         &mut self,
         val: Option<&ns::Dictionary<ns::String, ns::Id>>,
     );
 
+    #[deprecated(note = "use `try_set_video_settings`")]
     pub fn set_video_settings<'ear>(
         &mut self,
         val: Option<&ns::Dictionary<ns::String, ns::Id>>,
     ) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_video_settings_throws(val)) }
+        self.try_set_video_settings(val)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(recommendedVideoSettingsForAssetWriterWithOutputFileType:)]
@@ -46,7 +46,7 @@ pub use input_picker_interaction::InputPickerInteractionDelegate;
 #[cfg(target_os = "ios")]
 pub use input_picker_interaction::InputPickerInteractionDelegateImpl;
 
-#[link(name = "AVKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "AVKit", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "av_kit", kind = "static")]
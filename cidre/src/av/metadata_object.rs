@@ -372,7 +372,7 @@ impl FaceObj {
     #[objc::msg_send(hasRollAngle)]
     pub fn has_roll_angle(&self) -> bool;
 
-    #[objc::msg_send(rollAngle)]
+    #[objc::msg_send(rollAngle, throws)]
     pub unsafe fn roll_angle_throws(&self) -> cg::Float;
 
     /// The roll angle of the face in degrees.
@@ -392,7 +392,7 @@ impl FaceObj {
     /// The value of this property is a cg::Float indicating the face's angle of yaw (or turn) in degrees.
     /// A value of 0.0 indicates that the face is straight on in the picture.
     /// If has_yaw_angle() returns false, then reading this property throws an ns::GenericException.
-    #[objc::msg_send(yawAngle)]
+    #[objc::msg_send(yawAngle, throws)]
     pub unsafe fn yaw_angle_throws(&self) -> cg::Float;
 
     /// The yaw angle of the face in degrees.
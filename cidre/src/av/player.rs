@@ -30,6 +30,17 @@ pub enum Status {
     Failed = 2,
 }
 
+impl ns::KvValue for Status {
+    fn from_kv(raw: ns::KvRaw) -> Option<Self> {
+        Some(match <isize as ns::KvValue>::from_kv(raw)? {
+            0 => Self::Unknown,
+            1 => Self::ReadyToPlay,
+            2 => Self::Failed,
+            _ => return None,
+        })
+    }
+}
+
 #[doc(alias = "AVPlayerTimeControlStatus")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(isize)]
@@ -39,6 +50,17 @@ pub enum TimeControlStatus {
     Playing = 2,
 }
 
+impl ns::KvValue for TimeControlStatus {
+    fn from_kv(raw: ns::KvRaw) -> Option<Self> {
+        Some(match <isize as ns::KvValue>::from_kv(raw)? {
+            0 => Self::Paused,
+            1 => Self::WaitingToPlayAtSpecifiedRate,
+            2 => Self::Playing,
+            _ => return None,
+        })
+    }
+}
+
 #[doc(alias = "AVPlayerActionAtItemEnd")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(isize)]
@@ -75,7 +97,7 @@ impl arc::A<Player> {
     #[objc::msg_send(initWithURL:)]
     pub fn init_with_url(self, url: &ns::Url) -> arc::R<Player>;
 
-    #[objc::msg_send(initWithPlayerItem:)]
+    #[objc::msg_send(initWithPlayerItem:, throws)]
     pub unsafe fn init_with_player_item_throws(self, item: Option<&PlayerItem>) -> arc::R<Player>;
 }
 
@@ -90,11 +112,11 @@ impl Player {
         unsafe { Self::alloc().init_with_player_item_throws(item) }
     }
 
-    pub fn with_player_item<'ear>(item: Option<&PlayerItem>) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::with_player_item_throws(item) })
+    pub fn with_player_item(item: Option<&PlayerItem>) -> objc::TryResult<arc::R<Self>> {
+        objc::try_catch_retained(|| unsafe { Self::with_player_item_throws(item) })
     }
 
-    #[objc::msg_send(status)]
+    #[objc::msg_send(status, kvo)]
     pub fn status(&self) -> Status;
 
     /// If the receiver's status is Status::Failed, this describes the error that caused the failure.
@@ -129,7 +151,7 @@ impl Player {
     #[objc::msg_send(pause)]
     pub fn pause(&mut self);
 
-    #[objc::msg_send(timeControlStatus)]
+    #[objc::msg_send(timeControlStatus, kvo)]
     pub fn time_control_status(&self) -> TimeControlStatus;
 
     #[objc::msg_send(reasonForWaitingToPlay)]
@@ -171,24 +193,28 @@ impl Player {
     #[objc::msg_send(currentItem)]
     pub fn current_item(&self) -> Option<arc::R<av::PlayerItem>>;
 
-    #[objc::msg_send(replaceCurrentItemWithPlayerItem:)]
+    #[objc::msg_send(replaceCurrentItemWithPlayerItem:, throws)]
     pub unsafe fn replace_current_item_throws(&mut self, item: Option<&av::PlayerItem>);
 
+    #[deprecated(note = "use `try_replace_current_item`")]
     pub fn replace_current_item<'ear>(
         &mut self,
         item: Option<&av::PlayerItem>,
     ) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.replace_current_item_throws(item)) }
+        self.try_replace_current_item(item)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(actionAtItemEnd)]
     pub fn action_at_item_end(&self) -> ActionAtItemEnd;
 
-    #[objc::msg_send(setActionAtItemEnd:)]
+    #[objc::msg_send(setActionAtItemEnd:, throws)]
     pub unsafe fn set_action_at_item_end_throws(&mut self, val: ActionAtItemEnd);
 
+    #[deprecated(note = "use `try_set_action_at_item_end`")]
     pub fn set_action_at_item_end<'ear>(&mut self, val: ActionAtItemEnd) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_action_at_item_end_throws(val)) }
+        self.try_set_action_at_item_end(val)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -246,7 +272,7 @@ impl Player {
     #[objc::msg_send(setAutomaticallyWaitsToMinimizeStalling:)]
     pub fn set_automatically_waits_to_minimize_stalling(&mut self, val: bool);
 
-    #[objc::msg_send(setRate:time:atHostTime:)]
+    #[objc::msg_send(setRate:time:atHostTime:, throws)]
     pub unsafe fn set_rate_time_throws(
         &mut self,
         rate: f32,
@@ -254,13 +280,15 @@ impl Player {
         at_host_clock_time: cm::Time,
     );
 
+    #[deprecated(note = "use `try_set_rate_time`")]
     pub fn set_rate_time<'ear>(
         &mut self,
         rate: f32,
         time: cm::Time,
         at_host_clock_time: cm::Time,
     ) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.set_rate_time_throws(rate, time, at_host_clock_time)) }
+        self.try_set_rate_time(rate, time, at_host_clock_time)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(feature = "blocks")]
@@ -318,7 +346,7 @@ define_obj_type!(
 );
 
 impl arc::A<QueuePlayer> {
-    #[objc::msg_send(initWithItems:)]
+    #[objc::msg_send(initWithItems:, throws)]
     pub unsafe fn init_with_items_throws(
         self,
         items: &ns::Array<av::PlayerItem>,
@@ -326,8 +354,8 @@ impl arc::A<QueuePlayer> {
 }
 
 impl QueuePlayer {
-    pub fn with_items<'ear>(items: &ns::Array<av::PlayerItem>) -> ns::ExResult<'ear, arc::R<Self>> {
-        unsafe { ns::try_catch(|| Self::alloc().init_with_items_throws(items)) }
+    pub fn with_items(items: &ns::Array<av::PlayerItem>) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_items(items)
     }
 
     #[objc::msg_send(items)]
@@ -125,24 +125,26 @@ impl Item {
     pub fn seekable_time_ranges(&self) -> arc::R<ns::Array<ns::Value>>;
 
     #[cfg(feature = "blocks")]
-    #[objc::msg_send(seekToTime:completionHandler:)]
+    #[objc::msg_send(seekToTime:completionHandler:, throws)]
     pub unsafe fn seek_to_time_ch_throws(
         &mut self,
         val: cm::Time,
         block: Option<&mut blocks::SendBlock<fn(finished: bool)>>,
     );
 
+    #[deprecated(note = "use `try_seek_to_time_ch`")]
     #[cfg(feature = "blocks")]
     pub fn seek_to_time_ch<'ear>(
         &mut self,
         val: cm::Time,
         block: Option<&mut blocks::SendBlock<fn(finished: bool)>>,
     ) -> ns::ExResult<'ear> {
-        unsafe { ns::try_catch(|| self.seek_to_time_ch_throws(val, block)) }
+        self.try_seek_to_time_ch(val, block)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(feature = "blocks")]
-    #[objc::msg_send(seekToTime:toleranceBefore:toleranceAfter:completionHandler:)]
+    #[objc::msg_send(seekToTime:toleranceBefore:toleranceAfter:completionHandler:, throws)]
     pub unsafe fn seek_to_time_with_tolerance_ch_throws(
         &mut self,
         val: cm::Time,
@@ -151,6 +153,7 @@ impl Item {
         block: Option<&mut blocks::SendBlock<fn(finished: bool)>>,
     );
 
+    #[deprecated(note = "use `try_seek_to_time_with_tolerance_ch`")]
     #[cfg(feature = "blocks")]
     pub fn seek_to_time_with_tolerance_ch<'ear>(
         &mut self,
@@ -159,16 +162,8 @@ impl Item {
         tolerance_after: cm::Time,
         block: Option<&mut blocks::SendBlock<fn(finished: bool)>>,
     ) -> ns::ExResult<'ear> {
-        unsafe {
-            ns::try_catch(|| {
-                self.seek_to_time_with_tolerance_ch_throws(
-                    val,
-                    tolerance_befor,
-                    tolerance_after,
-                    block,
-                )
-            })
-        }
+        self.try_seek_to_time_with_tolerance_ch(val, tolerance_befor, tolerance_after, block)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(cancelPendingSeeks)]
@@ -29,34 +29,35 @@ unsafe impl Sync for Looper {}
 impl Looper {
     define_cls!(AV_PLAYER_LOOPER);
 
-    #[objc::msg_send(playerLooperWithPlayer:templateItem:)]
+    #[objc::msg_send(playerLooperWithPlayer:templateItem:, throws)]
     pub unsafe fn with_player_throws(
         player: &av::QueuePlayer,
         template_item: &av::PlayerItem,
     ) -> arc::R<Self>;
 
+    #[deprecated(note = "use `try_with_player`")]
     pub fn with_player<'ear>(
         player: &av::QueuePlayer,
         template_item: &av::PlayerItem,
     ) -> ns::ExResult<'ear, arc::R<Self>> {
-        unsafe { ns::try_catch(|| Self::with_player_throws(player, template_item)) }
+        Self::try_with_player(player, template_item).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(playerLooperWithPlayer:templateItem:timeRange:)]
+    #[objc::msg_send(playerLooperWithPlayer:templateItem:timeRange:, throws)]
     pub unsafe fn with_player_time_range_throws(
         player: &av::QueuePlayer,
         template_item: &av::PlayerItem,
         loop_range: cm::TimeRange,
     ) -> arc::R<Self>;
 
+    #[deprecated(note = "use `try_with_player_time_range`")]
     pub fn with_player_time_range<'ear>(
         player: &av::QueuePlayer,
         template_item: &av::PlayerItem,
         loop_range: cm::TimeRange,
     ) -> ns::ExResult<'ear, arc::R<Self>> {
-        unsafe {
-            ns::try_catch(|| Self::with_player_time_range_throws(player, template_item, loop_range))
-        }
+        Self::try_with_player_time_range(player, template_item, loop_range)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(status)]
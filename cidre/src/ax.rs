@@ -40,5 +40,8 @@ mod value;
 pub use value::Value;
 pub use value::ValueType;
 
-#[link(name = "ApplicationServices", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "ApplicationServices", kind = "framework")
+)]
 unsafe extern "C" {}
@@ -2,12 +2,20 @@
 // https://github.com/apple-oss-distributions/libclosure/blob/main/BlockImplementation.txt
 // https://developer.apple.com/documentation/swift/calling-objective-c-apis-asynchronously
 // https://github.com/apple/swift-corelibs-foundation/blob/main/Sources/BlocksRuntime/runtime.c
+// https://github.com/llvm/llvm-project/blob/main/compiler-rt/lib/BlocksRuntime/runtime.c
+//
+// On non-Apple targets blocks are backed by compiler-rt libBlocksRuntime,
+// which shares layout, flags and refcounting with libclosure.
 
 use std::{
     ffi::c_void, marker::PhantomData, marker::Send as MarkerSend, marker::Sync as MarkerSync, mem,
 };
 
-use crate::{arc, define_opts, ns, objc};
+#[cfg(feature = "ns")]
+use crate::ns;
+#[cfg(feature = "objc")]
+use crate::objc;
+use crate::{arc, define_opts};
 
 #[cfg(feature = "custom-allocator")]
 use crate::cf;
@@ -34,14 +42,30 @@ pub type CompletionBlock = EscBlock<fn()>;
 pub type WorkBlock<Attr = Sync> = Block<fn(), Attr>;
 
 /// Error Completion Handler
+#[cfg(feature = "ns")]
 pub type ErrCh<E = ns::Error> = EscBlock<fn(error: Option<&E>)>;
 
 /// Result Completion Handler
+#[cfg(feature = "ns")]
 pub type ResultCh<T> = EscBlock<fn(Option<&T>, Option<&ns::Error>)>;
 
+/// Blocks are objects when objc runtime is available.
+#[cfg(feature = "objc")]
+type Literal = objc::Id;
+#[cfg(not(feature = "objc"))]
 #[derive(Debug)]
 #[repr(transparent)]
-pub struct Block<Sig, Attr = NoEsc>(ns::Id, PhantomData<(Sig, Attr)>);
+struct Literal(std::ptr::NonNull<c_void>);
+
+/// `isa` of block literal.
+#[cfg(feature = "objc")]
+type Isa = objc::Class<objc::Id>;
+#[cfg(not(feature = "objc"))]
+type Isa = c_void;
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Block<Sig, Attr = NoEsc>(Literal, PhantomData<(Sig, Attr)>);
 
 #[derive(Debug)]
 #[repr(transparent)]
@@ -51,15 +75,19 @@ pub struct StackBlock<'a, Closure, Sig>(Layout1Mut<'a, Closure>, PhantomData<Sig
 #[repr(transparent)]
 pub struct StaticBlock<Sig>(Layout1, PhantomData<Sig>);
 
+#[cfg(feature = "objc")]
 impl<Sig> std::ops::Deref for Block<Sig, NoEsc> {
-    type Target = ns::Id;
+    type Target = objc::Id;
 
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(self) }
     }
 }
 
+#[cfg(feature = "objc")]
 impl<Sig, Attr> objc::Obj for Block<Sig, Attr> {
+    const ENCODING: &'static str = "@?";
+
     #[inline]
     unsafe fn retain(id: &Self) -> arc::R<Self> {
         unsafe { std::mem::transmute(_Block_copy(std::mem::transmute(id))) }
@@ -71,6 +99,22 @@ impl<Sig, Attr> objc::Obj for Block<Sig, Attr> {
     }
 }
 
+#[cfg(not(feature = "objc"))]
+impl<Sig, Attr> arc::Release for Block<Sig, Attr> {
+    #[inline]
+    unsafe fn release(&mut self) {
+        unsafe { _Block_release(self as *mut Self as _) }
+    }
+}
+
+#[cfg(not(feature = "objc"))]
+impl<Sig, Attr> arc::Retain for Block<Sig, Attr> {
+    #[inline]
+    fn retained(&self) -> arc::R<Self> {
+        unsafe { std::mem::transmute(_Block_copy(self as *const Self as _)) }
+    }
+}
+
 impl<'a, Closure, Sig> std::ops::Deref for StackBlock<'a, Closure, Sig> {
     type Target = Block<Sig, NoEsc>;
 
@@ -153,6 +197,7 @@ impl<Sig, Attr> Block<Sig, Attr> {
     with_fn!(with_fn4: A, B, C, D);
     with_fn!(with_fn5: A, B, C, D, E);
     with_fn!(with_fn6: A, B, C, D, E, F);
+    with_fn!(with_fn7: A, B, C, D, E, F, G);
 }
 
 impl<Sig> Block<Sig, NoEsc> {
@@ -163,6 +208,7 @@ impl<Sig> Block<Sig, NoEsc> {
     stack!(stack4, invoke4: A, B, C, D);
     stack!(stack5, invoke5: A, B, C, D, E);
     stack!(stack6, invoke6: A, B, C, D, E, F);
+    stack!(stack7, invoke7: A, B, C, D, E, F, G);
 
     new!(new0, invoke0:);
     new!(new1, invoke1: A);
@@ -171,6 +217,7 @@ impl<Sig> Block<Sig, NoEsc> {
     new!(new4, invoke4: A, B, C, D);
     new!(new5, invoke5: A, B, C, D, E);
     new!(new6, invoke6: A, B, C, D, E, F);
+    new!(new7, invoke7: A, B, C, D, E, F, G);
 }
 
 impl<Sig> Block<Sig, Esc> {
@@ -181,6 +228,7 @@ impl<Sig> Block<Sig, Esc> {
     new!(new4, invoke4: A, B, C, D + 'static);
     new!(new5, invoke5: A, B, C, D, E + 'static);
     new!(new6, invoke6: A, B, C, D, E, F + 'static);
+    new!(new7, invoke7: A, B, C, D, E, F, G + 'static);
 
     pub fn as_noesc_mut(&mut self) -> &mut Block<Sig, NoEsc> {
         unsafe { std::mem::transmute(self) }
@@ -195,6 +243,7 @@ impl<Sig> Block<Sig, Send> {
     new!(new4, invoke4: A, B, C, D + 'static + MarkerSend);
     new!(new5, invoke5: A, B, C, D, E + 'static + MarkerSend);
     new!(new6, invoke6: A, B, C, D, E, F + 'static + MarkerSend);
+    new!(new7, invoke7: A, B, C, D, E, F, G + 'static + MarkerSend);
 
     pub fn as_esc_mut(&mut self) -> &mut Block<Sig, Esc> {
         unsafe { std::mem::transmute(self) }
@@ -213,6 +262,7 @@ impl<Sig> Block<Sig, Sync> {
     new!(new4, invoke4: A, B, C, D + 'static + MarkerSync);
     new!(new5, invoke5: A, B, C, D, E + 'static + MarkerSync);
     new!(new6, invoke6: A, B, C, D, E, F + 'static + MarkerSync);
+    new!(new7, invoke7: A, B, C, D, E, F, G + 'static + MarkerSync);
 
     pub fn as_send_mut(&mut self) -> &mut Block<Sig, Send> {
         unsafe { std::mem::transmute(self) }
@@ -235,6 +285,7 @@ impl<Sig> StaticBlock<Sig> {
     with_fn!(new4: A, B, C, D);
     with_fn!(new5: A, B, C, D, E);
     with_fn!(new6: A, B, C, D, E, F);
+    with_fn!(new7: A, B, C, D, E, F, G);
 
     pub fn as_sync_mut(&mut self) -> &mut Block<Sig, Sync> {
         unsafe { std::mem::transmute(self) }
@@ -343,7 +394,7 @@ pub struct Desc2<T: Sized> {
 #[derive(Debug)]
 #[repr(C)]
 pub struct Layout1 {
-    isa: &'static objc::Class<ns::Id>,
+    isa: &'static Isa,
     flags: Flags,
     reserved: i32,
     invoke: *const c_void,
@@ -353,7 +404,7 @@ pub struct Layout1 {
 #[derive(Debug)]
 #[repr(C)]
 pub struct Layout1Mut<'a, Closure> {
-    isa: &'static objc::Class<ns::Id>,
+    isa: &'static Isa,
     flags: Flags,
     reserved: i32,
     invoke: *const c_void,
@@ -364,7 +415,7 @@ pub struct Layout1Mut<'a, Closure> {
 #[derive(Debug)]
 #[repr(C)]
 struct Layout2Mut<'a, F: Sized + 'a> {
-    isa: &'static objc::Class<ns::Id>,
+    isa: &'static Isa,
     flags: Flags,
     reserved: i32,
     invoke: *const c_void,
@@ -392,7 +443,7 @@ impl Layout1 {
 impl<'a, Closure> Layout1Mut<'a, Closure> {
     const DESCRIPTOR_1: Desc1 = Desc1 {
         reserved: 0,
-        size: std::mem::size_of::<&'static objc::Class<ns::Id>>()
+        size: std::mem::size_of::<&'static Isa>()
             + std::mem::size_of::<Flags>()
             + std::mem::size_of::<i32>()
             + std::mem::size_of::<*const c_void>()
@@ -407,6 +458,7 @@ impl<'a, Closure> Layout1Mut<'a, Closure> {
     invoke! {invoke4: a: A, b: B, c: C, d: D}
     invoke! {invoke5: a: A, b: B, c: C, d: D, e: E}
     invoke! {invoke6: a: A, b: B, c: C, d: D, e: E, f: F}
+    invoke! {invoke7: a: A, b: B, c: C, d: D, e: E, f: F, g: G}
 
     const fn new(invoke: *const c_void, f: &'a mut Closure) -> Self {
         Self {
@@ -448,6 +500,7 @@ impl<'a, Closure: 'a + Sized> Layout2Mut<'a, Closure> {
     invoke! {invoke4: a: A, b: B, c: C, d: D}
     invoke! {invoke5: a: A, b: B, c: C, d: D, e: E}
     invoke! {invoke6: a: A, b: B, c: C, d: D, e: E, f: F}
+    invoke! {invoke7: a: A, b: B, c: C, d: D, e: E, f: F, g: G}
 
     fn new(invoke: *const c_void, closure: Closure) -> &'a mut Self {
         let flags = if mem::needs_drop::<Closure>() {
@@ -499,17 +552,21 @@ impl<'a, Closure: 'a + Sized> Layout2Mut<'a, Closure> {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(
+    not(target_vendor = "apple"),
+    link(name = "BlocksRuntime", kind = "dylib")
+)]
 unsafe extern "C-unwind" {
     // static _NSConcreteGlobalBlock: objc::Class<ns::Id>;
-    static _NSConcreteStackBlock: objc::Class<ns::Id>;
-    static _NSConcreteMallocBlock: objc::Class<ns::Id>;
+    static _NSConcreteStackBlock: Isa;
+    static _NSConcreteMallocBlock: Isa;
 
     fn _Block_copy(block: *const c_void) -> *const c_void;
     fn _Block_release(block: *const c_void);
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "dispatch"))]
 mod tests {
 
     use crate::{blocks, dispatch};
@@ -620,7 +677,7 @@ pub fn retained1<R: arc::Retain + std::marker::Send>()
     )
 }
 
-#[cfg(feature = "async")]
+#[cfg(all(feature = "async", feature = "ns"))]
 pub fn ok<'a>() -> (Completion<Result<(), arc::R<ns::Error>>>, arc::R<ErrCh>) {
     let shared = Shared::new();
     (
@@ -634,7 +691,7 @@ pub fn ok<'a>() -> (Completion<Result<(), arc::R<ns::Error>>>, arc::R<ErrCh>) {
     )
 }
 
-#[cfg(feature = "async")]
+#[cfg(all(feature = "async", feature = "ns"))]
 pub fn result<T: arc::Retain + std::marker::Send>() -> (
     Completion<Result<arc::R<T>, arc::R<ns::Error>>>,
     arc::R<ResultCh<T>>,
@@ -652,3 +709,184 @@ pub fn result<T: arc::Retain + std::marker::Send>() -> (
         }),
     )
 }
+
+/// Blocks passed to and received from clang `-fblocks` code (`pomace/blocks/blocks.c`)
+#[cfg(all(test, not(target_vendor = "apple")))]
+mod abi_tests {
+    use std::{
+        ffi::c_void,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    #[cfg(feature = "async")]
+    use std::ffi::c_long;
+
+    use crate::{arc, blocks};
+
+    #[cfg(all(feature = "async", feature = "ns"))]
+    use crate::ns;
+
+    #[link(name = "cidre_blocks_tests", kind = "static")]
+    unsafe extern "C" {
+        fn cidre_blocks_call_i32(block: &mut blocks::NoEscBlock<fn(i32) -> i32>, a: i32) -> i32;
+        fn cidre_blocks_store(block: &mut blocks::EscBlock<fn()>);
+        fn cidre_blocks_call_stored();
+        fn cidre_blocks_release_stored();
+        fn cidre_blocks_adder(n: i32) -> arc::R<blocks::EscBlock<fn(i32) -> i32>>;
+        fn cidre_blocks_call_on_thread(block: &mut blocks::SendBlock<fn()>) -> i32;
+    }
+
+    #[cfg(feature = "async")]
+    #[link(name = "cidre_blocks_tests", kind = "static")]
+    unsafe extern "C" {
+        fn cidre_blocks_complete0(done: &mut blocks::CompletionBlock);
+        fn cidre_blocks_complete1(done: &mut blocks::SendBlock<fn(c_long)>, value: c_long);
+    }
+
+    #[cfg(all(feature = "async", feature = "ns"))]
+    #[link(name = "cidre_blocks_tests", kind = "static")]
+    unsafe extern "C" {
+        fn cidre_blocks_complete2(
+            done: &mut blocks::ResultCh<ns::String>,
+            value: Option<&ns::String>,
+            error: Option<&ns::Error>,
+        );
+    }
+
+    struct Tracker(Arc<AtomicUsize>);
+
+    impl Drop for Tracker {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    extern "C" fn double(_block: *const c_void, a: i32) -> i32 {
+        a * 2
+    }
+
+    #[test]
+    fn stack_and_static() {
+        let mut sum = 0;
+        let mut closure = |a: i32| {
+            sum += a;
+            a + 1
+        };
+        {
+            let mut block = unsafe { blocks::NoEscBlock::<fn(i32) -> i32>::stack1(&mut closure) };
+            assert_eq!(unsafe { cidre_blocks_call_i32(&mut block, 10) }, 11);
+            assert_eq!(unsafe { cidre_blocks_call_i32(&mut block, 20) }, 21);
+        }
+        assert_eq!(sum, 30);
+
+        let mut block = blocks::StaticBlock::<fn(i32) -> i32>::new1(double);
+        assert_eq!(
+            unsafe { cidre_blocks_call_i32(block.as_noesc_mut(), 21) },
+            42
+        );
+    }
+
+    #[test]
+    fn copy_dispose() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let calls = Arc::new(AtomicUsize::new(0));
+        {
+            let tracker = Tracker(drops.clone());
+            let c = calls.clone();
+            let mut block = blocks::EscBlock::<fn()>::new0(move || {
+                let _tracker = &tracker;
+                c.fetch_add(1, Ordering::SeqCst);
+            });
+            unsafe { cidre_blocks_store(&mut block) };
+        }
+        // C side still holds a copy
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        unsafe {
+            cidre_blocks_call_stored();
+            cidre_blocks_call_stored();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        unsafe { cidre_blocks_release_stored() };
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn clang_block() {
+        let mut adder = unsafe { cidre_blocks_adder(40) };
+        assert_eq!(adder.call(2), 42);
+
+        let mut copy = adder.retained();
+        drop(adder);
+        assert_eq!(copy.call(-40), 0);
+    }
+
+    #[test]
+    fn send_sync() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let c = calls.clone();
+        let mut block = blocks::SendBlock::<fn()>::new0(move || {
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(unsafe { cidre_blocks_call_on_thread(&mut block) }, 0);
+
+        let c = calls.clone();
+        let mut block = blocks::SyncBlock::<fn()>::new0(move || {
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(
+            unsafe { cidre_blocks_call_on_thread(block.as_send_mut()) },
+            0
+        );
+        assert_eq!(
+            unsafe { cidre_blocks_call_on_thread(block.as_send_mut()) },
+            0
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[cfg(feature = "async")]
+    fn ready<F: std::future::Future>(fut: F) -> Option<F::Output> {
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        match std::pin::pin!(fut).poll(&mut cx) {
+            std::task::Poll::Ready(res) => Some(res),
+            std::task::Poll::Pending => None,
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn completions() {
+        let (fut, mut block) = blocks::comp0();
+        unsafe { cidre_blocks_complete0(&mut block) };
+        assert_eq!(ready(fut), Some(()));
+
+        let (fut, mut block) = blocks::comp1::<c_long>();
+        unsafe { cidre_blocks_complete1(&mut block, 42) };
+        assert_eq!(ready(fut), Some(42));
+
+        let (fut, _block) = blocks::comp1::<c_long>();
+        assert_eq!(ready(fut), None);
+    }
+
+    #[cfg(all(feature = "async", feature = "ns"))]
+    #[test]
+    fn result() {
+        let (fut, mut block) = blocks::result::<ns::String>();
+        unsafe { cidre_blocks_complete2(&mut block, Some(&ns::String::with_str("done")), None) };
+        let value = ready(fut).unwrap().unwrap();
+        assert_eq!(value.to_string(), "done");
+
+        let (fut, mut block) = blocks::result::<ns::String>();
+        let error = ns::Error::with_posix(2, None);
+        unsafe { cidre_blocks_complete2(&mut block, None, Some(&error)) };
+        let error = ready(fut).unwrap().unwrap_err();
+        assert_eq!(error.code(), 2);
+    }
+}
@@ -66,7 +66,7 @@ pub use renderer::Renderer;
 mod transaction;
 pub use transaction::Transaction;
 
-#[link(name = "QuartzCore", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "QuartzCore", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "ca", kind = "static")]
@@ -30,11 +30,12 @@ impl MetalLayer {
     pub fn set_device(&mut self, val: Option<&mtl::Device>);
 
     /// throws when called from ca::MetalDisplayLink
-    #[objc::msg_send(nextDrawable)]
+    #[objc::msg_send(nextDrawable, throws)]
     pub unsafe fn next_drawable_throws(&self) -> Option<arc::R<AnyMetalDrawable>>;
 
+    #[deprecated(note = "use `try_next_drawable`")]
     pub fn next_drawable<'ear>(&self) -> ns::ExResult<'ear, Option<arc::R<AnyMetalDrawable>>> {
-        ns::try_catch(|| unsafe { self.next_drawable_throws() })
+        self.try_next_drawable().map_err(|e| &*e.autoreleased())
     }
 
     /// This property controls the pixel format of the [`mtl::Texture`] objects.
@@ -157,7 +158,7 @@ mod tests {
         let device = metal_layer.preferred_device().unwrap().retained();
         metal_layer.set_device(Some(&device));
         let drawable = metal_layer
-            .next_drawable()
+            .try_next_drawable()
             .expect("failed to get text")
             .unwrap();
         let texture = drawable.texture();
@@ -4,6 +4,12 @@ pub type UInteger = usize;
 mod base_types;
 pub use base_types::*;
 
+pub mod graph;
+pub mod hal_snapshot;
+pub mod kernel;
+pub mod meter;
+pub use meter::Meter;
+
 mod session_types;
 pub use session_types::ErrorCode as SessionErrorCode;
 pub use session_types::SessionId;
@@ -3,10 +3,13 @@ use std::{
     ptr::{slice_from_raw_parts, slice_from_raw_parts_mut},
 };
 
-use crate::{cf, define_opts, four_cc_to_str, objc::Obj, os};
+use crate::{define_opts, four_cc_to_str, os};
+
+#[cfg(feature = "cf")]
+use crate::cf;
 
 #[cfg(feature = "ns")]
-use crate::ns;
+use crate::{ns, objc::Obj};
 
 /// These are the error codes returned from the APIs found through Core Audio related frameworks.
 pub mod err {
@@ -453,24 +456,28 @@ impl Format {
     }
 }
 
+#[cfg(feature = "cf")]
 impl AsRef<cf::Number> for Format {
     fn as_ref(&self) -> &'static cf::Number {
         cf::Number::tagged_i32(self.0 as _)
     }
 }
 
+#[cfg(feature = "cf")]
 impl AsRef<cf::Type> for Format {
     fn as_ref(&self) -> &'static cf::Type {
         cf::Number::tagged_i32(self.0 as _).as_type_ref()
     }
 }
 
+#[cfg(feature = "ns")]
 impl AsRef<ns::Id> for Format {
     fn as_ref(&self) -> &'static ns::Id {
         self.to_ns_number().as_id_ref()
     }
 }
 
+#[cfg(feature = "ns")]
 impl AsRef<ns::Number> for Format {
     #[inline]
     fn as_ref(&self) -> &'static ns::Number {
@@ -1728,11 +1735,11 @@ impl Mpeg4Object {
 
 #[cfg(test)]
 mod tests {
-    use crate::at;
+    use crate::cat;
 
     #[test]
     fn basics() {
-        let asbd = at::audio::StreamBasicDesc::common_f32(44100.0, 2, false);
+        let asbd = cat::audio::StreamBasicDesc::common_f32(44100.0, 2, false);
         assert_eq!(asbd.interleaved_channels_num(), 1);
         assert!(!asbd.is_interleaved());
         assert!(asbd.is_common_f32());
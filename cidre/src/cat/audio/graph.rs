@@ -0,0 +1,884 @@
+use crate::{cat::audio, os};
+
+/// The error constants of the offline graph and kernels.
+///
+/// Values match `kAUGraphErr_*` and `kAudioUnitErr_*` so they read the same
+/// as AUGraph and audio unit failures.
+pub mod err {
+    use crate::os::Error;
+
+    #[doc(alias = "kAudioUnitErr_InvalidParameter")]
+    pub const INVALID_PARAM: Error = Error::new_unchecked(-10878);
+
+    #[doc(alias = "kAudioUnitErr_InvalidElement")]
+    pub const INVALID_ELEMENT: Error = Error::new_unchecked(-10877);
+
+    #[doc(alias = "kAudioUnitErr_NoConnection")]
+    pub const NO_CONNECTION: Error = Error::new_unchecked(-10876);
+
+    #[doc(alias = "kAudioUnitErr_TooManyFramesToProcess")]
+    pub const TOO_MANY_FRAMES_TO_PROCESS: Error = Error::new_unchecked(-10874);
+
+    #[doc(alias = "kAudioUnitErr_FormatNotSupported")]
+    pub const FORMAT_NOT_SUPPORTED: Error = Error::new_unchecked(-10868);
+
+    #[doc(alias = "kAudioUnitErr_Uninitialized")]
+    pub const UNINITIALIZED: Error = Error::new_unchecked(-10867);
+
+    #[doc(alias = "kAUGraphErr_NodeNotFound")]
+    pub const NODE_NOT_FOUND: Error = Error::new_unchecked(-10860);
+
+    /// Connection targets an input bus that is already connected or would introduce a cycle.
+    #[doc(alias = "kAUGraphErr_InvalidConnection")]
+    pub const INVALID_CONNECTION: Error = Error::new_unchecked(-10861);
+
+    /// Output bus is already connected, graph doesn't fan out.
+    pub const FAN_OUT: Error = Error::from_be_bytes(*b"fout");
+
+    /// Output node is not set.
+    #[doc(alias = "kAUGraphErr_OutputNodeErr")]
+    pub const OUTPUT_NODE_ERR: Error = Error::new_unchecked(-10862);
+
+    #[doc(alias = "kAUGraphErr_CannotDoInCurrentContext")]
+    pub const CANNOT_DO_IN_CURRENT_CONTEXT: Error = Error::new_unchecked(-10863);
+}
+
+#[doc(alias = "AUNode")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct NodeId(pub u32);
+
+/// Output bus `src_bus` of `src` feeds input bus `dst_bus` of `dst`.
+#[doc(alias = "AUNodeConnection")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Connection {
+    pub src: NodeId,
+    pub src_bus: u32,
+    pub dst: NodeId,
+    pub dst_bus: u32,
+}
+
+/// Pulls input bus of the node that is being rendered.
+///
+/// Arguments are `(input_bus, timestamp, frames, buf_list)`.
+pub type Pull<'a> = dyn FnMut(u32, &audio::TimeStamp, u32, &mut audio::BufList) -> os::Result + 'a;
+
+/// A node of the offline graph.
+///
+/// Implemented for audio units (`au::UnitNode`) and for Rust sources. Mock
+/// implementations let topology and negotiation be tested without AudioToolbox.
+pub trait Node {
+    fn input_buses(&self) -> u32;
+
+    fn output_buses(&self) -> u32 {
+        1
+    }
+
+    fn input_format(&self, bus: u32) -> os::Result<audio::StreamBasicDesc>;
+
+    fn set_input_format(&mut self, bus: u32, val: &audio::StreamBasicDesc) -> os::Result;
+
+    fn output_format(&self, bus: u32) -> os::Result<audio::StreamBasicDesc>;
+
+    /// Whether input formats may differ from output format (converters, mixers).
+    fn converts_format(&self) -> bool {
+        false
+    }
+
+    /// Called once topology is negotiated and before the first render.
+    fn prepare(&mut self, max_frames: u32) -> os::Result {
+        let _ = max_frames;
+        Ok(())
+    }
+
+    /// Renders `frames` of output `bus` into `buf_list`.
+    ///
+    /// Inputs must be requested through `pull`.
+    fn render(
+        &mut self,
+        ts: &audio::TimeStamp,
+        bus: u32,
+        frames: u32,
+        buf_list: &mut audio::BufList,
+        pull: &mut Pull,
+    ) -> os::Result;
+}
+
+/// Rust provided source node.
+pub struct Source<F>
+where
+    F: FnMut(&audio::TimeStamp, u32, &mut audio::BufList) -> os::Result,
+{
+    asbd: audio::StreamBasicDesc,
+    cb: F,
+}
+
+impl<F> Source<F>
+where
+    F: FnMut(&audio::TimeStamp, u32, &mut audio::BufList) -> os::Result,
+{
+    pub fn new(asbd: audio::StreamBasicDesc, cb: F) -> Self {
+        Self { asbd, cb }
+    }
+}
+
+impl<F> Node for Source<F>
+where
+    F: FnMut(&audio::TimeStamp, u32, &mut audio::BufList) -> os::Result,
+{
+    fn input_buses(&self) -> u32 {
+        0
+    }
+
+    fn input_format(&self, _bus: u32) -> os::Result<audio::StreamBasicDesc> {
+        Err(err::INVALID_ELEMENT)
+    }
+
+    fn set_input_format(&mut self, _bus: u32, _val: &audio::StreamBasicDesc) -> os::Result {
+        Err(err::INVALID_ELEMENT)
+    }
+
+    fn output_format(&self, bus: u32) -> os::Result<audio::StreamBasicDesc> {
+        if bus != 0 {
+            return Err(err::INVALID_ELEMENT);
+        }
+        Ok(self.asbd)
+    }
+
+    fn render(
+        &mut self,
+        ts: &audio::TimeStamp,
+        _bus: u32,
+        frames: u32,
+        buf_list: &mut audio::BufList,
+        _pull: &mut Pull,
+    ) -> os::Result {
+        (self.cb)(ts, frames, buf_list)
+    }
+}
+
+/// Buffers of the given list, honoring `number_buffers` rather than `N`.
+///
+/// # Safety
+///
+/// `buf_list` must point to storage with at least `number_buffers` buffers.
+pub unsafe fn bufs_mut(buf_list: &mut audio::BufList) -> &mut [audio::Buf] {
+    unsafe {
+        std::slice::from_raw_parts_mut(
+            buf_list.buffers.as_mut_ptr(),
+            buf_list.number_buffers as usize,
+        )
+    }
+}
+
+/// Number of buffers in a list for the given format.
+#[inline]
+pub fn bufs_count(asbd: &audio::StreamBasicDesc) -> u32 {
+    if asbd.is_interleaved() {
+        1
+    } else {
+        asbd.channels_per_frame
+    }
+}
+
+/// Owned storage of a variable length `BufList`.
+struct Scratch {
+    list: Vec<u64>,
+    data: Vec<Vec<u64>>,
+    bytes_per_frame: u32,
+}
+
+impl Scratch {
+    fn new(asbd: &audio::StreamBasicDesc, max_frames: u32) -> Self {
+        let n = bufs_count(asbd).max(1) as usize;
+        let list_size =
+            std::mem::size_of::<audio::BufList<1>>() + (n - 1) * std::mem::size_of::<audio::Buf>();
+        let buf_size = max_frames as usize * asbd.bytes_per_frame as usize;
+        let mut res = Self {
+            list: vec![0; list_size.div_ceil(8)],
+            data: (0..n).map(|_| vec![0; buf_size.div_ceil(8)]).collect(),
+            bytes_per_frame: asbd.bytes_per_frame,
+        };
+        let list = res.buf_list_mut();
+        list.number_buffers = n as _;
+        let number_channels = asbd.interleaved_channels_num();
+        for buf in unsafe { bufs_mut(list) } {
+            buf.number_channels = number_channels;
+        }
+        res
+    }
+
+    fn buf_list_mut(&mut self) -> &mut audio::BufList {
+        unsafe { &mut *(self.list.as_mut_ptr() as *mut audio::BufList) }
+    }
+
+    /// Points missing buffers of `buf_list` to scratch memory.
+    ///
+    /// Converters may ask for more frames than max frames per slice,
+    /// so storage grows on demand.
+    fn fill(&mut self, buf_list: &mut audio::BufList, frames: u32) {
+        let size = frames * self.bytes_per_frame;
+        let bufs = unsafe { bufs_mut(buf_list) };
+        for (buf, data) in bufs.iter_mut().zip(self.data.iter_mut()) {
+            if buf.data.is_null() {
+                let len = (size as usize).div_ceil(8);
+                if data.len() < len {
+                    data.resize(len, 0);
+                }
+                buf.data = data.as_mut_ptr() as *mut u8;
+            }
+            buf.data_bytes_size = size;
+        }
+    }
+
+    /// Resets scratch list and returns it ready for render.
+    fn prepared(&mut self, frames: u32) -> &mut audio::BufList {
+        let size = frames * self.bytes_per_frame;
+        let ptrs: Vec<*mut u8> = self
+            .data
+            .iter_mut()
+            .map(|d| d.as_mut_ptr() as *mut u8)
+            .collect();
+        let list = self.buf_list_mut();
+        for (buf, ptr) in unsafe { bufs_mut(list) }.iter_mut().zip(ptrs) {
+            buf.data = ptr;
+            buf.data_bytes_size = size;
+        }
+        list
+    }
+}
+
+/// Pull-model offline render graph.
+///
+/// Unlike AUGraph it is not tied to an output device: render is driven
+/// by the caller with deterministic sample times starting at zero.
+pub struct Graph<'a> {
+    nodes: Vec<Option<Box<dyn Node + 'a>>>,
+    conns: Vec<Connection>,
+    scratch: Vec<Scratch>,
+    output: Option<NodeId>,
+    max_frames: u32,
+    sample_time: f64,
+    prepared: bool,
+}
+
+impl Default for Graph<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Graph<'a> {
+    pub const DEFAULT_MAX_FRAMES: u32 = 1024;
+
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            conns: Vec::new(),
+            scratch: Vec::new(),
+            output: None,
+            max_frames: Self::DEFAULT_MAX_FRAMES,
+            sample_time: 0.0,
+            prepared: false,
+        }
+    }
+
+    #[doc(alias = "AUGraphAddNode")]
+    pub fn add_node<N: Node + 'a>(&mut self, node: N) -> NodeId {
+        self.prepared = false;
+        self.nodes.push(Some(Box::new(node)));
+        NodeId(self.nodes.len() as u32 - 1)
+    }
+
+    pub fn node(&self, id: NodeId) -> os::Result<&(dyn Node + 'a)> {
+        match self.nodes.get(id.0 as usize) {
+            Some(Some(node)) => Ok(node.as_ref()),
+            Some(None) => Err(err::CANNOT_DO_IN_CURRENT_CONTEXT),
+            None => Err(err::NODE_NOT_FOUND),
+        }
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> os::Result<&mut (dyn Node + 'a)> {
+        match self.nodes.get_mut(id.0 as usize) {
+            Some(Some(node)) => Ok(node.as_mut()),
+            Some(None) => Err(err::CANNOT_DO_IN_CURRENT_CONTEXT),
+            None => Err(err::NODE_NOT_FOUND),
+        }
+    }
+
+    #[inline]
+    pub fn nodes_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn connections(&self) -> &[Connection] {
+        &self.conns
+    }
+
+    #[inline]
+    pub fn output(&self) -> Option<NodeId> {
+        self.output
+    }
+
+    pub fn set_output(&mut self, node: NodeId) -> os::Result {
+        let n = self.node(node)?;
+        if n.output_buses() == 0 {
+            return Err(err::INVALID_ELEMENT);
+        }
+        self.prepared = false;
+        self.output = Some(node);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn max_frames_per_slice(&self) -> u32 {
+        self.max_frames
+    }
+
+    pub fn set_max_frames_per_slice(&mut self, val: u32) {
+        self.prepared = false;
+        self.max_frames = val;
+    }
+
+    /// Sample time of the next render cycle.
+    #[inline]
+    pub fn sample_time(&self) -> f64 {
+        self.sample_time
+    }
+
+    #[inline]
+    pub fn set_sample_time(&mut self, val: f64) {
+        self.sample_time = val;
+    }
+
+    #[inline]
+    pub fn is_prepared(&self) -> bool {
+        self.prepared
+    }
+
+    /// Connects output `src_bus` of `src` to input `dst_bus` of `dst`.
+    ///
+    /// Every input bus has one source and every output bus feeds one input:
+    /// sources are rendered on each pull, so a shared output would render twice
+    /// per cycle. Fan-out fails with [`err::FAN_OUT`], render the source into
+    /// a node with several outputs instead.
+    ///
+    /// Self-connection, taken input bus and cycles fail with [`err::INVALID_CONNECTION`].
+    #[doc(alias = "AUGraphConnectNodeInput")]
+    pub fn connect(&mut self, src: NodeId, src_bus: u32, dst: NodeId, dst_bus: u32) -> os::Result {
+        if src_bus >= self.node(src)?.output_buses() || dst_bus >= self.node(dst)?.input_buses() {
+            return Err(err::INVALID_ELEMENT);
+        }
+        if src == dst {
+            return Err(err::INVALID_CONNECTION);
+        }
+        if self
+            .conns
+            .iter()
+            .any(|c| c.src == src && c.src_bus == src_bus)
+        {
+            return Err(err::FAN_OUT);
+        }
+        let taken = self
+            .conns
+            .iter()
+            .any(|c| c.dst == dst && c.dst_bus == dst_bus);
+        if taken || self.reaches(dst, src) {
+            return Err(err::INVALID_CONNECTION);
+        }
+        self.prepared = false;
+        self.conns.push(Connection {
+            src,
+            src_bus,
+            dst,
+            dst_bus,
+        });
+        Ok(())
+    }
+
+    #[doc(alias = "AUGraphDisconnectNodeInput")]
+    pub fn disconnect(&mut self, dst: NodeId, dst_bus: u32) -> os::Result {
+        let Some(pos) = self
+            .conns
+            .iter()
+            .position(|c| c.dst == dst && c.dst_bus == dst_bus)
+        else {
+            return Err(err::NO_CONNECTION);
+        };
+        self.prepared = false;
+        self.conns.remove(pos);
+        Ok(())
+    }
+
+    /// Whether `to` is downstream of `from`.
+    fn reaches(&self, from: NodeId, to: NodeId) -> bool {
+        let mut stack = vec![from];
+        let mut seen = vec![false; self.nodes.len()];
+        while let Some(n) = stack.pop() {
+            if n == to {
+                return true;
+            }
+            if std::mem::replace(&mut seen[n.0 as usize], true) {
+                continue;
+            }
+            stack.extend(self.conns.iter().filter(|c| c.src == n).map(|c| c.dst));
+        }
+        false
+    }
+
+    /// Nodes ordered so that every source precedes its destinations.
+    ///
+    /// Ties are resolved by node id, so order is stable across runs.
+    pub fn render_order(&self) -> os::Result<Vec<NodeId>> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for c in &self.conns {
+            in_degree[c.dst.0 as usize] += 1;
+        }
+        let mut ready: Vec<NodeId> = (0..self.nodes.len() as u32)
+            .rev()
+            .map(NodeId)
+            .filter(|n| in_degree[n.0 as usize] == 0)
+            .collect();
+        let mut res = Vec::with_capacity(self.nodes.len());
+        while let Some(n) = ready.pop() {
+            res.push(n);
+            let mut next: Vec<NodeId> = Vec::new();
+            for c in self.conns.iter().filter(|c| c.src == n) {
+                let d = &mut in_degree[c.dst.0 as usize];
+                *d -= 1;
+                if *d == 0 {
+                    next.push(c.dst);
+                }
+            }
+            ready.extend(next);
+            ready.sort_by(|a, b| b.cmp(a));
+        }
+        if res.len() != self.nodes.len() {
+            return Err(err::INVALID_CONNECTION);
+        }
+        Ok(res)
+    }
+
+    /// Propagates output formats of sources to inputs of their destinations.
+    ///
+    /// Nodes that don't convert formats must keep sample rate and channel count
+    /// of their first output equal to their inputs.
+    pub fn negotiate(&mut self) -> os::Result {
+        for id in self.render_order()? {
+            let conns: Vec<Connection> =
+                self.conns.iter().filter(|c| c.dst == id).copied().collect();
+            for c in conns {
+                let src_asbd = self.node(c.src)?.output_format(c.src_bus)?;
+                let dst = self.node_mut(c.dst)?;
+                if dst.input_format(c.dst_bus).ok() != Some(src_asbd) {
+                    dst.set_input_format(c.dst_bus, &src_asbd)?;
+                }
+                if !dst.converts_format() && dst.output_buses() > 0 {
+                    let out = dst.output_format(0)?;
+                    if out.sample_rate != src_asbd.sample_rate
+                        || out.channels_per_frame != src_asbd.channels_per_frame
+                    {
+                        return Err(err::FORMAT_NOT_SUPPORTED);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Negotiates formats, allocates buffers and prepares every node.
+    #[doc(alias = "AUGraphInitialize")]
+    pub fn prepare(&mut self) -> os::Result {
+        let Some(output) = self.output else {
+            return Err(err::OUTPUT_NODE_ERR);
+        };
+        self.negotiate()?;
+        let mut scratch = Vec::with_capacity(self.conns.len() + 1);
+        for c in &self.conns {
+            let asbd = self.node(c.src)?.output_format(c.src_bus)?;
+            scratch.push(Scratch::new(&asbd, self.max_frames));
+        }
+        let asbd = self.node(output)?.output_format(0)?;
+        scratch.push(Scratch::new(&asbd, self.max_frames));
+        self.scratch = scratch;
+
+        let max_frames = self.max_frames;
+        for node in self.nodes.iter_mut().flatten() {
+            node.prepare(max_frames)?;
+        }
+        self.sample_time = 0.0;
+        self.prepared = true;
+        Ok(())
+    }
+
+    /// Renders next `frames` of the output node into `buf_list`.
+    ///
+    /// Buffers with null `data` are pointed to graph owned memory that
+    /// stays valid until the next render.
+    ///
+    /// Fails with [`err::FORMAT_NOT_SUPPORTED`] if `number_buffers`
+    /// doesn't match output format of the graph.
+    ///
+    /// # Panics
+    ///
+    /// If `number_buffers` is greater than `N`.
+    pub fn render<const N: usize>(
+        &mut self,
+        frames: u32,
+        buf_list: &mut audio::BufList<N>,
+    ) -> os::Result {
+        assert!(
+            buf_list.number_buffers as usize <= N,
+            "number_buffers {} is greater than storage {N}",
+            buf_list.number_buffers
+        );
+        if let Some(output) = self.scratch.last_mut()
+            && output.buf_list_mut().number_buffers != buf_list.number_buffers
+        {
+            return Err(err::FORMAT_NOT_SUPPORTED);
+        }
+        let list = unsafe { &mut *(buf_list as *mut audio::BufList<N> as *mut audio::BufList) };
+        self.render_list(frames, list)
+    }
+
+    fn render_list(&mut self, frames: u32, buf_list: &mut audio::BufList) -> os::Result {
+        if !self.prepared {
+            return Err(err::UNINITIALIZED);
+        }
+        if frames > self.max_frames {
+            return Err(err::TOO_MANY_FRAMES_TO_PROCESS);
+        }
+        let Some(output) = self.output else {
+            return Err(err::OUTPUT_NODE_ERR);
+        };
+        let ts = audio::TimeStamp::with_sample_time(self.sample_time);
+        if let Some(scratch) = self.scratch.last_mut() {
+            scratch.fill(buf_list, frames);
+        }
+        render_node(
+            &mut self.nodes,
+            &self.conns,
+            &mut self.scratch,
+            output,
+            &ts,
+            0,
+            frames,
+            buf_list,
+        )?;
+        self.sample_time += frames as f64;
+        Ok(())
+    }
+
+    /// Renders `frames` in slices of at most `max_frames_per_slice`,
+    /// passing each slice to `f` together with its sample time.
+    pub fn render_slices<F>(&mut self, mut frames: u64, mut f: F) -> os::Result
+    where
+        F: FnMut(f64, u32, &mut audio::BufList) -> os::Result,
+    {
+        let Some(output) = self.output else {
+            return Err(err::OUTPUT_NODE_ERR);
+        };
+        let asbd = self.node(output)?.output_format(0)?;
+        let mut out = Scratch::new(&asbd, self.max_frames);
+        while frames > 0 {
+            let n = frames.min(self.max_frames as u64) as u32;
+            let sample_time = self.sample_time;
+            let list = out.prepared(n);
+            self.render_list(n, list)?;
+            f(sample_time, n, list)?;
+            frames -= n as u64;
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_node(
+    nodes: &mut [Option<Box<dyn Node + '_>>],
+    conns: &[Connection],
+    scratch: &mut [Scratch],
+    id: NodeId,
+    ts: &audio::TimeStamp,
+    bus: u32,
+    frames: u32,
+    buf_list: &mut audio::BufList,
+) -> os::Result {
+    let Some(mut node) = nodes[id.0 as usize].take() else {
+        return Err(err::CANNOT_DO_IN_CURRENT_CONTEXT);
+    };
+    let mut pull = |dst_bus: u32, ts: &audio::TimeStamp, frames: u32, list: &mut audio::BufList| {
+        let Some(pos) = conns
+            .iter()
+            .position(|c| c.dst == id && c.dst_bus == dst_bus)
+        else {
+            return Err(err::NO_CONNECTION);
+        };
+        scratch[pos].fill(list, frames);
+        let c = conns[pos];
+        render_node(nodes, conns, scratch, c.src, ts, c.src_bus, frames, list)
+    };
+    let res = node.render(ts, bus, frames, buf_list, &mut pull);
+    nodes[id.0 as usize] = Some(node);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{cat::audio, os};
+
+    use super::{Graph, Node, NodeId, Pull, Source, bufs_mut, err};
+
+    fn samples(buf_list: &mut audio::BufList) -> Vec<&mut [f32]> {
+        unsafe { bufs_mut(buf_list) }
+            .iter_mut()
+            .map(|b| unsafe {
+                std::slice::from_raw_parts_mut(b.data as *mut f32, b.data_bytes_size as usize / 4)
+            })
+            .collect()
+    }
+
+    /// Single input node with format of the input following the output.
+    struct Gain {
+        asbd: audio::StreamBasicDesc,
+        gain: f32,
+        inputs: u32,
+        converts: bool,
+    }
+
+    impl Gain {
+        fn new(gain: f32) -> Self {
+            Self {
+                asbd: audio::StreamBasicDesc::common_f32(48_000.0, 2, false),
+                gain,
+                inputs: 1,
+                converts: false,
+            }
+        }
+
+        fn mixer(inputs: u32) -> Self {
+            Self {
+                inputs,
+                converts: true,
+                ..Self::new(1.0)
+            }
+        }
+    }
+
+    impl Node for Gain {
+        fn input_buses(&self) -> u32 {
+            self.inputs
+        }
+
+        fn input_format(&self, _bus: u32) -> os::Result<audio::StreamBasicDesc> {
+            Ok(self.asbd)
+        }
+
+        fn set_input_format(&mut self, _bus: u32, val: &audio::StreamBasicDesc) -> os::Result {
+            if !val.is_common_f32() {
+                return Err(err::FORMAT_NOT_SUPPORTED);
+            }
+            if !self.converts {
+                self.asbd = *val;
+            }
+            Ok(())
+        }
+
+        fn output_format(&self, _bus: u32) -> os::Result<audio::StreamBasicDesc> {
+            Ok(self.asbd)
+        }
+
+        fn converts_format(&self) -> bool {
+            self.converts
+        }
+
+        fn render(
+            &mut self,
+            ts: &audio::TimeStamp,
+            _bus: u32,
+            frames: u32,
+            buf_list: &mut audio::BufList,
+            pull: &mut Pull,
+        ) -> os::Result {
+            for ch in samples(buf_list) {
+                ch.fill(0.0);
+            }
+            for bus in 0..self.inputs {
+                let mut input = audio::BufList::<2>::default();
+                let list = unsafe { &mut *(&mut input as *mut _ as *mut audio::BufList) };
+                pull(bus, ts, frames, list)?;
+                for (o, i) in samples(buf_list).into_iter().zip(samples(list)) {
+                    for (o, i) in o.iter_mut().zip(i.iter()) {
+                        *o += i * self.gain;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn ramp(times: Rc<RefCell<Vec<f64>>>) -> impl Node {
+        let asbd = audio::StreamBasicDesc::common_f32(48_000.0, 2, false);
+        Source::new(asbd, move |ts, _frames, buf_list| {
+            times.borrow_mut().push(ts.sample_time);
+            for ch in samples(buf_list) {
+                for (i, s) in ch.iter_mut().enumerate() {
+                    *s = (ts.sample_time as f32) + i as f32;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn basics() {
+        let times = Rc::new(RefCell::new(Vec::new()));
+        let mut graph = Graph::new();
+        graph.set_max_frames_per_slice(512);
+        let src = graph.add_node(ramp(times.clone()));
+        let gain = graph.add_node(Gain::new(2.0));
+        graph.connect(src, 0, gain, 0).unwrap();
+
+        let err = graph.prepare().expect_err("no output");
+        assert_eq!(err, err::OUTPUT_NODE_ERR);
+
+        graph.set_output(gain).unwrap();
+        graph.prepare().unwrap();
+
+        let mut buf_list = audio::BufList::<2>::default();
+        graph.render(512, &mut buf_list).unwrap();
+        let list = unsafe { &mut *(&mut buf_list as *mut _ as *mut audio::BufList) };
+        let chans = samples(list);
+        assert_eq!(chans.len(), 2);
+        assert_eq!(chans[0][0], 0.0);
+        assert_eq!(chans[1][3], 6.0);
+
+        graph.render(256, &mut buf_list).unwrap();
+        let list = unsafe { &mut *(&mut buf_list as *mut _ as *mut audio::BufList) };
+        assert_eq!(samples(list)[0][1], 2.0 * 513.0);
+
+        let err = graph
+            .render(1024, &mut buf_list)
+            .expect_err("too many frames");
+        assert_eq!(err, err::TOO_MANY_FRAMES_TO_PROCESS);
+
+        let mut mono = audio::BufList::<1>::default();
+        let err = graph.render(512, &mut mono).expect_err("format mismatch");
+        assert_eq!(err, err::FORMAT_NOT_SUPPORTED);
+
+        let mut slices = Vec::new();
+        graph
+            .render_slices(1100, |st, n, _| {
+                slices.push((st, n));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(slices, [(768.0, 512), (1280.0, 512), (1792.0, 76)]);
+        assert_eq!(graph.sample_time(), 1868.0);
+        assert_eq!(&times.borrow()[..], &[0.0, 512.0, 768.0, 1280.0, 1792.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than storage")]
+    fn render_overflow() {
+        let mut graph = Graph::new();
+        let gain = graph.add_node(Gain::new(1.0));
+        graph.set_output(gain).unwrap();
+        let mut buf_list = audio::BufList::<1> {
+            number_buffers: 2,
+            ..Default::default()
+        };
+        let _ = graph.render(16, &mut buf_list);
+    }
+
+    #[test]
+    fn topology() {
+        let times = Rc::new(RefCell::new(Vec::new()));
+        let mut graph = Graph::new();
+        let a = graph.add_node(ramp(times.clone()));
+        let b = graph.add_node(ramp(times.clone()));
+        let mixer = graph.add_node(Gain::mixer(2));
+        let fx1 = graph.add_node(Gain::new(1.0));
+        let fx2 = graph.add_node(Gain::new(1.0));
+
+        graph.connect(fx1, 0, fx2, 0).unwrap();
+        graph.connect(fx2, 0, mixer, 1).unwrap();
+        graph.connect(a, 0, fx1, 0).unwrap();
+        graph.connect(b, 0, mixer, 0).unwrap();
+
+        assert_eq!(
+            graph.connect(mixer, 0, fx1, 0).unwrap_err(),
+            err::INVALID_CONNECTION,
+            "input bus is already taken"
+        );
+        graph.disconnect(fx1, 0).unwrap();
+        assert_eq!(
+            graph.connect(mixer, 0, fx1, 0).unwrap_err(),
+            err::INVALID_CONNECTION,
+            "cycle"
+        );
+        assert_eq!(
+            graph.connect(fx1, 0, fx1, 0).unwrap_err(),
+            err::INVALID_CONNECTION,
+        );
+        assert_eq!(
+            graph.connect(a, 0, mixer, 2).unwrap_err(),
+            err::INVALID_ELEMENT
+        );
+        assert_eq!(
+            graph.connect(a, 0, NodeId(42), 0).unwrap_err(),
+            err::NODE_NOT_FOUND
+        );
+        graph.connect(a, 0, fx1, 0).unwrap();
+        assert_eq!(
+            graph.connect(a, 0, fx2, 0).unwrap_err(),
+            err::FAN_OUT,
+            "output bus is already connected"
+        );
+
+        let order = graph.render_order().unwrap();
+        let pos = |n| order.iter().position(|x| *x == n).unwrap();
+        assert!(pos(a) < pos(fx1));
+        assert!(pos(fx1) < pos(fx2));
+        assert!(pos(fx2) < pos(mixer));
+        assert!(pos(b) < pos(mixer));
+
+        graph.set_output(mixer).unwrap();
+        graph.prepare().unwrap();
+
+        let mut slices = Vec::new();
+        graph
+            .render_slices(4, |_, _, list| {
+                slices.extend_from_slice(samples(list)[0]);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(slices, [0.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn negotiation() {
+        let mut graph = Graph::new();
+        let asbd = audio::StreamBasicDesc::common_f32(44_100.0, 1, false);
+        let src = graph.add_node(Source::new(asbd, |_, _, _| Ok(())));
+        let fx = graph.add_node(Gain::new(1.0));
+        graph.connect(src, 0, fx, 0).unwrap();
+        graph.set_output(fx).unwrap();
+        graph.prepare().unwrap();
+
+        let fx_asbd = graph.node(fx).unwrap().output_format(0);
+        assert_eq!(fx_asbd.unwrap(), asbd);
+
+        let mut graph = Graph::new();
+        let asbd = audio::StreamBasicDesc::common_f32(44_100.0, 2, true);
+        let src = graph.add_node(Source::new(asbd, |_, _, _| Ok(())));
+        let mixer = graph.add_node(Gain::mixer(1));
+        graph.connect(src, 0, mixer, 0).unwrap();
+        graph.set_output(mixer).unwrap();
+        let err = graph.prepare().expect_err("interleaved is not supported");
+        assert_eq!(err, err::FORMAT_NOT_SUPPORTED);
+        assert!(!graph.is_prepared());
+    }
+}
@@ -0,0 +1,617 @@
+//! Snapshots of HAL object tree and structural diff between them.
+//!
+//! Snapshot types are plain data, so they can be cloned, compared and
+//! (with `serde` feature) recorded to disk and replayed in tests without
+//! any audio hardware. Capturing and monitoring live in `core_audio::snapshot`.
+
+use crate::cat::audio::{Format as AudioFormat, FormatFlags, StreamBasicDesc};
+
+/// Plain copy of [`StreamBasicDesc`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Format {
+    pub sample_rate: f64,
+    pub format: u32,
+    pub format_flags: u32,
+    pub bytes_per_packet: u32,
+    pub frames_per_packet: u32,
+    pub bytes_per_frame: u32,
+    pub channels_per_frame: u32,
+    pub bits_per_channel: u32,
+}
+
+impl From<&StreamBasicDesc> for Format {
+    fn from(val: &StreamBasicDesc) -> Self {
+        Self {
+            sample_rate: val.sample_rate,
+            format: val.format.0,
+            format_flags: val.format_flags.0,
+            bytes_per_packet: val.bytes_per_packet,
+            frames_per_packet: val.frames_per_packet,
+            bytes_per_frame: val.bytes_per_frame,
+            channels_per_frame: val.channels_per_frame,
+            bits_per_channel: val.bits_per_channel,
+        }
+    }
+}
+
+impl From<&Format> for StreamBasicDesc {
+    fn from(val: &Format) -> Self {
+        Self {
+            sample_rate: val.sample_rate,
+            format: AudioFormat(val.format),
+            format_flags: FormatFlags(val.format_flags),
+            bytes_per_packet: val.bytes_per_packet,
+            frames_per_packet: val.frames_per_packet,
+            bytes_per_frame: val.bytes_per_frame,
+            channels_per_frame: val.channels_per_frame,
+            bits_per_channel: val.bits_per_channel,
+            reserved: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct System {
+    /// Uid of default input device.
+    pub default_input_device: Option<String>,
+    /// Uid of default output device.
+    pub default_output_device: Option<String>,
+    /// Uid of default system output device.
+    pub default_sys_output_device: Option<String>,
+    pub devices: Vec<Device>,
+    pub clocks: Vec<Clock>,
+    pub processes: Vec<Process>,
+    pub taps: Vec<Tap>,
+}
+
+impl System {
+    pub fn device(&self, uid: &str) -> Option<&Device> {
+        self.devices.iter().find(|d| d.uid == uid)
+    }
+
+    /// Ids of all objects in the snapshot, including the system object.
+    pub fn obj_ids(&self) -> Vec<u32> {
+        // kAudioObjectSystemObject
+        let mut res = vec![1];
+        for d in self.devices.iter() {
+            res.push(d.id);
+            res.extend(d.streams.iter().map(|s| s.id));
+            res.extend(d.controls.iter().map(|c| c.id));
+        }
+        res.extend(self.clocks.iter().map(|c| c.id));
+        res.extend(self.processes.iter().map(|p| p.id));
+        res.extend(self.taps.iter().map(|t| t.id));
+        res
+    }
+
+    /// Changes required to turn `self` into `new`.
+    pub fn diff(&self, new: &Self) -> Vec<Change> {
+        diff(self, new)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Device {
+    pub id: u32,
+    pub uid: String,
+    pub name: Option<String>,
+    pub manufacturer: Option<String>,
+    /// Four char code of `ca::DeviceTransportType`.
+    pub transport_type: u32,
+    pub nominal_sample_rate: f64,
+    pub is_alive: bool,
+    pub is_running: bool,
+    pub streams: Vec<Stream>,
+    pub controls: Vec<Control>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stream {
+    pub id: u32,
+    pub is_input: bool,
+    pub is_active: bool,
+    pub virtual_format: Format,
+    pub physical_format: Format,
+    pub latency: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ControlValue {
+    Level {
+        scalar: f32,
+        db: f32,
+    },
+    Bool(bool),
+    Selector(Vec<u32>),
+    /// Control without value we know how to read.
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Control {
+    pub id: u32,
+    /// Four char code of `ca::Class`.
+    pub class: u32,
+    /// Four char code of `ca::PropScope`.
+    pub scope: u32,
+    pub element: u32,
+    pub value: ControlValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clock {
+    pub id: u32,
+    pub uid: String,
+    pub name: Option<String>,
+    pub nominal_sample_rate: f64,
+    pub is_alive: bool,
+    pub is_running: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Process {
+    pub id: u32,
+    pub pid: i32,
+    pub bundle_id: Option<String>,
+    pub is_running: bool,
+    pub is_running_input: bool,
+    pub is_running_output: bool,
+    /// Uids of devices the process is doing io with.
+    pub devices: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tap {
+    pub id: u32,
+    pub uid: String,
+    pub format: Option<Format>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Delta<T> {
+    pub old: T,
+    pub new: T,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Change {
+    DefaultInputDevice(Delta<Option<String>>),
+    DefaultOutputDevice(Delta<Option<String>>),
+    DefaultSysOutputDevice(Delta<Option<String>>),
+    DeviceAdded(Device),
+    DeviceRemoved(Device),
+    Device { uid: String, change: DeviceChange },
+    ClockAdded(Clock),
+    ClockRemoved(Clock),
+    Clock { uid: String, change: ClockChange },
+    ProcessAdded(Process),
+    ProcessRemoved(Process),
+    Process { pid: i32, change: ProcessChange },
+    TapAdded(Tap),
+    TapRemoved(Tap),
+    Tap { uid: String, change: TapChange },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeviceChange {
+    Id(Delta<u32>),
+    Name(Delta<Option<String>>),
+    Manufacturer(Delta<Option<String>>),
+    TransportType(Delta<u32>),
+    NominalSampleRate(Delta<f64>),
+    IsAlive(Delta<bool>),
+    IsRunning(Delta<bool>),
+    StreamAdded(Stream),
+    StreamRemoved(Stream),
+    Stream { id: u32, change: StreamChange },
+    ControlAdded(Control),
+    ControlRemoved(Control),
+    ControlValue { id: u32, value: Delta<ControlValue> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StreamChange {
+    IsActive(Delta<bool>),
+    VirtualFormat(Delta<Format>),
+    PhysicalFormat(Delta<Format>),
+    Latency(Delta<u32>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClockChange {
+    Id(Delta<u32>),
+    Name(Delta<Option<String>>),
+    NominalSampleRate(Delta<f64>),
+    IsAlive(Delta<bool>),
+    IsRunning(Delta<bool>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProcessChange {
+    Id(Delta<u32>),
+    BundleId(Delta<Option<String>>),
+    IsRunning(Delta<bool>),
+    IsRunningInput(Delta<bool>),
+    IsRunningOutput(Delta<bool>),
+    Devices(Delta<Vec<String>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TapChange {
+    Id(Delta<u32>),
+    Format(Delta<Option<Format>>),
+}
+
+macro_rules! fields {
+    ($out:ident, $old:ident, $new:ident, $($field:ident => $variant:path),* $(,)?) => {
+        $(
+            if $old.$field != $new.$field {
+                $out.push($variant(Delta {
+                    old: $old.$field.clone(),
+                    new: $new.$field.clone(),
+                }));
+            }
+        )*
+    };
+}
+
+enum Pair<'a, T> {
+    Removed(&'a T),
+    Added(&'a T),
+    Both(&'a T, &'a T),
+}
+
+/// Matches items of two lists by key. Removed items go first in old order,
+/// then added and matched items in new order.
+fn pairs<'a, T, K: PartialEq>(
+    old: &'a [T],
+    new: &'a [T],
+    key: impl Fn(&T) -> K,
+) -> impl Iterator<Item = Pair<'a, T>> {
+    let removed = old
+        .iter()
+        .filter(|o| !new.iter().any(|n| key(n) == key(o)))
+        .map(Pair::Removed)
+        .collect::<Vec<_>>();
+    let rest = new
+        .iter()
+        .map(|n| match old.iter().find(|o| key(o) == key(n)) {
+            Some(o) => Pair::Both(o, n),
+            None => Pair::Added(n),
+        })
+        .collect::<Vec<_>>();
+    removed.into_iter().chain(rest)
+}
+
+fn diff_device(old: &Device, new: &Device) -> Vec<DeviceChange> {
+    let mut res = Vec::new();
+    fields!(res, old, new,
+        id => DeviceChange::Id,
+        name => DeviceChange::Name,
+        manufacturer => DeviceChange::Manufacturer,
+        transport_type => DeviceChange::TransportType,
+        nominal_sample_rate => DeviceChange::NominalSampleRate,
+        is_alive => DeviceChange::IsAlive,
+        is_running => DeviceChange::IsRunning,
+    );
+
+    for pair in pairs(&old.streams, &new.streams, |s| s.id) {
+        match pair {
+            Pair::Removed(s) => res.push(DeviceChange::StreamRemoved(s.clone())),
+            Pair::Added(s) => res.push(DeviceChange::StreamAdded(s.clone())),
+            Pair::Both(o, n) => {
+                let mut changes = Vec::new();
+                fields!(changes, o, n,
+                    is_active => StreamChange::IsActive,
+                    virtual_format => StreamChange::VirtualFormat,
+                    physical_format => StreamChange::PhysicalFormat,
+                    latency => StreamChange::Latency,
+                );
+                res.extend(
+                    changes
+                        .into_iter()
+                        .map(|change| DeviceChange::Stream { id: n.id, change }),
+                );
+            }
+        }
+    }
+
+    for pair in pairs(&old.controls, &new.controls, |c| c.id) {
+        match pair {
+            Pair::Removed(c) => res.push(DeviceChange::ControlRemoved(c.clone())),
+            Pair::Added(c) => res.push(DeviceChange::ControlAdded(c.clone())),
+            Pair::Both(o, n) if o.value != n.value => res.push(DeviceChange::ControlValue {
+                id: n.id,
+                value: Delta {
+                    old: o.value.clone(),
+                    new: n.value.clone(),
+                },
+            }),
+            Pair::Both(..) => {}
+        }
+    }
+
+    res
+}
+
+/// Typed changes required to turn `old` snapshot into `new` one.
+///
+/// Devices, clocks and taps are matched by uid, since ids of objects may be
+/// reused by the HAL. Processes are matched by pid, streams and controls
+/// by id within their device.
+pub fn diff(old: &System, new: &System) -> Vec<Change> {
+    let mut res = Vec::new();
+    fields!(res, old, new,
+        default_input_device => Change::DefaultInputDevice,
+        default_output_device => Change::DefaultOutputDevice,
+        default_sys_output_device => Change::DefaultSysOutputDevice,
+    );
+
+    for pair in pairs(&old.devices, &new.devices, |d| d.uid.clone()) {
+        match pair {
+            Pair::Removed(d) => res.push(Change::DeviceRemoved(d.clone())),
+            Pair::Added(d) => res.push(Change::DeviceAdded(d.clone())),
+            Pair::Both(o, n) => {
+                res.extend(diff_device(o, n).into_iter().map(|change| Change::Device {
+                    uid: n.uid.clone(),
+                    change,
+                }))
+            }
+        }
+    }
+
+    for pair in pairs(&old.clocks, &new.clocks, |c| c.uid.clone()) {
+        match pair {
+            Pair::Removed(c) => res.push(Change::ClockRemoved(c.clone())),
+            Pair::Added(c) => res.push(Change::ClockAdded(c.clone())),
+            Pair::Both(o, n) => {
+                let mut changes = Vec::new();
+                fields!(changes, o, n,
+                    id => ClockChange::Id,
+                    name => ClockChange::Name,
+                    nominal_sample_rate => ClockChange::NominalSampleRate,
+                    is_alive => ClockChange::IsAlive,
+                    is_running => ClockChange::IsRunning,
+                );
+                res.extend(changes.into_iter().map(|change| Change::Clock {
+                    uid: n.uid.clone(),
+                    change,
+                }));
+            }
+        }
+    }
+
+    for pair in pairs(&old.processes, &new.processes, |p| p.pid) {
+        match pair {
+            Pair::Removed(p) => res.push(Change::ProcessRemoved(p.clone())),
+            Pair::Added(p) => res.push(Change::ProcessAdded(p.clone())),
+            Pair::Both(o, n) => {
+                let mut changes = Vec::new();
+                fields!(changes, o, n,
+                    id => ProcessChange::Id,
+                    bundle_id => ProcessChange::BundleId,
+                    is_running => ProcessChange::IsRunning,
+                    is_running_input => ProcessChange::IsRunningInput,
+                    is_running_output => ProcessChange::IsRunningOutput,
+                    devices => ProcessChange::Devices,
+                );
+                res.extend(
+                    changes
+                        .into_iter()
+                        .map(|change| Change::Process { pid: n.pid, change }),
+                );
+            }
+        }
+    }
+
+    for pair in pairs(&old.taps, &new.taps, |t| t.uid.clone()) {
+        match pair {
+            Pair::Removed(t) => res.push(Change::TapRemoved(t.clone())),
+            Pair::Added(t) => res.push(Change::TapAdded(t.clone())),
+            Pair::Both(o, n) => {
+                let mut changes = Vec::new();
+                fields!(changes, o, n,
+                    id => TapChange::Id,
+                    format => TapChange::Format,
+                );
+                res.extend(changes.into_iter().map(|change| Change::Tap {
+                    uid: n.uid.clone(),
+                    change,
+                }));
+            }
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio::hal_snapshot::{
+        Change, Control, ControlValue, Delta, Device, DeviceChange, Format, Process, ProcessChange,
+        Stream, StreamChange, System, diff,
+    };
+
+    fn system() -> System {
+        System {
+            default_input_device: Some("mic".into()),
+            default_output_device: Some("speakers".into()),
+            default_sys_output_device: Some("speakers".into()),
+            devices: vec![
+                Device {
+                    id: 10,
+                    uid: "mic".into(),
+                    name: Some("Mic".into()),
+                    nominal_sample_rate: 48_000.0,
+                    is_alive: true,
+                    streams: vec![Stream {
+                        id: 11,
+                        is_input: true,
+                        is_active: true,
+                        virtual_format: Format {
+                            sample_rate: 48_000.0,
+                            channels_per_frame: 1,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                Device {
+                    id: 20,
+                    uid: "speakers".into(),
+                    name: Some("Speakers".into()),
+                    nominal_sample_rate: 44_100.0,
+                    is_alive: true,
+                    controls: vec![Control {
+                        id: 21,
+                        class: u32::from_be_bytes(*b"vlme"),
+                        scope: u32::from_be_bytes(*b"outp"),
+                        element: 0,
+                        value: ControlValue::Level {
+                            scalar: 0.5,
+                            db: -12.0,
+                        },
+                    }],
+                    ..Default::default()
+                },
+            ],
+            processes: vec![Process {
+                id: 30,
+                pid: 42,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn basics() {
+        let old = system();
+        assert!(diff(&old, &old).is_empty());
+        assert_eq!(old.obj_ids(), [1, 10, 11, 20, 21, 30]);
+
+        let mut new = old.clone();
+        new.default_output_device = Some("headphones".into());
+        new.devices.remove(0);
+        new.devices.push(Device {
+            id: 40,
+            uid: "headphones".into(),
+            ..Default::default()
+        });
+        new.processes[0].is_running_output = true;
+        new.processes[0].devices.push("headphones".into());
+
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 5);
+        assert_eq!(
+            changes[0],
+            Change::DefaultOutputDevice(Delta {
+                old: Some("speakers".into()),
+                new: Some("headphones".into())
+            })
+        );
+        assert_eq!(changes[1], Change::DeviceRemoved(old.devices[0].clone()));
+        assert_eq!(changes[2], Change::DeviceAdded(new.devices[1].clone()));
+        assert_eq!(
+            changes[3],
+            Change::Process {
+                pid: 42,
+                change: ProcessChange::IsRunningOutput(Delta {
+                    old: false,
+                    new: true
+                })
+            }
+        );
+        assert!(matches!(
+            changes[4],
+            Change::Process {
+                change: ProcessChange::Devices(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn device() {
+        let old = system();
+        let mut new = old.clone();
+        // same device with new id must not be reported as new one
+        new.devices[1].id = 50;
+        new.devices[1].controls[0].value = ControlValue::Level {
+            scalar: 1.0,
+            db: 0.0,
+        };
+        new.devices[0].streams[0].is_active = false;
+        new.devices[0].streams.push(Stream {
+            id: 12,
+            ..Default::default()
+        });
+
+        let changes = diff(&old, &new);
+        assert_eq!(
+            changes,
+            [
+                Change::Device {
+                    uid: "mic".into(),
+                    change: DeviceChange::Stream {
+                        id: 11,
+                        change: StreamChange::IsActive(Delta {
+                            old: true,
+                            new: false
+                        })
+                    }
+                },
+                Change::Device {
+                    uid: "mic".into(),
+                    change: DeviceChange::StreamAdded(new.devices[0].streams[1].clone())
+                },
+                Change::Device {
+                    uid: "speakers".into(),
+                    change: DeviceChange::Id(Delta { old: 20, new: 50 })
+                },
+                Change::Device {
+                    uid: "speakers".into(),
+                    change: DeviceChange::ControlValue {
+                        id: 21,
+                        value: Delta {
+                            old: ControlValue::Level {
+                                scalar: 0.5,
+                                db: -12.0
+                            },
+                            new: ControlValue::Level {
+                                scalar: 1.0,
+                                db: 0.0
+                            }
+                        }
+                    }
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let old = system();
+        let json = serde_json::to_string(&old).unwrap();
+        let recorded: System = serde_json::from_str(&json).unwrap();
+        assert!(diff(&old, &recorded).is_empty());
+    }
+}
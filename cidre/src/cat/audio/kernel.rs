@@ -0,0 +1,933 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+
+use crate::{cat::audio, define_opts, os};
+
+use super::graph::{bufs_count, bufs_mut, err};
+
+#[doc(alias = "AUParameterAddress")]
+pub type ParamAddr = u64;
+
+define_opts!(
+    #[doc(alias = "AudioUnitParameterOptions")]
+    pub ParamFlags(u32)
+);
+
+impl ParamFlags {
+    #[doc(alias = "kAudioUnitParameterFlag_CFNameRelease")]
+    pub const CF_NAME_RELEASE: Self = Self(1 << 4);
+
+    #[doc(alias = "kAudioUnitParameterFlag_OmitFromPresets")]
+    pub const OMIT_FROM_PRESETS: Self = Self(1 << 13);
+
+    #[doc(alias = "kAudioUnitParameterFlag_PlotHistory")]
+    pub const PLOT_HISTORY: Self = Self(1 << 14);
+
+    #[doc(alias = "kAudioUnitParameterFlag_MeterReadOnly")]
+    pub const METER_READ_ONLY: Self = Self(1 << 15);
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplayMask")]
+    pub const DISPLAY_MASK: Self = Self((7 << 16) | (1 << 22));
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplaySquareRoot")]
+    pub const DISPLAY_SQUARE_ROOT: Self = Self(1 << 16);
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplaySquared")]
+    pub const DISPLAY_SQUARED: Self = Self(2 << 16);
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplayCubed")]
+    pub const DISPLAY_CUBED: Self = Self(3 << 16);
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplayCubeRoot")]
+    pub const DISPLAY_CUBE_ROOT: Self = Self(4 << 16);
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplayExponential")]
+    pub const DISPLAY_EXPONENTIAL: Self = Self(4 << 16);
+
+    #[doc(alias = "kAudioUnitParameterFlag_HasClump")]
+    pub const HAS_CLUMP: Self = Self(1 << 20);
+
+    #[doc(alias = "kAudioUnitParameterFlag_ValuesHaveStrings")]
+    pub const VALUES_HAVE_STRINGS: Self = Self(1 << 21);
+
+    #[doc(alias = "kAudioUnitParameterFlag_DisplayLogarithmic")]
+    pub const DISPLAY_LOGARITHMIC: Self = Self(1 << 22);
+
+    /// This flag provides a hint to a host that this parameter should be controlled through the
+    /// highest resolution if the host has limitations on the control resolution of parameter
+    /// values. Generally this means that controlling this parameter with a single MIDI Control
+    /// message (i.e. 128 values) is too course a grain for that parameter, and a finer control
+    /// resolution should be used if possible. If this flag is not set, then a host can assume that
+    /// a 7-bit control quantization is acceptable. Ideally, parameters should be controlled in the
+    /// fullest resolution that they are published with.
+    #[doc(alias = "kAudioUnitParameterFlag_IsHighResolution")]
+    pub const IS_HIGH_RESOLUTION: Self = Self(1 << 23);
+
+    /// Changing the parameter in real-time will cause a glitch or otherwise undesirable effect.
+    #[doc(alias = "kAudioUnitParameterFlag_NonRealTime")]
+    pub const NON_REAL_TIME: Self = Self(1 << 24);
+
+    /// If set, the parameter can be ramped.
+    #[doc(alias = "kAudioUnitParameterFlag_CanRamp")]
+    pub const CAN_RAMP: Self = Self(1 << 25);
+
+    /// If set, the parameter is obscure (hint to UI to only display in expert mode).
+    #[doc(alias = "kAudioUnitParameterFlag_ExpertMode")]
+    pub const EXPERT_MODE: Self = Self(1 << 26);
+
+    /// In the original ParameterInfo a C string only was specified. With MacOS 10.2 and later, the
+    /// last four bytes of this string are reserved for a CFStringRef, which gives the ability to
+    /// used Unicode encoding, necessary for providing a name in languages using non-ASCII
+    /// characters. If this flag bit is set, the CFStringRef is valid.
+    #[doc(alias = "kAudioUnitParameterFlag_HasCFNameString")]
+    pub const HAS_CF_NAME_STRING: Self = Self(1 << 27);
+
+    /// If set, changing this parameter may change any number of others in the AudioUnit.
+    #[doc(alias = "kAudioUnitParameterFlag_IsGlobalMeta")]
+    pub const IS_GLOBAL_META: Self = Self(1 << 28);
+
+    /// If set, changing this parameter may change others in the same element as the current
+    /// parameter.
+    #[doc(alias = "kAudioUnitParameterFlag_IsElementMeta")]
+    pub const IS_ELEMENT_META: Self = Self(1 << 29);
+
+    #[doc(alias = "kAudioUnitParameterFlag_IsReadable")]
+    pub const IS_READABLE: Self = Self(1 << 30);
+
+    #[doc(alias = "kAudioUnitParameterFlag_IsWritable")]
+    pub const IS_WRITABLE: Self = Self(1 << 31);
+}
+
+#[doc(alias = "AudioUnitParameterUnit")]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[repr(u32)]
+pub enum ParamUnit {
+    /// untyped value generally between 0.0 and 1.0
+    Generic = 0,
+
+    /// takes an integer value (good for menu selections)
+    Indexed = 1,
+
+    /// 0.0 means false, non-zero means true
+    Boolean = 2,
+
+    /// usually from 0 -> 100, sometimes -50 -> +50
+    Percent = 3,
+
+    /// absolute or relative time
+    Seconds = 4,
+
+    /// one sample frame equals (1.0/sampleRate) seconds
+    SampleFrames = 5,
+
+    /// -180 to 180 degrees
+    Phase = 6,
+
+    /// rate multiplier, for playback speed, etc. (e.g. 2.0 == twice as fast)
+    Rate = 7,
+
+    /// absolute frequency/pitch in cycles/second
+    Hertz = 8,
+
+    /// unit of relative pitch
+    Cents = 9,
+
+    /// useful for coarse detuning
+    RelativeSemiTones = 10,
+
+    /// absolute pitch as defined in the MIDI spec (exact freq may depend on tuning table)
+    MidiNoteNumber = 11,
+
+    /// a generic MIDI controller value from 0 -> 127
+    MidiController = 12,
+
+    /// logarithmic relative gain
+    Decibels = 13,
+
+    /// linear relative gain
+    LinearGain = 14,
+
+    /// -180 to 180 degrees, similar to phase but more general (good for 3D coord system)
+    Degrees = 15,
+
+    /// 0 -> 100, crossfade mix two sources according to sqrt(x) and sqrt(1.0 - x)
+    EqualPowerCrossfade = 16,
+
+    /// 0.0 -> 1.0, pow(x, 3.0) -> linear gain to simulate a reasonable mixer channel fader response
+    MixerFaderCurve1 = 17,
+
+    /// standard left to right mixer pan
+    Pan = 18,
+
+    /// distance measured in meters
+    Meters = 19,
+
+    /// absolute frequency measurement :
+    ///if f is freq in hertz then absoluteCents = 1200 * log2(f / 440) + 6900
+    AbsoluteCents = 20,
+
+    /// octaves in relative pitch where a value of 1 is equal to 1200 cents
+    Octaves = 21,
+
+    /// beats per minute, ie tempo
+    Bpm = 22,
+
+    /// time relative to tempo, i.e., 1.0 at 120 BPM would equal 1/2 a second
+    Beats = 23,
+
+    /// parameter is expressed in milliseconds
+    Milliseconds = 24,
+
+    /// for compression, expansion ratio, etc.
+    Ratio = 25,
+
+    /// this is the parameter unit type for parameters that present a custom unit name
+    CustomUnit = 26,
+
+    /// a generic MIDI 2.0 controller value with 32-bit range
+    Midi2Controller = 27,
+}
+
+/// Description of a parameter of Rust implemented audio unit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamDesc {
+    /// Non-localized, permanent name for the parameter.
+    pub identifier: String,
+    pub name: String,
+    pub address: ParamAddr,
+    pub min: f32,
+    pub max: f32,
+    pub default: f32,
+    pub unit: ParamUnit,
+    pub flags: ParamFlags,
+}
+
+impl ParamDesc {
+    pub fn new(
+        identifier: impl Into<String>,
+        name: impl Into<String>,
+        address: ParamAddr,
+        range: std::ops::RangeInclusive<f32>,
+        default: f32,
+    ) -> Self {
+        Self {
+            identifier: identifier.into(),
+            name: name.into(),
+            address,
+            min: *range.start(),
+            max: *range.end(),
+            default,
+            unit: ParamUnit::Generic,
+            flags: ParamFlags::IS_READABLE | ParamFlags::IS_WRITABLE,
+        }
+    }
+
+    pub fn with_unit(mut self, val: ParamUnit) -> Self {
+        self.unit = val;
+        self
+    }
+
+    pub fn with_flags(mut self, val: ParamFlags) -> Self {
+        self.flags = val;
+        self
+    }
+
+    #[inline]
+    pub fn clamp(&self, val: f32) -> f32 {
+        val.clamp(self.min, self.max)
+    }
+}
+
+/// Node of the parameter tree, mirrors AUParameterNode hierarchy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamNode {
+    #[doc(alias = "AUParameter")]
+    Param(ParamDesc),
+    #[doc(alias = "AUParameterGroup")]
+    Group {
+        identifier: String,
+        name: String,
+        children: Vec<ParamNode>,
+    },
+}
+
+impl ParamNode {
+    pub fn group(
+        identifier: impl Into<String>,
+        name: impl Into<String>,
+        children: impl IntoIterator<Item = ParamNode>,
+    ) -> Self {
+        Self::Group {
+            identifier: identifier.into(),
+            name: name.into(),
+            children: children.into_iter().collect(),
+        }
+    }
+
+    #[inline]
+    pub fn identifier(&self) -> &str {
+        match self {
+            Self::Param(p) => &p.identifier,
+            Self::Group { identifier, .. } => identifier,
+        }
+    }
+
+    fn collect<'a>(&'a self, res: &mut Vec<&'a ParamDesc>) {
+        match self {
+            Self::Param(p) => res.push(p),
+            Self::Group { children, .. } => children.iter().for_each(|c| c.collect(res)),
+        }
+    }
+}
+
+impl From<ParamDesc> for ParamNode {
+    fn from(val: ParamDesc) -> Self {
+        Self::Param(val)
+    }
+}
+
+/// Parameter tree model of Rust implemented audio unit.
+///
+/// Holds the layout of the tree and lock-free storage of current values
+/// shared between UI/host threads and render thread. Hosts see it as
+/// `au::ParameterTree` created with `au::ParameterTree::with_model`.
+#[derive(Debug, Clone)]
+pub struct ParamModel {
+    nodes: Vec<ParamNode>,
+    values: Arc<ParamValues>,
+}
+
+impl ParamModel {
+    /// Validates the layout and creates storage of values initialized with defaults.
+    ///
+    /// Fails with [`err::INVALID_PARAM`] on duplicated addresses or identifiers
+    /// and on defaults out of range.
+    pub fn new(nodes: impl IntoIterator<Item = ParamNode>) -> os::Result<Self> {
+        let nodes: Vec<ParamNode> = nodes.into_iter().collect();
+        let mut params = Vec::new();
+        nodes.iter().for_each(|n| n.collect(&mut params));
+        params.sort_by_key(|p| p.address);
+        for w in params.windows(2) {
+            if w[0].address == w[1].address {
+                return Err(err::INVALID_PARAM);
+            }
+        }
+        let mut ids: Vec<&str> = params.iter().map(|p| p.identifier.as_str()).collect();
+        ids.sort_unstable();
+        if ids.windows(2).any(|w| w[0] == w[1]) {
+            return Err(err::INVALID_PARAM);
+        }
+        if params
+            .iter()
+            .any(|p| p.min > p.max || p.default < p.min || p.default > p.max)
+        {
+            return Err(err::INVALID_PARAM);
+        }
+        let values = ParamValues {
+            addrs: params.iter().map(|p| p.address).collect(),
+            ranges: params.iter().map(|p| (p.min, p.max)).collect(),
+            vals: params
+                .iter()
+                .map(|p| AtomicU32::new(p.default.to_bits()))
+                .collect(),
+        };
+        Ok(Self {
+            nodes,
+            values: Arc::new(values),
+        })
+    }
+
+    #[inline]
+    pub fn nodes(&self) -> &[ParamNode] {
+        &self.nodes
+    }
+
+    /// Parameters in depth-first order.
+    pub fn params(&self) -> Vec<&ParamDesc> {
+        let mut res = Vec::with_capacity(self.values.len());
+        self.nodes.iter().for_each(|n| n.collect(&mut res));
+        res
+    }
+
+    #[doc(alias = "parameterWithAddress:")]
+    pub fn param(&self, address: ParamAddr) -> Option<&ParamDesc> {
+        self.params().into_iter().find(|p| p.address == address)
+    }
+
+    #[inline]
+    pub fn values(&self) -> &Arc<ParamValues> {
+        &self.values
+    }
+}
+
+/// Current values of the parameter tree.
+///
+/// Reads and writes are wait-free so they are safe to use on render thread.
+#[derive(Debug)]
+pub struct ParamValues {
+    addrs: Vec<ParamAddr>,
+    ranges: Vec<(f32, f32)>,
+    vals: Vec<AtomicU32>,
+}
+
+impl ParamValues {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.addrs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.addrs.is_empty()
+    }
+
+    #[inline]
+    fn index(&self, address: ParamAddr) -> Option<usize> {
+        self.addrs.binary_search(&address).ok()
+    }
+
+    #[inline]
+    pub fn contains(&self, address: ParamAddr) -> bool {
+        self.index(address).is_some()
+    }
+
+    pub fn get(&self, address: ParamAddr) -> Option<f32> {
+        let i = self.index(address)?;
+        Some(f32::from_bits(self.vals[i].load(Ordering::Relaxed)))
+    }
+
+    /// Stores value clamped to the parameter range and returns it.
+    pub fn set(&self, address: ParamAddr, val: f32) -> os::Result<f32> {
+        let Some(i) = self.index(address) else {
+            return Err(err::INVALID_PARAM);
+        };
+        let (min, max) = self.ranges[i];
+        let val = val.clamp(min, max);
+        self.vals[i].store(val.to_bits(), Ordering::Relaxed);
+        Ok(val)
+    }
+}
+
+/// Event delivered to a kernel in the middle of render cycle.
+///
+/// `offset` is in frames relative to the start of the cycle.
+#[doc(alias = "AURenderEvent")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RenderEvent {
+    /// Parameter change. Ramps are applied as a jump to the final value.
+    #[doc(alias = "AUParameterEvent")]
+    Param {
+        offset: u32,
+        address: ParamAddr,
+        value: f32,
+        ramp: u32,
+    },
+    #[doc(alias = "AUMIDIEvent")]
+    Midi {
+        offset: u32,
+        cable: u8,
+        len: u8,
+        data: [u8; 3],
+    },
+}
+
+impl RenderEvent {
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        match self {
+            Self::Param { offset, .. } | Self::Midi { offset, .. } => *offset,
+        }
+    }
+}
+
+/// Pulls input bus of the audio unit.
+///
+/// Arguments are `(input_bus, timestamp, frames, buf_list)`.
+#[doc(alias = "AURenderPullInputBlock")]
+pub type PullInput<'a> =
+    dyn FnMut(isize, &audio::TimeStamp, u32, &mut audio::BufList) -> os::Result + 'a;
+
+/// Arguments of a single [`Kernel::process`] call.
+pub struct Ctx<'a> {
+    ts: &'a audio::TimeStamp,
+    frames: u32,
+    bus: isize,
+    output: &'a mut audio::BufList,
+    params: &'a ParamValues,
+    pull: Option<&'a mut PullInput<'a>>,
+}
+
+impl<'a> Ctx<'a> {
+    #[inline]
+    pub fn ts(&self) -> &audio::TimeStamp {
+        self.ts
+    }
+
+    #[inline]
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Output bus being rendered.
+    #[inline]
+    pub fn bus(&self) -> isize {
+        self.bus
+    }
+
+    #[inline]
+    pub fn output(&mut self) -> &mut audio::BufList {
+        self.output
+    }
+
+    #[inline]
+    pub fn channels(&self) -> usize {
+        self.output.number_buffers as usize
+    }
+
+    /// Samples of non-interleaved float output buffer.
+    pub fn channel_mut(&mut self, index: usize) -> Option<&mut [f32]> {
+        let buf = unsafe { bufs_mut(self.output) }.get_mut(index)?;
+        let len = (self.frames * buf.number_channels) as usize;
+        Some(unsafe { std::slice::from_raw_parts_mut(buf.data as *mut f32, len) })
+    }
+
+    /// Current value of the parameter.
+    #[inline]
+    pub fn param(&self, address: ParamAddr) -> f32 {
+        self.params.get(address).unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn params(&self) -> &ParamValues {
+        self.params
+    }
+
+    #[inline]
+    pub fn has_input(&self) -> bool {
+        self.pull.is_some()
+    }
+
+    /// Pulls `bus` input into `buf_list` for the frames of this call.
+    pub fn pull_input(&mut self, bus: isize, buf_list: &mut audio::BufList) -> os::Result {
+        let Some(pull) = self.pull.as_mut() else {
+            return Err(err::NO_CONNECTION);
+        };
+        pull(bus, self.ts, self.frames, buf_list)
+    }
+
+    /// Pulls `bus` input directly into output buffers for in-place processing.
+    pub fn pull_input_in_place(&mut self, bus: isize) -> os::Result {
+        let Some(pull) = self.pull.as_mut() else {
+            return Err(err::NO_CONNECTION);
+        };
+        pull(bus, self.ts, self.frames, self.output)
+    }
+}
+
+/// DSP part of Rust implemented audio unit.
+///
+/// `process` is called on the render thread and must not allocate, lock or block.
+/// Other methods are called by the host outside of render cycle.
+pub trait Kernel: Send + 'static {
+    #[doc(alias = "allocateRenderResourcesAndReturnError:")]
+    fn allocate(&mut self, asbd: &audio::StreamBasicDesc, max_frames: u32) -> os::Result {
+        let _ = (asbd, max_frames);
+        Ok(())
+    }
+
+    #[doc(alias = "deallocateRenderResources")]
+    fn deallocate(&mut self) {}
+
+    fn reset(&mut self) {}
+
+    /// Called on render thread after parameter event is applied to values.
+    fn param_changed(&mut self, address: ParamAddr, value: f32) {
+        let _ = (address, value);
+    }
+
+    /// Called on render thread for MIDI events.
+    fn midi(&mut self, cable: u8, data: &[u8]) {
+        let _ = (cable, data);
+    }
+
+    fn process(&mut self, ctx: &mut Ctx) -> os::Result;
+}
+
+impl<F> Kernel for F
+where
+    F: FnMut(&mut Ctx) -> os::Result + Send + 'static,
+{
+    fn process(&mut self, ctx: &mut Ctx) -> os::Result {
+        self(ctx)
+    }
+}
+
+/// Drives a [`Kernel`] the way internalRenderBlock does.
+///
+/// Replaces missing output buffers with internal ones, splits render cycle
+/// at event offsets for sample accurate parameter changes and checks frame limits.
+/// Used by registered audio units and by tests with a mock host.
+pub struct Renderer<K> {
+    kernel: K,
+    params: Arc<ParamValues>,
+    asbd: Option<audio::StreamBasicDesc>,
+    max_frames: u32,
+    data: Vec<Vec<u64>>,
+    slice: Vec<u64>,
+}
+
+impl<K: Kernel> Renderer<K> {
+    pub fn new(kernel: K, params: Arc<ParamValues>) -> Self {
+        Self {
+            kernel,
+            params,
+            asbd: None,
+            max_frames: 0,
+            data: Vec::new(),
+            slice: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn kernel(&self) -> &K {
+        &self.kernel
+    }
+
+    #[inline]
+    pub fn kernel_mut(&mut self) -> &mut K {
+        &mut self.kernel
+    }
+
+    #[inline]
+    pub fn params(&self) -> &Arc<ParamValues> {
+        &self.params
+    }
+
+    #[inline]
+    pub fn is_allocated(&self) -> bool {
+        self.asbd.is_some()
+    }
+
+    #[inline]
+    pub fn max_frames(&self) -> u32 {
+        self.max_frames
+    }
+
+    /// Allocates internal buffers and calls [`Kernel::allocate`].
+    pub fn allocate(&mut self, asbd: &audio::StreamBasicDesc, max_frames: u32) -> os::Result {
+        if asbd.bytes_per_frame == 0 {
+            return Err(err::FORMAT_NOT_SUPPORTED);
+        }
+        self.kernel.allocate(asbd, max_frames)?;
+        let n = bufs_count(asbd).max(1) as usize;
+        let buf_size = (max_frames as usize * asbd.bytes_per_frame as usize).div_ceil(8);
+        self.data = (0..n).map(|_| vec![0; buf_size]).collect();
+        let list_size =
+            std::mem::size_of::<audio::BufList<1>>() + (n - 1) * std::mem::size_of::<audio::Buf>();
+        self.slice = vec![0; list_size.div_ceil(8)];
+        self.asbd = Some(*asbd);
+        self.max_frames = max_frames;
+        Ok(())
+    }
+
+    pub fn deallocate(&mut self) {
+        if self.asbd.take().is_some() {
+            self.kernel.deallocate();
+        }
+        self.data = Vec::new();
+        self.slice = Vec::new();
+        self.max_frames = 0;
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.kernel.reset();
+    }
+
+    /// Renders `frames` into `output`.
+    ///
+    /// Events must be ordered by offset. Offsets past the end of the cycle
+    /// are applied at the end.
+    pub fn render(
+        &mut self,
+        ts: &audio::TimeStamp,
+        frames: u32,
+        bus: isize,
+        output: &mut audio::BufList,
+        events: impl IntoIterator<Item = RenderEvent>,
+        mut pull: Option<&mut PullInput>,
+    ) -> os::Result {
+        let Some(asbd) = self.asbd else {
+            return Err(err::UNINITIALIZED);
+        };
+        if frames > self.max_frames {
+            return Err(err::TOO_MANY_FRAMES_TO_PROCESS);
+        }
+        let bpf = asbd.bytes_per_frame;
+        let bufs = unsafe { bufs_mut(output) };
+        if bufs.len() > self.data.len() {
+            return Err(err::FORMAT_NOT_SUPPORTED);
+        }
+        for (buf, data) in bufs.iter_mut().zip(self.data.iter_mut()) {
+            if buf.data.is_null() {
+                buf.data = data.as_mut_ptr() as *mut u8;
+            }
+            buf.data_bytes_size = frames * bpf;
+        }
+
+        let mut start = 0;
+        for event in events {
+            let offset = event.offset().min(frames);
+            if offset > start {
+                self.process(ts, start, offset, bus, output, pull.as_deref_mut())?;
+                start = offset;
+            }
+            match event {
+                RenderEvent::Param { address, value, .. } => {
+                    if let Ok(value) = self.params.set(address, value) {
+                        self.kernel.param_changed(address, value);
+                    }
+                }
+                RenderEvent::Midi {
+                    cable, len, data, ..
+                } => {
+                    let len = (len as usize).min(data.len());
+                    self.kernel.midi(cable, &data[..len]);
+                }
+            }
+        }
+        if start < frames {
+            self.process(ts, start, frames, bus, output, pull)?;
+        }
+        Ok(())
+    }
+
+    fn process(
+        &mut self,
+        ts: &audio::TimeStamp,
+        start: u32,
+        end: u32,
+        bus: isize,
+        output: &mut audio::BufList,
+        pull: Option<&mut PullInput>,
+    ) -> os::Result {
+        let frames = end - start;
+        let mut sub_ts = unsafe { std::ptr::read(ts) };
+        sub_ts.sample_time += start as f64;
+
+        // Each slice gets its own list so the caller's one keeps sizes of the whole cycle.
+        let bpf = self.asbd.map(|a| a.bytes_per_frame).unwrap_or_default();
+        let list = unsafe { &mut *(self.slice.as_mut_ptr() as *mut audio::BufList) };
+        list.number_buffers = output.number_buffers;
+        let dst = unsafe { bufs_mut(list) };
+        for (d, s) in dst.iter_mut().zip(unsafe { bufs_mut(output) }.iter()) {
+            d.number_channels = s.number_channels;
+            d.data = unsafe { s.data.add((start * bpf) as usize) };
+            d.data_bytes_size = frames * bpf;
+        }
+
+        let mut ctx = Ctx {
+            ts: &sub_ts,
+            frames,
+            bus,
+            output: list,
+            params: &self.params,
+            pull: pull.map(|p| p as &mut PullInput),
+        };
+        self.kernel.process(&mut ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{cat::audio, os};
+
+    use super::{
+        Ctx, Kernel, ParamDesc, ParamModel, ParamNode, ParamUnit, RenderEvent, Renderer, bufs_mut,
+        err,
+    };
+
+    const GAIN: u64 = 0;
+    const MIX: u64 = 1;
+
+    fn model() -> ParamModel {
+        ParamModel::new([
+            ParamDesc::new("gain", "Gain", GAIN, 0.0..=2.0, 1.0).into(),
+            ParamNode::group(
+                "fx",
+                "Effect",
+                [ParamDesc::new("mix", "Mix", MIX, 0.0..=1.0, 0.5)
+                    .with_unit(ParamUnit::Percent)
+                    .into()],
+            ),
+        ])
+        .unwrap()
+    }
+
+    fn samples(buf_list: &mut audio::BufList) -> Vec<&mut [f32]> {
+        unsafe { bufs_mut(buf_list) }
+            .iter_mut()
+            .map(|b| unsafe {
+                std::slice::from_raw_parts_mut(b.data as *mut f32, b.data_bytes_size as usize / 4)
+            })
+            .collect()
+    }
+
+    #[derive(Default)]
+    struct Gain {
+        calls: Arc<Mutex<Vec<(f64, u32)>>>,
+        changes: Vec<(u64, f32)>,
+    }
+
+    impl Kernel for Gain {
+        fn param_changed(&mut self, address: u64, value: f32) {
+            self.changes.push((address, value));
+        }
+
+        fn process(&mut self, ctx: &mut Ctx) -> os::Result {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((ctx.ts().sample_time, ctx.frames()));
+            ctx.pull_input_in_place(0)?;
+            let gain = ctx.param(GAIN);
+            for i in 0..ctx.channels() {
+                for s in ctx.channel_mut(i).unwrap() {
+                    *s *= gain;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn params() {
+        let tree = model();
+        let ids: Vec<_> = tree
+            .params()
+            .iter()
+            .map(|p| p.identifier.as_str())
+            .collect();
+        assert_eq!(ids, ["gain", "mix"]);
+        assert_eq!(tree.param(MIX).unwrap().unit, ParamUnit::Percent);
+        assert!(tree.param(42).is_none());
+
+        let values = tree.values();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.get(MIX), Some(0.5));
+        assert_eq!(values.set(GAIN, 10.0), Ok(2.0));
+        assert_eq!(values.get(GAIN), Some(2.0));
+        assert_eq!(values.set(42, 1.0), Err(err::INVALID_PARAM));
+
+        let dup = ParamModel::new([
+            ParamDesc::new("a", "A", 0, 0.0..=1.0, 0.0).into(),
+            ParamDesc::new("b", "B", 0, 0.0..=1.0, 0.0).into(),
+        ]);
+        assert_eq!(dup.unwrap_err(), err::INVALID_PARAM);
+        let out_of_range = ParamModel::new([ParamDesc::new("a", "A", 0, 0.0..=1.0, 2.0).into()]);
+        assert_eq!(out_of_range.unwrap_err(), err::INVALID_PARAM);
+    }
+
+    #[test]
+    fn basics() {
+        let tree = model();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let kernel = Gain {
+            calls: calls.clone(),
+            ..Default::default()
+        };
+        let mut renderer = Renderer::new(kernel, tree.values().clone());
+        let asbd = audio::StreamBasicDesc::common_f32(48_000.0, 2, false);
+        let ts = audio::TimeStamp::with_sample_time(1000.0);
+
+        // mock host: input is a constant signal
+        let mut pull =
+            |bus: isize, _ts: &audio::TimeStamp, _frames: u32, list: &mut audio::BufList| {
+                assert_eq!(bus, 0);
+                for ch in samples(list) {
+                    ch.fill(1.0);
+                }
+                Ok(())
+            };
+
+        let mut out = audio::BufList::<2>::default();
+        let list = unsafe { &mut *(&mut out as *mut _ as *mut audio::BufList) };
+        let err = renderer
+            .render(&ts, 8, 0, list, [], Some(&mut pull))
+            .unwrap_err();
+        assert_eq!(err, err::UNINITIALIZED);
+
+        renderer.allocate(&asbd, 8).unwrap();
+        let err = renderer
+            .render(&ts, 16, 0, list, [], Some(&mut pull))
+            .unwrap_err();
+        assert_eq!(err, err::TOO_MANY_FRAMES_TO_PROCESS);
+
+        let events = [
+            RenderEvent::Param {
+                offset: 4,
+                address: GAIN,
+                value: 0.5,
+                ramp: 0,
+            },
+            RenderEvent::Param {
+                offset: 6,
+                address: GAIN,
+                value: 5.0,
+                ramp: 0,
+            },
+        ];
+        renderer
+            .render(&ts, 8, 0, list, events, Some(&mut pull))
+            .unwrap();
+
+        let chans = samples(list);
+        assert_eq!(chans.len(), 2);
+        assert_eq!(chans[0], [1.0, 1.0, 1.0, 1.0, 0.5, 0.5, 2.0, 2.0]);
+        assert_eq!(chans[1], chans[0].to_vec());
+        assert_eq!(
+            &calls.lock().unwrap()[..],
+            &[(1000.0, 4), (1004.0, 2), (1006.0, 2)]
+        );
+        assert_eq!(renderer.kernel().changes, [(GAIN, 0.5), (GAIN, 2.0)]);
+        assert_eq!(tree.values().get(GAIN), Some(2.0));
+
+        let err = renderer.render(&ts, 8, 0, list, [], None).unwrap_err();
+        assert_eq!(err, err::NO_CONNECTION);
+
+        renderer.deallocate();
+        assert!(!renderer.is_allocated());
+    }
+
+    #[test]
+    fn closure() {
+        let tree = model();
+        let mut renderer = Renderer::new(
+            |ctx: &mut Ctx| {
+                let mix = ctx.param(MIX);
+                ctx.channel_mut(0).unwrap().fill(mix);
+                Ok(())
+            },
+            tree.values().clone(),
+        );
+        renderer
+            .allocate(&audio::StreamBasicDesc::common_f32(44_100.0, 1, false), 4)
+            .unwrap();
+        let mut out = audio::BufList::<1>::default();
+        renderer
+            .render(
+                &audio::TimeStamp::with_sample_time(0.0),
+                4,
+                0,
+                &mut out,
+                [],
+                None,
+            )
+            .unwrap();
+        assert_eq!(samples(&mut out)[0], [0.5; 4]);
+    }
+}
@@ -0,0 +1,697 @@
+use std::collections::VecDeque;
+
+use crate::cat::audio::{Buf, BufList, Format, FormatFlags, StreamBasicDesc, err};
+use crate::os;
+
+#[cfg(feature = "cm")]
+use crate::cm;
+
+/// Absolute gating threshold of integrated loudness.
+pub const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+
+/// Relative gating threshold of integrated loudness.
+pub const RELATIVE_GATE_LU: f64 = -10.0;
+
+#[inline]
+pub fn to_db(linear: f32) -> f32 {
+    20.0 * linear.log10()
+}
+
+#[inline]
+pub fn energy_to_lufs(energy: f64) -> f64 {
+    -0.691 + 10.0 * energy.log10()
+}
+
+#[inline]
+pub fn lufs_to_energy(lufs: f64) -> f64 {
+    10.0f64.powf((lufs + 0.691) / 10.0)
+}
+
+/// Second order IIR filter in transposed direct form II.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Biquad {
+    pub b: [f64; 3],
+    /// Feedback coefficients with `a0` normalized to 1.
+    pub a: [f64; 2],
+    z: [f64; 2],
+}
+
+impl Biquad {
+    pub const fn new(b: [f64; 3], a: [f64; 2]) -> Self {
+        Self { b, a, z: [0.0; 2] }
+    }
+
+    /// High shelf of K-weighting (stage 1 of ITU-R BS.1770).
+    pub fn k_shelf(sample_rate: f64) -> Self {
+        let f0 = 1681.974450955533;
+        let g = 3.999843853973347;
+        let q = 0.7071752369554196;
+
+        let k = (std::f64::consts::PI * f0 / sample_rate).tan();
+        let vh = 10.0f64.powf(g / 20.0);
+        let vb = vh.powf(0.4996667741545416);
+        let a0 = 1.0 + k / q + k * k;
+        Self::new(
+            [
+                (vh + vb * k / q + k * k) / a0,
+                2.0 * (k * k - vh) / a0,
+                (vh - vb * k / q + k * k) / a0,
+            ],
+            [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        )
+    }
+
+    /// RLB high pass of K-weighting (stage 2 of ITU-R BS.1770).
+    pub fn k_high_pass(sample_rate: f64) -> Self {
+        let f0 = 38.13547087602444;
+        let q = 0.5003270373238773;
+
+        let k = (std::f64::consts::PI * f0 / sample_rate).tan();
+        let a0 = 1.0 + k / q + k * k;
+        Self::new(
+            [1.0, -2.0, 1.0],
+            [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        )
+    }
+
+    #[inline]
+    pub fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z[0];
+        self.z[0] = self.b[1] * x - self.a[0] * y + self.z[1];
+        self.z[1] = self.b[2] * x - self.a[1] * y;
+        y
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.z = [0.0; 2];
+    }
+}
+
+/// Polyphase interpolator used for true peak estimation (ITU-R BS.1770 Annex 2).
+#[derive(Debug, Clone)]
+struct TruePeak {
+    factor: usize,
+    /// `taps` coefficients for every phase.
+    phases: Vec<Vec<f32>>,
+    history: Vec<f32>,
+    pos: usize,
+}
+
+impl TruePeak {
+    const TAPS: usize = 12;
+
+    fn new(sample_rate: f64) -> Self {
+        let factor = if sample_rate < 96_000.0 {
+            4
+        } else if sample_rate < 192_000.0 {
+            2
+        } else {
+            1
+        };
+        let len = Self::TAPS * factor;
+        let center = (len - 1) as f64 / 2.0;
+        let mut phases = vec![Vec::with_capacity(Self::TAPS); factor];
+        for n in 0..len {
+            let t = (n as f64 - center) / factor as f64;
+            let sinc = if t == 0.0 {
+                1.0
+            } else {
+                (std::f64::consts::PI * t).sin() / (std::f64::consts::PI * t)
+            };
+            let w = 0.5 - 0.5 * (2.0 * std::f64::consts::PI * (n as f64 + 0.5) / len as f64).cos();
+            phases[n % factor].push((sinc * w) as f32);
+        }
+        Self {
+            factor,
+            phases,
+            history: vec![0.0; Self::TAPS],
+            pos: 0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, x: f32) -> f32 {
+        self.history[self.pos] = x;
+        self.pos = (self.pos + 1) % Self::TAPS;
+        if self.factor == 1 {
+            return x.abs();
+        }
+        let mut peak = 0.0f32;
+        for phase in &self.phases {
+            let mut acc = 0.0f32;
+            let mut i = self.pos;
+            for h in phase.iter() {
+                // oldest sample first
+                acc += h * self.history[i];
+                i = (i + 1) % Self::TAPS;
+            }
+            peak = peak.max(acc.abs());
+        }
+        peak.max(x.abs())
+    }
+
+    fn reset(&mut self) {
+        self.history.fill(0.0);
+        self.pos = 0;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Channel {
+    weight: f64,
+    shelf: Biquad,
+    high_pass: Biquad,
+    true_peak: TruePeak,
+    /// K-weighted sum of squares of current 100ms sub block.
+    sum: f64,
+    peak: f32,
+    rms: f32,
+    max_peak: f32,
+    max_true_peak: f32,
+}
+
+/// Peak, RMS, true peak and loudness meter (EBU R128 / ITU-R BS.1770-4).
+///
+/// Momentary loudness uses 400ms window, short term uses 3s window
+/// and integrated loudness is gated with absolute and relative gates.
+#[derive(Debug, Clone)]
+pub struct Meter {
+    sample_rate: f64,
+    channels: Vec<Channel>,
+    sub_block_len: usize,
+    sub_block_pos: usize,
+    /// Weighted mean squares of the last 30 sub blocks.
+    sub_blocks: VecDeque<f64>,
+    /// Energies of 400ms gating blocks with 75% overlap.
+    blocks: Vec<f64>,
+    scratch: Vec<f32>,
+}
+
+impl Meter {
+    const SUB_BLOCKS_MOMENTARY: usize = 4;
+    const SUB_BLOCKS_SHORT_TERM: usize = 30;
+
+    /// Sub-blocks are 100ms but at least one frame long,
+    /// so rates below 5 Hz are metered frame by frame.
+    pub fn new(sample_rate: f64, channels_num: u32) -> Self {
+        let channel = Channel {
+            weight: 1.0,
+            shelf: Biquad::k_shelf(sample_rate),
+            high_pass: Biquad::k_high_pass(sample_rate),
+            true_peak: TruePeak::new(sample_rate),
+            sum: 0.0,
+            peak: 0.0,
+            rms: 0.0,
+            max_peak: 0.0,
+            max_true_peak: 0.0,
+        };
+        let mut channels = vec![channel; channels_num as usize];
+        // L R C LFE Ls Rs
+        if channels_num == 6 {
+            channels[3].weight = 0.0;
+            channels[4].weight = 1.41;
+            channels[5].weight = 1.41;
+        }
+        Self {
+            sample_rate,
+            channels,
+            sub_block_len: ((sample_rate / 10.0).round() as usize).max(1),
+            sub_block_pos: 0,
+            sub_blocks: VecDeque::with_capacity(Self::SUB_BLOCKS_SHORT_TERM),
+            blocks: Vec::new(),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Meter for linear PCM f32 stream.
+    ///
+    /// Fails with [`err::PARAM`] for sample rates below 5 Hz.
+    pub fn with_asbd(asbd: &StreamBasicDesc) -> os::Result<Self> {
+        if !is_f32(asbd) {
+            return Err(err::UNIMPLEMENTED);
+        }
+        if asbd.sample_rate.is_nan() || asbd.sample_rate < 5.0 {
+            return Err(err::PARAM);
+        }
+        Ok(Self::new(asbd.sample_rate, asbd.channels_per_frame))
+    }
+
+    #[inline]
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    #[inline]
+    pub fn channels_num(&self) -> usize {
+        self.channels.len()
+    }
+
+    #[inline]
+    pub fn channel_weight(&self, channel: usize) -> f64 {
+        self.channels[channel].weight
+    }
+
+    /// Channel weights are 1.0 except LFE (0.0) and surrounds (1.41) of 5.1 layout.
+    #[inline]
+    pub fn set_channel_weight(&mut self, channel: usize, val: f64) {
+        self.channels[channel].weight = val;
+    }
+
+    pub fn reset(&mut self) {
+        for ch in self.channels.iter_mut() {
+            ch.shelf.reset();
+            ch.high_pass.reset();
+            ch.true_peak.reset();
+            ch.sum = 0.0;
+            ch.peak = 0.0;
+            ch.rms = 0.0;
+            ch.max_peak = 0.0;
+            ch.max_true_peak = 0.0;
+        }
+        self.sub_block_pos = 0;
+        self.sub_blocks.clear();
+        self.blocks.clear();
+    }
+
+    /// Sample peak of the channel in the last processed buffer, linear.
+    #[inline]
+    pub fn peak(&self, channel: usize) -> f32 {
+        self.channels[channel].peak
+    }
+
+    /// RMS of the channel in the last processed buffer, linear.
+    #[inline]
+    pub fn rms(&self, channel: usize) -> f32 {
+        self.channels[channel].rms
+    }
+
+    /// Sample peak of the channel since reset, linear.
+    #[inline]
+    pub fn max_peak(&self, channel: usize) -> f32 {
+        self.channels[channel].max_peak
+    }
+
+    /// True peak of the channel since reset, linear.
+    #[inline]
+    pub fn true_peak(&self, channel: usize) -> f32 {
+        self.channels[channel].max_true_peak
+    }
+
+    /// Maximum true peak across all channels in dBTP.
+    pub fn true_peak_db(&self) -> f32 {
+        to_db(
+            self.channels
+                .iter()
+                .map(|c| c.max_true_peak)
+                .fold(0.0, f32::max),
+        )
+    }
+
+    fn loudness_of_last(&self, n: usize) -> f64 {
+        if self.sub_blocks.len() < n {
+            return f64::NEG_INFINITY;
+        }
+        let sum: f64 = self.sub_blocks.iter().rev().take(n).sum();
+        energy_to_lufs(sum / n as f64)
+    }
+
+    /// Momentary loudness (400ms) in LUFS.
+    ///
+    /// `-inf` until first 400ms are processed.
+    #[inline]
+    pub fn momentary(&self) -> f64 {
+        self.loudness_of_last(Self::SUB_BLOCKS_MOMENTARY)
+    }
+
+    /// Short term loudness (3s) in LUFS.
+    ///
+    /// `-inf` until first 3s are processed.
+    #[inline]
+    pub fn short_term(&self) -> f64 {
+        self.loudness_of_last(Self::SUB_BLOCKS_SHORT_TERM)
+    }
+
+    /// Gated integrated loudness in LUFS since reset.
+    pub fn integrated(&self) -> f64 {
+        let abs_gate = lufs_to_energy(ABSOLUTE_GATE_LUFS);
+        let (sum, n) = self
+            .blocks
+            .iter()
+            .filter(|e| **e > abs_gate)
+            .fold((0.0, 0usize), |(s, n), e| (s + e, n + 1));
+        if n == 0 {
+            return f64::NEG_INFINITY;
+        }
+        let rel_gate = lufs_to_energy(energy_to_lufs(sum / n as f64) + RELATIVE_GATE_LU);
+        let gate = abs_gate.max(rel_gate);
+        let (sum, n) = self
+            .blocks
+            .iter()
+            .filter(|e| **e > gate)
+            .fold((0.0, 0usize), |(s, n), e| (s + e, n + 1));
+        if n == 0 {
+            return f64::NEG_INFINITY;
+        }
+        energy_to_lufs(sum / n as f64)
+    }
+
+    /// Processes non interleaved channels.
+    ///
+    /// Fails with [`err::PARAM`] if number of channels doesn't match the meter.
+    /// Frames past the end of the shortest channel are ignored.
+    pub fn process_f32(&mut self, channels: &[&[f32]]) -> os::Result {
+        if channels.len() != self.channels.len() {
+            return Err(err::PARAM);
+        }
+        let frames = channels.iter().map(|c| c.len()).min().unwrap_or(0);
+        for (ch, data) in self.channels.iter_mut().zip(channels) {
+            ch.levels(&data[..frames], 1);
+        }
+        self.process_loudness(frames, |ch, i| channels[ch][i]);
+        Ok(())
+    }
+
+    /// Processes interleaved frames.
+    ///
+    /// Trailing incomplete frame is ignored.
+    pub fn process_interleaved_f32(&mut self, data: &[f32]) {
+        let n = self.channels.len().max(1);
+        let data = &data[..data.len() / n * n];
+        for (i, ch) in self.channels.iter_mut().enumerate() {
+            ch.levels(data.get(i..).unwrap_or_default(), n);
+        }
+        self.process_loudness(data.len() / n, |ch, i| data[i * n + ch]);
+    }
+
+    /// Processes buffers described with `asbd`.
+    pub fn process_bufs(&mut self, asbd: &StreamBasicDesc, bufs: &[Buf]) -> os::Result {
+        if !is_f32(asbd) {
+            return Err(err::UNIMPLEMENTED);
+        }
+        if asbd.channels_per_frame as usize != self.channels.len() {
+            return Err(err::PARAM);
+        }
+        let slices: Vec<&[f32]> = bufs
+            .iter()
+            .map(|b| {
+                if b.data.is_null() {
+                    &[][..]
+                } else {
+                    unsafe {
+                        std::slice::from_raw_parts(
+                            b.data as *const f32,
+                            b.data_bytes_size as usize / std::mem::size_of::<f32>(),
+                        )
+                    }
+                }
+            })
+            .collect();
+        if asbd.is_interleaved() {
+            let Some(data) = slices.first() else {
+                return Err(err::PARAM);
+            };
+            self.process_interleaved_f32(data);
+        } else {
+            self.process_f32(&slices)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn process_buf_list<const N: usize>(
+        &mut self,
+        asbd: &StreamBasicDesc,
+        buf_list: &BufList<N>,
+    ) -> os::Result {
+        self.process_bufs(asbd, buf_list.as_slice())
+    }
+
+    /// Processes audio of the sample buffer.
+    #[cfg(feature = "cm")]
+    pub fn process_sample_buf(&mut self, sample_buf: &cm::SampleBuf) -> os::Result {
+        let Some(asbd) = sample_buf
+            .format_desc()
+            .and_then(|fd| fd.stream_basic_desc())
+        else {
+            return Err(err::UNIMPLEMENTED);
+        };
+        let asbd = *asbd;
+        let mut list = Default::default();
+        let list = sample_buf.audio_buf_list_n(&mut list)?;
+        self.process_bufs(&asbd, list.list.buffers())
+    }
+
+    fn process_loudness(&mut self, frames: usize, sample: impl Fn(usize, usize) -> f32) {
+        let mut i = 0;
+        while i < frames {
+            let n = (self.sub_block_len - self.sub_block_pos).min(frames - i);
+            for (c, ch) in self.channels.iter_mut().enumerate() {
+                self.scratch.clear();
+                self.scratch.extend((i..i + n).map(|j| sample(c, j)));
+                for x in self.scratch.iter() {
+                    let x = *x as f64;
+                    let y = ch.high_pass.process(ch.shelf.process(x));
+                    ch.sum += y * y;
+                }
+            }
+            self.sub_block_pos += n;
+            i += n;
+            if self.sub_block_pos == self.sub_block_len {
+                self.finish_sub_block();
+            }
+        }
+    }
+
+    fn finish_sub_block(&mut self) {
+        let len = self.sub_block_len as f64;
+        let energy = self
+            .channels
+            .iter_mut()
+            .map(|ch| ch.weight * std::mem::take(&mut ch.sum) / len)
+            .sum();
+        self.sub_block_pos = 0;
+        if self.sub_blocks.len() == Self::SUB_BLOCKS_SHORT_TERM {
+            self.sub_blocks.pop_front();
+        }
+        self.sub_blocks.push_back(energy);
+        if self.sub_blocks.len() >= Self::SUB_BLOCKS_MOMENTARY {
+            let sum: f64 = self
+                .sub_blocks
+                .iter()
+                .rev()
+                .take(Self::SUB_BLOCKS_MOMENTARY)
+                .sum();
+            self.blocks.push(sum / Self::SUB_BLOCKS_MOMENTARY as f64);
+        }
+    }
+}
+
+impl Channel {
+    /// Updates peak, RMS and true peak with every `stride` sample of `data`.
+    fn levels(&mut self, data: &[f32], stride: usize) {
+        let frames = data.len().div_ceil(stride);
+        if frames == 0 {
+            self.peak = 0.0;
+            self.rms = 0.0;
+            return;
+        }
+        // last sample is at (frames - 1) * stride < data.len()
+        let (peak, mean_sq) = peak_mean_sq(data.as_ptr(), stride, frames);
+        self.peak = peak;
+        self.rms = mean_sq.sqrt();
+        self.max_peak = self.max_peak.max(peak);
+        let mut tp = self.max_true_peak;
+        for x in data.iter().step_by(stride) {
+            tp = tp.max(self.true_peak.process(*x));
+        }
+        self.max_true_peak = tp;
+    }
+}
+
+#[cfg(all(feature = "vdsp", target_vendor = "apple"))]
+#[inline]
+fn peak_mean_sq(ptr: *const f32, stride: usize, frames: usize) -> (f32, f32) {
+    use crate::vdsp;
+    let mut peak = 0.0f32;
+    let mut mean_sq = 0.0f32;
+    unsafe {
+        vdsp::_maxmg_f32(ptr, stride as _, &mut peak, frames);
+        vdsp::_meansq_f32(ptr, stride as _, &mut mean_sq, frames);
+    }
+    (peak, mean_sq)
+}
+
+#[cfg(not(all(feature = "vdsp", target_vendor = "apple")))]
+#[inline]
+fn peak_mean_sq(ptr: *const f32, stride: usize, frames: usize) -> (f32, f32) {
+    let mut peak = 0.0f32;
+    let mut sum = 0.0f64;
+    for i in 0..frames {
+        let x = unsafe { *ptr.add(i * stride) };
+        peak = peak.max(x.abs());
+        sum += (x * x) as f64;
+    }
+    (peak, (sum / frames as f64) as f32)
+}
+
+fn is_f32(asbd: &StreamBasicDesc) -> bool {
+    asbd.format == Format::LINEAR_PCM
+        && asbd.format_flags.contains(FormatFlags::IS_FLOAT)
+        && asbd.bits_per_channel == 32
+        && asbd.is_native_endian()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio::{self, Meter, meter};
+
+    fn sine(sr: f64, freq: f64, db: f64, secs: f64, phase: f64) -> Vec<f32> {
+        let amp = 10.0f64.powf(db / 20.0);
+        let n = (sr * secs) as usize;
+        (0..n)
+            .map(|i| {
+                (amp * (2.0 * std::f64::consts::PI * freq * i as f64 / sr + phase).sin()) as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn k_weighting() {
+        // ITU-R BS.1770-4 table 1 and table 2 coefficients at 48 kHz
+        let shelf = meter::Biquad::k_shelf(48_000.0);
+        let b = [1.53512485958697, -2.69169618940638, 1.19839281085285];
+        let a = [-1.69065929318241, 0.73248077421585];
+        for (x, y) in shelf.b.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+        for (x, y) in shelf.a.iter().zip(a.iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+
+        let hp = meter::Biquad::k_high_pass(48_000.0);
+        assert_eq!(hp.b, [1.0, -2.0, 1.0]);
+        let a = [-1.99004745483398, 0.99007225036621];
+        for (x, y) in hp.a.iter().zip(a.iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn basics() {
+        let mut m = Meter::new(48_000.0, 2);
+        assert_eq!(m.momentary(), f64::NEG_INFINITY);
+        assert_eq!(m.integrated(), f64::NEG_INFINITY);
+
+        let l = sine(48_000.0, 1000.0, -6.0, 0.5, 0.0);
+        let r = vec![0.0; l.len()];
+        m.process_f32(&[&l, &r]).unwrap();
+        assert!((m.peak(0) - 0.5012).abs() < 1e-3);
+        assert!((m.rms(0) - 0.5012 / 2.0f32.sqrt()).abs() < 1e-3);
+        assert_eq!(m.peak(1), 0.0);
+        assert!(m.momentary().is_finite());
+        assert_eq!(m.short_term(), f64::NEG_INFINITY);
+
+        let mut interleaved = Vec::with_capacity(l.len() * 2);
+        for x in l.iter() {
+            interleaved.extend_from_slice(&[0.0, *x]);
+        }
+        m.reset();
+        m.process_interleaved_f32(&interleaved);
+        assert_eq!(m.peak(0), 0.0);
+        assert!((m.max_peak(1) - 0.5012).abs() < 1e-3);
+
+        let asbd = audio::StreamBasicDesc::common_f32(48_000.0, 2, true);
+        let mut buf_list = audio::BufList::<1>::default();
+        buf_list.buffers[0].data = interleaved.as_mut_ptr() as *mut u8;
+        buf_list.buffers[0].data_bytes_size = (interleaved.len() * 4) as u32;
+        buf_list.buffers[0].number_channels = 2;
+        m.process_buf_list(&asbd, &buf_list).unwrap();
+        assert!((m.peak(1) - 0.5012).abs() < 1e-3);
+
+        let asbd = audio::StreamBasicDesc::common_f32(48_000.0, 1, true);
+        let err = m.process_buf_list(&asbd, &buf_list).unwrap_err();
+        assert_eq!(err, audio::err::PARAM);
+    }
+
+    #[test]
+    fn low_sample_rate() {
+        let asbd = audio::StreamBasicDesc::common_f32(4.0, 1, true);
+        let err = Meter::with_asbd(&asbd).err().unwrap();
+        assert_eq!(err, audio::err::PARAM);
+
+        let mut m = Meter::new(4.0, 1);
+        m.process_f32(&[&[0.5; 16]]).unwrap();
+        assert_eq!(m.peak(0), 0.5);
+    }
+
+    #[test]
+    fn short_bufs() {
+        let mut m = Meter::new(48_000.0, 2);
+        let l = sine(48_000.0, 1000.0, -6.0, 0.1, 0.0);
+        let r = vec![1.0; l.len() / 2];
+        m.process_f32(&[&l, &r]).unwrap();
+        assert_eq!(m.peak(1), 1.0);
+        assert!(m.peak(0) < 0.6);
+
+        let err = m.process_f32(&[&l]).unwrap_err();
+        assert_eq!(err, audio::err::PARAM);
+        let err = m.process_f32(&[&l, &l, &l]).unwrap_err();
+        assert_eq!(err, audio::err::PARAM);
+
+        let mut m = Meter::new(48_000.0, 4);
+        m.process_interleaved_f32(&[]);
+        m.process_interleaved_f32(&[0.5, 0.5]);
+        assert_eq!(m.peak(0), 0.0);
+        assert_eq!(m.max_peak(3), 0.0);
+
+        m.process_interleaved_f32(&[0.1, 0.2, 0.3, 0.4, 0.9]);
+        assert_eq!(m.peak(0), 0.1);
+        assert_eq!(m.peak(3), 0.4);
+    }
+
+    /// EBU Tech 3341, test case 1
+    #[test]
+    fn ebu_3341_1() {
+        let mut m = Meter::new(48_000.0, 2);
+        let s = sine(48_000.0, 1000.0, -23.0, 20.0, 0.0);
+        m.process_f32(&[&s, &s]).unwrap();
+        assert!((m.integrated() + 23.0).abs() < 0.1, "{}", m.integrated());
+        assert!((m.momentary() + 23.0).abs() < 0.1);
+        assert!((m.short_term() + 23.0).abs() < 0.1);
+    }
+
+    /// EBU Tech 3341, test case 3 (scaled down 5 times)
+    #[test]
+    fn relative_gate() {
+        let mut m = Meter::new(48_000.0, 2);
+        for (db, secs) in [(-36.0, 2.0), (-23.0, 12.0), (-36.0, 2.0)] {
+            let s = sine(48_000.0, 1000.0, db, secs, 0.0);
+            m.process_f32(&[&s, &s]).unwrap();
+        }
+        assert!((m.integrated() + 23.0).abs() < 0.1, "{}", m.integrated());
+    }
+
+    #[test]
+    fn absolute_gate() {
+        let mut m = Meter::new(44_100.0, 1);
+        let silence = vec![0.0f32; 44_100 * 5];
+        m.process_f32(&[&silence]).unwrap();
+        assert_eq!(m.integrated(), f64::NEG_INFINITY);
+
+        let s = sine(44_100.0, 1000.0, -20.0, 20.0, 0.0);
+        m.process_f32(&[&s]).unwrap();
+        // single channel of -20 dBFS sine is -23 LUFS
+        assert!((m.integrated() + 23.0).abs() < 0.1, "{}", m.integrated());
+    }
+
+    /// EBU Tech 3341, true peak of 1/4 fs sine with 45 degree phase
+    #[test]
+    fn true_peak() {
+        let mut m = Meter::new(48_000.0, 1);
+        let s = sine(48_000.0, 12_000.0, -6.0, 1.0, std::f64::consts::FRAC_PI_4);
+        m.process_f32(&[&s]).unwrap();
+        assert!((meter::to_db(m.max_peak(0)) + 9.0).abs() < 0.1);
+        assert!((m.true_peak_db() + 6.0).abs() < 0.4, "{}", m.true_peak_db());
+    }
+}
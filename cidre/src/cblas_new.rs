@@ -1,6 +1,6 @@
 pub mod cblas {
 
-    #[link(name = "Accelerate", kind = "framework")]
+    #[cfg_attr(target_vendor = "apple", link(name = "Accelerate", kind = "framework"))]
     unsafe extern "C-unwind" {
         #[link_name = "cblas_scopy"]
         #[doc(alias = "cblas_scopy")]
@@ -29,7 +29,7 @@ pub mod cblas {
 
 pub mod catlas {
 
-    #[link(name = "Accelerate", kind = "framework")]
+    #[cfg_attr(target_vendor = "apple", link(name = "Accelerate", kind = "framework"))]
     unsafe extern "C-unwind" {
         #[link_name = "catlas_saxpby"]
         #[doc(alias = "catlas_saxpby")]
@@ -128,15 +128,25 @@ pub use socket::NativeHandle as SocketNativeHandle;
 pub use socket::Signature as SocketSignature;
 pub use socket::Socket;
 
+#[cfg(target_vendor = "apple")]
 mod mach_port;
+#[cfg(target_vendor = "apple")]
 pub use mach_port::MachPort;
+#[cfg(target_vendor = "apple")]
 pub use mach_port::MachPortCb;
+#[cfg(target_vendor = "apple")]
 pub use mach_port::MachPortCtx;
+#[cfg(target_vendor = "apple")]
 pub use mach_port::MachPortInvalidationCb;
 
 mod attributed_string;
 pub use attributed_string::AttrString;
 pub use attributed_string::AttrStringMut;
 
-#[link(name = "CoreFoundation", kind = "framework")]
+// On non-Apple targets `libCoreFoundation` of swift-corelibs-foundation
+// is linked by build script. There is no toll-free bridging with `ns` there.
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "CoreFoundation", kind = "framework")
+)]
 unsafe extern "C" {}
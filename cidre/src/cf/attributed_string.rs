@@ -1,6 +1,6 @@
 use crate::{arc, cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 define_cf_type!(
@@ -82,7 +82,7 @@ impl AttrString {
         unsafe { std::mem::transmute(CFAttributedStringCreateMutableCopy(None, 0, self)) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::AttrString {
         unsafe { std::mem::transmute(self) }
@@ -1,6 +1,6 @@
 use crate::{arc, cf, define_cf_type, define_opts};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 use super::{String, runtime::Type};
@@ -181,14 +181,14 @@ impl Null {
         unsafe { kCFNull }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Null {
         unsafe { std::mem::transmute(self) }
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl AsRef<ns::Id> for Null {
     fn as_ref(&self) -> &ns::Id {
         self.as_ns()
@@ -2,7 +2,7 @@ use std::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
 
 use crate::{arc, cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 define_cf_type!(
@@ -86,7 +86,7 @@ impl Data {
         unsafe { &*slice_from_raw_parts(self.bytes_ptr() as _, self.len()) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Data {
         unsafe { std::mem::transmute(self) }
@@ -141,7 +141,7 @@ impl DataMut {
         unsafe { &mut *slice_from_raw_parts_mut(self.bytes_ptr_mut(), self.len()) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns_mut(&mut self) -> &mut ns::DataMut {
         unsafe { std::mem::transmute(self) }
@@ -10,7 +10,7 @@ pub const ABS_TIME_INTERVAL_SINCE_1970: TimeInterval = 978307200.0;
 #[doc(alias = "kCFAbsoluteTimeIntervalSince1904")]
 pub const ABS_TIME_INTERVAL_SINCE_1904: TimeInterval = 3061152000.0;
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 /// The current absolute time.
@@ -81,7 +81,7 @@ impl Date {
         unsafe { CFDateCompare(self, other_date, context) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Date {
         unsafe { std::mem::transmute(self) }
@@ -1,4 +1,4 @@
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 #[cfg(feature = "objc")]
@@ -498,7 +498,7 @@ where
     V: objc::Obj,
 {
     /// Toll-Free Bridged
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     pub fn as_ns(&self) -> &ns::Dictionary<K, V> {
         unsafe { std::mem::transmute(self) }
     }
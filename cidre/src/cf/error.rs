@@ -1,6 +1,6 @@
 use crate::{arc, cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 #[inline]
@@ -100,7 +100,7 @@ impl Error {
         unsafe { CFErrorCopyRecoverySuggestion(self) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Error {
         unsafe { std::mem::transmute(self) }
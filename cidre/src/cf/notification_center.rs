@@ -2,7 +2,7 @@ use std::ffi::c_void;
 
 use crate::{cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 define_cf_type!(
@@ -15,7 +15,7 @@ impl NotificationName {
         unsafe { std::mem::transmute(string) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::NotificationName {
         unsafe { std::mem::transmute(self) }
@@ -1,6 +1,6 @@
 use crate::{FourCharCode, arc, cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 use crate::cf::{Allocator, ComparisonResult, Index, Type, TypeId};
@@ -452,7 +452,7 @@ impl Number {
         }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Number {
         unsafe { std::mem::transmute(self) }
@@ -277,7 +277,7 @@ impl Mode {
         unsafe { kCFRunLoopCommonModes }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::RunLoopMode {
         unsafe { std::mem::transmute(self) }
@@ -559,11 +559,11 @@ impl Timer {
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 impl Timer {
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     pub fn as_ns(&self) -> &ns::Timer {
         unsafe { std::mem::transmute(self) }
     }
@@ -691,7 +691,11 @@ mod tests {
         };
 
         assert_eq!(cf::RunLoopTimer::type_id(), timer.get_type_id());
+    }
 
+    #[cfg(feature = "blocks")]
+    #[test]
+    fn timer_with_handler() {
         let timer = cf::RunLoopTimer::with_handler(0.0, 0.0, Default::default(), 1, |_timer| {});
 
         assert_eq!(cf::RunLoopTimer::type_id(), timer.get_type_id());
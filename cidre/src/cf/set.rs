@@ -2,10 +2,10 @@ use std::{ffi::c_void, marker::PhantomData, ops::Deref};
 
 use crate::{arc, cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::objc::Obj;
 
 define_cf_type!(Set(cf::Type));
@@ -57,7 +57,7 @@ where
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl<T: Obj> SetOf<T> {
     pub fn as_ns(&self) -> &ns::Set<T> {
         unsafe { std::mem::transmute(self) }
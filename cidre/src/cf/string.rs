@@ -12,7 +12,7 @@ use crate::{
     define_cf_type, define_opts,
 };
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::{ns, objc::Obj};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -267,7 +267,7 @@ impl String {
         unsafe { self.copy_mut_in(max_length, None).unwrap_unchecked() }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::String {
         unsafe { std::mem::transmute(self) }
@@ -313,14 +313,14 @@ impl String {
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl AsRef<ns::String> for String {
     fn as_ref(&self) -> &ns::String {
         self.as_ns()
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl AsRef<ns::Id> for String {
     fn as_ref(&self) -> &ns::Id {
         self.as_ns().as_id_ref()
@@ -338,12 +338,11 @@ macro_rules! cfstr {
 
         // #[link_section = "__DATA,__cfstring"]
         static STR: $crate::cf::string::ConstStr = unsafe {
-            $crate::cf::string::ConstStr {
-                isa: &__CFConstantStringClassReference,
-                info: 0x7c8,
-                ptr: VALUE.as_ptr(),
-                len: VALUE.to_bytes().len(),
-            }
+            $crate::cf::string::ConstStr::new(
+                &__CFConstantStringClassReference,
+                VALUE.as_ptr(),
+                VALUE.to_bytes().len(),
+            )
         };
         unsafe {
             std::mem::transmute::<&'static $crate::cf::string::ConstStr, &'static $crate::cf::String>(
@@ -491,14 +490,49 @@ unsafe extern "C-unwind" {
     // fn __CFStringMakeConstantString(str: *const c_char) -> &'static String;
 }
 
+/// swift-corelibs `_CF_SWIFT_RC_PINNED_FLAG`
+#[cfg(not(target_vendor = "apple"))]
+const _CF_SWIFT_RC_PINNED_FLAG: usize = 0x1;
+
+/// swift-corelibs `_CF_CONSTANT_OBJECT_STRONG_RC`.
+///
+/// `CFRetain` and `CFRelease` skip objects with pinned Swift retain count,
+/// so static literals are never written to.
+#[cfg(not(target_vendor = "apple"))]
+pub const _CF_CONSTANT_OBJECT_STRONG_RC: usize = _CF_SWIFT_RC_PINNED_FLAG;
+
+/// Layout of `CFSTR` literal.
 #[repr(C)]
 pub struct ConstStr {
     pub isa: &'static std::ffi::c_void,
+    /// swift-corelibs `CFRuntimeBase` reserves Swift retain count after isa
+    #[cfg(not(target_vendor = "apple"))]
+    pub swift_rc: usize,
+    #[cfg(target_vendor = "apple")]
     pub info: u32,
-    pub ptr: *const i8,
+    #[cfg(not(target_vendor = "apple"))]
+    pub info: u64,
+    pub ptr: *const std::ffi::c_char,
     pub len: usize,
 }
 
+impl ConstStr {
+    pub const fn new(
+        isa: &'static std::ffi::c_void,
+        ptr: *const std::ffi::c_char,
+        len: usize,
+    ) -> Self {
+        Self {
+            isa,
+            #[cfg(not(target_vendor = "apple"))]
+            swift_rc: _CF_CONSTANT_OBJECT_STRONG_RC,
+            info: 0x7c8,
+            ptr,
+            len,
+        }
+    }
+}
+
 unsafe impl Send for ConstStr {}
 unsafe impl Sync for ConstStr {}
 
@@ -588,9 +622,12 @@ mod tests {
         let std_str = s.to_string();
         assert_eq!(std_str.chars().count(), 5);
 
-        let ns_str = s.as_ns();
-        assert_eq!(&ns_str.to_string(), "hello");
-        assert_eq!(ns_str, "hello");
+        #[cfg(all(feature = "ns", target_vendor = "apple"))]
+        {
+            let ns_str = s.as_ns();
+            assert_eq!(&ns_str.to_string(), "hello");
+            assert_eq!(ns_str, "hello");
+        }
     }
 
     #[test]
@@ -602,4 +639,18 @@ mod tests {
 
         assert_eq!(s, "nice");
     }
+
+    #[cfg(not(target_vendor = "apple"))]
+    #[test]
+    fn cfstr_retain_release() {
+        let s = cf::str!(c"constant");
+        let retain_count = s.retain_count();
+        for _ in 0..3 {
+            let r = s.retained();
+            assert_eq!(r.as_ref(), s);
+            drop(r);
+        }
+        assert_eq!(s.retain_count(), retain_count);
+        assert_eq!(s, "constant");
+    }
 }
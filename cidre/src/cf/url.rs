@@ -2,7 +2,7 @@ use std::path::Path;
 
 use crate::{arc, cf, define_cf_type};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::{ns, objc::Obj};
 
 #[doc(alias = "CFURLPathStyle")]
@@ -165,7 +165,7 @@ impl Url {
         unsafe { CFURLGetPortNumber(self) }
     }
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Url {
         unsafe { std::mem::transmute(self) }
@@ -202,21 +202,21 @@ impl Url {
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl AsRef<ns::Url> for Url {
     fn as_ref(&self) -> &ns::Url {
         self.as_ns()
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl AsRef<ns::Url> for arc::R<Url> {
     fn as_ref(&self) -> &ns::Url {
         self.as_ns()
     }
 }
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 impl AsRef<ns::Id> for Url {
     fn as_ref(&self) -> &ns::Id {
         self.as_ns().as_id_ref()
@@ -128,8 +128,11 @@ pub use display_stream::UpdateRectType as DisplayStreamUpdateRectType;
 #[cfg(target_os = "macos")]
 pub use display_stream::YCbCrMatrix as DisplayStreamYCbCrMatrix;
 
-#[link(name = "CoreGraphics", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "CoreGraphics", kind = "framework")
+)]
 unsafe extern "C" {}
 
-#[link(name = "ImageIO", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "ImageIO", kind = "framework"))]
 unsafe extern "C" {}
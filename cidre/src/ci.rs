@@ -12,7 +12,7 @@ pub use vector::Vec;
 mod barcode_descriptor;
 pub use barcode_descriptor::BarcodeDesc;
 
-#[link(name = "CoreImage", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreImage", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "ci", kind = "static")]
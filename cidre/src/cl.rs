@@ -36,7 +36,10 @@ pub use location::accuracy as location_accuracy;
 mod heading;
 pub use heading::Heading;
 
-#[link(name = "CoreLocation", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "CoreLocation", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "cl", kind = "static")]
@@ -117,7 +117,7 @@ pub mod simple_queue;
 pub use simple_queue::SimpleQueue;
 pub use simple_queue::err as simple_queue_err;
 
-#[link(name = "CoreMedia", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreMedia", kind = "framework"))]
 unsafe extern "C" {}
 
 #[cfg(all(feature = "cmio", target_os = "macos"))]
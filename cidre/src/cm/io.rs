@@ -11,5 +11,8 @@ pub use hardware::PropScope;
 pub use hardware::PropSelector;
 pub use hardware::err;
 
-#[link(name = "CoreMediaIO", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "CoreMediaIO", kind = "framework")
+)]
 unsafe extern "C" {}
@@ -2,36 +2,66 @@ mod hardware_base;
 pub use hardware_base::hardware_err;
 pub use hardware_base::*;
 
+mod hardware_selectors;
+
+#[cfg(target_os = "macos")]
 pub mod hardware;
 
+#[cfg(target_os = "macos")]
 pub use hardware::AggregateDevice;
+#[cfg(target_os = "macos")]
 pub use hardware::Device;
-#[cfg(all(feature = "blocks", feature = "dispatch"))]
+#[cfg(all(target_os = "macos", feature = "blocks", feature = "dispatch"))]
 pub use hardware::DeviceIoBlock;
+#[cfg(target_os = "macos")]
 pub use hardware::DeviceIoProc;
+#[cfg(target_os = "macos")]
 pub use hardware::DeviceIoProcId;
+#[cfg(target_os = "macos")]
 pub use hardware::Process;
-#[cfg(all(feature = "blocks", feature = "dispatch"))]
+#[cfg(all(target_os = "macos", feature = "blocks", feature = "dispatch"))]
 pub use hardware::PropListenerBlock;
+#[cfg(target_os = "macos")]
 pub use hardware::PropListenerFn;
+#[cfg(target_os = "macos")]
 pub use hardware::Stream;
+#[cfg(target_os = "macos")]
 pub use hardware::StreamDir;
+#[cfg(target_os = "macos")]
 pub use hardware::System;
+#[cfg(target_os = "macos")]
 pub use hardware::aggregate_device_keys;
+#[cfg(target_os = "macos")]
 pub use hardware::device_start;
+#[cfg(target_os = "macos")]
 pub use hardware::sub_device_keys;
 
+pub mod prop;
+pub use prop::Prop;
+
+#[cfg(target_os = "macos")]
 mod tap_description;
+#[cfg(target_os = "macos")]
 pub use tap_description::TapDesc;
+#[cfg(target_os = "macos")]
 pub use tap_description::TapMuteBehavior;
 
+#[cfg(target_os = "macos")]
 pub mod hardware_tapping;
+#[cfg(target_os = "macos")]
 pub use hardware_tapping::Tap;
-#[cfg(feature = "macos_14_2")]
+#[cfg(all(target_os = "macos", feature = "macos_14_2"))]
 pub use hardware_tapping::TapGuard;
 
-#[link(name = "CoreAudio", kind = "framework")]
+#[cfg(target_os = "macos")]
+pub mod snapshot;
+#[cfg(all(target_os = "macos", feature = "blocks", feature = "dispatch"))]
+pub use snapshot::Monitor as SnapshotMonitor;
+
+#[cfg(target_os = "macos")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreAudio", kind = "framework"))]
 unsafe extern "C" {}
 
+#[cfg(target_os = "macos")]
 #[link(name = "core_audio", kind = "static")]
 unsafe extern "C" {}
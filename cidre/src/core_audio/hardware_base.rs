@@ -1,5 +1,5 @@
 use crate::{
-    at::audio::{StreamBasicDesc, ValueRange},
+    cat::audio::{StreamBasicDesc, ValueRange},
     four_cc_fmt_debug,
 };
 
@@ -273,6 +273,10 @@ impl Class {
     #[doc(alias = "kAudioEndPointClassID")]
     pub const END_POINT: Self = Self(u32::from_be_bytes(*b"endp"));
 
+    /// The AudioClassID that identifies the Process class.
+    #[doc(alias = "kAudioProcessClassID")]
+    pub const PROCESS: Self = Self(u32::from_be_bytes(*b"clnt"));
+
     /// The AudioClassId that identifies the AudioStream class.
     #[doc(alias = "kAudioStreamClassID")]
     pub const STREAM: Self = Self(u32::from_be_bytes(*b"astr"));
@@ -402,6 +406,62 @@ impl Class {
     pub const AGGREGATE_DEVICE: Self = Self(u32::from_be_bytes(*b"aagg"));
 }
 
+impl Class {
+    /// Class this one is derived from.
+    ///
+    /// `None` for [`Class::OBJECT`] and classes unknown to the headers,
+    /// use `kAudioObjectPropertyBaseClass` of the object for those.
+    pub const fn base(self) -> Option<Self> {
+        Some(match self {
+            Self::SYSTEM
+            | Self::PLUG_IN
+            | Self::BOX
+            | Self::DEVICE
+            | Self::TAP
+            | Self::SUB_TAP
+            | Self::PROCESS
+            | Self::STREAM
+            | Self::CLOCK
+            | Self::CONTROL => Self::OBJECT,
+            Self::TRANSPORT_MANAGER => Self::PLUG_IN,
+            Self::SUB_DEVICE | Self::END_POINT | Self::AGGREGATE_DEVICE => Self::DEVICE,
+            Self::SLIDER_CONTROL
+            | Self::LEVEL_CONTROL
+            | Self::BOOLEAN_CONTROL
+            | Self::SELECTOR_CONTROL
+            | Self::STEREO_PAN_CONTROL => Self::CONTROL,
+            Self::VOLUME_CONTROL | Self::LFE_VOLUME_CONTROL => Self::LEVEL_CONTROL,
+            Self::MUTE_CONTROL
+            | Self::SOLO_CONTROL
+            | Self::JACK_CONTROL
+            | Self::LFE_MUTE_CONTROL
+            | Self::PHANTOM_POWER_CONTROL
+            | Self::PHASE_INVERT_CONTROL
+            | Self::CLIP_LIGHT_CONTROL
+            | Self::TALKBACK_CONTROL
+            | Self::LISTENBACK_CONTROL => Self::BOOLEAN_CONTROL,
+            Self::DATA_SRC_CONTROL
+            | Self::DATA_DST_CONTROL
+            | Self::CLOCK_SRC_CONTROL
+            | Self::LINE_LEVEL_CONTROL
+            | Self::HIGH_PASS_FILTER_CONTROL => Self::SELECTOR_CONTROL,
+            _ => return None,
+        })
+    }
+
+    /// Whether `self` is `class` or is derived from it.
+    pub fn is_kind_of(self, class: Self) -> bool {
+        let mut cur = Some(self);
+        while let Some(c) = cur {
+            if c == class {
+                return true;
+            }
+            cur = c.base();
+        }
+        false
+    }
+}
+
 impl PropSelector {
     #[doc(alias = "kAudioObjectPropertySelectorWildcard")]
     pub const WILDCARD: Self = Self(u32::from_be_bytes(*b"****"));
@@ -969,3 +1029,33 @@ impl PropSelector {
     pub const CLOCK_DEVICE_AVAILABLE_NOMINAL_SAMPLE_RATES: Self =
         Self(u32::from_be_bytes(*b"nsr#"));
 }
+
+/// AudioControl Properties
+impl PropSelector {
+    /// The AudioObjectPropertyScope in the owning AudioObject that contains the
+    /// AudioControl.
+    #[doc(alias = "kAudioControlPropertyScope")]
+    pub const CONTROL_SCOPE: Self = Self(u32::from_be_bytes(*b"cscp"));
+
+    /// The AudioObjectPropertyElement in the owning AudioObject that contains the
+    /// AudioControl.
+    #[doc(alias = "kAudioControlPropertyElement")]
+    pub const CONTROL_ELEMENT: Self = Self(u32::from_be_bytes(*b"celm"));
+
+    /// A f32 that represents the value of the LevelControl. The range is between
+    /// 0.0 and 1.0 (inclusive).
+    #[doc(alias = "kAudioLevelControlPropertyScalarValue")]
+    pub const LEVEL_CONTROL_SCALAR_VALUE: Self = Self(u32::from_be_bytes(*b"lcsv"));
+
+    /// A f32 that represents the value of the LevelControl in dB.
+    #[doc(alias = "kAudioLevelControlPropertyDecibelValue")]
+    pub const LEVEL_CONTROL_DECIBEL_VALUE: Self = Self(u32::from_be_bytes(*b"lcdv"));
+
+    /// A u32 where 0 means false and 1 means true.
+    #[doc(alias = "kAudioBooleanControlPropertyValue")]
+    pub const BOOLEAN_CONTROL_VALUE: Self = Self(u32::from_be_bytes(*b"bcvl"));
+
+    /// An array of u32s that are the IDs of the items currently selected.
+    #[doc(alias = "kAudioSelectorControlPropertyCurrentItem")]
+    pub const SELECTOR_CONTROL_CURRENT_ITEM: Self = Self(u32::from_be_bytes(*b"scci"));
+}
@@ -0,0 +1,671 @@
+//! AudioObjectPropertySelector values of AudioHardware.h.
+//!
+//! Plain constants kept apart from the macOS only `hardware` module,
+//! so [`prop::CATALOG`](super::prop::CATALOG) is available on every platform.
+
+use crate::core_audio::PropSelector;
+
+/// Processes AudioObjectPropertySelector values provided by the Process class.
+impl PropSelector {
+    /// A pid_t indicating the process ID associated with the process.
+    #[doc(alias = "kAudioProcessPropertyPID")]
+    pub const PROCESS_PID: Self = Self(u32::from_be_bytes(*b"ppid"));
+
+    /// A cf::String that contains the bundle ID of the process. The caller is
+    /// responsible for releasing the returned cf::Object.
+    #[doc(alias = "kAudioProcessPropertyBundleID")]
+    pub const PROCESS_BUNDLE_ID: Self = Self(u32::from_be_bytes(*b"pbid"));
+
+    /// An array of AudioObjectIds that represent the devices currently used by the
+    /// process for input or used by the process for output. The scope will select
+    /// the input or output device list.
+    pub const PROCESS_DEVICES: Self = Self(u32::from_be_bytes(*b"pdv#"));
+
+    /// A u32 where a value of 0 indicates that there is not audio IO in progress
+    /// in the process, and a value of 1 indicates that there is audio IO in progress
+    /// in the process. Note that audio IO may in progress even if no input or output
+    /// streams are active.
+    pub const PROCESS_IS_RUNNING: Self = Self(u32::from_be_bytes(*b"pir?"));
+
+    /// A u32 where a value of 0 indicates that the process is not running any
+    /// IO or there is not any active input streams, and a value of 1 indicates that
+    /// the process is running IO and there is at least one active input stream.
+    pub const PROCESS_IS_RUNNING_INPUT: Self = Self(u32::from_be_bytes(*b"piri"));
+
+    /// A u32 where a value of 0 indicates that the process is not running any
+    /// IO or there is not any active output streams, and a value of 1 indicates that
+    /// the process is running IO and there is at least one active output stream.
+    pub const PROCESS_IS_RUNNING_OUTPUT: Self = Self(u32::from_be_bytes(*b"piro"));
+}
+
+/// AudioSystemObject Properties
+impl PropSelector {
+    /// A u32 where a non-zero value indicates that all data coming into the process
+    /// for all devices will be silent. A value of 0 indicates that input data will be
+    /// received normally.
+    #[doc(alias = "kAudioHardwarePropertyProcessInputMute")]
+    pub const HW_PROCESS_INPUT_MUTE: Self = Self(u32::from_be_bytes(*b"pmin"));
+
+    /// An array of the AudioObjectIds that represent all the devices currently
+    /// available to the system.
+    #[doc(alias = "kAudioHardwarePropertyDevices")]
+    pub const HW_DEVICES: Self = Self(u32::from_be_bytes(*b"dev#"));
+
+    /// The AudioObjectId of the default input AudioDevice.
+    #[doc(alias = "kAudioHardwarePropertyDefaultInputDevice")]
+    pub const HW_DEFAULT_INPUT_DEVICE: Self = Self(u32::from_be_bytes(*b"dIn "));
+
+    /// The AudioObjectId of the default output AudioDevice.
+    #[doc(alias = "kAudioHardwarePropertyDefaultOutputDevice")]
+    pub const HW_DEFAULT_OUTPUT_DEVICE: Self = Self(u32::from_be_bytes(*b"dOut"));
+
+    /// The AudioObjectId of the output AudioDevice to use for system related sound
+    /// from the alert sound to digital call progress.
+    #[doc(alias = "kAudioHardwarePropertyDefaultSystemOutputDevice")]
+    pub const HW_DEFAULT_SYS_OUTPUT_DEVICE: Self = Self(u32::from_be_bytes(*b"sOut"));
+
+    /// This property fetches the AudioObjectId that corresponds to the AudioDevice
+    /// that has the given UID. The UID is passed in via the qualifier as a cf::String
+    /// while the AudioObjectId for the AudioDevice is returned to the caller as the
+    /// property's data. Note that an error is not returned if the UID doesn't refer
+    /// to any AudioDevices. Rather, this property will return kAudioObjectUnknown
+    /// as the value of the property.
+    #[doc(alias = "kAudioHardwarePropertyTranslateUIDToDevice")]
+    pub const HW_TRANSLATE_UID_TO_DEVICE: Self = Self(u32::from_be_bytes(*b"uidd"));
+
+    /// An array of AudioObjectIds that represent the Process objects for all client processes
+    /// currently connected to the system.
+    #[doc(alias = "kAudioHardwarePropertyProcessObjectList")]
+    pub const HW_PROCESS_OBJ_LIST: Self = Self(u32::from_be_bytes(*b"prs#"));
+
+    /// This property fetches the AudioObjectID that corresponds to the Process object
+    /// that has the given PID. The PID is passed in via the qualifier as a pid_t
+    /// while the AudioObjectID for the Process is returned to the caller as the
+    /// property's data. Note that an error is not returned if the PID doesn't refer
+    /// to any Process. Rather, this property will return kAudioObjectUnknown
+    /// as the value of the property.
+    #[doc(alias = "kAudioHardwarePropertyTranslatePIDToProcessObject")]
+    pub const HW_TRANSLATE_PID_TO_PROCESS_OBJ: Self = Self(u32::from_be_bytes(*b"id2p"));
+
+    /// An array of AudioObjectIDs that represent all the AudioClockDevice objects
+    /// currently provided by the system.
+    #[doc(alias = "kAudioHardwarePropertyClockDeviceList")]
+    pub const HW_CLOCK_DEVICE_LIST: Self = Self(u32::from_be_bytes(*b"clk#"));
+
+    /// An array of AudioObjectIDs that represent the Tap objects on the system.
+    #[doc(alias = "kAudioHardwarePropertyTapList")]
+    pub const HW_TAP_LIST: Self = Self(u32::from_be_bytes(*b"tps#"));
+}
+
+/// AudioAggregateDevice Properties
+impl PropSelector {
+    /// A CFArray of CFStrings that contain the UIDs of all the devices, active or
+    /// inactive, contained in the AudioAggregateDevice. The order of the items in
+    /// the array is significant and is used to determine the order of the streams
+    /// of the AudioAggregateDevice. The caller is responsible for releasing the
+    /// returned CFObject.
+    #[doc(alias = "kAudioAggregateDevicePropertyFullSubDeviceList")]
+    pub const AGGREGATE_DEVICE_FULL_SUB_DEVICE_LIST: Self = Self(u32::from_be_bytes(*b"grup"));
+
+    /// An array of AudioObjectIDs for all the active sub-devices in the aggregate
+    /// device.
+    #[doc(alias = "kAudioAggregateDevicePropertyActiveSubDeviceList")]
+    pub const AGGREGATE_DEVICE_ACTIVE_SUB_DEVICE_LIST: Self = Self(u32::from_be_bytes(*b"agrp"));
+
+    /// A CFDictionary that describes the composition of the AudioAggregateDevice.
+    /// The keys for this CFDicitionary are defined in the AudioAggregateDevice
+    /// Constants section. The caller is responsible for releasing the returned CFObject.
+    #[doc(alias = "kAudioAggregateDevicePropertyComposition")]
+    pub const AGGREGATE_DEVICE_COMPOSITION: Self = Self(u32::from_be_bytes(*b"acom"));
+
+    /// A CFString that contains the UID for the AudioDevice that is currently
+    /// serving as the time base of the aggregate device. The caller is
+    /// responsible for releasing the returned CFObject.
+    #[doc(alias = "kAudioAggregateDevicePropertyMainSubDevice")]
+    pub const AGGREGATE_DEVICE_MAIN_SUB_DEVICE: Self = Self(u32::from_be_bytes(*b"amst"));
+
+    /// A CFString that contains the UID for the AudioClockDevice that is currently
+    /// serving as the time base of the aggregate device. If the aggregate
+    /// device includes both a main audio device and a clock device, the clock
+    /// device will control the time base. Setting this property will enable
+    /// drift correction for all subdevices in the aggregate device. The caller is
+    /// responsible for releasing the returned CFObject.
+    #[doc(alias = "kAudioAggregateDevicePropertyClockDevice")]
+    pub const AGGREGATE_DEVICE_CLOCK_DEVICE: Self = Self(u32::from_be_bytes(*b"apcd"));
+
+    /// A CFArray of CFStrings that contain the UUIDs of all the tap objects in the
+    /// contained in the AudioAggregateDevice.
+    #[doc(alias = "kAudioAggregateDevicePropertyTapList")]
+    pub const AGGREGATE_DEVICE_TAP_LIST: Self = Self(u32::from_be_bytes(*b"tap#"));
+
+    /// An array of AudioObjectIDs for all the active sub-taps in the aggregate device.
+    #[doc(alias = "kAudioAggregateDevicePropertySubTapList")]
+    pub const AGGREGATE_DEVICE_SUB_TAP_LIST: Self = Self(u32::from_be_bytes(*b"atap"));
+}
+
+/// AudioDevice Properties
+impl PropSelector {
+    /// An os::Status that contains any error codes generated by loading the IOAudio
+    /// driver plug-in for the AudioDevice or kAudioHardwareNoError if the plug-in
+    /// loaded successfully. This property only exists for IOAudio-based
+    /// AudioDevices whose driver has specified a plug-in to load.
+    #[doc(alias = "kAudioDevicePropertyPlugIn")]
+    pub const DEVICE_PLUG_IN: Self = Self(u32::from_be_bytes(*b"plug"));
+
+    /// The type of this property is a u32, but its value has no meaning. This
+    /// property exists so that clients can listen to it and be told when the
+    /// configuration of the AudioDevice has changed in ways that cannot otherwise
+    /// be conveyed through other notifications. In response to this notification,
+    /// clients should re-evaluate everything they need to know about the device,
+    /// particularly the layout and values of the controls.
+    #[doc(alias = "kAudioDevicePropertyDeviceHasChanged")]
+    pub const DEVICE_HAS_CHANGED: Self = Self(u32::from_be_bytes(*b"diff"));
+
+    /// u32 where 1 means that the AudioDevice is running in at least one
+    /// process on the system and 0 means that it isn't running at all.
+    #[doc(alias = "kAudioDevicePropertyDeviceIsRunningSomewhere")]
+    pub const DEVICE_IS_RUNNING_SOMEWHERE: Self = Self(u32::from_be_bytes(*b"gone"));
+
+    /// A u32 where the value has no meaning. This property exists so that
+    /// clients can be notified when the AudioDevice detects that an IO cycle has
+    /// run past its deadline. Note that the notification for this property is
+    /// usually sent from the AudioDevice's IO thread.
+    #[doc(alias = "kAudioDeviceProcessorOverload")]
+    pub const DEVICE_PROCESSOR_OVERLOAD: Self = Self(u32::from_be_bytes(*b"over"));
+
+    /// A u32 where the value has no meaning. This property exists so that
+    /// clients can be notified when IO on the device has stopped outside of the
+    /// normal mechanisms. This typically comes up when IO is stopped after
+    /// AudioDeviceStart has returned successfully but prior to the notification for
+    /// kAudioDevicePropertyIsRunning being sent.
+    #[doc(alias = "kAudioDevicePropertyIOStoppedAbnormally")]
+    pub const DEVICE_IO_STOPPED_ABNORMALLY: Self = Self(u32::from_be_bytes(*b"stpd"));
+
+    /// A pid_t indicating the process that currently owns exclusive access to the
+    /// AudioDevice or a value of -1 indicating that the device is currently
+    /// available to all processes.
+    ///
+    /// If the AudioDevice is in a non-mixable mode,
+    /// the HAL will automatically take hog mode on behalf of the first process to
+    /// start an IOProc.
+    ///
+    /// Note that when setting this property, the value passed in is ignored. If
+    /// another process owns exclusive access, that remains unchanged. If the
+    /// current process owns exclusive access, it is released and made available to
+    /// all processes again. If no process has exclusive access (meaning the current
+    /// value is -1), this process gains ownership of exclusive access.  On return,
+    /// the pid_t pointed to by inPropertyData will contain the new value of the
+    /// property.
+    #[doc(alias = "kAudioDevicePropertyHogMode")]
+    pub const DEVICE_HOG_MODE: Self = Self(u32::from_be_bytes(*b"oink"));
+
+    /// A u32 whose value indicates the number of frames in the IO buffers.
+    #[doc(alias = "kAudioDevicePropertyBufferFrameSize")]
+    pub const DEVICE_BUF_FRAME_SIZE: Self = Self(u32::from_be_bytes(*b"fsiz"));
+
+    /// An AudioValueRange indicating the minimum and maximum values, inclusive, for
+    /// kAudioDevicePropertyBufferFrameSize.
+    #[doc(alias = "kAudioDevicePropertyBufferFrameSizeRange")]
+    pub const DEVICE_BUF_FRAME_SIZE_RANGE: Self = Self(u32::from_be_bytes(*b"fsz#"));
+
+    /// A u32 that, if implemented by a device, indicates that the sizes of the
+    /// buffers passed to an IOProc will vary by a small amount. The value of this
+    /// property will indicate the largest buffer that will be passed and
+    /// kAudioDevicePropertyBufferFrameSize will indicate the smallest buffer that
+    /// will get passed to the IOProc. The usage of this property is narrowed to
+    /// only allow for devices whose buffer sizes vary by small amounts greater than
+    /// kAudioDevicePropertyBufferFrameSize. It is not intended to be a license for
+    /// devices to be able to send buffers however they please. Rather, it is
+    /// intended to allow for hardware whose natural rhythms lead to this necessity.
+    #[doc(alias = "kAudioDevicePropertyUsesVariableBufferFrameSizes")]
+    pub const DEVICE_USES_VARIABLE_BUF_FRAME_SIZES: Self = Self(u32::from_be_bytes(*b"vfsz"));
+
+    /// A f32 whose range is from 0 to 1. This value indicates how much of the
+    /// client portion of the IO cycle the process will use. The client portion of
+    /// the IO cycle is the portion of the cycle in which the device calls the
+    /// IOProcs so this property does not the apply to the duration of the entire
+    /// cycle.
+    #[doc(alias = "kAudioDevicePropertyIOCycleUsage")]
+    pub const DEVICE_IO_CYCLE_USAGE: Self = Self(u32::from_be_bytes(*b"ncyc"));
+
+    /// This property returns the stream configuration of the device in an
+    /// AudioBufListN (with the buffer pointers set to NULL) which describes the
+    /// list of streams and the number of channels in each stream. This corresponds
+    /// to what will be passed into the IOProc.
+    #[doc(alias = "kAudioDevicePropertyStreamConfiguration")]
+    pub const DEVICE_STREAM_CFG: Self = Self(u32::from_be_bytes(*b"slay"));
+
+    /// An AudioHardwareIOProcStreamUsage structure which details the stream usage
+    /// of a given IO proc. If a stream is marked as not being used, the given
+    /// IOProc will see a corresponding NULL buffer pointer in the AudioBufferList
+    /// passed to its IO proc. Note that the number of streams detailed in the
+    /// AudioHardwareIOProcStreamUsage must include all the streams of that
+    /// direction on the device. Also, when getting the value of the property, one
+    /// must fill out the mIOProc field of the AudioHardwareIOProcStreamUsage with
+    /// the address of the of the IOProc whose stream usage is to be retrieved.
+    #[doc(alias = "kAudioDevicePropertyIOProcStreamUsage")]
+    pub const DEVICE_IO_PROC_STREAM_USAGE: Self = Self(u32::from_be_bytes(*b"suse"));
+
+    /// A f64 that indicates the current actual sample rate of the AudioDevice
+    /// as measured by its time stamps.
+    #[doc(alias = "kAudioDevicePropertyActualSampleRate")]
+    pub const DEVICE_ACTUAL_SAMPLE_RATE: Self = Self(u32::from_be_bytes(*b"asrt"));
+
+    /// A cf::String that contains the UID for the AudioClockDevice that is currently
+    /// serving as the main time base of the device. The caller is responsible
+    /// for releasing the returned cf::String.
+    #[doc(alias = "kAudioDevicePropertyClockDevice")]
+    pub const DEVICE_CLOCK_DEVICE: Self = Self(u32::from_be_bytes(*b"apcd"));
+
+    /// An os_workgroup_t that represents the thread workgroup the AudioDevice's
+    /// IO thread belongs to. The caller is responsible for releasing the returned
+    /// object.
+    #[doc(alias = "kAudioDevicePropertyIOThreadOSWorkgroup")]
+    pub const DEVICE_IO_THREAD_OS_WORKGROUP: Self = Self(u32::from_be_bytes(*b"oswg"));
+
+    /// A u32 where a non-zero value indicates that the current process's audio
+    /// will be zeroed out by the system. Note that this property does not apply to
+    /// aggregate devices, just real, physical devices.
+    #[doc(alias = "kAudioDevicePropertyProcessMute")]
+    pub const DEVICE_PROCESS_MUTE: Self = Self(u32::from_be_bytes(*b"appm"));
+}
+
+/// AudioDevice Properties Implemented via AudioControl objects
+///
+/// AudioObjectPropertySelector values for AudioDevice properties that are
+/// implemented by AudioControl objects.
+///
+/// These properties are also accessible by locating the AudioControl object
+/// attached to the AudioDevice and using that object to access the properties of
+/// the control.
+impl PropSelector {
+    /// A u32 where a value of 0 means that there isn't anything plugged into the
+    /// jack associated withe given element and scope. This property is implemented
+    /// by an AudioJackControl, a subclass of AudioBooleanControl.
+    #[doc(alias = "kAudioDevicePropertyJackIsConnected")]
+    pub const DEVICE_JACK_IS_CONNECTED: Self = Self(u32::from_be_bytes(*b"jack"));
+
+    /// A f32 that represents the value of the volume control. The range is
+    /// between 0.0 and 1.0 (inclusive). Note that the set of all Float32 values
+    /// between 0.0 and 1.0 inclusive is much larger than the set of actual values
+    /// that the hardware can select. This means that the Float32 range has a many
+    /// to one mapping with the underlying hardware values. As such, setting a
+    /// scalar value will result in the control taking on the value nearest to what
+    /// was set. This property is implemented by an AudioControl object that is a
+    /// subclass of AudioVolumeControl.
+    #[doc(alias = "kAudioDevicePropertyVolumeScalar")]
+    pub const DEVICE_VOLUME_SCALAR: Self = Self(u32::from_be_bytes(*b"volm"));
+
+    /// A f32 that represents the value of the volume control in dB. Note that
+    /// the set of all f32 values in the dB range for the control is much larger
+    /// than the set of actual values that the hardware can select. This means that
+    /// the f32 range has a many to one mapping with the underlying hardware
+    /// values. As such, setting a dB value will result in the control taking on the
+    /// value nearest to what was set. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioVolumeControl.
+    #[doc(alias = "kAudioDevicePropertyVolumeDecibels")]
+    pub const DEVICE_VOLUME_DECIBELS: Self = Self(u32::from_be_bytes(*b"vold"));
+
+    /// An AudioValueRange that contains the minimum and maximum dB values the
+    /// control can have. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioVolumeControl.
+    #[doc(alias = "kAudioDevicePropertyVolumeRangeDecibels")]
+    pub const DEVICE_VOLUME_RANGE_DECIBELS: Self = Self(u32::from_be_bytes(*b"vdb#"));
+
+    /// A f32 that on input contains a scalar volume value for the and on exit
+    /// contains the equivalent dB value. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioVolumeControl.
+    #[doc(alias = "kAudioDevicePropertyVolumeScalarToDecibels")]
+    pub const DEVICE_VOLUME_SCALAR_TO_DECIBELS: Self = Self(u32::from_be_bytes(*b"v2db"));
+
+    /// A f32 that on input contains a dB volume value for the and on exit
+    /// contains the equivalent scalar value. This property is implemented by
+    /// AudioControl object that is a subclass of AudioVolumeControl.
+    #[doc(alias = "kAudioDevicePropertyVolumeDecibelsToScalar")]
+    pub const DEVICE_VOLUME_DECIBELS_TO_SCALAR: Self = Self(u32::from_be_bytes(*b"db2v"));
+
+    /// A f32 where 0.0 is full left, 1.0 is full right, and 0.5 is center. This
+    /// property is implemented by an AudioControl object that is a subclass of
+    /// AudioStereoPanControl.
+    #[doc(alias = "kAudioDevicePropertyStereoPan")]
+    pub const DEVICE_STEREO_PAN: Self = Self(u32::from_be_bytes(*b"span"));
+
+    /// An array of two u32s that indicate which elements of the owning object
+    /// the signal is being panned between. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioStereoPanControl.
+    #[doc(alias = "kAudioDevicePropertyStereoPanChannels")]
+    pub const DEVICE_STEREO_PAN_CHANNELS: Self = Self(u32::from_be_bytes(*b"spn#"));
+
+    /// A u32 where a value of 1 means that mute is enabled making that element
+    /// inaudible. The property is implemented by an AudioControl object that is a
+    /// subclass of AudioMuteControl.
+    #[doc(alias = "kAudioDevicePropertyMute")]
+    pub const DEVICE_MUTE: Self = Self(u32::from_be_bytes(*b"mute"));
+
+    /// A u32 where a value of 1 means that just that element is audible and the
+    /// other elements are inaudible. The property is implemented by an AudioControl
+    /// object that is a subclass of AudioSoloControl.
+    #[doc(alias = "kAudioDevicePropertySolo")]
+    pub const DEVICE_SOLO: Self = Self(u32::from_be_bytes(*b"solo"));
+
+    /// A u32 where a value of 1 means that the AudioDevice has enabled phantom
+    /// power for the given element. The property is implemented by an AudioControl
+    /// object that is a subclass of AudioPhantomPowerControl.
+    #[doc(alias = "kAudioDevicePropertyPhantomPower")]
+    pub const DEVICE_PHANTOM_POWER: Self = Self(u32::from_be_bytes(*b"phan"));
+
+    /// A u32 where a value of 1 means that phase of the signal for the given
+    /// element has been flipped 180 degrees. The property is implemented by an
+    /// AudioControl object that is a subclass of AudioPhaseInvertControl.
+    #[doc(alias = "kAudioDevicePropertyPhaseInvert")]
+    pub const DEVICE_PHASE_INVERT: Self = Self(u32::from_be_bytes(*b"phsi"));
+
+    /// A u32 where a value of 1 means that the signal for the element has
+    /// exceeded the sample range. Once a clip light is turned on, it is to stay on
+    /// until either the value of the control is set to false or the current IO
+    /// session stops and a new IO session starts. The property is implemented by an
+    /// AudioControl object that is a subclass of AudioClipLightControl.
+    #[doc(alias = "kAudioDevicePropertyClipLight")]
+    pub const DEVICE_CLIP_LIGHT: Self = Self(u32::from_be_bytes(*b"clip"));
+
+    /// A u32 where a value of 1 means that the talkback channel is enabled. The
+    /// property is implemented by an AudioControl object that is a subclass of
+    /// AudioTalkbackControl.
+    #[doc(alias = "kAudioDevicePropertyTalkback")]
+    pub const DEVICE_TALKBACK: Self = Self(u32::from_be_bytes(*b"talb"));
+
+    /// A u32 where a value of 1 means that the listenback channel is enabled.
+    /// The property is implemented by an AudioControl object that is a subclass of
+    /// AudioListenbackControl.
+    #[doc(alias = "kAudioDevicePropertyListenback")]
+    pub const DEVICE_LISTENBACK: Self = Self(u32::from_be_bytes(*b"lsnb"));
+
+    /// An array of u32s whose values are the item IDs for the currently selected
+    /// data sources. This property is implemented by an AudioControl object that is
+    /// a subclass of AudioDataSourceControl.
+    #[doc(alias = "kAudioDevicePropertyDataSource")]
+    pub const DEVICE_DATA_SRC: Self = Self(u32::from_be_bytes(*b"ssrc"));
+
+    /// An array of u32s that are represent all the IDs of all the data sources
+    /// currently available. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioDataSourceControl.
+    #[doc(alias = "kAudioDevicePropertyDataSources")]
+    pub const DEVICE_DATA_SRCS: Self = Self(u32::from_be_bytes(*b"ssc#"));
+
+    /// This property translates the given data source item ID into a human readable
+    /// name using an AudioValueTranslation structure. The input data is the u32
+    /// containing the item ID to translated and the output data is a cf::String. The
+    /// caller is responsible for releasing the returned cf::Object. This property is
+    /// implemented by an AudioControl object that is a subclass of
+    /// AudioDataSourceControl.
+    #[doc(alias = "kAudioDevicePropertyDataSourceNameForIDCFString")]
+    pub const DEVICE_DATA_SRC_NAME_FOR_IDCF_STR: Self = Self(u32::from_be_bytes(*b"lscn"));
+
+    /// This property returns a u32 that identifies the kind of data source
+    /// the item ID refers to using an AudioValueTranslation structure. The input
+    /// data is the u32 containing the item ID and the output data is the u32.
+    #[doc(alias = "kAudioDevicePropertyDataSourceKindForID")]
+    pub const DEVICE_DATA_SRC_KIND_FOR_ID: Self = Self(u32::from_be_bytes(*b"ssck"));
+
+    /// An array of u32s whose values are the item IDs for the currently selected
+    /// clock sources. This property is implemented by an AudioControl object that
+    /// is a subclass of AudioClockControl.
+    #[doc(alias = "kAudioDevicePropertyClockSource")]
+    pub const DEVICE_CLOCK_SRC: Self = Self(u32::from_be_bytes(*b"csrc"));
+
+    /// An array of u32s that are represent all the IDs of all the clock sources
+    /// currently available. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioClockControl.
+    #[doc(alias = "kAudioDevicePropertyClockSources")]
+    pub const DEVICE_CLOCK_SRCS: Self = Self(u32::from_be_bytes(*b"csc#"));
+
+    /// This property translates the given clock source item ID into a human
+    /// readable name using an AudioValueTranslation structure. The input data is
+    /// the u32 containing the item ID to translated and the output data is a
+    /// cf::String. The caller is responsible for releasing the returned cf::Object.
+    /// This property is implemented by an AudioControl object that is a subclass of
+    /// AudioClockControl.
+    #[doc(alias = "kAudioDevicePropertyClockSourceNameForIDCFString")]
+    pub const DEVICE_CLOCK_SRC_NAME_FOR_IDCF_STR: Self = Self(u32::from_be_bytes(*b"lcsn"));
+
+    /// This property returns a u32 that identifies the kind of clock source
+    /// the item ID refers to using an AudioValueTranslation structure. The input
+    /// data is the u32 containing the item ID and the output data is the u32.
+    #[doc(alias = "kAudioDevicePropertyClockSourceKindForID")]
+    pub const DEVICE_CLOCK_SRC_KIND_FOR_ID: Self = Self(u32::from_be_bytes(*b"csck"));
+
+    /// A u32 where a value of 0 means that play through is off and a value of 1
+    /// means that it is on. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioMuteControl. Further, the control that implements
+    /// this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThru")]
+    pub const DEVICE_PLAY_THRU: Self = Self(u32::from_be_bytes(*b"thru"));
+
+    /// A u32 where a value of 1 means that just that play through element is
+    /// audible and the other elements are inaudible. The property is implemented by
+    /// an AudioControl object that is a subclass of AudioSoloControl. Further, the
+    /// control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruSolo")]
+    pub const DEVICE_PLAY_THRU_SOLO: Self = Self(u32::from_be_bytes(*b"thrs"));
+
+    /// A f32 that represents the value of the volume control. The range is
+    /// between 0.0 and 1.0 (inclusive). Note that the set of all f32 values
+    /// between 0.0 and 1.0 inclusive is much larger than the set of actual values
+    /// that the hardware can select. This means that the f32 range has a many
+    /// to one mapping with the underlying hardware values. As such, setting a
+    /// scalar value will result in the control taking on the value nearest to what
+    /// was set. This property is implemented by an AudioControl object that is a
+    /// subclass of AudioVolumeControl.Further, the control that implements this
+    /// property is only available through kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruVolumeScalar")]
+    pub const DEVICE_PLAY_THRU_VOLUME_SCALAR: Self = Self(u32::from_be_bytes(*b"mvsc"));
+
+    /// A f32 that represents the value of the volume control in dB. Note that
+    /// the set of all f32 values in the dB range for the control is much larger
+    /// than the set of actual values that the hardware can select. This means that
+    /// the f32 range has a many to one mapping with the underlying hardware
+    /// values. As such, setting a dB value will result in the control taking on the
+    /// value nearest to what was set. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioVolumeControl. Further, the
+    /// control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruVolumeDecibels")]
+    pub const DEVICE_PLAY_THRU_VOLUME_DECIBELS: Self = Self(u32::from_be_bytes(*b"mvdb"));
+
+    /// An AudioValueRange that contains the minimum and maximum dB values the
+    /// control can have. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioVolumeControl. Further, the control that
+    /// implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruVolumeRangeDecibels")]
+    pub const DEVICE_PLAY_THRU_VOLUME_RANGE_DECIBELS: Self = Self(u32::from_be_bytes(*b"mvd#"));
+
+    /// A f32 that on input contains a scalar volume value for the and on exit
+    /// contains the equivalent dB value. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioVolumeControl. Further, the
+    /// control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruVolumeScalarToDecibels")]
+    pub const DEVICE_PLAY_THRU_VOLUME_SCALAR_TO_DECIBELS: Self = Self(u32::from_be_bytes(*b"mv2d"));
+
+    /// A f32 that on input contains a dB volume value for the and on exit
+    /// contains the equivalent scalar value. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioVolumeControl. Further, the
+    /// control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruVolumeDecibelsToScalar")]
+    pub const DEVICE_PLAY_THRU_VOLUME_DECIBELS_TO_SCALAR: Self = Self(u32::from_be_bytes(*b"mv2s"));
+
+    /// A f32 where 0.0 is full left, 1.0 is full right, and 0.5 is center. This
+    /// property is implemented by an AudioControl object that is a subclass of
+    /// AudioStereoPanControl. Further, the control that implements this property is
+    /// only available through kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruStereoPan")]
+    pub const DEVICE_PLAY_THRU_STEREO_PAN: Self = Self(u32::from_be_bytes(*b"mspn"));
+
+    /// An array of two u32s that indicate which elements of the owning object
+    /// the signal is being panned between. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioStereoPanControl. Further,
+    /// the control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruStereoPanChannels")]
+    pub const DEVICE_PLAY_THRU_STEREO_PAN_CHANNELS: Self = Self(u32::from_be_bytes(*b"msp#"));
+
+    /// An array of u32s whose values are the item IDs for the currently selected
+    /// play through data destinations. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioDataDestinationControl.
+    /// Further, the control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruDestination")]
+    pub const DEVICE_PLAY_THRU_DST: Self = Self(u32::from_be_bytes(*b"mdds"));
+
+    /// An array of u32s that are represent all the IDs of all the play through
+    /// data destinations currently available. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioDataDestinationControl.
+    /// Further, the control that implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruDestinations")]
+    pub const DEVICE_PLAY_THRU_DSTS: Self = Self(u32::from_be_bytes(*b"mdd#"));
+
+    /// This property translates the given play through data destination item ID
+    /// into a human readable name using an AudioValueTranslation structure. The
+    /// input data is the u32 containing the item ID to translated and the output
+    /// data is a cf::String. The caller is responsible for releasing the returned
+    /// cf::Object. This property is implemented by an AudioControl object that is a
+    /// subclass of AudioDataDestinationControl. Further, the control that
+    /// implements this property is only available through
+    /// kAudioDevicePropertyScopePlayThrough.
+    #[doc(alias = "kAudioDevicePropertyPlayThruDestinationNameForIDCFString")]
+    pub const DEVICE_PLAY_THRU_DST_NAME_FOR_IDCF_STR: Self = Self(u32::from_be_bytes(*b"mddc"));
+
+    /// An array of u32s whose values are the item IDs for the currently selected
+    /// nominal line levels. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioLineLevelControl.
+    #[doc(alias = "kAudioDevicePropertyChannelNominalLineLevel")]
+    pub const DEVICE_CHANNEL_NOMINAL_LINE_LEVEL: Self = Self(u32::from_be_bytes(*b"nlvl"));
+
+    /// An array of u32s that represent all the IDs of all the nominal line
+    /// levels currently available. This property is implemented by an AudioControl
+    /// object that is a subclass of AudioLineLevelControl.
+    #[doc(alias = "kAudioDevicePropertyChannelNominalLineLevels")]
+    pub const DEVICE_CHANNEL_NOMINAL_LINE_LEVELS: Self = Self(u32::from_be_bytes(*b"nlv#"));
+
+    /// This property translates the given nominal line level item ID into a human
+    /// readable name using an AudioValueTranslation structure. The input data is
+    /// the u32 containing the item ID to be translated and the output data is a
+    /// cf::String. The caller is responsible for releasing the returned cf::Object.
+    /// This property is implemented by an AudioControl object that is a subclass of
+    /// AudioLineLevelControl.
+    #[doc(alias = "kAudioDevicePropertyChannelNominalLineLevelNameForIDCFString")]
+    pub const DEVICE_CHANNEL_NOMINAL_LINE_LEVEL_NAME_FOR_IDCF_STR: Self =
+        Self(u32::from_be_bytes(*b"lcnl"));
+
+    /// An array of u32s whose values are the item IDs for the currently selected
+    /// high pass filter setting. This property is implemented by an AudioControl
+    /// object that is a subclass of AudioHighPassFilterControl.
+    #[doc(alias = "kAudioDevicePropertyHighPassFilterSetting")]
+    pub const DEVICE_HIGH_PASS_FILTER_SETTING: Self = Self(u32::from_be_bytes(*b"hipf"));
+
+    /// An array of u32s that represent all the IDs of all the high pass filter
+    /// settings currently available. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioHighPassFilterControl.
+    #[doc(alias = "kAudioDevicePropertyHighPassFilterSettings")]
+    pub const DEVICE_HIGH_PASS_FILTER_SETTINGS: Self = Self(u32::from_be_bytes(*b"hip#"));
+
+    /// This property translates the given high pass filter setting item ID into a
+    /// human readable name using an AudioValueTranslation structure. The input data
+    /// is the u32 containing the item ID to be translated and the output data is
+    /// a cf::String. The caller is responsible for releasing the returned cf::Object.
+    /// This property is implemented by an AudioControl object that is a subclass of
+    /// AudioHighPassFilterControl.
+    #[doc(alias = "kAudioDevicePropertyHighPassFilterSettingNameForIDCFString")]
+    pub const DEVICE_HIGH_PASS_FILTER_SETTING_NAME_FOR_IDCF_STR: Self =
+        Self(u32::from_be_bytes(*b"hipl"));
+
+    /// A f32 that represents the value of the LFE volume control. The range is
+    /// between 0.0 and 1.0 (inclusive). Note that the set of all f32 values
+    /// between 0.0 and 1.0 inclusive is much larger than the set of actual values
+    /// that the hardware can select. This means that the f32 range has a many
+    /// to one mapping with the underlying hardware values. As such, setting a
+    /// scalar value will result in the control taking on the value nearest to what
+    /// was set. This property is implemented by an AudioControl object that is a
+    /// subclass of AudioLFEVolumeControl.
+    #[doc(alias = "kAudioDevicePropertySubVolumeScalar")]
+    pub const DEVICE_SUB_VOLUME_SCALAR: Self = Self(u32::from_be_bytes(*b"svlm"));
+
+    /// A f32 that represents the value of the LFE volume control in dB. Note
+    /// that the set of all f32 values in the dB range for the control is much
+    /// larger than the set of actual values that the hardware can select. This
+    /// means that the f32 range has a many to one mapping with the underlying
+    /// hardware values. As such, setting a dB value will result in the control
+    /// taking on the value nearest to what was set. This property is implemented by
+    /// an AudioControl object that is a subclass of AudioLFE VolumeControl.
+    #[doc(alias = "kAudioDevicePropertySubVolumeDecibels")]
+    pub const DEVICE_SUB_VOLUME_DECIBELS: Self = Self(u32::from_be_bytes(*b"svld"));
+
+    /// An AudioValueRange that contains the minimum and maximum dB values the
+    /// control can have. This property is implemented by an AudioControl object
+    /// that is a subclass of AudioLFEVolumeControl.
+    #[doc(alias = "kAudioDevicePropertySubVolumeRangeDecibels")]
+    pub const DEVICE_SUB_VOLUME_RANGE_DECIBELS: Self = Self(u32::from_be_bytes(*b"svd#"));
+
+    /// A f32 that on input contains a scalar volume value for the and on exit
+    /// contains the equivalent dB value. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioLFEVolumeControl.
+    #[doc(alias = "kAudioDevicePropertySubVolumeScalarToDecibels")]
+    pub const DEVICE_SUB_VOLUME_SCALAR_TO_DECIBELS: Self = Self(u32::from_be_bytes(*b"sv2d"));
+
+    /// A f32 that on input contains a dB volume value for the and on exit
+    /// contains the equivalent scalar value. This property is implemented by an
+    /// AudioControl object that is a subclass of AudioLFEVolumeControl.
+    #[doc(alias = "kAudioDevicePropertySubVolumeDecibelsToScalar")]
+    pub const DEVICE_SUB_VOLUME_DECIBELS_TO_SCALAR: Self = Self(u32::from_be_bytes(*b"sd2v"));
+
+    /// A u32 where a value of 1 means that mute is enabled making the LFE on
+    ///
+    /// that element inaudible. The property is implemented by an AudioControl
+    /// object that is a subclass of AudioLFEMuteControl.
+    #[doc(alias = "kAudioDevicePropertySubMute")]
+    pub const DEVICE_SUB_MUTE: Self = Self(u32::from_be_bytes(*b"smut"));
+
+    /// A u32 where 0 disables voice activity detection process and non-zero enables it.
+    ///
+    /// Voice activity detection can be used with input audio and has echo cancellation.
+    /// Detection works when a process mute is used, but not with hardware mute.
+    #[doc(alias = "kAudioDevicePropertyVoiceActivityDetectionEnable")]
+    pub const DEVICE_VOICE_ACTIVITY_DETECTION_ENABLE: Self = Self(u32::from_be_bytes(*b"vAd+"));
+
+    /// A read-only u32 where 0 indicates no voice currently detected and 1 indicates voice.
+    ///
+    /// Used in conjunction with VOICE_ACTIVITY_DETECTION_ENABLE.
+    /// A client would normally register to listen to this property for changes and then query
+    /// the state rather than continuously poll the value.
+    /// NOTE: If input audio is not active/runnning or the voice activity detection is disabled,
+    /// then it is not analyzed and this will provide 0.
+    #[doc(alias = "kAudioDevicePropertyVoiceActivityDetectionState")]
+    pub const DEVICE_VOICE_ACTIVITY_DETECTION_STATE: Self = Self(u32::from_be_bytes(*b"vAdS"));
+}
+
+/// AudioObjectPropertySelector values provided by the Tap Object class.
+/// The Tap class is a subclass of the AudioObject class. the class
+/// has just the global scope, kAudioObjectPropertyScopeGlobal, and only a master element.
+impl PropSelector {
+    /// A cf::String that contains a persistent identifier for the Tap. A Taps UID
+    /// persists until the tap is destroyed. The caller is responsible for releasing
+    /// the returned cf::Object.
+    #[doc(alias = "kAudioTapPropertyUID")]
+    pub const TAP_UID: Self = Self(u32::from_be_bytes(*b"tuid"));
+
+    /// The ca::TapDesc used to initially create this tap. This property can be used
+    /// to modify and set the description of an existing tap.
+    #[doc(alias = "kAudioTapPropertyDescription")]
+    pub const TAP_DESCRIPTION: Self = Self(u32::from_be_bytes(*b"tdsc"));
+
+    /// An AudioStreamBasicDescription that describes the current data format for
+    /// the tap. This is the format of that data that will be accessible in any aggregate
+    /// device that contains the tap.
+    #[doc(alias = "kAudioTapPropertyFormat")]
+    pub const TAP_FORMAT: Self = Self(u32::from_be_bytes(*b"tfmt"));
+}
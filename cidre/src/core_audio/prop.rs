@@ -0,0 +1,194 @@
+//! Typed keys for AudioObject properties.
+//!
+//! Each key carries type of the property value, default address, whether
+//! property is settable and classes of objects it applies to.
+//!
+//! ```no_run
+//! use cidre::core_audio as ca;
+//!
+//! let device = ca::System::default_output_device().unwrap();
+//! let rate: f64 = device.get(&ca::prop::DEVICE_NOMINAL_SAMPLE_RATE).unwrap();
+//! device.set(&ca::prop::DEVICE_NOMINAL_SAMPLE_RATE, &rate).unwrap();
+//! ```
+//!
+//! Read-only properties can't be set:
+//!
+//! ```compile_fail
+//! use cidre::core_audio as ca;
+//!
+//! let device = ca::System::default_output_device().unwrap();
+//! device.set(&ca::prop::DEVICE_IS_ALIVE, &true).unwrap();
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::core_audio::{Class, PropAddr, PropElement, PropScope, PropSelector};
+
+mod catalog;
+pub use catalog::*;
+
+#[cfg(target_os = "macos")]
+mod value;
+#[cfg(target_os = "macos")]
+pub use value::*;
+
+/// Marker of properties that can only be read.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOnly;
+
+/// Marker of properties that can be read and set.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadWrite;
+
+pub trait Access {
+    const SETTABLE: bool;
+}
+
+impl Access for ReadOnly {
+    const SETTABLE: bool = false;
+}
+
+impl Access for ReadWrite {
+    const SETTABLE: bool = true;
+}
+
+/// Value of property that requires qualifier data to be read.
+#[derive(Debug)]
+pub struct Qualified<T>(PhantomData<T>);
+
+/// Value of property that is both input and output of the read,
+/// like volume scalar to decibels translation.
+#[derive(Debug)]
+pub struct InOut<T>(PhantomData<T>);
+
+/// Value of property that can't be accessed generically.
+#[derive(Debug)]
+pub struct Opaque;
+
+/// Typed key of AudioObject property.
+pub struct Prop<T, A = ReadOnly> {
+    pub selector: PropSelector,
+    pub scope: PropScope,
+    pub element: PropElement,
+    /// Classes of objects the property applies to, including subclasses.
+    pub classes: &'static [Class],
+    marker: PhantomData<(fn() -> T, A)>,
+}
+
+impl<T, A> Clone for Prop<T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A> Copy for Prop<T, A> {}
+
+impl<T, A> std::fmt::Debug for Prop<T, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ca::Prop")
+            .field("selector", &self.selector)
+            .field("scope", &self.scope)
+            .field("element", &self.element)
+            .finish()
+    }
+}
+
+impl<T, A> Prop<T, A> {
+    pub const fn new(selector: PropSelector, classes: &'static [Class]) -> Self {
+        Self {
+            selector,
+            scope: PropScope::GLOBAL,
+            element: PropElement::MAIN,
+            classes,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub const fn addr(&self) -> PropAddr {
+        self.selector.addr(self.scope, self.element)
+    }
+
+    /// Same property in another scope.
+    #[inline]
+    pub const fn with_scope(mut self, scope: PropScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Same property of another element.
+    #[inline]
+    pub const fn with_element(mut self, element: PropElement) -> Self {
+        self.element = element;
+        self
+    }
+
+    #[inline]
+    pub const fn input(self) -> Self {
+        self.with_scope(PropScope::INPUT)
+    }
+
+    #[inline]
+    pub const fn output(self) -> Self {
+        self.with_scope(PropScope::OUTPUT)
+    }
+
+    /// Whether property applies to objects of `class`.
+    pub fn applies_to(&self, class: Class) -> bool {
+        self.classes.iter().any(|c| class.is_kind_of(*c))
+    }
+}
+
+impl<T, A: Access> Prop<T, A> {
+    pub const SETTABLE: bool = A::SETTABLE;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_audio::{self as ca, prop};
+
+    const RATE: prop::Prop<f64, prop::ReadWrite> = prop::Prop::new(
+        ca::PropSelector::DEVICE_NOMINAL_SAMPLE_RATE,
+        &[ca::Class::DEVICE, ca::Class::CLOCK],
+    );
+
+    #[test]
+    fn addr() {
+        const { assert!(prop::Prop::<f64, prop::ReadWrite>::SETTABLE) };
+        const { assert!(!prop::Prop::<bool, prop::ReadOnly>::SETTABLE) };
+        assert_eq!(
+            RATE.addr(),
+            ca::PropSelector::DEVICE_NOMINAL_SAMPLE_RATE.global_addr()
+        );
+        assert_eq!(
+            RATE.output().addr(),
+            ca::PropSelector::DEVICE_NOMINAL_SAMPLE_RATE.output_addr()
+        );
+        assert_eq!(
+            RATE.input().with_element(ca::PropElement(1)).addr(),
+            ca::PropSelector::DEVICE_NOMINAL_SAMPLE_RATE
+                .addr(ca::PropScope::INPUT, ca::PropElement(1))
+        );
+        assert_eq!(
+            RATE.with_scope(ca::PropScope::PLAY_THROUGH).element,
+            ca::PropElement::MAIN
+        );
+    }
+
+    #[test]
+    fn applies_to() {
+        assert!(RATE.applies_to(ca::Class::DEVICE));
+        assert!(RATE.applies_to(ca::Class::CLOCK));
+        assert!(RATE.applies_to(ca::Class::AGGREGATE_DEVICE));
+        assert!(!RATE.applies_to(ca::Class::OBJECT));
+        assert!(!RATE.applies_to(ca::Class::STREAM));
+        assert!(!RATE.applies_to(ca::Class(u32::from_be_bytes(*b"xxxx"))));
+
+        assert!(ca::Class::MUTE_CONTROL.is_kind_of(ca::Class::BOOLEAN_CONTROL));
+        assert!(ca::Class::MUTE_CONTROL.is_kind_of(ca::Class::CONTROL));
+        assert!(ca::Class::VOLUME_CONTROL.is_kind_of(ca::Class::OBJECT));
+        assert!(!ca::Class::VOLUME_CONTROL.is_kind_of(ca::Class::BOOLEAN_CONTROL));
+        assert!(ca::Class::TRANSPORT_MANAGER.is_kind_of(ca::Class::PLUG_IN));
+        assert_eq!(ca::Class::OBJECT.base(), None);
+    }
+}
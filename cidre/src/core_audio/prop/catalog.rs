@@ -0,0 +1,424 @@
+//! Typed keys of the HAL properties and their [`CATALOG`].
+//!
+//! The table itself is plain data available on every platform, typed keys
+//! and reading of values are macOS only.
+
+use crate::core_audio::{Class, PropSelector};
+
+#[cfg(target_os = "macos")]
+use crate::{
+    arc,
+    cat::audio::{StreamBasicDesc, ValueRange},
+    cf,
+    core_audio::{
+        DeviceTransportType, Obj, PropElement, PropScope, StreamRangedDesc, StreamTerminalType,
+        TapDesc,
+    },
+    sys,
+};
+
+use super::{Access, ReadOnly, ReadWrite};
+#[cfg(target_os = "macos")]
+use super::{
+    InOut, Opaque, Prop, Qualified,
+    value::{Catalogued, ReadFn},
+};
+
+/// Untyped description of property in the [`CATALOG`].
+#[derive(Debug)]
+pub struct PropInfo {
+    pub name: &'static str,
+    /// Name of the constant in CoreAudio headers.
+    pub alias: &'static str,
+    pub selector: PropSelector,
+    pub classes: &'static [Class],
+    pub settable: bool,
+    pub value_type: &'static str,
+    #[cfg(target_os = "macos")]
+    pub(super) read: Option<ReadFn>,
+}
+
+impl PropInfo {
+    /// Whether property applies to objects of `class`.
+    pub fn applies_to(&self, class: Class) -> bool {
+        self.classes.iter().any(|c| class.is_kind_of(*c))
+    }
+}
+
+macro_rules! props {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident: $ty:ty, $access:ty, [$($class:ident),+] = $alias:literal;
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[cfg(target_os = "macos")]
+            #[doc(alias = $alias)]
+            pub const $name: Prop<$ty, $access> =
+                Prop::new(PropSelector::$name, &[$(Class::$class),+]);
+        )*
+
+        /// All known properties.
+        pub static CATALOG: &[PropInfo] = &[
+            $(
+                PropInfo {
+                    name: stringify!($name),
+                    alias: $alias,
+                    selector: PropSelector::$name,
+                    classes: &[$(Class::$class),+],
+                    settable: <$access as Access>::SETTABLE,
+                    value_type: stringify!($ty),
+                    #[cfg(target_os = "macos")]
+                    read: <$ty as Catalogued>::READ,
+                },
+            )*
+        ];
+    };
+}
+
+#[cfg(target_os = "macos")]
+type Str = arc::R<cf::String>;
+
+props! {
+    // AudioObject
+
+    BASE_CLASS: Class, ReadOnly, [OBJECT] = "kAudioObjectPropertyBaseClass";
+    CLASS: Class, ReadOnly, [OBJECT] = "kAudioObjectPropertyClass";
+    OWNER: Obj, ReadOnly, [OBJECT] = "kAudioObjectPropertyOwner";
+    NAME: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyName";
+    MODEL_NAME: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyModelName";
+    MANUFACTURER: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyManufacturer";
+    ELEMENT_NAME: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyElementName";
+    ELEMENT_CATEGORY_NAME: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyElementCategoryName";
+    ELEMENT_NUMBER_NAME: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyElementNumberName";
+    OWNED_OBJS: Vec<Obj>, ReadOnly, [OBJECT] = "kAudioObjectPropertyOwnedObjects";
+    IDENTIFY: bool, ReadWrite, [OBJECT] = "kAudioObjectPropertyIdentify";
+    SERIAL_NUMBER: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertySerialNumber";
+    FIRMWARE_VERSION: Str, ReadOnly, [OBJECT] = "kAudioObjectPropertyFirmwareVersion";
+    CTRL_LIST: Vec<Obj>, ReadOnly, [OBJECT] = "kAudioObjectPropertyControlList";
+
+    // AudioPlugIn
+
+    PLUG_IN_BUNDLE_ID: Str, ReadOnly, [PLUG_IN] = "kAudioPlugInPropertyBundleID";
+    PLUG_IN_DEVICE_LIST: Vec<Obj>, ReadOnly, [PLUG_IN] = "kAudioPlugInPropertyDeviceList";
+    PLUG_IN_TRANSLATE_UID_TO_DEVICE: Qualified<Obj>, ReadOnly, [PLUG_IN]
+        = "kAudioPlugInPropertyTranslateUIDToDevice";
+    PLUG_IN_BOX_LIST: Vec<Obj>, ReadOnly, [PLUG_IN] = "kAudioPlugInPropertyBoxList";
+    PLUG_IN_TRANSLATE_UID_TO_BOX: Qualified<Obj>, ReadOnly, [PLUG_IN]
+        = "kAudioPlugInPropertyTranslateUIDToBox";
+    PLUG_IN_CLOCK_DEVICE_LIST: Vec<Obj>, ReadOnly, [PLUG_IN]
+        = "kAudioPlugInPropertyClockDeviceList";
+    PLUG_IN_TRANSLATE_UID_TO_CLOCK_DEVICE: Qualified<Obj>, ReadOnly, [PLUG_IN]
+        = "kAudioPlugInPropertyTranslateUIDToClockDevice";
+
+    // AudioTransportManager
+
+    TRANSPORT_MANAGER_END_POINT_LIST: Vec<Obj>, ReadOnly, [TRANSPORT_MANAGER]
+        = "kAudioTransportManagerPropertyEndPointList";
+    TRANSPORT_MANAGER_TRANSLATE_UID_TO_END_POINT: Qualified<Obj>, ReadOnly, [TRANSPORT_MANAGER]
+        = "kAudioTransportManagerPropertyTranslateUIDToEndPoint";
+    TRANSPORT_MANAGER_TRANSPORT_TYPE: DeviceTransportType, ReadOnly, [TRANSPORT_MANAGER]
+        = "kAudioTransportManagerPropertyTransportType";
+
+    // AudioBox
+
+    BOX_UID: Str, ReadOnly, [BOX] = "kAudioBoxPropertyBoxUID";
+    BOX_TRANSPORT_TYPE: DeviceTransportType, ReadOnly, [BOX] = "kAudioBoxPropertyTransportType";
+    BOX_HAS_AUDIO: bool, ReadOnly, [BOX] = "kAudioBoxPropertyHasAudio";
+    BOX_HAS_VIDEO: bool, ReadOnly, [BOX] = "kAudioBoxPropertyHasVideo";
+    BOX_HAS_MIDI: bool, ReadOnly, [BOX] = "kAudioBoxPropertyHasMIDI";
+    BOX_IS_PROTECTED: bool, ReadOnly, [BOX] = "kAudioBoxPropertyIsProtected";
+    BOX_ACQUIRED: bool, ReadWrite, [BOX] = "kAudioBoxPropertyAcquired";
+    BOX_ACQUISITION_FAILED: i32, ReadOnly, [BOX] = "kAudioBoxPropertyAcquisitionFailed";
+    BOX_DEVICE_LIST: Vec<Obj>, ReadOnly, [BOX] = "kAudioBoxPropertyDeviceList";
+    BOX_CLOCK_DEVICE_LIST: Vec<Obj>, ReadOnly, [BOX] = "kAudioBoxPropertyClockDeviceList";
+
+    // AudioSystemObject
+
+    HW_DEVICES: Vec<Obj>, ReadOnly, [SYSTEM] = "kAudioHardwarePropertyDevices";
+    HW_DEFAULT_INPUT_DEVICE: Obj, ReadWrite, [SYSTEM]
+        = "kAudioHardwarePropertyDefaultInputDevice";
+    HW_DEFAULT_OUTPUT_DEVICE: Obj, ReadWrite, [SYSTEM]
+        = "kAudioHardwarePropertyDefaultOutputDevice";
+    HW_DEFAULT_SYS_OUTPUT_DEVICE: Obj, ReadWrite, [SYSTEM]
+        = "kAudioHardwarePropertyDefaultSystemOutputDevice";
+    HW_TRANSLATE_UID_TO_DEVICE: Qualified<Obj>, ReadOnly, [SYSTEM]
+        = "kAudioHardwarePropertyTranslateUIDToDevice";
+    HW_PROCESS_INPUT_MUTE: bool, ReadWrite, [SYSTEM] = "kAudioHardwarePropertyProcessInputMute";
+    HW_PROCESS_OBJ_LIST: Vec<Obj>, ReadOnly, [SYSTEM]
+        = "kAudioHardwarePropertyProcessObjectList";
+    HW_TRANSLATE_PID_TO_PROCESS_OBJ: Qualified<Obj>, ReadOnly, [SYSTEM]
+        = "kAudioHardwarePropertyTranslatePIDToProcessObject";
+    HW_CLOCK_DEVICE_LIST: Vec<Obj>, ReadOnly, [SYSTEM] = "kAudioHardwarePropertyClockDeviceList";
+    HW_TAP_LIST: Vec<Obj>, ReadOnly, [SYSTEM] = "kAudioHardwarePropertyTapList";
+
+    // AudioProcess
+
+    PROCESS_PID: sys::Pid, ReadOnly, [PROCESS] = "kAudioProcessPropertyPID";
+    PROCESS_BUNDLE_ID: Str, ReadOnly, [PROCESS] = "kAudioProcessPropertyBundleID";
+    PROCESS_DEVICES: Vec<Obj>, ReadOnly, [PROCESS] = "kAudioProcessPropertyDevices";
+    PROCESS_IS_RUNNING: bool, ReadOnly, [PROCESS] = "kAudioProcessPropertyIsRunning";
+    PROCESS_IS_RUNNING_INPUT: bool, ReadOnly, [PROCESS] = "kAudioProcessPropertyIsRunningInput";
+    PROCESS_IS_RUNNING_OUTPUT: bool, ReadOnly, [PROCESS]
+        = "kAudioProcessPropertyIsRunningOutput";
+
+    // AudioDevice
+
+    DEVICE_CONFIGURATION_APP: Str, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyConfigurationApplication";
+    DEVICE_UID: Str, ReadOnly, [DEVICE] = "kAudioDevicePropertyDeviceUID";
+    DEVICE_MODEL_UID: Str, ReadOnly, [DEVICE] = "kAudioDevicePropertyModelUID";
+    DEVICE_TRANSPORT_TYPE: DeviceTransportType, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyTransportType";
+    DEVICE_RELATED_DEVICES: Vec<Obj>, ReadOnly, [DEVICE] = "kAudioDevicePropertyRelatedDevices";
+    DEVICE_CLOCK_DOMAIN: u32, ReadOnly, [DEVICE] = "kAudioDevicePropertyClockDomain";
+    DEVICE_IS_ALIVE: bool, ReadOnly, [DEVICE] = "kAudioDevicePropertyDeviceIsAlive";
+    DEVICE_IS_RUNNING: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyDeviceIsRunning";
+    DEVICE_CAN_BE_DEFAULT_DEVICE: bool, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyDeviceCanBeDefaultDevice";
+    DEVICE_CAN_BE_DEFAULT_SYS_DEVICE: bool, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyDeviceCanBeDefaultSystemDevice";
+    DEVICE_LATENCY: u32, ReadOnly, [DEVICE] = "kAudioDevicePropertyLatency";
+    DEVICE_STREAMS: Vec<Obj>, ReadOnly, [DEVICE] = "kAudioDevicePropertyStreams";
+    DEVICE_SAFETY_OFFSET: u32, ReadOnly, [DEVICE] = "kAudioDevicePropertySafetyOffset";
+    DEVICE_NOMINAL_SAMPLE_RATE: f64, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyNominalSampleRate";
+    DEVICE_AVAILABLE_NOMINAL_SAMPLE_RATES: Vec<ValueRange>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyAvailableNominalSampleRates";
+    DEVICE_ICON: arc::R<cf::Url>, ReadOnly, [DEVICE] = "kAudioDevicePropertyIcon";
+    DEVICE_IS_HIDDEN: bool, ReadOnly, [DEVICE] = "kAudioDevicePropertyIsHidden";
+    DEVICE_PREFERRED_CHANNELS_FOR_STEREO: [u32; 2], ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyPreferredChannelsForStereo";
+    /// Variable length AudioChannelLayout.
+    DEVICE_PREFERRED_CHANNEL_LAYOUT: Vec<u8>, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyPreferredChannelLayout";
+    DEVICE_PLUG_IN: i32, ReadOnly, [DEVICE] = "kAudioDevicePropertyPlugIn";
+    DEVICE_HAS_CHANGED: u32, ReadOnly, [DEVICE] = "kAudioDevicePropertyDeviceHasChanged";
+    DEVICE_IS_RUNNING_SOMEWHERE: bool, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyDeviceIsRunningSomewhere";
+    DEVICE_PROCESSOR_OVERLOAD: u32, ReadOnly, [DEVICE] = "kAudioDeviceProcessorOverload";
+    DEVICE_IO_STOPPED_ABNORMALLY: u32, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyIOStoppedAbnormally";
+    DEVICE_HOG_MODE: sys::Pid, ReadWrite, [DEVICE] = "kAudioDevicePropertyHogMode";
+    DEVICE_BUF_FRAME_SIZE: u32, ReadWrite, [DEVICE] = "kAudioDevicePropertyBufferFrameSize";
+    DEVICE_BUF_FRAME_SIZE_RANGE: ValueRange, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyBufferFrameSizeRange";
+    DEVICE_USES_VARIABLE_BUF_FRAME_SIZES: u32, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyUsesVariableBufferFrameSizes";
+    DEVICE_IO_CYCLE_USAGE: f32, ReadWrite, [DEVICE] = "kAudioDevicePropertyIOCycleUsage";
+    /// Variable length AudioBufferList.
+    DEVICE_STREAM_CFG: Vec<u8>, ReadOnly, [DEVICE] = "kAudioDevicePropertyStreamConfiguration";
+    DEVICE_IO_PROC_STREAM_USAGE: Opaque, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyIOProcStreamUsage";
+    DEVICE_ACTUAL_SAMPLE_RATE: f64, ReadOnly, [DEVICE] = "kAudioDevicePropertyActualSampleRate";
+    DEVICE_CLOCK_DEVICE: Str, ReadWrite, [DEVICE] = "kAudioDevicePropertyClockDevice";
+    DEVICE_IO_THREAD_OS_WORKGROUP: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyIOThreadOSWorkgroup";
+    DEVICE_PROCESS_MUTE: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyProcessMute";
+    DEVICE_JACK_IS_CONNECTED: bool, ReadOnly, [DEVICE] = "kAudioDevicePropertyJackIsConnected";
+    DEVICE_VOLUME_SCALAR: f32, ReadWrite, [DEVICE] = "kAudioDevicePropertyVolumeScalar";
+    DEVICE_VOLUME_DECIBELS: f32, ReadWrite, [DEVICE] = "kAudioDevicePropertyVolumeDecibels";
+    DEVICE_VOLUME_RANGE_DECIBELS: ValueRange, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyVolumeRangeDecibels";
+    DEVICE_VOLUME_SCALAR_TO_DECIBELS: InOut<f32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyVolumeScalarToDecibels";
+    DEVICE_VOLUME_DECIBELS_TO_SCALAR: InOut<f32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyVolumeDecibelsToScalar";
+    DEVICE_STEREO_PAN: f32, ReadWrite, [DEVICE] = "kAudioDevicePropertyStereoPan";
+    DEVICE_STEREO_PAN_CHANNELS: [u32; 2], ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyStereoPanChannels";
+    DEVICE_MUTE: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyMute";
+    DEVICE_SOLO: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertySolo";
+    DEVICE_PHANTOM_POWER: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyPhantomPower";
+    DEVICE_PHASE_INVERT: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyPhaseInvert";
+    DEVICE_CLIP_LIGHT: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyClipLight";
+    DEVICE_TALKBACK: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyTalkback";
+    DEVICE_LISTENBACK: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyListenback";
+    DEVICE_DATA_SRC: u32, ReadWrite, [DEVICE] = "kAudioDevicePropertyDataSource";
+    DEVICE_DATA_SRCS: Vec<u32>, ReadOnly, [DEVICE] = "kAudioDevicePropertyDataSources";
+    DEVICE_DATA_SRC_NAME_FOR_IDCF_STR: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyDataSourceNameForIDCFString";
+    DEVICE_DATA_SRC_KIND_FOR_ID: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyDataSourceKindForID";
+    DEVICE_CLOCK_SRC: u32, ReadWrite, [DEVICE] = "kAudioDevicePropertyClockSource";
+    DEVICE_CLOCK_SRCS: Vec<u32>, ReadOnly, [DEVICE] = "kAudioDevicePropertyClockSources";
+    DEVICE_CLOCK_SRC_NAME_FOR_IDCF_STR: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyClockSourceNameForIDCFString";
+    DEVICE_CLOCK_SRC_KIND_FOR_ID: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyClockSourceKindForID";
+    DEVICE_PLAY_THRU: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyPlayThru";
+    DEVICE_PLAY_THRU_SOLO: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertyPlayThruSolo";
+    DEVICE_PLAY_THRU_VOLUME_SCALAR: f32, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyPlayThruVolumeScalar";
+    DEVICE_PLAY_THRU_VOLUME_DECIBELS: f32, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyPlayThruVolumeDecibels";
+    DEVICE_PLAY_THRU_VOLUME_RANGE_DECIBELS: ValueRange, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyPlayThruVolumeRangeDecibels";
+    DEVICE_PLAY_THRU_VOLUME_SCALAR_TO_DECIBELS: InOut<f32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyPlayThruVolumeScalarToDecibels";
+    DEVICE_PLAY_THRU_VOLUME_DECIBELS_TO_SCALAR: InOut<f32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyPlayThruVolumeDecibelsToScalar";
+    DEVICE_PLAY_THRU_STEREO_PAN: f32, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyPlayThruStereoPan";
+    DEVICE_PLAY_THRU_STEREO_PAN_CHANNELS: [u32; 2], ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyPlayThruStereoPanChannels";
+    DEVICE_PLAY_THRU_DST: u32, ReadWrite, [DEVICE] = "kAudioDevicePropertyPlayThruDestination";
+    DEVICE_PLAY_THRU_DSTS: Vec<u32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyPlayThruDestinations";
+    DEVICE_PLAY_THRU_DST_NAME_FOR_IDCF_STR: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyPlayThruDestinationNameForIDCFString";
+    DEVICE_CHANNEL_NOMINAL_LINE_LEVEL: u32, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyChannelNominalLineLevel";
+    DEVICE_CHANNEL_NOMINAL_LINE_LEVELS: Vec<u32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyChannelNominalLineLevels";
+    DEVICE_CHANNEL_NOMINAL_LINE_LEVEL_NAME_FOR_IDCF_STR: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyChannelNominalLineLevelNameForIDCFString";
+    DEVICE_HIGH_PASS_FILTER_SETTING: u32, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyHighPassFilterSetting";
+    DEVICE_HIGH_PASS_FILTER_SETTINGS: Vec<u32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyHighPassFilterSettings";
+    DEVICE_HIGH_PASS_FILTER_SETTING_NAME_FOR_IDCF_STR: Opaque, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyHighPassFilterSettingNameForIDCFString";
+    DEVICE_SUB_VOLUME_SCALAR: f32, ReadWrite, [DEVICE] = "kAudioDevicePropertySubVolumeScalar";
+    DEVICE_SUB_VOLUME_DECIBELS: f32, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertySubVolumeDecibels";
+    DEVICE_SUB_VOLUME_RANGE_DECIBELS: ValueRange, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertySubVolumeRangeDecibels";
+    DEVICE_SUB_VOLUME_SCALAR_TO_DECIBELS: InOut<f32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertySubVolumeScalarToDecibels";
+    DEVICE_SUB_VOLUME_DECIBELS_TO_SCALAR: InOut<f32>, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertySubVolumeDecibelsToScalar";
+    DEVICE_SUB_MUTE: bool, ReadWrite, [DEVICE] = "kAudioDevicePropertySubMute";
+    DEVICE_VOICE_ACTIVITY_DETECTION_ENABLE: bool, ReadWrite, [DEVICE]
+        = "kAudioDevicePropertyVoiceActivityDetectionEnable";
+    DEVICE_VOICE_ACTIVITY_DETECTION_STATE: bool, ReadOnly, [DEVICE]
+        = "kAudioDevicePropertyVoiceActivityDetectionState";
+
+    // AudioAggregateDevice
+
+    AGGREGATE_DEVICE_FULL_SUB_DEVICE_LIST: arc::R<cf::ArrayOf<cf::String>>, ReadWrite,
+        [AGGREGATE_DEVICE] = "kAudioAggregateDevicePropertyFullSubDeviceList";
+    AGGREGATE_DEVICE_ACTIVE_SUB_DEVICE_LIST: Vec<Obj>, ReadOnly, [AGGREGATE_DEVICE]
+        = "kAudioAggregateDevicePropertyActiveSubDeviceList";
+    AGGREGATE_DEVICE_COMPOSITION: arc::R<cf::DictionaryOf<cf::String, cf::Type>>, ReadWrite,
+        [AGGREGATE_DEVICE] = "kAudioAggregateDevicePropertyComposition";
+    AGGREGATE_DEVICE_MAIN_SUB_DEVICE: Str, ReadWrite, [AGGREGATE_DEVICE]
+        = "kAudioAggregateDevicePropertyMainSubDevice";
+    AGGREGATE_DEVICE_CLOCK_DEVICE: Str, ReadWrite, [AGGREGATE_DEVICE]
+        = "kAudioAggregateDevicePropertyClockDevice";
+    AGGREGATE_DEVICE_TAP_LIST: arc::R<cf::ArrayOf<cf::String>>, ReadWrite, [AGGREGATE_DEVICE]
+        = "kAudioAggregateDevicePropertyTapList";
+    AGGREGATE_DEVICE_SUB_TAP_LIST: Vec<Obj>, ReadOnly, [AGGREGATE_DEVICE]
+        = "kAudioAggregateDevicePropertySubTapList";
+
+    // AudioStream
+
+    STREAM_IS_ACTIVE: bool, ReadOnly, [STREAM] = "kAudioStreamPropertyIsActive";
+    STREAM_DIRECTION: u32, ReadOnly, [STREAM] = "kAudioStreamPropertyDirection";
+    STREAM_TERMINAL_TYPE: StreamTerminalType, ReadOnly, [STREAM]
+        = "kAudioStreamPropertyTerminalType";
+    STREAM_STARTING_CHANNEL: u32, ReadOnly, [STREAM] = "kAudioStreamPropertyStartingChannel";
+    STREAM_LATENCY: u32, ReadOnly, [STREAM] = "kAudioStreamPropertyLatency";
+    STREAM_VIRTUAL_FORMAT: StreamBasicDesc, ReadWrite, [STREAM]
+        = "kAudioStreamPropertyVirtualFormat";
+    STREAM_AVAILABLE_VIRTUAL_FORMATS: Vec<StreamRangedDesc>, ReadOnly, [STREAM]
+        = "kAudioStreamPropertyAvailableVirtualFormats";
+    STREAM_PHYSICAL_FORMAT: StreamBasicDesc, ReadWrite, [STREAM]
+        = "kAudioStreamPropertyPhysicalFormat";
+    STREAM_AVAILABLE_PHYSICAL_FORMATS: Vec<StreamRangedDesc>, ReadOnly, [STREAM]
+        = "kAudioStreamPropertyAvailablePhysicalFormats";
+
+    // AudioClockDevice
+
+    CLOCK_DEVICE_UID: Str, ReadOnly, [CLOCK] = "kAudioClockDevicePropertyDeviceUID";
+    CLOCK_DEVICE_TRANSPORT_TYPE: DeviceTransportType, ReadOnly, [CLOCK]
+        = "kAudioClockDevicePropertyTransportType";
+    CLOCK_DEVICE_DOMAIN: u32, ReadOnly, [CLOCK] = "kAudioClockDevicePropertyClockDomain";
+    CLOCK_DEVICE_IS_ALIVE: bool, ReadOnly, [CLOCK] = "kAudioClockDevicePropertyDeviceIsAlive";
+    CLOCK_DEVICE_IS_RUNNING: bool, ReadOnly, [CLOCK]
+        = "kAudioClockDevicePropertyDeviceIsRunning";
+    CLOCK_DEVICE_LATENCY: u32, ReadOnly, [CLOCK] = "kAudioClockDevicePropertyLatency";
+    CLOCK_DEVICE_CONTROL_LIST: Vec<Obj>, ReadOnly, [CLOCK]
+        = "kAudioClockDevicePropertyControlList";
+    CLOCK_DEVICE_NOMINAL_SAMPLE_RATE: f64, ReadWrite, [CLOCK]
+        = "kAudioClockDevicePropertyNominalSampleRate";
+    CLOCK_DEVICE_AVAILABLE_NOMINAL_SAMPLE_RATES: Vec<ValueRange>, ReadOnly, [CLOCK]
+        = "kAudioClockDevicePropertyAvailableNominalSampleRates";
+
+    // AudioControl
+
+    CONTROL_SCOPE: PropScope, ReadOnly, [CONTROL] = "kAudioControlPropertyScope";
+    CONTROL_ELEMENT: PropElement, ReadOnly, [CONTROL] = "kAudioControlPropertyElement";
+    LEVEL_CONTROL_SCALAR_VALUE: f32, ReadWrite, [LEVEL_CONTROL]
+        = "kAudioLevelControlPropertyScalarValue";
+    LEVEL_CONTROL_DECIBEL_VALUE: f32, ReadWrite, [LEVEL_CONTROL]
+        = "kAudioLevelControlPropertyDecibelValue";
+    BOOLEAN_CONTROL_VALUE: bool, ReadWrite, [BOOLEAN_CONTROL]
+        = "kAudioBooleanControlPropertyValue";
+    SELECTOR_CONTROL_CURRENT_ITEM: Vec<u32>, ReadWrite, [SELECTOR_CONTROL]
+        = "kAudioSelectorControlPropertyCurrentItem";
+
+    // AudioTap
+
+    TAP_UID: Str, ReadOnly, [TAP] = "kAudioTapPropertyUID";
+    TAP_DESCRIPTION: arc::R<TapDesc>, ReadWrite, [TAP] = "kAudioTapPropertyDescription";
+    TAP_FORMAT: StreamBasicDesc, ReadOnly, [TAP] = "kAudioTapPropertyFormat";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_audio::{self as ca, prop};
+
+    #[test]
+    fn catalog() {
+        for (i, info) in prop::CATALOG.iter().enumerate() {
+            assert!(info.alias.starts_with("kAudio"), "{}", info.name);
+            assert!(!info.classes.is_empty());
+            for other in prop::CATALOG[i + 1..].iter() {
+                assert_ne!(info.name, other.name);
+                assert_ne!(info.alias, other.alias);
+            }
+        }
+
+        let info = prop::CATALOG
+            .iter()
+            .find(|i| i.name == "DEVICE_NOMINAL_SAMPLE_RATE")
+            .unwrap();
+        assert!(info.settable);
+        #[cfg(target_os = "macos")]
+        assert!(info.is_readable());
+        assert_eq!(info.value_type, "f64");
+        assert!(info.applies_to(ca::Class::DEVICE));
+
+        let info = prop::CATALOG
+            .iter()
+            .find(|i| i.name == "HW_TRANSLATE_UID_TO_DEVICE")
+            .unwrap();
+        assert_eq!(info.value_type, "Qualified<Obj>");
+        #[cfg(target_os = "macos")]
+        assert!(!info.is_readable());
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn keys() {
+        let key: prop::Prop<f64, prop::ReadWrite> = prop::DEVICE_NOMINAL_SAMPLE_RATE;
+        const { assert!(prop::Prop::<f64, prop::ReadWrite>::SETTABLE) };
+        const { assert!(!prop::Prop::<bool, prop::ReadOnly>::SETTABLE) };
+        assert_eq!(
+            key.addr(),
+            ca::PropSelector::DEVICE_NOMINAL_SAMPLE_RATE.global_addr()
+        );
+        assert_eq!(
+            prop::DEVICE_VOLUME_SCALAR.output().addr(),
+            ca::PropSelector::DEVICE_VOLUME_SCALAR.output_addr()
+        );
+        assert_eq!(
+            prop::DEVICE_VOLUME_SCALAR
+                .input()
+                .with_element(ca::PropElement(1))
+                .addr(),
+            ca::PropSelector::DEVICE_VOLUME_SCALAR.addr(ca::PropScope::INPUT, ca::PropElement(1))
+        );
+    }
+}
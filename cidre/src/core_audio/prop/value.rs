@@ -0,0 +1,226 @@
+//! Reading and setting typed properties of [`Obj`].
+
+use crate::{
+    arc,
+    cat::audio::{StreamBasicDesc, ValueRange},
+    core_audio::{
+        Class, DeviceTransportType, Obj, PropAddr, PropElement, PropScope, StreamRangedDesc,
+        StreamTerminalType, hardware_err,
+    },
+    os,
+};
+
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+use crate::{core_audio::PropListenerBlock, dispatch};
+
+use super::{CATALOG, InOut, Opaque, Prop, PropInfo, Qualified, ReadWrite};
+
+/// Types stored in property data as is.
+pub trait Plain: Sized {}
+
+impl Plain for u8 {}
+impl Plain for u32 {}
+impl Plain for i32 {}
+impl Plain for f32 {}
+impl Plain for f64 {}
+impl Plain for [u32; 2] {}
+impl Plain for Obj {}
+impl Plain for Class {}
+impl Plain for PropScope {}
+impl Plain for PropElement {}
+impl Plain for DeviceTransportType {}
+impl Plain for StreamTerminalType {}
+impl Plain for StreamBasicDesc {}
+impl Plain for ValueRange {}
+impl Plain for StreamRangedDesc {}
+
+/// Value of property that can be accessed without qualifier.
+pub trait PropValue: Sized {
+    fn get(obj: &Obj, address: &PropAddr) -> os::Result<Self>;
+    fn set(&self, obj: &Obj, address: &PropAddr) -> os::Result;
+}
+
+impl<T: Plain> PropValue for T {
+    fn get(obj: &Obj, address: &PropAddr) -> os::Result<Self> {
+        obj.prop(address)
+    }
+
+    fn set(&self, obj: &Obj, address: &PropAddr) -> os::Result {
+        obj.set_prop(address, self)
+    }
+}
+
+impl<T: Plain> PropValue for Vec<T> {
+    fn get(obj: &Obj, address: &PropAddr) -> os::Result<Self> {
+        obj.prop_vec(address)
+    }
+
+    fn set(&self, obj: &Obj, address: &PropAddr) -> os::Result {
+        obj.set_prop_slice(address, self)
+    }
+}
+
+impl PropValue for bool {
+    fn get(obj: &Obj, address: &PropAddr) -> os::Result<Self> {
+        obj.bool_prop(address)
+    }
+
+    fn set(&self, obj: &Obj, address: &PropAddr) -> os::Result {
+        obj.set_prop(address, &(*self as u32))
+    }
+}
+
+impl<T: arc::Release> PropValue for arc::R<T> {
+    fn get(obj: &Obj, address: &PropAddr) -> os::Result<Self> {
+        obj.cf_prop(address)
+    }
+
+    fn set(&self, obj: &Obj, address: &PropAddr) -> os::Result {
+        obj.set_prop(address, self)
+    }
+}
+
+pub(super) type ReadFn = fn(&Obj, &PropAddr) -> os::Result<String>;
+
+/// Provides generic read of property for the [`CATALOG`].
+pub trait Catalogued {
+    const READ: Option<ReadFn>;
+}
+
+fn read<T: PropValue + std::fmt::Debug>(obj: &Obj, address: &PropAddr) -> os::Result<String> {
+    T::get(obj, address).map(|v| format!("{v:?}"))
+}
+
+impl<T: PropValue + std::fmt::Debug> Catalogued for T {
+    const READ: Option<ReadFn> = Some(read::<T>);
+}
+
+impl<T> Catalogued for Qualified<T> {
+    const READ: Option<ReadFn> = None;
+}
+
+impl<T> Catalogued for InOut<T> {
+    const READ: Option<ReadFn> = None;
+}
+
+impl Catalogued for Opaque {
+    const READ: Option<ReadFn> = None;
+}
+
+impl Obj {
+    pub fn get<T: PropValue, A>(&self, prop: &Prop<T, A>) -> os::Result<T> {
+        T::get(self, &prop.addr())
+    }
+
+    pub fn set<T: PropValue>(&self, prop: &Prop<T, ReadWrite>, val: &T) -> os::Result {
+        val.set(self, &prop.addr())
+    }
+
+    pub fn get_with_qualifier<T: Plain, Q: Sized, A>(
+        &self,
+        prop: &Prop<Qualified<T>, A>,
+        qualifier: &Q,
+    ) -> os::Result<T> {
+        self.prop_with_qualifier(&prop.addr(), qualifier)
+    }
+
+    pub fn get_in_out<T: Plain, A>(&self, prop: &Prop<InOut<T>, A>, val: &mut T) -> os::Result {
+        self.prop_mut(&prop.addr(), val)
+    }
+
+    pub fn has<T, A>(&self, prop: &Prop<T, A>) -> bool {
+        self.has_prop(&prop.addr())
+    }
+
+    /// Whether property is settable right now. Settable properties may
+    /// still be locked by the HAL, for example when device is hogged.
+    pub fn is_settable<T, A>(&self, prop: &Prop<T, A>) -> os::Result<bool> {
+        self.is_prop_settable(&prop.addr())
+    }
+
+    #[cfg(all(feature = "blocks", feature = "dispatch"))]
+    pub fn listen<T, A>(
+        &self,
+        prop: &Prop<T, A>,
+        queue: Option<&dispatch::Queue>,
+        listener: &mut PropListenerBlock,
+    ) -> os::Result {
+        self.add_prop_listener_block(&prop.addr(), queue, listener)
+    }
+
+    #[cfg(all(feature = "blocks", feature = "dispatch"))]
+    pub fn unlisten<T, A>(
+        &self,
+        prop: &Prop<T, A>,
+        queue: Option<&dispatch::Queue>,
+        listener: &mut PropListenerBlock,
+    ) -> os::Result {
+        self.remove_prop_listener_block(&prop.addr(), queue, listener)
+    }
+}
+
+impl PropInfo {
+    /// Whether value of the property can be read without extra input.
+    pub fn is_readable(&self) -> bool {
+        self.read.is_some()
+    }
+
+    /// Reads value of the property at `address` and formats it with `Debug`.
+    pub fn read(&self, obj: &Obj, address: &PropAddr) -> os::Result<String> {
+        match self.read {
+            Some(read) => read(obj, address),
+            None => Err(hardware_err::UNSUPPORTED_OP),
+        }
+    }
+}
+
+/// Reads every readable property of the catalog that `obj` has.
+///
+/// Properties are read at their default address. Values are formatted
+/// with `Debug`.
+pub fn dump(obj: &Obj) -> os::Result<Vec<(&'static PropInfo, os::Result<String>)>> {
+    let mut classes = vec![Class::OBJECT, obj.class()?];
+    if let Ok(base) = obj.base_class() {
+        classes.push(base);
+    }
+
+    let mut res = Vec::new();
+    for info in CATALOG.iter() {
+        if !info.is_readable() || !classes.iter().any(|c| info.applies_to(*c)) {
+            continue;
+        }
+        let addr = info.selector.global_addr();
+        if obj.has_prop(&addr) {
+            res.push((info, info.read(obj, &addr)));
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_audio::{self as ca, prop};
+
+    #[test]
+    fn basics() {
+        let Ok(device) = ca::System::default_output_device() else {
+            // no audio hardware
+            return;
+        };
+        if device.is_unknown() {
+            return;
+        }
+        let rate = device.get(&prop::DEVICE_NOMINAL_SAMPLE_RATE).unwrap();
+        assert_eq!(rate, device.nominal_sample_rate().unwrap());
+        assert_eq!(device.get(&prop::CLASS).unwrap(), device.class().unwrap());
+        let streams = device.get(&prop::DEVICE_STREAMS).unwrap();
+        assert!(!streams.is_empty());
+
+        let props = prop::dump(&device).unwrap();
+        assert!(
+            props
+                .iter()
+                .any(|(i, v)| i.name == "DEVICE_UID" && v.is_ok())
+        );
+    }
+}
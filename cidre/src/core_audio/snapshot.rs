@@ -0,0 +1,317 @@
+//! Capturing of HAL object tree into [`System`] snapshots.
+//!
+//! Snapshot types and [`diff`] are platform independent and live in
+//! [`crate::cat::audio::hal_snapshot`].
+
+pub use crate::cat::audio::hal_snapshot::*;
+use crate::{core_audio as ca, os};
+
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+use crate::{arc, dispatch};
+
+fn is_control_class(class: ca::Class) -> bool {
+    class == ca::Class::CONTROL
+        || class == ca::Class::SLIDER_CONTROL
+        || class == ca::Class::LEVEL_CONTROL
+        || class == ca::Class::BOOLEAN_CONTROL
+        || class == ca::Class::SELECTOR_CONTROL
+        || class == ca::Class::STEREO_PAN_CONTROL
+}
+
+impl System {
+    /// Reads the whole object tree from the HAL.
+    pub fn capture() -> os::Result<Self> {
+        let uid = |d: os::Result<ca::Device>| {
+            d.ok()
+                .filter(|d| !d.is_unknown())
+                .and_then(|d| d.uid().ok())
+                .map(|uid| uid.to_string())
+        };
+        // devices may go away while we are walking them
+        let devices = ca::System::devices()?
+            .iter()
+            .filter_map(|d| Device::capture(d).ok())
+            .collect();
+        // process and tap lists are not available on older systems
+        Ok(Self {
+            default_input_device: uid(ca::System::default_input_device()),
+            default_output_device: uid(ca::System::default_output_device()),
+            default_sys_output_device: uid(ca::System::default_sys_output_device()),
+            devices,
+            clocks: ca::System::clocks()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|c| Clock::capture(c).ok())
+                .collect(),
+            processes: ca::System::processes()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|p| Process::capture(p).ok())
+                .collect(),
+            taps: ca::System::taps()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|t| Tap::capture(t).ok())
+                .collect(),
+        })
+    }
+}
+
+impl Device {
+    pub fn capture(device: &ca::Device) -> os::Result<Self> {
+        let mut streams = Vec::new();
+        for s in device.streams()? {
+            streams.push(Stream::capture(&s)?);
+        }
+        let mut controls = Vec::new();
+        let owned: Vec<ca::Obj> = device.prop_vec(&ca::PropSelector::OWNED_OBJS.global_addr())?;
+        for obj in owned {
+            let Ok(class) = obj.class() else {
+                continue;
+            };
+            if (is_control_class(class) || obj.base_class().is_ok_and(is_control_class))
+                && let Ok(control) = Control::capture(obj, class)
+            {
+                controls.push(control);
+            }
+        }
+        Ok(Self {
+            id: device.0.0,
+            uid: device.uid()?.to_string(),
+            name: device.name().ok().map(|n| n.to_string()),
+            manufacturer: device.manufacturer().ok().map(|n| n.to_string()),
+            transport_type: device.transport_type()?.0,
+            nominal_sample_rate: device.nominal_sample_rate()?,
+            is_alive: device.is_alive()?,
+            is_running: device.is_running()?,
+            streams,
+            controls,
+        })
+    }
+}
+
+impl Stream {
+    pub fn capture(stream: &ca::Stream) -> os::Result<Self> {
+        Ok(Self {
+            id: stream.0.0,
+            is_input: stream.dir()?.is_input(),
+            is_active: stream.is_active()?,
+            virtual_format: Format::from(&stream.virtual_format()?),
+            physical_format: Format::from(&stream.physical_format()?),
+            latency: stream.latency()?,
+        })
+    }
+}
+
+impl Control {
+    fn capture(obj: ca::Obj, class: ca::Class) -> os::Result<Self> {
+        let scope: ca::PropScope = obj.prop(&ca::PropSelector::CONTROL_SCOPE.global_addr())?;
+        let element: ca::PropElement =
+            obj.prop(&ca::PropSelector::CONTROL_ELEMENT.global_addr())?;
+        let base = obj.base_class().unwrap_or(ca::Class::CONTROL);
+        let is = |c: ca::Class| class == c || base == c;
+        let value = if is(ca::Class::LEVEL_CONTROL) {
+            ControlValue::Level {
+                scalar: obj.prop(&ca::PropSelector::LEVEL_CONTROL_SCALAR_VALUE.global_addr())?,
+                db: obj.prop(&ca::PropSelector::LEVEL_CONTROL_DECIBEL_VALUE.global_addr())?,
+            }
+        } else if is(ca::Class::BOOLEAN_CONTROL) {
+            ControlValue::Bool(
+                obj.bool_prop(&ca::PropSelector::BOOLEAN_CONTROL_VALUE.global_addr())?,
+            )
+        } else if is(ca::Class::SELECTOR_CONTROL) {
+            ControlValue::Selector(
+                obj.prop_vec(&ca::PropSelector::SELECTOR_CONTROL_CURRENT_ITEM.global_addr())?,
+            )
+        } else {
+            ControlValue::Other
+        };
+        Ok(Self {
+            id: obj.0,
+            class: class.0,
+            scope: scope.0,
+            element: element.0,
+            value,
+        })
+    }
+}
+
+impl Clock {
+    pub fn capture(clock: &ca::hardware::Clock) -> os::Result<Self> {
+        Ok(Self {
+            id: clock.0.0,
+            uid: clock.uid()?.to_string(),
+            name: clock.name().ok().map(|n| n.to_string()),
+            nominal_sample_rate: clock.nominal_sample_rate()?,
+            is_alive: clock.is_alive()?,
+            is_running: clock.is_running()?,
+        })
+    }
+}
+
+impl Process {
+    pub fn capture(process: &ca::Process) -> os::Result<Self> {
+        let mut devices = Vec::new();
+        for d in process.devices()? {
+            devices.push(d.uid()?.to_string());
+        }
+        Ok(Self {
+            id: process.0.0,
+            pid: process.pid()?,
+            bundle_id: process.bundle_id().ok().map(|b| b.to_string()),
+            is_running: process.is_running()?,
+            is_running_input: process.is_running_input()?,
+            is_running_output: process.is_running_output()?,
+            devices,
+        })
+    }
+}
+
+impl Tap {
+    pub fn capture(tap: &ca::Tap) -> os::Result<Self> {
+        let ca::Obj(id) = **tap;
+        Ok(Self {
+            id,
+            uid: tap.uid()?.to_string(),
+            format: tap.asbd().ok().map(|asbd| Format::from(&asbd)),
+        })
+    }
+}
+
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+struct MonitorState {
+    snapshot: System,
+    block: Option<arc::R<ca::PropListenerBlock>>,
+    queue: Option<arc::R<dispatch::Queue>>,
+    watched: Vec<u32>,
+    handler: Box<dyn FnMut(&System, &[Change]) + Send + 'static>,
+}
+
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+impl MonitorState {
+    const ADDR: ca::PropAddr = ca::PropAddr {
+        selector: ca::PropSelector::WILDCARD,
+        scope: ca::PropScope::WILDCARD,
+        element: ca::PropElement::WILDCARD,
+    };
+
+    /// Syncs listeners with objects of the current snapshot.
+    fn watch(&mut self) {
+        let Some(block) = self.block.as_mut() else {
+            return;
+        };
+        let queue = self.queue.as_deref();
+        let ids = self.snapshot.obj_ids();
+        self.watched.retain(|id| {
+            if ids.contains(id) {
+                return true;
+            }
+            // object is gone already, so error here is expected
+            let _ = ca::Obj(*id).remove_prop_listener_block(&Self::ADDR, queue, block);
+            false
+        });
+        for id in ids {
+            if !self.watched.contains(&id)
+                && ca::Obj(id)
+                    .add_prop_listener_block(&Self::ADDR, queue, block)
+                    .is_ok()
+            {
+                self.watched.push(id);
+            }
+        }
+    }
+
+    fn refresh(&mut self) {
+        let Ok(snapshot) = System::capture() else {
+            return;
+        };
+        let changes = diff(&self.snapshot, &snapshot);
+        self.snapshot = snapshot;
+        if changes.is_empty() {
+            return;
+        }
+        self.watch();
+        (self.handler)(&self.snapshot, &changes);
+    }
+
+    fn unwatch(&mut self) {
+        let Some(mut block) = self.block.take() else {
+            return;
+        };
+        let queue = self.queue.as_deref();
+        for id in self.watched.drain(..) {
+            let _ = ca::Obj(id).remove_prop_listener_block(&Self::ADDR, queue, &mut block);
+        }
+    }
+}
+
+/// Keeps snapshot of the HAL up to date and reports changes.
+///
+/// Listens to every object of the snapshot and recaptures the tree
+/// on any property change. `handler` is called with the new snapshot and
+/// changes from the previous one. Handler is called on the listener queue
+/// with internal lock held, so it must be `Send` and must not call back
+/// into the monitor.
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+pub struct Monitor {
+    state: std::sync::Arc<std::sync::Mutex<MonitorState>>,
+}
+
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+impl Monitor {
+    pub fn new(
+        queue: Option<arc::R<dispatch::Queue>>,
+        handler: impl FnMut(&System, &[Change]) + Send + 'static,
+    ) -> os::Result<Self> {
+        let state = std::sync::Arc::new(std::sync::Mutex::new(MonitorState {
+            snapshot: System::capture()?,
+            block: None,
+            queue,
+            watched: Vec::new(),
+            handler: Box::new(handler),
+        }));
+        let weak = std::sync::Arc::downgrade(&state);
+        let block = ca::PropListenerBlock::new2(move |_n: u32, _addrs: *const ca::PropAddr| {
+            if let Some(state) = weak.upgrade()
+                && let Ok(mut state) = state.lock()
+            {
+                state.refresh();
+            }
+        });
+        {
+            let mut s = state.lock().unwrap();
+            s.block = Some(block);
+            s.watch();
+        }
+        Ok(Self { state })
+    }
+
+    /// Latest captured snapshot.
+    pub fn snapshot(&self) -> System {
+        self.state.lock().unwrap().snapshot.clone()
+    }
+}
+
+#[cfg(all(feature = "blocks", feature = "dispatch"))]
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.unwatch();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_audio::snapshot::{System, diff};
+
+    #[test]
+    fn capture() {
+        let snapshot = System::capture().unwrap();
+        assert!(!snapshot.devices.is_empty());
+        if let Some(uid) = snapshot.default_output_device.as_deref() {
+            assert!(snapshot.device(uid).is_some());
+        }
+        assert!(diff(&snapshot, &snapshot).is_empty());
+    }
+}
@@ -62,7 +62,7 @@ mod log_item;
 pub use log_item::LogItem;
 
 #[cfg(not(target_os = "tvos"))]
-#[link(name = "CoreMotion", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreMotion", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "core_motion", kind = "static")]
@@ -46,5 +46,5 @@ pub use string_attributes::AttrName as StringAttrName;
 pub use string_attributes::UnderlineStyle;
 pub use string_attributes::UnderlineStyleModifiers;
 
-#[link(name = "CoreText", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreText", kind = "framework"))]
 unsafe extern "C" {}
@@ -55,5 +55,5 @@ pub use host_time::current_host_time;
 pub use host_time::host_clock_frequency;
 pub use host_time::host_clock_minimum_time_delta;
 
-#[link(name = "CoreVideo", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreVideo", kind = "framework"))]
 unsafe extern "C" {}
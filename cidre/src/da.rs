@@ -45,5 +45,8 @@ define_opts!(
     pub DiskOpts(u32)
 );
 
-#[link(name = "DiskArbitration", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "DiskArbitration", kind = "framework")
+)]
 unsafe extern "C" {}
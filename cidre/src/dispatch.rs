@@ -1,3 +1,68 @@
+/// Dispatch objects are Objective-C objects only on Apple platforms.
+#[cfg(all(target_vendor = "apple", feature = "objc"))]
+macro_rules! define_dispatch_type {
+    ($($t:tt)*) => {
+        $crate::define_obj_type!($($t)*);
+    };
+}
+
+/// Without objc runtime (and with swift-corelibs-libdispatch)
+/// objects are retained with `dispatch_retain`.
+#[cfg(not(all(target_vendor = "apple", feature = "objc")))]
+macro_rules! define_dispatch_type {
+    (
+        $(#[$outer:meta])*
+        $vis:vis
+        $NewType:ident($BaseType:path)
+    ) => {
+        $(#[$outer])*
+        #[derive(Debug)]
+        #[repr(transparent)]
+        $vis struct $NewType($BaseType);
+
+        impl $crate::arc::Release for $NewType {
+            #[inline]
+            unsafe fn release(&mut self) {
+                unsafe { $crate::dispatch::object::dispatch_release(std::mem::transmute(self)) }
+            }
+        }
+
+        impl $crate::arc::Retain for $NewType {
+            #[inline]
+            fn retained(&self) -> $crate::arc::R<Self> {
+                unsafe {
+                    $crate::dispatch::object::dispatch_retain(std::mem::transmute(self));
+                    std::mem::transmute(self)
+                }
+            }
+        }
+
+        impl std::ops::Deref for $NewType {
+            type Target = $BaseType;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $NewType {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl $NewType {
+            #[allow(dead_code)]
+            #[inline]
+            pub fn retained(&self) -> $crate::arc::R<Self> {
+                $crate::arc::Retain::retained(self)
+            }
+        }
+    };
+}
+
 mod base;
 
 pub use base::Fn;
@@ -31,7 +96,9 @@ mod semaphore;
 pub use semaphore::Semaphore;
 
 pub mod source;
+#[cfg(target_vendor = "apple")]
 pub use source::MachRecvFlags as SourceMachRecvFlags;
+#[cfg(target_vendor = "apple")]
 pub use source::MachSendFlags as SourceMachSendFlags;
 pub use source::MemoryPressureFlags as SourceMemoryPressureFlags;
 pub use source::ProcFlags as SourceProcFlags;
@@ -64,7 +131,8 @@ pub fn main() {
     unsafe { dispatch_main() }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     fn dispatch_main();
 }
@@ -1,8 +1,8 @@
 use std::{ffi::c_void, ptr::slice_from_raw_parts};
 
-use crate::{arc, define_obj_type, dispatch};
+use crate::{arc, dispatch};
 
-#[cfg(feature = "ns")]
+#[cfg(all(feature = "ns", target_vendor = "apple"))]
 use crate::ns;
 
 #[cfg(feature = "blocks")]
@@ -12,7 +12,7 @@ use crate::blocks;
 #[cfg(feature = "blocks")]
 pub type Applier<Attr> = blocks::Block<fn(&dispatch::Data, usize, *const u8, usize) -> bool, Attr>;
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_data_t")]
     pub Data(dispatch::Object)
 );
@@ -60,7 +60,8 @@ impl Data {
         }
     }
 
-    #[cfg(feature = "ns")]
+    /// dispatch data is bridged to `NSData` only on Apple platforms
+    #[cfg(all(feature = "ns", target_vendor = "apple"))]
     #[inline]
     pub fn as_ns(&self) -> &ns::Data {
         unsafe { std::mem::transmute(self) }
@@ -163,7 +164,8 @@ impl From<Box<[u8]>> for arc::R<Data> {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     static _dispatch_data_empty: Data;
 
@@ -240,9 +242,13 @@ mod tests {
         assert!(data.is_empty());
 
         let data = dispatch::Data::concat(&data, &data);
-
-        let data = data.as_ns();
         assert!(data.is_empty());
+
+        #[cfg(target_vendor = "apple")]
+        {
+            let data = data.as_ns();
+            assert!(data.is_empty());
+        }
     }
 
     #[test]
@@ -254,20 +260,24 @@ mod tests {
 
         let data3 = dispatch::Data::concat(&data1, &data2);
         assert_eq!(data3.len(), 10);
-        assert_eq!(data3.as_ns().len(), 10);
-        let mut ranges = vec![];
-        data3.as_ns().enum_ranges(|ptr, range, _done| {
-            assert!(!ptr.is_null());
-            ranges.push(range);
-        });
-        assert_eq!(ranges.len(), 2);
-        ranges.clear();
-        data3.as_ns().enum_ranges(|ptr, range, done| {
-            assert!(!ptr.is_null());
-            ranges.push(range);
-            *done = true;
-        });
-        assert_eq!(ranges.len(), 1);
+
+        #[cfg(target_vendor = "apple")]
+        {
+            assert_eq!(data3.as_ns().len(), 10);
+            let mut ranges = vec![];
+            data3.as_ns().enum_ranges(|ptr, range, _done| {
+                assert!(!ptr.is_null());
+                ranges.push(range);
+            });
+            assert_eq!(ranges.len(), 2);
+            ranges.clear();
+            data3.as_ns().enum_ranges(|ptr, range, done| {
+                assert!(!ptr.is_null());
+                ranges.push(range);
+                *done = true;
+            });
+            assert_eq!(ranges.len(), 1);
+        }
     }
 
     #[test]
@@ -1,10 +1,10 @@
 use std::{ffi::c_void, mem::transmute};
 
-use crate::{arc, define_obj_type, dispatch};
+use crate::{arc, dispatch};
 
 use super::{Queue, Time};
 
-define_obj_type!(pub Group(dispatch::Object));
+define_dispatch_type!(pub Group(dispatch::Object));
 
 impl Group {
     #[inline]
@@ -33,7 +33,8 @@ impl Group {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     fn dispatch_group_create() -> arc::R<Group>;
     fn dispatch_group_wait(group: &Group, timeout: Time) -> isize;
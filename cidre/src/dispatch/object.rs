@@ -1,12 +1,12 @@
 use std::{ffi::c_void, mem::transmute};
 
-use crate::{
-    define_obj_type,
-    dispatch::{self, QosClass},
-    ns,
-};
+use crate::dispatch::{self, QosClass};
 
-define_obj_type!(pub Object(ns::Id));
+#[cfg(all(target_vendor = "apple", feature = "objc"))]
+define_dispatch_type!(pub Object(crate::objc::Id));
+
+#[cfg(not(all(target_vendor = "apple", feature = "objc")))]
+define_dispatch_type!(pub Object(c_void));
 
 unsafe impl Send for Object {}
 unsafe impl Sync for Object {}
@@ -59,7 +59,8 @@ impl Object {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     fn dispatch_activate(object: &Object);
     fn dispatch_suspend(object: &Object);
@@ -74,4 +75,9 @@ unsafe extern "C-unwind" {
     );
 
     fn dispatch_set_target_queue(object: &mut Object, queue: Option<&dispatch::Queue>);
+
+    #[cfg(not(all(target_vendor = "apple", feature = "objc")))]
+    pub(crate) fn dispatch_retain(object: &Object);
+    #[cfg(not(all(target_vendor = "apple", feature = "objc")))]
+    pub(crate) fn dispatch_release(object: &mut Object);
 }
@@ -1,42 +1,42 @@
 use std::ffi::{CStr, c_char, c_long, c_void};
 
-use crate::{arc, define_obj_type, dispatch};
+use crate::{arc, dispatch};
 
 #[cfg(feature = "blocks")]
 use crate::blocks;
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_queue")]
     #[doc(alias = "dispatch_queue_t")]
     #[doc(alias = "DispatchQueue")]
     pub Queue(dispatch::Object)
 );
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_queue_global")]
     #[doc(alias = "dispatch_queue_global_t")]
     pub Global(Queue)
 );
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_queue_serial")]
     #[doc(alias = "dispatch_queue_serial_t")]
     pub Serial(Queue)
 );
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_queue_main")]
     #[doc(alias = "dispatch_queue_main_t")]
     pub Main(Serial)
 );
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_queue_concurrent")]
     #[doc(alias = "dispatch_queue_concurrent_t")]
     pub Concurrent(Queue)
 );
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_queue_attr")]
     #[doc(alias = "dispatch_queue_attr_t")]
     pub Attr(dispatch::Object)
@@ -134,12 +134,14 @@ impl Queue {
         Self::with_label_and_attrs(None::<&CStr>, attr)
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn serial_with_ar_pool() -> arc::R<Self> {
         let attr = Attr::serial_with_ar_pool();
         Self::with_label_and_attrs(None::<&CStr>, Some(&attr))
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn serial_with_ar_pool_qos(qos_class: QosClass, relative_priority: i32) -> arc::R<Self> {
         // debug_assert!(relative_priority >= QosClass::QOS_MIN_RELATIVE_PRIORITY);
@@ -149,12 +151,14 @@ impl Queue {
         Self::with_label_and_attrs(None::<&CStr>, Some(&attr))
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn concurrent_with_ar_pool() -> arc::R<Self> {
         let attr = Attr::concurrent_with_ar_pool();
         Self::with_label_and_attrs(None::<&CStr>, Some(&attr))
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn concurrent_without_ar_pool() -> arc::R<Self> {
         let attr = Attr::concurrent_without_ar_pool();
@@ -425,16 +429,19 @@ impl Attr {
         Self::make_initially_inactive(Self::concurrent())
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn serial_with_ar_pool() -> arc::R<Attr> {
         Self::make_with_ar_frequency(Self::serial(), AutoreleaseFrequency::WorkItem)
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn concurrent_with_ar_pool() -> arc::R<Attr> {
         Self::make_with_ar_frequency(Self::concurrent(), AutoreleaseFrequency::WorkItem)
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn concurrent_without_ar_pool() -> arc::R<Attr> {
         Self::make_with_ar_frequency(Self::concurrent(), AutoreleaseFrequency::Never)
@@ -477,7 +484,8 @@ impl Attr {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     static _dispatch_main_q: Main;
     static _dispatch_queue_attr_concurrent: Attr;
@@ -553,6 +561,7 @@ mod tests {
     fn queue() {
         let q = dispatch::Queue::new();
 
+        #[cfg(target_vendor = "apple")]
         q.as_type_ref().show();
 
         q.sync_f(std::ptr::null_mut(), foo);
@@ -574,6 +583,7 @@ mod tests {
         let q = dispatch::Queue::new();
 
         let foo = Foo {};
+        #[cfg(target_vendor = "apple")]
         q.as_type_ref().show();
         let b = move || {
             println!("nice! {:?}", foo);
@@ -590,12 +600,14 @@ mod tests {
     fn global_queue() {
         let q = dispatch::Queue::global_with_qos(dispatch::QosClass::BACKGROUND).unwrap();
 
+        #[cfg(target_vendor = "apple")]
         q.as_type_ref().show();
         q.sync_f(std::ptr::null_mut(), foo);
         q.async_and_wait_f(std::ptr::null_mut(), foo);
 
         let q = dispatch::Queue::global_with_priority(dispatch::QueuePriority::HIGH).unwrap();
 
+        #[cfg(target_vendor = "apple")]
         q.as_type_ref().show();
         q.sync_f(std::ptr::null_mut(), foo);
         q.async_and_wait_f(std::ptr::null_mut(), foo);
@@ -610,6 +622,7 @@ mod tests {
         assert!(q.label().is_empty());
     }
 
+    #[cfg(target_vendor = "apple")]
     #[test]
     fn qos() {
         let qos = dispatch::QosClass::USER_INITIATED;
@@ -628,6 +641,7 @@ mod tests {
         );
     }
 
+    #[cfg(target_vendor = "apple")]
     #[test]
     fn label() {
         let q = dispatch::Queue::serial_with_ar_pool();
@@ -637,4 +651,16 @@ mod tests {
         let q = dispatch::Queue::with_label_and_attrs(Some(label), None);
         assert_eq!(q.label(), label);
     }
+
+    /// autorelease frequency attributes are Apple only
+    #[cfg(not(target_vendor = "apple"))]
+    #[test]
+    fn label() {
+        let q = dispatch::Queue::new();
+        assert!(q.label().is_empty());
+
+        let label = c"label";
+        let q = dispatch::Queue::with_label_and_attrs(Some(label), None);
+        assert_eq!(q.label(), label);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{arc, define_obj_type, dispatch};
+use crate::{arc, dispatch};
 
 pub struct SignalGuard {
     sema: arc::R<Semaphore>,
@@ -15,7 +15,7 @@ impl Drop for SignalGuard {
     }
 }
 
-define_obj_type!(
+define_dispatch_type!(
     #[doc(alias = "dispatch_semaphore_t")]
     #[doc(alias = "DispatchSemaphore")]
     pub Semaphore(dispatch::Object)
@@ -57,7 +57,8 @@ impl Semaphore {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     fn dispatch_semaphore_create(value: isize) -> arc::R<Semaphore>;
     fn dispatch_semaphore_wait(sema: &Semaphore, timeout: dispatch::Time) -> isize;
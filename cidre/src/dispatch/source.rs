@@ -4,10 +4,10 @@ use std::{
     time::Duration,
 };
 
-use crate::{arc, define_obj_type, define_opts, dispatch, mach};
+use crate::{arc, define_opts, dispatch};
 
-define_obj_type!(pub Src(dispatch::Object));
-define_obj_type!(pub TimerSrc(Src));
+define_dispatch_type!(pub Src(dispatch::Object));
+define_dispatch_type!(pub TimerSrc(Src));
 
 /// The dispatch framework provides a suite of interfaces for monitoring low-
 /// level system objects (file descriptors, Mach ports, signals, VFS nodes, etc.)
@@ -17,6 +17,9 @@ define_obj_type!(pub TimerSrc(Src));
 
 /// Dispatch sources are used to automatically submit event handler blocks to
 /// dispatch queues in response to external events.
+///
+/// Mach, memory pressure, proc and vnode sources are not available
+/// in swift-corelibs-libdispatch.
 impl Src {}
 
 #[repr(transparent)]
@@ -38,21 +41,25 @@ impl Type {
         unsafe { &_dispatch_source_type_data_replace }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn mach_send() -> &'static TypeMachSend {
         unsafe { &_dispatch_source_type_mach_send }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn mach_recv() -> &'static TypeMachRecv {
         unsafe { &_dispatch_source_type_mach_recv }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn memory_pressure() -> &'static TypeMemoryPressure {
         unsafe { &_dispatch_source_type_memorypressure }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn proc() -> &'static TypeProc {
         unsafe { &_dispatch_source_type_proc }
@@ -73,6 +80,7 @@ impl Type {
         unsafe { &_dispatch_source_type_timer }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn vnode() -> &'static TypeVNode {
         unsafe { &_dispatch_source_type_vnode }
@@ -87,7 +95,9 @@ impl Type {
 pub type TypeDataAdd = Type;
 pub type TypeDataOr = Type;
 pub type TypeDataReplace = Type;
+#[cfg(target_vendor = "apple")]
 pub type TypeMachSend = Type;
+#[cfg(target_vendor = "apple")]
 pub type TypeMachRecv = Type;
 pub type TypeMemoryPressure = Type;
 pub type TypeProc = Type;
@@ -97,13 +107,16 @@ pub type TypeTimer = Type;
 pub type TypeVNode = Type;
 pub type TypeWrite = Type;
 
+#[cfg(target_vendor = "apple")]
 define_opts!(pub MachSendFlags(c_ulong));
 
+#[cfg(target_vendor = "apple")]
 impl MachSendFlags {
     pub const NONE: Self = Self(0);
     pub const SEND_DEAD: Self = Self(0x1);
 }
 
+#[cfg(target_vendor = "apple")]
 define_opts!(pub MachRecvFlags(c_ulong));
 
 define_opts!(pub MemoryPressureFlags(c_ulong));
@@ -155,23 +168,26 @@ impl Src {
         unsafe { dispatch_source_create(type_, handle, mask, queue) }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn new_mach_send(
-        port: mach::Port,
+        port: crate::mach::Port,
         flags: MachSendFlags,
         queue: Option<&dispatch::Queue>,
     ) -> Option<arc::R<Src>> {
         unsafe { Self::create(Type::mach_send(), port.0 as _, flags.0 as _, queue) }
     }
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn new_mach_recv(
-        port: mach::Port,
+        port: crate::mach::Port,
         flags: MachRecvFlags,
         queue: Option<&dispatch::Queue>,
     ) -> Option<arc::R<Src>> {
         unsafe { Self::create(Type::mach_recv(), port.0 as _, flags.0 as _, queue) }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn new_memory_pressure(
         flags: MemoryPressureFlags,
@@ -180,6 +196,7 @@ impl Src {
         unsafe { Self::create(Type::memory_pressure(), 0, flags.0 as _, queue) }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn new_proc(
         pid: crate::sys::Pid,
@@ -266,13 +283,18 @@ unsafe extern "C-unwind" {
     static _dispatch_source_type_data_add: TypeDataAdd;
     static _dispatch_source_type_data_or: TypeDataOr;
     static _dispatch_source_type_data_replace: TypeDataReplace;
+    #[cfg(target_vendor = "apple")]
     static _dispatch_source_type_mach_send: TypeMachSend;
+    #[cfg(target_vendor = "apple")]
     static _dispatch_source_type_mach_recv: TypeMachRecv;
+    #[cfg(target_vendor = "apple")]
     static _dispatch_source_type_memorypressure: TypeMemoryPressure;
+    #[cfg(target_vendor = "apple")]
     static _dispatch_source_type_proc: TypeProc;
     static _dispatch_source_type_read: TypeRead;
     static _dispatch_source_type_signal: TypeSignal;
     static _dispatch_source_type_timer: TypeTimer;
+    #[cfg(target_vendor = "apple")]
     static _dispatch_source_type_vnode: TypeVNode;
     static _dispatch_source_type_write: TypeWrite;
 
@@ -68,7 +68,8 @@ impl WallTime {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     fn dispatch_time(when: Time, delta: i64) -> Time;
     fn dispatch_walltime(when: *const TimeSpec, delta: i64) -> WallTime;
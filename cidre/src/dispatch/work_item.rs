@@ -1,6 +1,8 @@
 use std::{ffi::c_void, mem::transmute};
 
-use crate::{arc, blocks, dispatch, objc};
+#[cfg(feature = "objc")]
+use crate::objc;
+use crate::{arc, blocks, dispatch};
 
 /// The work you want to perform, encapsulated in a way that lets
 /// you attach a completion handle or execution dependencies.
@@ -12,6 +14,7 @@ use crate::{arc, blocks, dispatch, objc};
 #[repr(transparent)]
 pub struct WorkItem(dispatch::Block<blocks::Sync>);
 
+#[cfg(feature = "objc")]
 impl objc::Obj for WorkItem {
     #[inline]
     unsafe fn retain(id: &Self) -> arc::R<Self> {
@@ -24,6 +27,22 @@ impl objc::Obj for WorkItem {
     }
 }
 
+#[cfg(not(feature = "objc"))]
+impl arc::Release for WorkItem {
+    #[inline]
+    unsafe fn release(&mut self) {
+        unsafe { _Block_release(self as *mut Self as _) }
+    }
+}
+
+#[cfg(not(feature = "objc"))]
+impl arc::Retain for WorkItem {
+    #[inline]
+    fn retained(&self) -> arc::R<Self> {
+        unsafe { std::mem::transmute(_Block_copy(self as *const Self as _)) }
+    }
+}
+
 impl WorkItem {
     #[inline]
     pub fn with_flags(flags: dispatch::BlockFlags, block: &mut dispatch::Block) -> arc::R<Self> {
@@ -94,7 +113,8 @@ impl WorkItem {
     }
 }
 
-#[link(name = "System", kind = "dylib")]
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "dispatch", kind = "dylib"))]
 unsafe extern "C-unwind" {
     fn dispatch_block_create<'a>(
         flags: dispatch::BlockFlags,
@@ -33,7 +33,10 @@ mod controller;
 pub use controller::Controller;
 pub use controller::PlayerIndex as ControllerPlayerIndex;
 
-#[link(name = "GameController", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "GameController", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "gc", kind = "static")]
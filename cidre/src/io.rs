@@ -15,7 +15,7 @@ pub use surface::Surf;
 pub use surface::SurfId;
 
 #[cfg(feature = "io_surface")]
-#[link(name = "IOSurface", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "IOSurface", kind = "framework"))]
 unsafe extern "C" {}
 
 #[cfg(all(target_os = "macos", feature = "io_kit"))]
@@ -8,5 +8,5 @@ pub mod pm;
 
 pub mod hid;
 
-#[link(name = "IOKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "IOKit", kind = "framework"))]
 unsafe extern "C" {}
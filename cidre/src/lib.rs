@@ -66,7 +66,8 @@ pub mod cl;
 #[cfg(feature = "cm")]
 pub mod cm;
 
-#[cfg(all(target_os = "macos", feature = "core_audio"))]
+/// Core Audio
+#[cfg(feature = "core_audio")]
 pub mod core_audio;
 
 /// Core Motion
@@ -155,7 +156,7 @@ pub mod nw;
 #[cfg(feature = "notify")]
 pub mod notify;
 
-#[cfg(feature = "ns")]
+#[cfg(feature = "objc")]
 pub mod objc;
 
 pub mod os;
@@ -349,7 +350,7 @@ macro_rules! define_opts {
     };
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "cf"))]
 mod tests {
     use crate::cf;
 
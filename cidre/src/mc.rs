@@ -36,7 +36,10 @@ pub use nearby_service_browser::Delegate as NearbyServiceBrowserDelegate;
 pub use nearby_service_browser::DelegateImpl as NearbyServiceBrowserDelegateImpl;
 pub use nearby_service_browser::NearbyServiceBrowser;
 
-#[link(name = "MultipeerConnectivity", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "MultipeerConnectivity", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "mc", kind = "static")]
@@ -5,7 +5,7 @@ define_obj_type!(
 );
 
 impl arc::A<AdvertiserAssistant> {
-    #[objc::msg_send(initwithpeer:servicetype:)]
+    #[objc::msg_send(initwithpeer:servicetype:, throws)]
     pub unsafe fn init_service_type_throws(
         self,
         peer: &mc::PeerId,
@@ -16,11 +16,11 @@ impl arc::A<AdvertiserAssistant> {
 impl AdvertiserAssistant {
     define_cls!(MC_ADVERTISER_ASSISTANT);
 
-    pub fn with_service_type<'ear>(
+    pub fn with_service_type(
         peer: &mc::PeerId,
         service_type: &ns::String,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::alloc().init_service_type_throws(peer, service_type) })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_service_type(peer, service_type)
     }
 
     #[objc::msg_send(start)]
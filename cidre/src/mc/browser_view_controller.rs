@@ -24,14 +24,14 @@ define_obj_type!(
 impl mc::NearbyServiceBrowserDelegate for BrowserViewController {}
 
 impl arc::A<BrowserViewController> {
-    #[objc::msg_send(initWithServiceType:session:)]
+    #[objc::msg_send(initWithServiceType:session:, throws)]
     pub unsafe fn init_with_service_type_throws(
         self,
         service_type: &ns::String,
         session: &mc::Session,
     ) -> arc::R<BrowserViewController>;
 
-    #[objc::msg_send(initWithBrowser:session:)]
+    #[objc::msg_send(initWithBrowser:session:, throws)]
     pub unsafe fn init_with_browser_throws(
         self,
         browser: &mc::NearbyServiceBrowser,
@@ -42,20 +42,18 @@ impl arc::A<BrowserViewController> {
 impl BrowserViewController {
     define_cls!(MC_BROWSER_VIEW_CONTROLLER);
 
-    pub fn with_service_type<'ear>(
+    pub fn with_service_type(
         service_type: &ns::String,
         session: &mc::Session,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_service_type_throws(service_type, session)
-        })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_service_type(service_type, session)
     }
 
-    pub fn with_browser<'ear>(
+    pub fn with_browser(
         browser: &mc::NearbyServiceBrowser,
         session: &mc::Session,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::alloc().init_with_browser_throws(browser, session) })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_browser(browser, session)
     }
 
     #[objc::msg_send(browser)]
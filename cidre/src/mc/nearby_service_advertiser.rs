@@ -6,7 +6,7 @@ define_obj_type!(
 );
 
 impl arc::A<NearbyServiceAdvertiser> {
-    #[objc::msg_send(initWithPeer:discoveryInfo:serviceType:)]
+    #[objc::msg_send(initWithPeer:discoveryInfo:serviceType:, throws)]
     pub unsafe fn init_with_peer_throws(
         self,
         my_peer: &mc::PeerId,
@@ -18,14 +18,12 @@ impl arc::A<NearbyServiceAdvertiser> {
 impl NearbyServiceAdvertiser {
     define_cls!(MC_NEARBY_SERVICE_ADVERTISER);
 
-    pub fn with_peer<'ear>(
+    pub fn with_peer(
         my_peer: &mc::PeerId,
         discorvery_info: Option<&ns::Dictionary<ns::String, ns::String>>,
         service_type: &ns::String,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_peer_throws(my_peer, discorvery_info, service_type)
-        })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_peer(my_peer, discorvery_info, service_type)
     }
 
     #[objc::msg_send(startAdvertisingPeer)]
@@ -6,7 +6,7 @@ define_obj_type!(
 );
 
 impl arc::A<NearbyServiceBrowser> {
-    #[objc::msg_send(initWithPeer:serviceType:)]
+    #[objc::msg_send(initWithPeer:serviceType:, throws)]
     pub unsafe fn init_with_peer_and_service_type_throws(
         self,
         peer: &mc::PeerId,
@@ -17,13 +17,11 @@ impl arc::A<NearbyServiceBrowser> {
 impl NearbyServiceBrowser {
     define_cls!(MC_NEARBY_SERVICE_BROWSER);
 
-    pub fn with_peer<'ear>(
+    pub fn with_peer(
         peer: &mc::PeerId,
         service_type: &ns::String,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_peer_and_service_type_throws(peer, service_type)
-        })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_peer_and_service_type(peer, service_type)
     }
 
     #[objc::msg_send(startBrowsingForPeers)]
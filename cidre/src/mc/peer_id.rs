@@ -6,7 +6,7 @@ define_obj_type!(
 );
 
 impl arc::A<PeerId> {
-    #[objc::msg_send(initWithDisplayName:)]
+    #[objc::msg_send(initWithDisplayName:, throws)]
     pub unsafe fn init_with_display_name_throws(self, display_name: &ns::String) -> arc::R<PeerId>;
 }
 
@@ -14,8 +14,8 @@ impl PeerId {
     define_cls!(MC_PEER_ID);
 
     #[inline]
-    pub fn with_display_name<'ear>(name: &ns::String) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::alloc().init_with_display_name_throws(name) })
+    pub fn with_display_name(name: &ns::String) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_display_name(name)
     }
 
     #[objc::msg_send(displayName)]
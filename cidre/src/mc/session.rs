@@ -68,10 +68,10 @@ define_obj_type!(
 );
 
 impl arc::A<Session> {
-    #[objc::msg_send(initWithPeer:)]
+    #[objc::msg_send(initWithPeer:, throws)]
     pub unsafe fn init_with_peer_throws(self, peer: &mc::PeerId) -> arc::R<Session>;
 
-    #[objc::msg_send(initWithPeer:securityIdentity:encryptionPreference:)]
+    #[objc::msg_send(initWithPeer:securityIdentity:encryptionPreference:, throws)]
     pub unsafe fn init_with_encryption_throws(
         self,
         peer: &mc::PeerId,
@@ -85,19 +85,17 @@ impl Session {
 
     /// Create a session with an MCPeerID for the local peer.
     #[inline]
-    pub fn with_peer<'ear>(peer: &mc::PeerId) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::alloc().init_with_peer_throws(peer) })
+    pub fn with_peer(peer: &mc::PeerId) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_peer(peer)
     }
 
     #[inline]
-    pub fn with_encryption<'ear>(
+    pub fn with_encryption(
         peer: &mc::PeerId,
         sec_identity: Option<&ns::Array<ns::Id>>,
         encryption_preference: mc::EncryptionPreference,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_encryption_throws(peer, sec_identity, encryption_preference)
-        })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_encryption(peer, sec_identity, encryption_preference)
     }
 
     #[objc::msg_send(sendData:toPeers:withMode:error:)]
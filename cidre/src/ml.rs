@@ -65,7 +65,7 @@ pub use model_configuration::ModelCfg;
 mod model_description;
 pub use model_description::ModelDesc;
 
-#[link(name = "CoreML", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreML", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "ml", kind = "static")]
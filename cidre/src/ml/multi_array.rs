@@ -75,7 +75,7 @@ impl arc::A<MultiArray> {
         error: *mut Option<&'ear ns::Error>,
     ) -> Option<arc::R<MultiArray>>;
 
-    #[objc::msg_send(initWithPixelBuffer:shape:)]
+    #[objc::msg_send(initWithPixelBuffer:shape:, throws)]
     pub unsafe fn init_with_pixel_buf_throws(
         self,
         pixel_buf: &cv::PixelBuf,
@@ -99,13 +99,11 @@ impl MultiArray {
     ) -> arc::R<Self> {
         Self::alloc().init_with_shape_strides(shape.as_ref(), d_type, strides.as_ref())
     }
-    pub fn with_pixel_buf<'ear, S: AsRef<ns::Array<ns::Number>>>(
+    pub fn with_pixel_buf<S: AsRef<ns::Array<ns::Number>>>(
         pixel_buf: &cv::PixelBuf,
         shape: S,
-    ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe {
-            Self::alloc().init_with_pixel_buf_throws(pixel_buf, shape.as_ref())
-        })
+    ) -> objc::TryResult<arc::R<Self>> {
+        Self::alloc().try_init_with_pixel_buf(pixel_buf, shape.as_ref())
     }
 
     pub unsafe fn with_ptr<'ear, S: AsRef<ns::Array<ns::Number>>>(
@@ -165,19 +163,20 @@ impl MultiArray {
 
 /// Concatenating
 impl MultiArray {
-    #[objc::msg_send(multiArrayByConcatenatingMultiArrays:alongAxis:dataType:)]
+    #[objc::msg_send(multiArrayByConcatenatingMultiArrays:alongAxis:dataType:, throws)]
     pub unsafe fn concat_throws(
         multi_arrays: &ns::Array<Self>,
         axis: isize,
         d_type: MultiArrayDType,
     ) -> arc::R<Self>;
 
+    #[deprecated(note = "use `try_concat`")]
     pub fn concat<'ear, S: AsRef<ns::Array<Self>>>(
         multi_arrays: S,
         axis: isize,
         d_type: MultiArrayDType,
     ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::concat_throws(multi_arrays.as_ref(), axis, d_type) })
+        Self::try_concat(multi_arrays.as_ref(), axis, d_type).map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -75,7 +75,7 @@ mod matmul;
 pub use matmul::Desc as MatMulDesc;
 pub use matmul::Layer as MatMulLayer;
 
-#[link(name = "MLCompute", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "MLCompute", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "mlc", kind = "static")]
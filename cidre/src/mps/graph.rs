@@ -268,7 +268,10 @@ unsafe extern "C" {
 }
 
 #[cfg(not(target_os = "watchos"))]
-#[link(name = "MetalPerformanceShadersGraph", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "MetalPerformanceShadersGraph", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[cfg(test)]
@@ -21,7 +21,10 @@ pub fn register_professional_video_workflow_format_readers() {
 }
 
 #[cfg(target_os = "macos")]
-#[link(name = "MediaToolbox", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "MediaToolbox", kind = "framework")
+)]
 unsafe extern "C" {
     fn MTRegisterProfessionalVideoWorkflowFormatReaders();
 }
@@ -8,7 +8,7 @@ pub use texture_loader::TextureLoaderCubeLayout;
 pub use texture_loader::TextureLoaderOpt;
 pub use texture_loader::TextureLoaderOrigin;
 
-#[link(name = "MetalKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "MetalKit", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "mtk", kind = "static")]
@@ -357,6 +357,6 @@ mod tests {
 
         assert_eq!(1, textures.len());
         assert!(err.is_some());
-        assert!(textures.get(0).unwrap().is_none());
+        assert!(textures.try_get(0).unwrap().is_none());
     }
 }
@@ -456,7 +456,7 @@ macro_rules! define_mtl {
     };
 }
 
-#[link(name = "Metal", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Metal", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "mtl", kind = "static")]
@@ -429,19 +429,19 @@ impl Device {
 
     /// Returns the minimum alignment required for offset and rowBytes when creating a linear texture.
     /// An error is thrown for queries with invalid pixel formats (depth, stencil, or compressed formats).
-    #[objc::msg_send(minimumLinearTextureAlignmentForPixelFormat:)]
+    #[objc::msg_send(minimumLinearTextureAlignmentForPixelFormat:, throws)]
     pub unsafe fn min_linear_texture_alignment_for_pixel_format_throws(
         &self,
         format: mtl::PixelFormat,
     ) -> usize;
 
+    #[deprecated(note = "use `try_min_linear_texture_alignment_for_pixel_format`")]
     pub fn min_linear_texture_alignment_for_pixel_format<'ear>(
         &self,
         format: mtl::PixelFormat,
     ) -> ns::ExResult<'ear, usize> {
-        ns::try_catch(|| unsafe {
-            self.min_linear_texture_alignment_for_pixel_format_throws(format)
-        })
+        self.try_min_linear_texture_alignment_for_pixel_format(format)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(minimumTextureBufferAlignmentForPixelFormat:)]
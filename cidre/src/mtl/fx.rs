@@ -17,7 +17,7 @@ pub use spatial_scaler::SpatialScaler;
 pub use spatial_scaler::SpatialScalerBase;
 pub use spatial_scaler::SpatialScalerColorProcessingMode;
 
-#[link(name = "MetalFX", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "MetalFX", kind = "framework"))]
 unsafe extern "C" {}
 
 #[cfg(all(feature = "mtl_fx", not(target_env = "sim")))]
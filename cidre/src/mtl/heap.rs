@@ -94,7 +94,7 @@ impl Heap {
     pub fn max_available_size_with_alignment(&self, alignment: usize) -> usize;
 
     /// Create a new buffer backed by heap memory.
-    #[objc::msg_send(newBufferWithLength:options:)]
+    #[objc::msg_send(newBufferWithLength:options:, throws)]
     pub unsafe fn new_buf_throws(
         &self,
         length: usize,
@@ -102,12 +102,14 @@ impl Heap {
     ) -> Option<arc::R<mtl::Buf>>;
 
     /// Create a new buffer backed by heap memory.
+    #[deprecated(note = "use `try_new_buf`")]
     pub fn new_buf<'ear>(
         &self,
         length: usize,
         options: mtl::ResOpts,
     ) -> ns::ExResult<'ear, Option<arc::R<mtl::Buf>>> {
-        ns::try_catch(|| unsafe { self.new_buf_throws(length, options) })
+        self.try_new_buf(length, options)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(newTextureWithDescriptor:)]
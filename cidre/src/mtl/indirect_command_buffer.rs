@@ -144,32 +144,36 @@ impl IndirectCmdBuf {
         self.reset_with_range(ns::Range::new(0, len))
     }
 
-    #[objc::msg_send(indirectRenderCommandAtIndex:)]
+    #[objc::msg_send(indirectRenderCommandAtIndex:, throws)]
     pub unsafe fn indirect_render_cmd_at_throws(
         &self,
         index: usize,
     ) -> arc::R<mtl::IndirectRenderCmd>;
 
+    #[deprecated(note = "use `try_indirect_render_cmd_at`")]
     #[inline]
     pub fn indirect_render_cmd_at<'ear>(
         &self,
         index: usize,
     ) -> ns::ExResult<'ear, arc::R<mtl::IndirectRenderCmd>> {
-        ns::try_catch(|| unsafe { self.indirect_render_cmd_at_throws(index) })
+        self.try_indirect_render_cmd_at(index)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(indirectComputeCommandAtIndex:)]
+    #[objc::msg_send(indirectComputeCommandAtIndex:, throws)]
     pub unsafe fn indirect_compute_cmd_at_throws(
         &self,
         index: usize,
     ) -> arc::R<mtl::IndirectComputeCmd>;
 
+    #[deprecated(note = "use `try_indirect_compute_cmd_at`")]
     #[inline]
     pub fn indirect_compute_cmd_at<'ear>(
         &self,
         index: usize,
     ) -> ns::ExResult<'ear, arc::R<mtl::IndirectComputeCmd>> {
-        ns::try_catch(|| unsafe { self.indirect_compute_cmd_at_throws(index) })
+        self.try_indirect_compute_cmd_at(index)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
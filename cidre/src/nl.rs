@@ -9,7 +9,10 @@ pub use embedding::Distance;
 pub use embedding::DistanceType;
 pub use embedding::Embedding;
 
-#[link(name = "NaturalLanguage", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "NaturalLanguage", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "nl", kind = "static")]
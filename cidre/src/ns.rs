@@ -242,13 +242,18 @@ mod locale;
 pub use locale::Locale;
 
 mod key_value_observing;
+pub use key_value_observing::Change;
+pub use key_value_observing::ChangeObserver;
+#[cfg(feature = "async")]
+pub use key_value_observing::ChangeStream;
 pub use key_value_observing::CidreObserver;
 pub use key_value_observing::KvChange;
 pub use key_value_observing::KvChangeKey;
 pub use key_value_observing::KvObserverRegistration;
-pub use key_value_observing::KvObserverRegistrationImpl;
 pub use key_value_observing::KvObserving;
+pub use key_value_observing::KvRaw;
 pub use key_value_observing::KvSetMutationKind;
+pub use key_value_observing::KvValue;
 pub use key_value_observing::KvoOpts;
 pub use key_value_observing::Observer;
 
@@ -327,7 +332,8 @@ macro_rules! log {
 
 pub use log;
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(target_vendor = "apple", link(name = "ns", kind = "static"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "gnustep", kind = "static"))]
 unsafe extern "C" {
     fn cidre_log(str: &crate::ns::String);
 }
@@ -394,5 +400,5 @@ pub trait CopyingMut {
     unsafe fn copy_with_zone_mut(&self, zone: *mut Zone) -> Option<arc::Retained<Id>>;
 }
 
-#[link(name = "Foundation", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Foundation", kind = "framework"))]
 unsafe extern "C" {}
@@ -240,7 +240,7 @@ pub use workspace::AuthorizationType as WorkspaceAuthorizationType;
 pub use workspace::Workspace;
 pub use workspace::WorkspaceOpenCfg;
 
-#[link(name = "AppKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "AppKit", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "app", kind = "static")]
@@ -34,63 +34,73 @@ impl Color {
         Self::color_named(name.as_ref())
     }
 
-    #[objc::msg_send(whiteComponent)]
+    #[objc::msg_send(whiteComponent, throws)]
     pub unsafe fn white_component_throws(&self) -> cg::Float;
 
+    #[deprecated(note = "use `try_white_component`")]
     pub fn white_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.white_component_throws() })
+        self.try_white_component().map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(alphaComponent)]
     pub fn alpha_component(&self) -> cg::Float;
 
-    #[objc::msg_send(redComponent)]
+    #[objc::msg_send(redComponent, throws)]
     pub unsafe fn red_component_throws(&self) -> cg::Float;
 
-    #[objc::msg_send(greenComponent)]
-    pub unsafe fn green_component_throws(&self) -> cg::Float;
-
-    #[objc::msg_send(blueComponent)]
-    pub unsafe fn blue_component_throws(&self) -> cg::Float;
-
+    #[deprecated(note = "use `try_red_component`")]
     pub fn red_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.red_component_throws() })
+        self.try_red_component().map_err(|e| &*e.autoreleased())
     }
 
+    #[objc::msg_send(greenComponent, throws)]
+    pub unsafe fn green_component_throws(&self) -> cg::Float;
+
+    #[deprecated(note = "use `try_green_component`")]
     pub fn green_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.green_component_throws() })
+        self.try_green_component().map_err(|e| &*e.autoreleased())
     }
 
+    #[objc::msg_send(blueComponent, throws)]
+    pub unsafe fn blue_component_throws(&self) -> cg::Float;
+
+    #[deprecated(note = "use `try_blue_component`")]
     pub fn blue_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.blue_component_throws() })
+        self.try_blue_component().map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(hueComponent)]
+    #[objc::msg_send(hueComponent, throws)]
     pub unsafe fn hue_component_throws(&self) -> cg::Float;
 
-    #[objc::msg_send(saturationComponent)]
-    pub unsafe fn saturation_component_throws(&self) -> cg::Float;
-
-    #[objc::msg_send(brightnessComponent)]
-    pub unsafe fn brightness_component_throws(&self) -> cg::Float;
-
+    #[deprecated(note = "use `try_hue_component`")]
     pub fn hue_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.hue_component_throws() })
+        self.try_hue_component().map_err(|e| &*e.autoreleased())
     }
 
+    #[objc::msg_send(saturationComponent, throws)]
+    pub unsafe fn saturation_component_throws(&self) -> cg::Float;
+
+    #[deprecated(note = "use `try_saturation_component`")]
     pub fn saturation_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.saturation_component_throws() })
+        self.try_saturation_component()
+            .map_err(|e| &*e.autoreleased())
     }
 
+    #[objc::msg_send(brightnessComponent, throws)]
+    pub unsafe fn brightness_component_throws(&self) -> cg::Float;
+
+    #[deprecated(note = "use `try_brightness_component`")]
     pub fn brightness_component<'ar>(&self) -> Result<cg::Float, &'ar ns::Exception> {
-        ns::try_catch(|| unsafe { self.brightness_component_throws() })
+        self.try_brightness_component()
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(colorSpace)]
+    #[objc::msg_send(colorSpace, throws)]
     pub unsafe fn color_space_throws(&self) -> arc::R<ns::ColorSpace>;
 
+    #[deprecated(note = "use `try_color_space`")]
     pub fn color_space<'ear>(&self) -> ns::ExResult<'ear, arc::R<ns::ColorSpace>> {
-        ns::try_catch(|| unsafe { self.color_space_throws() })
+        self.try_color_space().map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(colorWithRed:green:blue:alpha:exposure:)]
@@ -460,11 +470,11 @@ mod tests {
     #[test]
     fn basics() {
         let black = ns::Color::with_white_alpha(0.0, 1.0);
-        assert_eq!(black.white_component().unwrap(), 0.0);
+        assert_eq!(black.try_white_component().unwrap(), 0.0);
 
         assert_eq!(black.alpha_component(), 1.0);
 
-        black.red_component().expect_err("should be err");
+        black.try_red_component().expect_err("should be err");
 
         assert!(ns::Color::named(ns::str!(c"foo")).is_none());
     }
@@ -474,17 +484,17 @@ mod tests {
         let color = ns::Color::with_hsba(0.5, 0.75, 0.25, 1.0);
 
         // Test hue component
-        assert!((color.hue_component().unwrap() - 0.5f64).abs() < f64::EPSILON);
+        assert!((color.try_hue_component().unwrap() - 0.5f64).abs() < f64::EPSILON);
 
         // Test saturation component
-        assert!((color.saturation_component().unwrap() - 0.75).abs() < f64::EPSILON);
+        assert!((color.try_saturation_component().unwrap() - 0.75).abs() < f64::EPSILON);
 
         // Test brightness component
-        assert!((color.brightness_component().unwrap() - 0.25).abs() < f64::EPSILON);
+        assert!((color.try_brightness_component().unwrap() - 0.25).abs() < f64::EPSILON);
 
         // Test error case (color doesn't support HSB)
         let gray = ns::Color::with_white_alpha(0.5, 1.0);
-        gray.hue_component()
+        gray.try_hue_component()
             .expect_err("gray color shouldn't have hue component");
     }
 
@@ -497,11 +507,11 @@ mod tests {
                 // we can't create hdr colors from system colors
                 let bright_red = red.applying_content_headroom(2.0);
                 assert_eq!(bright_red.linear_exposure(), 1.0);
-                assert!(bright_red.color_space().is_err());
+                assert!(bright_red.try_color_space().is_err());
 
                 let bright_red = ns::Color::with_linear_exposure(1.0, 0.0, 0.0, 1.0, 2.0);
                 assert_eq!(bright_red.linear_exposure(), 2.0);
-                assert!(bright_red.color_space().is_ok());
+                assert!(bright_red.try_color_space().is_ok());
 
                 let red = bright_red.sdr();
                 assert_eq!(red.linear_exposure(), 1.0);
@@ -97,7 +97,7 @@ mod tests {
         let apps = ns::Workspace::shared().running_apps();
         assert!(!apps.is_empty());
 
-        let app = apps.get(0).unwrap();
+        let app = apps.try_get(0).unwrap();
         assert!(!app.is_terminated());
 
         let _app = ns::RunningApp::new();
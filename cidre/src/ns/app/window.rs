@@ -268,11 +268,12 @@ impl Window {
     #[objc::msg_send(styleMask)]
     pub fn style_mask(&self) -> ns::WindowStyleMask;
 
-    #[objc::msg_send(setStyleMask:)]
+    #[objc::msg_send(setStyleMask:, throws)]
     pub unsafe fn set_style_mask_throws(&mut self, val: ns::WindowStyleMask);
 
+    #[deprecated(note = "use `try_set_style_mask`")]
     pub fn set_style_mask<'ear>(&mut self, val: ns::WindowStyleMask) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_style_mask_throws(val) })
+        self.try_set_style_mask(val).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(hasShadow)]
@@ -141,12 +141,13 @@ impl<T: objc::Obj> Array<T> {
     ///
     /// # Safety
     /// Throws an ObjC exception if `index` is out of bounds.
-    #[objc::msg_send(objectAtIndex:)]
+    #[objc::msg_send(objectAtIndex:, throws)]
     pub unsafe fn get_throws(&self, index: usize) -> arc::R<T>;
 
     /// Returns the object at `index`, capturing ObjC exceptions as `ExResult`.
+    #[deprecated(note = "use `try_get`")]
     pub fn get<'ear>(&self, index: usize) -> ns::ExResult<'ear, arc::R<T>> {
-        unsafe { ns::try_catch(|| self.get_throws(index)) }
+        self.try_get(index).map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(feature = "cf")]
@@ -256,26 +257,29 @@ impl<T: objc::Obj> ArrayMut<T> {
     ///
     /// # Safety
     /// Throws an ObjC exception if `index` is out of bounds.
-    #[objc::msg_send(removeObjectAtIndex:)]
+    #[objc::msg_send(removeObjectAtIndex:, throws)]
     pub unsafe fn remove_throws(&mut self, index: usize);
 
     /// Removes the element at `index`, capturing ObjC exceptions as `ExResult`.
+    #[deprecated(note = "use `try_remove`")]
     #[inline]
     pub fn remove<'ear>(&mut self, index: usize) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.remove_throws(index) })
+        self.try_remove(index).map_err(|e| &*e.autoreleased())
     }
 
     /// Inserts `obj` at `at_index`.
     ///
     /// # Safety
     /// Throws an ObjC exception if `at_index` is out of bounds.
-    #[objc::msg_send(insertObject:atIndex:)]
+    #[objc::msg_send(insertObject:atIndex:, throws)]
     pub unsafe fn insert_obj_throws(&mut self, obj: &T, at_index: usize);
 
     /// Inserts `element` at `index`, capturing ObjC exceptions as `ExResult`.
+    #[deprecated(note = "use `try_insert_obj`")]
     #[inline]
     pub fn insert<'ear>(&mut self, index: usize, element: &T) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.insert_obj_throws(element, index) })
+        self.try_insert_obj(element, index)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(feature = "cf")]
@@ -611,12 +615,14 @@ mod tests {
         let arr = ns::Array::<ns::Number>::new();
         let mut mut_copy = arr.copy_mut();
         assert!(mut_copy.is_empty());
-        mut_copy.insert(0, &ns::Number::tagged_i8(1)).unwrap();
+        mut_copy
+            .try_insert_obj(&ns::Number::tagged_i8(1), 0)
+            .unwrap();
         assert_eq!(1, mut_copy.len());
         assert!(arr.is_empty());
         assert!(!mut_copy.is_empty());
 
-        mut_copy.remove(10).expect_err("should be exception");
+        mut_copy.try_remove(10).expect_err("should be exception");
         mut_copy.clear();
         assert!(mut_copy.is_empty());
     }
@@ -624,7 +630,7 @@ mod tests {
     #[test]
     fn exception() {
         let arr = ns::Array::<ns::Number>::new();
-        arr.get(0).expect_err("Should be exception");
+        arr.try_get(0).expect_err("Should be exception");
     }
 
     #[test]
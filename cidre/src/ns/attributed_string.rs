@@ -202,19 +202,21 @@ impl AttrString {
         self.len() == 0
     }
 
-    #[objc::msg_send(attributesAtIndex:effectiveRange:)]
+    #[objc::msg_send(attributesAtIndex:effectiveRange:, throws)]
     pub unsafe fn attrs_at_throws(
         &self,
         index: usize,
         effective_range: Option<&ns::Range>,
     ) -> arc::R<ns::Dictionary<AttrStringKey, ns::Id>>;
 
+    #[deprecated(note = "use `try_attrs_at`")]
     pub fn attrs_at<'ear>(
         &self,
         index: usize,
         effective_range: Option<&ns::Range>,
     ) -> ns::ExResult<'ear, arc::R<ns::Dictionary<AttrStringKey, ns::Id>>> {
-        ns::try_catch(|| unsafe { self.attrs_at_throws(index, effective_range) })
+        self.try_attrs_at(index, effective_range)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[inline]
@@ -279,7 +281,7 @@ impl AttrStringMut {
     #[objc::msg_send(mutableString)]
     pub fn string_mut(&mut self) -> &mut ns::StringMut;
 
-    #[objc::msg_send(addAttribute:value:range:)]
+    #[objc::msg_send(addAttribute:value:range:, throws)]
     pub unsafe fn add_attr_throws(
         &mut self,
         name: &ns::AttrStringKey,
@@ -287,39 +289,45 @@ impl AttrStringMut {
         range: ns::Range,
     );
 
+    #[deprecated(note = "use `try_add_attr`")]
     pub fn add_attr<'ear>(
         &mut self,
         name: &ns::AttrStringKey,
         val: &ns::Id,
         range: ns::Range,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.add_attr_throws(name, val, range) })
+        self.try_add_attr(name, val, range)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(addAttributes:range:)]
+    #[objc::msg_send(addAttributes:range:, throws)]
     pub unsafe fn add_attrs_throws(
         &mut self,
         attrs: &ns::Dictionary<ns::AttrStringKey, ns::Id>,
         range: ns::Range,
     );
 
+    #[deprecated(note = "use `try_add_attrs`")]
     pub fn add_attrs<'ear>(
         &mut self,
         attrs: &ns::Dictionary<ns::AttrStringKey, ns::Id>,
         range: ns::Range,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.add_attrs_throws(attrs, range) })
+        self.try_add_attrs(attrs, range)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(removeAttribute:range:)]
+    #[objc::msg_send(removeAttribute:range:, throws)]
     pub unsafe fn remove_attr_throws(&mut self, name: &ns::AttrStringKey, range: ns::Range);
 
+    #[deprecated(note = "use `try_remove_attr`")]
     pub fn remove_attr<'ear>(
         &mut self,
         name: &ns::AttrStringKey,
         range: ns::Range,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.remove_attr_throws(name, range) })
+        self.try_remove_attr(name, range)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(insertAttributedString:atIndex:)]
@@ -328,19 +336,21 @@ impl AttrStringMut {
     #[objc::msg_send(appendAttributedString:)]
     pub fn append_attr_string(&mut self, val: &ns::AttrString);
 
-    #[objc::msg_send(replaceCharactersInRange:withAttributedString:)]
+    #[objc::msg_send(replaceCharactersInRange:withAttributedString:, throws)]
     pub unsafe fn replace_with_attr_string_throws(
         &mut self,
         range: ns::Range,
         attr_str: &ns::AttrString,
     );
 
+    #[deprecated(note = "use `try_replace_with_attr_string`")]
     pub fn replace_with_attr_string<'ear>(
         &mut self,
         range: ns::Range,
         attr_str: &ns::AttrString,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.replace_with_attr_string_throws(range, attr_str) })
+        self.try_replace_with_attr_string(range, attr_str)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -375,7 +385,7 @@ mod tests {
         let astr = ns::AttrString::with_string(&str);
 
         assert_eq!(&astr.string(), &str);
-        let attrs = astr.attrs_at(0, None).unwrap();
+        let attrs = astr.try_attrs_at(0, None).unwrap();
         assert!(attrs.is_empty());
 
         let copy = astr.copy_mut();
@@ -393,20 +403,22 @@ mod tests {
 
         let attrs = ns::Dictionary::with_keys_values(&[color_key], &[color_val.as_id_ref()]);
 
-        mcopy.add_attrs(&attrs, ns::Range::new(0, 5)).expect("ok");
         mcopy
-            .add_attr(color_key, &color_val, ns::Range::new(1000, 10))
+            .try_add_attrs(&attrs, ns::Range::new(0, 5))
+            .expect("ok");
+        mcopy
+            .try_add_attr(color_key, &color_val, ns::Range::new(1000, 10))
             .expect_err("ok");
         mcopy
-            .add_attr(color_key, &color_val, ns::Range::new(0, 3))
+            .try_add_attr(color_key, &color_val, ns::Range::new(0, 3))
             .expect("ok");
 
         mcopy
-            .remove_attr(color_key, ns::Range::new(100, 200))
+            .try_remove_attr(color_key, ns::Range::new(100, 200))
             .expect_err("should be out of bounds");
 
         mcopy
-            .remove_attr(color_key, ns::Range::new(0, 5))
+            .try_remove_attr(color_key, ns::Range::new(0, 5))
             .expect("failed to remove valid range");
 
         let mstr = ns::AttrStringMut::with_str("nice");
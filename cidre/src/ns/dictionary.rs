@@ -14,7 +14,7 @@ impl<K: Obj, V: Obj> arc::A<Dictionary<K, V>> {
     #[objc::msg_send(init)]
     pub fn init(self) -> arc::R<Dictionary<K, V>>;
 
-    #[objc::msg_send(initWithObjects:forKeys:count:)]
+    #[objc::msg_send(initWithObjects:forKeys:count:, throws)]
     pub unsafe fn init_with_objs_for_keys_count_throws(
         self,
         objects: *const V,
@@ -45,125 +45,141 @@ impl<I: objc::Obj> DiffableDataSrcSectionSnapshot<I> {
     #[objc::msg_send(parentForItemIdentifier:)]
     pub fn parent_of_item_id(&self, item_id: &I) -> Option<arc::R<I>>;
 
-    #[objc::msg_send(appendItemsWithIdentifiers:)]
+    #[objc::msg_send(appendItemsWithIdentifiers:, throws)]
     pub unsafe fn append_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_append_items`")]
     #[inline]
     pub fn append_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.append_items_throws(items) })
+        self.try_append_items(items).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(appendItemsWithIdentifiers:intoItemWithIdentifier:)]
+    #[objc::msg_send(appendItemsWithIdentifiers:intoItemWithIdentifier:, throws)]
     pub unsafe fn append_items_into_throws(&mut self, items: &ns::Array<I>, parent: &I);
 
+    #[deprecated(note = "use `try_append_items_into`")]
     #[inline]
     pub fn append_items_into<'ear>(
         &mut self,
         items: &ns::Array<I>,
         parent: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.append_items_into_throws(items, parent.as_ref()) })
+        self.try_append_items_into(items, parent.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertItemsWithIdentifiers:beforeItemWithIdentifier:)]
+    #[objc::msg_send(insertItemsWithIdentifiers:beforeItemWithIdentifier:, throws)]
     pub unsafe fn insert_items_before_item_throws(&mut self, items: &ns::Array<I>, item: &I);
 
+    #[deprecated(note = "use `try_insert_items_before_item`")]
     #[inline]
     pub fn insert_items_before_item<'ear>(
         &mut self,
         items: &ns::Array<I>,
         item: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.insert_items_before_item_throws(items, item.as_ref()) })
+        self.try_insert_items_before_item(items, item.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertItemsWithIdentifiers:afterItemWithIdentifier:)]
+    #[objc::msg_send(insertItemsWithIdentifiers:afterItemWithIdentifier:, throws)]
     pub unsafe fn insert_items_after_item_throws(&mut self, items: &ns::Array<I>, item: &I);
 
+    #[deprecated(note = "use `try_insert_items_after_item`")]
     #[inline]
     pub fn insert_items_after_item<'ear>(
         &mut self,
         items: &ns::Array<I>,
         item: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.insert_items_after_item_throws(items, item.as_ref()) })
+        self.try_insert_items_after_item(items, item.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(deleteItemsWithIdentifiers:)]
+    #[objc::msg_send(deleteItemsWithIdentifiers:, throws)]
     pub unsafe fn delete_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_delete_items`")]
     #[inline]
     pub fn delete_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.delete_items_throws(items) })
+        self.try_delete_items(items).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(deleteAllItems)]
     pub fn delete_all_items(&mut self);
 
-    #[objc::msg_send(moveItemWithIdentifier:beforeItemWithIdentifier:)]
+    #[objc::msg_send(moveItemWithIdentifier:beforeItemWithIdentifier:, throws)]
     pub unsafe fn move_item_before_item_throws(&mut self, item: &I, before: &I);
 
+    #[deprecated(note = "use `try_move_item_before_item`")]
     #[inline]
     pub fn move_item_before_item<'ear>(
         &mut self,
         item: impl AsRef<I>,
         before: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.move_item_before_item_throws(item.as_ref(), before.as_ref())
-        })
+        self.try_move_item_before_item(item.as_ref(), before.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(moveItemWithIdentifier:afterItemWithIdentifier:)]
+    #[objc::msg_send(moveItemWithIdentifier:afterItemWithIdentifier:, throws)]
     pub unsafe fn move_item_after_item_throws(&mut self, item: &I, after: &I);
 
+    #[deprecated(note = "use `try_move_item_after_item`")]
     #[inline]
     pub fn move_item_after_item<'ear>(
         &mut self,
         item: impl AsRef<I>,
         after: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.move_item_after_item_throws(item.as_ref(), after.as_ref()) })
+        self.try_move_item_after_item(item.as_ref(), after.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(reloadItemsWithIdentifiers:)]
+    #[objc::msg_send(reloadItemsWithIdentifiers:, throws)]
     pub unsafe fn reload_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_reload_items`")]
     #[inline]
     pub fn reload_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.reload_items_throws(items) })
+        self.try_reload_items(items).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(reconfigureItemsWithIdentifiers:)]
+    #[objc::msg_send(reconfigureItemsWithIdentifiers:, throws)]
     #[objc::available(ios = 15.0, tvos = 15.0, macos = 12.0, watchos = 8.0, visionos = 1.0)]
     pub unsafe fn reconfigure_items_throws(&mut self, items: &ns::Array<I>);
 
-    #[objc::msg_send(expandItemsWithIdentifiers:)]
+    #[objc::msg_send(expandItemsWithIdentifiers:, throws)]
     pub unsafe fn expand_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_expand_items`")]
     #[inline]
     pub fn expand_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.expand_items_throws(items) })
+        self.try_expand_items(items).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(collapseItemsWithIdentifiers:)]
+    #[objc::msg_send(collapseItemsWithIdentifiers:, throws)]
     pub unsafe fn collapse_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_collapse_items`")]
     #[inline]
     pub fn collapse_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.collapse_items_throws(items) })
+        self.try_collapse_items(items)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(collapseItemsWithIdentifiers:mode:)]
+    #[objc::msg_send(collapseItemsWithIdentifiers:mode:, throws)]
     pub unsafe fn collapse_items_mode_throws(&mut self, items: &ns::Array<I>, mode: ns::Integer);
 
+    #[deprecated(note = "use `try_collapse_items_mode`")]
     #[inline]
     pub fn collapse_items_mode<'ear>(
         &mut self,
         items: &ns::Array<I>,
         mode: ns::Integer,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.collapse_items_mode_throws(items, mode) })
+        self.try_collapse_items_mode(items, mode)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(isItemExpanded:)]
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use crate::{
-    api, arc, define_cls,
+    arc, define_cls,
     ns::{self, Copying},
     objc,
 };
@@ -44,26 +44,30 @@ impl<S: objc::Obj, I: objc::Obj> DiffableDataSrcSnapshot<S, I> {
     #[objc::msg_send(itemIdentifiers)]
     pub fn item_ids(&self) -> arc::R<ns::Array<I>>;
 
-    #[objc::msg_send(numberOfItemsInSection:)]
+    #[objc::msg_send(numberOfItemsInSection:, throws)]
     pub unsafe fn items_n_in_section_throws(&self, section_id: &S) -> ns::Integer;
 
+    #[deprecated(note = "use `try_items_n_in_section`")]
     #[inline]
     pub fn items_n_in_section<'ear>(
         &self,
         section_id: impl AsRef<S>,
     ) -> ns::ExResult<'ear, ns::Integer> {
-        ns::try_catch(|| unsafe { self.items_n_in_section_throws(section_id.as_ref()) })
+        self.try_items_n_in_section(section_id.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(itemIdentifiersInSectionWithIdentifier:)]
+    #[objc::msg_send(itemIdentifiersInSectionWithIdentifier:, throws)]
     pub unsafe fn item_ids_in_section_throws(&self, section_id: &S) -> arc::R<ns::Array<I>>;
 
+    #[deprecated(note = "use `try_item_ids_in_section`")]
     #[inline]
     pub fn item_ids_in_section<'ear>(
         &self,
         section_id: impl AsRef<S>,
     ) -> ns::ExResult<'ear, arc::R<ns::Array<I>>> {
-        ns::try_catch(|| unsafe { self.item_ids_in_section_throws(section_id.as_ref()) })
+        self.try_item_ids_in_section(section_id.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(sectionIdentifierForSectionContainingItemIdentifier:)]
@@ -75,191 +79,202 @@ impl<S: objc::Obj, I: objc::Obj> DiffableDataSrcSnapshot<S, I> {
     #[objc::msg_send(indexOfSectionIdentifier:)]
     pub fn index_of_section_id(&self, section_id: &S) -> ns::Integer;
 
-    #[objc::msg_send(appendItemsWithIdentifiers:)]
+    #[objc::msg_send(appendItemsWithIdentifiers:, throws)]
     pub unsafe fn append_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_append_items`")]
     #[inline]
     pub fn append_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.append_items_throws(items) })
+        self.try_append_items(items).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(appendItemsWithIdentifiers:intoSectionWithIdentifier:)]
+    #[objc::msg_send(appendItemsWithIdentifiers:intoSectionWithIdentifier:, throws)]
     pub unsafe fn append_items_to_section_throws(&mut self, items: &ns::Array<I>, section_id: &S);
 
+    #[deprecated(note = "use `try_append_items_to_section`")]
     #[inline]
     pub fn append_items_to_section<'ear>(
         &mut self,
         items: &ns::Array<I>,
         section_id: impl AsRef<S>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.append_items_to_section_throws(items, section_id.as_ref()) })
+        self.try_append_items_to_section(items, section_id.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertItemsWithIdentifiers:beforeItemWithIdentifier:)]
+    #[objc::msg_send(insertItemsWithIdentifiers:beforeItemWithIdentifier:, throws)]
     pub unsafe fn insert_items_before_item_throws(&mut self, items: &ns::Array<I>, item: &I);
 
+    #[deprecated(note = "use `try_insert_items_before_item`")]
     #[inline]
     pub fn insert_items_before_item<'ear>(
         &mut self,
         items: &ns::Array<I>,
         item: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.insert_items_before_item_throws(items, item.as_ref()) })
+        self.try_insert_items_before_item(items, item.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertItemsWithIdentifiers:afterItemWithIdentifier:)]
+    #[objc::msg_send(insertItemsWithIdentifiers:afterItemWithIdentifier:, throws)]
     pub unsafe fn insert_items_after_item_throws(&mut self, items: &ns::Array<I>, item: &I);
 
+    #[deprecated(note = "use `try_insert_items_after_item`")]
     #[inline]
     pub fn insert_items_after_item<'ear>(
         &mut self,
         items: &ns::Array<I>,
         item: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.insert_items_after_item_throws(items, item.as_ref()) })
+        self.try_insert_items_after_item(items, item.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(deleteItemsWithIdentifiers:)]
+    #[objc::msg_send(deleteItemsWithIdentifiers:, throws)]
     pub unsafe fn delete_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_delete_items`")]
     #[inline]
     pub fn delete_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.delete_items_throws(items) })
+        self.try_delete_items(items).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(deleteAllItems)]
     pub fn delete_all_items(&mut self);
 
-    #[objc::msg_send(moveItemWithIdentifier:beforeItemWithIdentifier:)]
+    #[objc::msg_send(moveItemWithIdentifier:beforeItemWithIdentifier:, throws)]
     pub unsafe fn move_item_before_item_throws(&mut self, item: &I, before: &I);
 
+    #[deprecated(note = "use `try_move_item_before_item`")]
     #[inline]
     pub fn move_item_before_item<'ear>(
         &mut self,
         item: impl AsRef<I>,
         before: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.move_item_before_item_throws(item.as_ref(), before.as_ref())
-        })
+        self.try_move_item_before_item(item.as_ref(), before.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(moveItemWithIdentifier:afterItemWithIdentifier:)]
+    #[objc::msg_send(moveItemWithIdentifier:afterItemWithIdentifier:, throws)]
     pub unsafe fn move_item_after_item_throws(&mut self, item: &I, after: &I);
 
+    #[deprecated(note = "use `try_move_item_after_item`")]
     #[inline]
     pub fn move_item_after_item<'ear>(
         &mut self,
         item: impl AsRef<I>,
         after: impl AsRef<I>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.move_item_after_item_throws(item.as_ref(), after.as_ref()) })
+        self.try_move_item_after_item(item.as_ref(), after.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(reloadItemsWithIdentifiers:)]
+    #[objc::msg_send(reloadItemsWithIdentifiers:, throws)]
     pub unsafe fn reload_items_throws(&mut self, items: &ns::Array<I>);
 
+    #[deprecated(note = "use `try_reload_items`")]
     #[inline]
     pub fn reload_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.reload_items_throws(items) })
+        self.try_reload_items(items).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(reconfigureItemsWithIdentifiers:)]
+    #[objc::msg_send(reconfigureItemsWithIdentifiers:, throws)]
     #[api::available(ios = 15.0, tvos = 15.0)]
     pub unsafe fn reconfigure_items_throws(&mut self, items: &ns::Array<I>);
 
-    #[inline]
-    #[api::available(ios = 15.0, tvos = 15.0)]
-    pub fn reconfigure_items<'ear>(&mut self, items: &ns::Array<I>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.reconfigure_items_throws(items) })
-    }
-
-    #[objc::msg_send(appendSectionsWithIdentifiers:)]
+    #[objc::msg_send(appendSectionsWithIdentifiers:, throws)]
     pub unsafe fn append_sections_throws(&mut self, sections: &ns::Array<S>);
 
+    #[deprecated(note = "use `try_append_sections`")]
     #[inline]
     pub fn append_sections<'ear>(&mut self, sections: &ns::Array<S>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.append_sections_throws(sections) })
+        self.try_append_sections(sections)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertSectionsWithIdentifiers:beforeSectionWithIdentifier:)]
+    #[objc::msg_send(insertSectionsWithIdentifiers:beforeSectionWithIdentifier:, throws)]
     pub unsafe fn insert_sections_before_section_throws(
         &mut self,
         sections: &ns::Array<S>,
         before: &S,
     );
 
+    #[deprecated(note = "use `try_insert_sections_before_section`")]
     #[inline]
     pub fn insert_sections_before_section<'ear>(
         &mut self,
         sections: &ns::Array<S>,
         before: impl AsRef<S>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.insert_sections_before_section_throws(sections, before.as_ref())
-        })
+        self.try_insert_sections_before_section(sections, before.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertSectionsWithIdentifiers:afterSectionWithIdentifier:)]
+    #[objc::msg_send(insertSectionsWithIdentifiers:afterSectionWithIdentifier:, throws)]
     pub unsafe fn insert_sections_after_section_throws(
         &mut self,
         sections: &ns::Array<S>,
         after: &S,
     );
 
+    #[deprecated(note = "use `try_insert_sections_after_section`")]
     #[inline]
     pub fn insert_sections_after_section<'ear>(
         &mut self,
         sections: &ns::Array<S>,
         after: impl AsRef<S>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.insert_sections_after_section_throws(sections, after.as_ref())
-        })
+        self.try_insert_sections_after_section(sections, after.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(deleteSectionsWithIdentifiers:)]
+    #[objc::msg_send(deleteSectionsWithIdentifiers:, throws)]
     pub unsafe fn delete_sections_throws(&mut self, sections: &ns::Array<S>);
 
+    #[deprecated(note = "use `try_delete_sections`")]
     #[inline]
     pub fn delete_sections<'ear>(&mut self, sections: &ns::Array<S>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.delete_sections_throws(sections) })
+        self.try_delete_sections(sections)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(moveSectionWithIdentifier:beforeSectionWithIdentifier:)]
+    #[objc::msg_send(moveSectionWithIdentifier:beforeSectionWithIdentifier:, throws)]
     pub unsafe fn move_section_before_section_throws(&mut self, section: &S, before: &S);
 
+    #[deprecated(note = "use `try_move_section_before_section`")]
     #[inline]
     pub fn move_section_before_section<'ear>(
         &mut self,
         section: impl AsRef<S>,
         before: impl AsRef<S>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.move_section_before_section_throws(section.as_ref(), before.as_ref())
-        })
+        self.try_move_section_before_section(section.as_ref(), before.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(moveSectionWithIdentifier:afterSectionWithIdentifier:)]
+    #[objc::msg_send(moveSectionWithIdentifier:afterSectionWithIdentifier:, throws)]
     pub unsafe fn move_section_after_section_throws(&mut self, section: &S, after: &S);
 
+    #[deprecated(note = "use `try_move_section_after_section`")]
     #[inline]
     pub fn move_section_after_section<'ear>(
         &mut self,
         section: impl AsRef<S>,
         after: impl AsRef<S>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.move_section_after_section_throws(section.as_ref(), after.as_ref())
-        })
+        self.try_move_section_after_section(section.as_ref(), after.as_ref())
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(reloadSectionsWithIdentifiers:)]
+    #[objc::msg_send(reloadSectionsWithIdentifiers:, throws)]
     pub unsafe fn reload_sections_throws(&mut self, sections: &ns::Array<S>);
 
+    #[deprecated(note = "use `try_reload_sections`")]
     #[inline]
     pub fn reload_sections<'ear>(&mut self, sections: &ns::Array<S>) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.reload_sections_throws(sections) })
+        self.try_reload_sections(sections)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -299,41 +314,41 @@ mod tests {
         assert_eq!(index, ns::NOT_FOUND);
 
         snapshot
-            .append_items(&ns::arr![ns::str!(c"foo")])
+            .try_append_items(&ns::arr![ns::str!(c"foo")])
             .expect_err("we need to add section first");
 
         snapshot
-            .append_sections(&ns::arr![0])
+            .try_append_sections(&ns::arr![0])
             .expect("Failed to add section");
 
         snapshot
-            .append_sections(&ns::arr![0])
+            .try_append_sections(&ns::arr![0])
             .expect_err("we already have section with id 0");
 
         snapshot
-            .append_items(&ns::arr![ns::str!(c"foo")])
+            .try_append_items(&ns::arr![ns::str!(c"foo")])
             .expect("Failed to append item in section");
 
         assert_eq!(snapshot.sections_n(), 1);
         assert_eq!(snapshot.items_n(), 1);
-        assert_eq!(snapshot.items_n_in_section(0u8), Ok(1));
+        assert_eq!(snapshot.try_items_n_in_section(0u8.as_ref()).unwrap(), 1);
         snapshot
-            .items_n_in_section(1u8)
+            .try_items_n_in_section(1u8.as_ref())
             .expect_err("No section with id 1");
 
         snapshot
-            .delete_items(&ns::arr![ns::str!(c"b"), ns::str!(c"!"), ns::str!(c"!")])
+            .try_delete_items(&ns::arr![ns::str!(c"b"), ns::str!(c"!"), ns::str!(c"!")])
             .expect_err("Not unique id err");
 
         snapshot
-            .reload_items(&ns::arr![ns::str!(c"b"), ns::str!(c"!")])
+            .try_reload_items(&ns::arr![ns::str!(c"b"), ns::str!(c"!")])
             .expect_err("b doesnt exists");
 
-        snapshot.reload_sections(&ns::arr![10u8]).expect_err(
+        snapshot.try_reload_sections(&ns::arr![10u8]).expect_err(
             "Attempted to reload section identifier that does not exist in the snapshot: 10",
         );
         snapshot
-            .reload_sections(&ns::arr![0u8])
+            .try_reload_sections(&ns::arr![0u8])
             .expect("Failed to reload section with id 0");
 
         let clone = snapshot.clone();
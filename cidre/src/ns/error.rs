@@ -420,7 +420,7 @@ mod tests {
         assert_eq!(error.domain().as_ref(), ns::ErrorDomain::posix());
         let debug_desc = error
             .user_info()
-            .value_for_key(ns::error_user_info_keys::debug_desc())
+            .try_value_for_key(ns::error_user_info_keys::debug_desc())
             .unwrap()
             .unwrap();
 
@@ -1,6 +1,6 @@
 use std::ffi::c_void;
 
-use crate::{arc, define_obj_type, ns, objc};
+use crate::{arc, define_cls, define_obj_type, ns, objc};
 
 use super::objc_runtime::ExceptionName;
 
@@ -147,10 +147,19 @@ define_obj_type!(
 );
 
 impl Exception {
+    define_cls!(NS_EXCEPTION);
+
     pub fn raise(message: &ns::String) -> ! {
         unsafe { cidre_raise_exception(message) }
     }
 
+    #[objc::msg_send(exceptionWithName:reason:userInfo:)]
+    pub fn with_name(
+        name: &ns::ExceptionName,
+        reason: Option<&ns::String>,
+        user_info: Option<&ns::Dictionary<ns::Id, ns::Id>>,
+    ) -> arc::R<Self>;
+
     #[objc::msg_send(name)]
     pub fn name(&self) -> arc::R<ns::ExceptionName>;
 
@@ -181,6 +190,10 @@ pub unsafe fn set_uncaught_exception_handler(handler: *const UncaughtExceptionHa
     unsafe { NSSetUncaughtExceptionHandler(handler) }
 }
 
+unsafe extern "C" {
+    static NS_EXCEPTION: &'static objc::Class<Exception>;
+}
+
 unsafe extern "C" {
     fn NSGetUncaughtExceptionHandler() -> *const UncaughtExceptionHandler;
     fn NSSetUncaughtExceptionHandler(handler: *const UncaughtExceptionHandler);
@@ -1,50 +1,57 @@
 use crate::{arc, ns, objc};
 
 impl ns::Id {
-    #[objc::msg_send(valueForKey:)]
+    #[objc::msg_send(valueForKey:, throws)]
     pub unsafe fn value_for_key_throws(&self, key: &ns::String) -> Option<arc::R<Self>>;
 
+    #[deprecated(note = "use `try_value_for_key`")]
     pub fn value_for_key<'ear>(
         &self,
         key: &ns::String,
     ) -> ns::ExResult<'ear, Option<arc::R<Self>>> {
-        ns::try_catch(|| unsafe { self.value_for_key_throws(key) })
+        self.try_value_for_key(key).map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(setValue:forKey:)]
+    #[objc::msg_send(setValue:forKey:, throws)]
     pub unsafe fn set_value_for_key_throws(&mut self, val: Option<&Self>, key: &ns::String);
 
+    #[deprecated(note = "use `try_set_value_for_key`")]
     pub fn set_value_for_key<'ear>(
         &mut self,
         val: Option<&Self>,
         key: &ns::String,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_value_for_key_throws(val, key) })
+        self.try_set_value_for_key(val, key)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(valueForKeyPath:)]
+    #[objc::msg_send(valueForKeyPath:, throws)]
     pub unsafe fn value_for_key_path_throws(&self, key_path: &ns::String) -> Option<arc::R<Self>>;
 
+    #[deprecated(note = "use `try_value_for_key_path`")]
     pub fn value_for_key_path<'ear>(
         &self,
         key_path: &ns::String,
     ) -> ns::ExResult<'ear, Option<arc::R<Self>>> {
-        ns::try_catch(|| unsafe { self.value_for_key_path_throws(key_path) })
+        self.try_value_for_key_path(key_path)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(setValue:forKeyPath:)]
+    #[objc::msg_send(setValue:forKeyPath:, throws)]
     pub unsafe fn set_value_for_key_path_throws(
         &mut self,
         val: Option<&Self>,
         key_path: &ns::String,
     );
 
+    #[deprecated(note = "use `try_set_value_for_key_path`")]
     pub fn set_value_for_key_path<'ear>(
         &mut self,
         val: Option<&Self>,
         key_path: &ns::String,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_value_for_key_path_throws(val, key_path) })
+        self.try_set_value_for_key_path(val, key_path)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
@@ -55,13 +62,15 @@ mod tests {
     #[test]
     fn basics() {
         let s = ns::str!(c"nice");
-        let v = s.value_for_key(ns::str!(c"length")).expect("should be ok");
+        let v = s
+            .try_value_for_key(ns::str!(c"length"))
+            .expect("should be ok");
         let v = v.expect("number");
         let num = v.try_cast(ns::Number::cls()).unwrap();
         assert_eq!(num.as_i8(), 4);
 
         let _ = s
-            .value_for_key(ns::str!(c"invalid_key"))
+            .try_value_for_key(ns::str!(c"invalid_key"))
             .expect_err("should be ns::Exception");
     }
 }
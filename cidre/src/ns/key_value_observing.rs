@@ -1,6 +1,11 @@
-use std::ffi::c_void;
+use std::{ffi::c_void, marker::PhantomData};
 
-use crate::{arc, define_obj_type, define_opts, ns, objc};
+use crate::{
+    arc, define_obj_type, define_opts, ns,
+    objc::{self, Obj},
+};
+
+pub use crate::objc::{KvChange, KvRaw, KvValue};
 
 define_opts!(
     #[doc(alias = "NSKeyValueObservingOptions")]
@@ -13,16 +18,6 @@ impl KvoOpts {
     pub const PRIOR: Self = Self(0x08);
 }
 
-#[doc(alias = "NSKeyValueChange")]
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-#[repr(usize)]
-pub enum KvChange {
-    Setting = 1,
-    Insertion = 2,
-    Removal = 3,
-    Replacement = 4,
-}
-
 #[doc(alias = "NSKeyValueSetMutationKind")]
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[repr(usize)]
@@ -59,6 +54,75 @@ impl KvChangeKey {
     }
 }
 
+impl<'a> KvRaw<'a> {
+    pub fn with_id(val: &'a ns::Id) -> Self {
+        if val.is_kind_of_class(ns::Null::cls()) {
+            return Self::Null;
+        }
+        match val.try_cast(ns::Number::cls()) {
+            Some(n) => Self::Number {
+                int: n.as_i64(),
+                float: n.as_f64(),
+                obj: Some(n.as_id_ref()),
+            },
+            None => Self::Obj(val),
+        }
+    }
+}
+
+/// Decoded change dictionary.
+#[derive(Debug, Clone)]
+pub struct Change<T> {
+    pub kind: KvChange,
+    /// Set with `KvoOpts::OLD`
+    pub old: Option<T>,
+    /// Set with `KvoOpts::NEW`, not set for prior notification
+    pub new: Option<T>,
+    /// Indexes of changed elements of to-many relationship
+    pub indexes: Option<arc::R<ns::IndexSet>>,
+    /// Notification sent before change with `KvoOpts::PRIOR`
+    pub is_prior: bool,
+}
+
+impl<T: KvValue> Change<T> {
+    pub fn decode(
+        kind: usize,
+        old: Option<KvRaw>,
+        new: Option<KvRaw>,
+        indexes: Option<&ns::IndexSet>,
+        is_prior: bool,
+    ) -> Option<Self> {
+        Some(Self {
+            kind: KvChange::from_raw(kind)?,
+            old: old.and_then(T::from_kv),
+            new: new.and_then(T::from_kv),
+            indexes: indexes.map(|i| i.retained()),
+            is_prior,
+        })
+    }
+
+    pub fn with_dict(change: &ns::Dictionary<KvChangeKey, ns::Id>) -> Option<Self> {
+        let number = |key: &KvChangeKey| {
+            let val = change.get(key)?;
+            val.try_cast(ns::Number::cls()).map(|n| n.as_i64())
+        };
+        let kind = number(KvChangeKey::kind())?;
+        let is_prior = number(KvChangeKey::is_prior_key()).is_some_and(|v| v != 0);
+        let old = change.get(KvChangeKey::old());
+        let new = change.get(KvChangeKey::new());
+        let indexes = change.get(KvChangeKey::indexes());
+        Self::decode(
+            kind as usize,
+            old.as_deref().map(KvRaw::with_id),
+            new.as_deref().map(KvRaw::with_id),
+            indexes
+                .as_deref()
+                .and_then(|i| i.try_cast(ns::IndexSet::cls())),
+            is_prior,
+        )
+    }
+}
+
 pub trait KvObserving {
     #[objc::msg_send(observeValueForKeyPath:ofObject:change:context:)]
     fn observe_value_for_key_path(
@@ -102,21 +166,18 @@ where
         (self.closure)(key_path, object, change)
     }
 
-    pub fn with_obj<'ar, O: objc::Obj>(
+    pub fn with_obj<O: objc::Obj>(
         obj: &mut O,
         key_path: &ns::String,
         options: KvoOpts,
         closure: F,
-    ) -> Result<Box<Self>, &'ar ns::Exception>
-    where
-        O: objc::Obj + KvObserverRegistration,
-    {
+    ) -> objc::TryResult<Box<Self>> {
         let mut bx = Box::new(Self {
             closure,
             cidre_observer: None,
         });
 
-        let o = ns::try_catch(|| unsafe {
+        let o = objc::try_catch_retained(|| unsafe {
             cidre_create_observer(
                 obj.as_id_ref(),
                 key_path,
@@ -142,9 +203,111 @@ where
     }
 }
 
-#[objc::protocol(CidreKVObserverRegistration)]
-pub trait KvObserverRegistration {
-    #[objc::msg_send(addObserver:forKeyPath:options:context:)]
+type DynChangeHandler = Box<
+    dyn FnMut(Option<&ns::String>, Option<&ns::Id>, Option<&ns::Dictionary<KvChangeKey, ns::Id>>),
+>;
+
+/// Observer of typed key path.
+///
+/// Observation stops on drop.
+pub struct ChangeObserver<T> {
+    _observer: Box<Observer<DynChangeHandler>>,
+    _marker: PhantomData<fn(Change<T>)>,
+}
+
+impl<T> std::fmt::Debug for ChangeObserver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeObserver")
+            .field("observer", &self._observer)
+            .finish()
+    }
+}
+
+impl<T: KvValue + 'static> ChangeObserver<T> {
+    pub fn with_obj<O>(
+        obj: &mut O,
+        key_path: objc::KeyPath<O, T>,
+        options: KvoOpts,
+        mut closure: impl FnMut(Change<T>) + 'static,
+    ) -> objc::TryResult<Self>
+    where
+        O: objc::Obj,
+    {
+        let handler: DynChangeHandler = Box::new(move |_key_path, _obj, change| {
+            if let Some(change) = change.and_then(Change::with_dict) {
+                closure(change)
+            }
+        });
+        let observer = Observer::with_obj(obj, &key_path.ns_string(), options, handler)?;
+        Ok(Self {
+            _observer: observer,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Changes as `Stream`. Observation stops when stream is dropped.
+    #[cfg(feature = "async")]
+    pub fn stream<O>(
+        obj: &mut O,
+        key_path: objc::KeyPath<O, T>,
+        options: KvoOpts,
+    ) -> objc::TryResult<ChangeStream<T>>
+    where
+        O: objc::Obj,
+    {
+        let shared = std::sync::Arc::new(parking_lot::Mutex::new(StreamShared {
+            changes: Default::default(),
+            pending: None,
+        }));
+        let sender = shared.clone();
+        let observer = Self::with_obj(obj, key_path, options, move |change| {
+            let mut lock = sender.lock();
+            lock.changes.push_back(change);
+            if let Some(waker) = lock.pending.take() {
+                waker.wake();
+            }
+        })?;
+        Ok(ChangeStream {
+            _observer: observer,
+            shared,
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+struct StreamShared<T> {
+    changes: std::collections::VecDeque<Change<T>>,
+    pending: Option<std::task::Waker>,
+}
+
+#[cfg(feature = "async")]
+pub struct ChangeStream<T> {
+    _observer: ChangeObserver<T>,
+    shared: std::sync::Arc<parking_lot::Mutex<StreamShared<T>>>,
+}
+
+#[cfg(feature = "async")]
+impl<T> futures_core::Stream for ChangeStream<T> {
+    type Item = Change<T>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let mut lock = self.shared.lock();
+        match lock.changes.pop_front() {
+            Some(change) => std::task::Poll::Ready(Some(change)),
+            None => {
+                lock.pending = Some(cx.waker().clone());
+                std::task::Poll::Pending
+            }
+        }
+    }
+}
+
+/// Manual observer registration, prefer [`Observer`] and [`ChangeObserver`].
+pub trait KvObserverRegistration: objc::Obj {
+    #[objc::msg_send(addObserver:forKeyPath:options:context:, throws)]
     unsafe fn add_observer_throws(
         &mut self,
         observer: &ns::Id,
@@ -153,17 +316,7 @@ pub trait KvObserverRegistration {
         context: *mut c_void,
     );
 
-    #[objc::msg_send(removeObserver:forKeyPath:context:)]
-    unsafe fn remove_observer_ctx_throws(
-        &mut self,
-        observer: &ns::Id,
-        key_path: &ns::String,
-        context: *mut c_void,
-    );
-
-    #[objc::msg_send(removeObserver:forKeyPath:)]
-    unsafe fn remove_observer_throws(&mut self, observer: &ns::Id, key_path: &ns::String);
-
+    #[deprecated(note = "use `try_add_observer`")]
     fn add_observer<'ear>(
         &mut self,
         observer: &ns::Id,
@@ -171,31 +324,45 @@ pub trait KvObserverRegistration {
         options: KvoOpts,
         context: *mut c_void,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.add_observer_throws(observer, for_key_path, options, context)
-        })
+        self.try_add_observer(observer, for_key_path, options, context)
+            .map_err(|e| &*e.autoreleased())
     }
 
+    #[objc::msg_send(removeObserver:forKeyPath:context:, throws)]
+    unsafe fn remove_observer_ctx_throws(
+        &mut self,
+        observer: &ns::Id,
+        key_path: &ns::String,
+        context: *mut c_void,
+    );
+
+    #[deprecated(note = "use `try_remove_observer_ctx`")]
     fn remove_observer_ctx<'ear>(
         &mut self,
         observer: &ns::Id,
         for_key_path: &ns::String,
         context: *mut c_void,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe {
-            self.remove_observer_ctx_throws(observer, for_key_path, context)
-        })
+        self.try_remove_observer_ctx(observer, for_key_path, context)
+            .map_err(|e| &*e.autoreleased())
     }
 
+    #[objc::msg_send(removeObserver:forKeyPath:, throws)]
+    unsafe fn remove_observer_throws(&mut self, observer: &ns::Id, key_path: &ns::String);
+
+    #[deprecated(note = "use `try_remove_observer`")]
     fn remove_observer<'ear>(
         &mut self,
         observer: &ns::Id,
         for_key_path: &ns::String,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.remove_observer_throws(observer, for_key_path) })
+        self.try_remove_observer(observer, for_key_path)
+            .map_err(|e| &*e.autoreleased())
     }
 }
 
+impl<T: objc::Obj> KvObserverRegistration for T {}
+
 define_obj_type!(pub CidreObserver(ns::Id));
 impl CidreObserver {
     #[objc::msg_send(invalidate)]
@@ -269,4 +436,81 @@ mod tests {
         )
         .expect_err("should fail");
     }
+
+    #[test]
+    fn typed() {
+        let mut q = ns::OpQueue::new();
+        let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = calls.clone();
+        let _observer = ns::ChangeObserver::with_obj(
+            q.as_mut(),
+            ns::OpQueue::KEY_PATH_MAX_CONCURRENT_OPS,
+            ns::KvoOpts::NEW | ns::KvoOpts::OLD,
+            move |change| {
+                assert_eq!(change.kind, ns::KvChange::Setting);
+                assert_eq!(change.new, Some(2));
+                counter.set(counter.get() + 1);
+            },
+        )
+        .unwrap();
+
+        q.set_max_concurrent_ops(2);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn stream() {
+        use std::{
+            pin::Pin,
+            sync::{
+                Arc,
+                atomic::{AtomicUsize, Ordering},
+            },
+            task::{Context, Poll, Wake, Waker},
+        };
+
+        use futures_core::Stream;
+
+        struct Wakes(AtomicUsize);
+
+        impl Wake for Wakes {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let wakes = Arc::new(Wakes(AtomicUsize::new(0)));
+        let waker = Waker::from(wakes.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut q = ns::OpQueue::new();
+        let mut stream = ns::ChangeObserver::stream(
+            q.as_mut(),
+            ns::OpQueue::KEY_PATH_MAX_CONCURRENT_OPS,
+            ns::KvoOpts::NEW,
+        )
+        .unwrap();
+
+        assert!(Pin::new(&mut stream).poll_next(&mut cx).is_pending());
+
+        q.set_max_concurrent_ops(3);
+        q.set_max_concurrent_ops(4);
+        // only pending poll is woken
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+
+        let Poll::Ready(Some(change)) = Pin::new(&mut stream).poll_next(&mut cx) else {
+            panic!("expected change");
+        };
+        assert_eq!(change.kind, ns::KvChange::Setting);
+        assert_eq!(change.new, Some(3));
+        assert_eq!(change.old, None);
+
+        let Poll::Ready(Some(change)) = Pin::new(&mut stream).poll_next(&mut cx) else {
+            panic!("expected change");
+        };
+        assert_eq!(change.new, Some(4));
+
+        assert!(Pin::new(&mut stream).poll_next(&mut cx).is_pending());
+    }
 }
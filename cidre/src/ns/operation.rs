@@ -77,16 +77,11 @@ impl BlockOp {
     pub fn with_block(block: &mut blocks::WorkBlock) -> arc::R<Self>;
 }
 
-impl ns::KvObserverRegistration for Op {}
-impl ns::KvObserverRegistration for BlockOp {}
-
 define_obj_type!(
     #[doc(alias = "NSOperationQueue")]
     pub OpQueue(ns::Id), NS_OPERATION_QUEUE
 );
 
-impl ns::KvObserverRegistration for OpQueue {}
-
 impl OpQueue {
     /// The default maximum number of operations to invoke concurrently in a queue.
     ///
@@ -121,7 +116,7 @@ impl OpQueue {
     /// The default value of this property is defaultMaxConcurrentOperationCount. You may monitor changes to the value
     /// of this property using Key-value observing. Configure an observer to monitor the maxConcurrentOperationCount
     /// key path of the operation queue.
-    #[objc::msg_send(maxConcurrentOperationCount)]
+    #[objc::msg_send(maxConcurrentOperationCount, kvo)]
     pub fn max_concurrent_ops(&self) -> isize;
 
     #[objc::msg_send(setMaxConcurrentOperationCount:)]
@@ -153,15 +148,17 @@ impl OpQueue {
     pub fn underlying_queue(&self) -> Option<arc::R<dispatch::Queue>>;
 
     #[cfg(feature = "dispatch")]
-    #[objc::msg_send(setUnderlyingQueue:)]
+    #[objc::msg_send(setUnderlyingQueue:, throws)]
     pub unsafe fn set_underlying_queue_throws(&mut self, val: Option<&dispatch::Queue>);
 
+    #[deprecated(note = "use `try_set_underlying_queue`")]
     #[cfg(feature = "dispatch")]
     pub fn set_underlying_queue<'ear>(
         &mut self,
         val: Option<&dispatch::Queue>,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.set_underlying_queue_throws(val) })
+        self.try_set_underlying_queue(val)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(currentQueue)]
@@ -197,7 +194,7 @@ mod tests {
         );
         assert!(queue.underlying_queue().is_none());
         let dqueue = dispatch::Queue::new();
-        queue.set_underlying_queue(Some(&dqueue)).unwrap();
+        queue.try_set_underlying_queue(Some(&dqueue)).unwrap();
         assert_eq!(queue.underlying_queue().as_ref(), Some(&dqueue));
         assert_eq!(-1, queue.max_concurrent_ops());
         queue.set_max_concurrent_ops(10);
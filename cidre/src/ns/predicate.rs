@@ -10,28 +10,30 @@ define_obj_type!(
 impl ns::Copying for Predicate {}
 
 impl Predicate {
-    #[objc::msg_send(predicateWithFormat:argumentArray:)]
+    #[objc::msg_send(predicateWithFormat:argumentArray:, throws)]
     pub unsafe fn with_format_throws(
         format: &ns::String,
         args: Option<&ns::Array<ns::Id>>,
     ) -> arc::R<Self>;
 
+    #[deprecated(note = "use `try_with_format`")]
     pub fn with_format<'ear>(
         format: &ns::String,
         args: Option<&ns::Array<ns::Id>>,
     ) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::with_format_throws(format, args) })
+        Self::try_with_format(format, args).map_err(|e| &*e.autoreleased())
     }
 
     #[cfg(target_os = "macos")]
-    #[objc::msg_send(predicateFromMetadataQueryString:)]
+    #[objc::msg_send(predicateFromMetadataQueryString:, throws)]
     pub unsafe fn from_metadata_query_throws(query: &ns::String) -> Option<arc::R<Self>>;
 
+    #[deprecated(note = "use `try_from_metadata_query`")]
     #[cfg(target_os = "macos")]
     pub fn from_metadata_query<'ear>(
         query: &ns::String,
     ) -> ns::ExResult<'ear, Option<arc::R<Self>>> {
-        ns::try_catch(|| unsafe { Self::from_metadata_query_throws(query) })
+        Self::try_from_metadata_query(query).map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(predicateWithValue:)]
@@ -48,11 +50,12 @@ impl Predicate {
         >,
     ) -> arc::R<Self>;
 
-    #[objc::msg_send(predicateFormat)]
+    #[objc::msg_send(predicateFormat, throws)]
     pub unsafe fn format_throws(&self) -> arc::R<ns::String>;
 
+    #[deprecated(note = "use `try_format`")]
     pub fn format<'ear>(&self) -> ns::ExResult<'ear, arc::R<ns::String>> {
-        ns::try_catch(|| unsafe { self.format_throws() })
+        self.try_format().map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(evaluateWithObject:)]
@@ -97,24 +100,25 @@ mod tests {
     #[test]
     fn basics() {
         let p = ns::Predicate::new();
-        let format = p.format();
+        let format = p.try_format();
         assert!(format.is_err());
 
         let p = ns::Predicate::with_value(false);
-        let format = p.format().unwrap();
+        let format = p.try_format().unwrap();
         assert_eq!(format.as_ref(), "FALSEPREDICATE");
 
-        let p = ns::Predicate::with_format(ns::str!(c"!"), None);
+        let p = ns::Predicate::try_with_format(ns::str!(c"!"), None);
         assert!(p.is_err());
-        let p = ns::Predicate::with_format(ns::str!(c"typeName CONTAINS 'Effect'"), None).unwrap();
-        let format = p.format().unwrap();
+        let p =
+            ns::Predicate::try_with_format(ns::str!(c"typeName CONTAINS 'Effect'"), None).unwrap();
+        let format = p.try_format().unwrap();
         assert_eq!(format.as_ref(), "typeName CONTAINS \"Effect\"");
     }
 
     #[cfg(target_os = "macos")]
     #[test]
     fn metadata_query() {
-        let p = ns::Predicate::from_metadata_query(ns::str!(c"!"));
+        let p = ns::Predicate::try_from_metadata_query(ns::str!(c"!"));
         assert!(p.is_err());
     }
 }
@@ -30,8 +30,6 @@ define_obj_type!(
 
 unsafe impl Send for ProcessInfo {}
 
-impl ns::KvObserverRegistration for ProcessInfo {}
-
 #[doc(alias = "NSOperatingSystemVersion")]
 pub type OsVersion = crate::api::OsVersion;
 
@@ -128,7 +126,7 @@ unsafe extern "C" {
 
 #[cfg(test)]
 mod tests {
-    use crate::ns;
+    use crate::{api, ns};
 
     #[test]
     fn basics() {
@@ -141,4 +139,15 @@ mod tests {
         assert!(!pi.is_ios_app_on_mac());
         assert!(!pi.is_mac_catalyst_app());
     }
+
+    #[test]
+    fn available() {
+        let pi = ns::ProcessInfo::current();
+        let on_vision = api::if_available!(macos = 26.1, ios = 26.1, tvos = 26.1 => |av| {
+            pi.is_ios_app_on_vision_available(av)
+        } else {
+            false
+        });
+        assert!(!on_vision);
+    }
 }
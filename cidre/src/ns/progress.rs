@@ -6,7 +6,7 @@ define_obj_type!(
 );
 
 impl Progress {
-    #[objc::msg_send(isCancelled)]
+    #[objc::msg_send(isCancelled, kvo)]
     pub fn is_cancelled(&self) -> bool;
 
     #[objc::msg_send(isPaused)]
@@ -15,10 +15,10 @@ impl Progress {
     #[objc::msg_send(isIndeterminate)]
     pub fn is_indeterminate(&self) -> bool;
 
-    #[objc::msg_send(fractionCompleted)]
+    #[objc::msg_send(fractionCompleted, kvo)]
     pub fn fraction_completed(&self) -> f64;
 
-    #[objc::msg_send(isFinished)]
+    #[objc::msg_send(isFinished, kvo)]
     pub fn is_finished(&self) -> bool;
 
     #[objc::msg_send(cancel)]
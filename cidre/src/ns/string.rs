@@ -115,20 +115,19 @@ impl String {
     /// # Safety
     ///
     /// Throws an Objective-C exception if `range` is outside the string bounds.
-    #[objc::msg_send(substringWithRange:)]
+    #[objc::msg_send(substringWithRange:, throws)]
     pub unsafe fn substring_with_range_throws(&self, range: ns::Range) -> arc::R<Self>;
 
+    #[deprecated(note = "use `try_substring_with_range`")]
     #[inline]
     pub fn substring_with_range<'ear>(&self, range: ns::Range) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { self.substring_with_range_throws(range) })
+        self.try_substring_with_range(range)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[inline]
-    pub fn substring<'ear>(
-        &self,
-        range: std::ops::Range<usize>,
-    ) -> ns::ExResult<'ear, arc::R<ns::String>> {
-        self.substring_with_range(range.into())
+    pub fn substring(&self, range: std::ops::Range<usize>) -> objc::TryResult<arc::R<ns::String>> {
+        self.try_substring_with_range(range.into())
     }
 
     #[objc::msg_send(cStringUsingEncoding:)]
@@ -199,37 +198,43 @@ impl StringMut {
         Self::alloc().with_capacity(capacity)
     }
 
-    #[objc::msg_send(replaceCharactersInRange:withString:)]
+    #[objc::msg_send(replaceCharactersInRange:withString:, throws)]
     pub unsafe fn replace_characters_in_throws(
         &mut self,
         range: ns::Range,
         with_string: &ns::String,
     );
 
+    #[deprecated(note = "use `try_replace_characters_in`")]
     pub fn replace_characters_in<'ear>(
         &mut self,
         range: ns::Range,
         with_string: &ns::String,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.replace_characters_in_throws(range, with_string) })
+        self.try_replace_characters_in(range, with_string)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(insertString:atIndex:)]
+    #[objc::msg_send(insertString:atIndex:, throws)]
     pub unsafe fn insert_string_at_throws(&mut self, string: &ns::String, at_index: ns::UInteger);
 
+    #[deprecated(note = "use `try_insert_string_at`")]
     pub fn insert_string_at<'ear>(
         &mut self,
         string: &ns::String,
         at_index: ns::UInteger,
     ) -> ns::ExResult<'ear> {
-        ns::try_catch(|| unsafe { self.insert_string_at_throws(string, at_index) })
+        self.try_insert_string_at(string, at_index)
+            .map_err(|e| &*e.autoreleased())
     }
 
-    #[objc::msg_send(deleteCharactersInRange:)]
-    pub fn delete_characters_in_throws(&mut self, range: ns::Range);
+    #[objc::msg_send(deleteCharactersInRange:, throws)]
+    pub unsafe fn delete_characters_in_throws(&mut self, range: ns::Range);
 
+    #[deprecated(note = "use `try_delete_characters_in`")]
     pub fn delete_characters_in<'ear>(&mut self, range: ns::Range) -> ns::ExResult<'ear> {
-        ns::try_catch(|| self.delete_characters_in_throws(range))
+        self.try_delete_characters_in(range)
+            .map_err(|e| &*e.autoreleased())
     }
 
     #[objc::msg_send(append:)]
@@ -384,9 +389,9 @@ mod tests {
     fn mut_throws() {
         let one = ns::str!(c"1");
         let mut zero = ns::str!(c"0").copy_mut();
-        zero.replace_characters_in(ns::Range::new(0, 1), &one)
+        zero.try_replace_characters_in(ns::Range::new(0, 1), &one)
             .unwrap();
-        zero.replace_characters_in(ns::Range::new(0, 10), &one)
+        zero.try_replace_characters_in(ns::Range::new(0, 10), &one)
             .expect_err("Should be exceptions");
     }
 
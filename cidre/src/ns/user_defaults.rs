@@ -119,8 +119,6 @@ impl UserDefaults {
     }
 }
 
-impl ns::KvObserverRegistration for UserDefaults {}
-
 unsafe impl Send for UserDefaults {}
 unsafe impl Sync for UserDefaults {}
 
@@ -16,6 +16,15 @@ impl Value {
 
     #[objc::msg_send(isEqualToValue:)]
     pub fn eq_to_value(&self, other: &Self) -> bool;
+
+    #[objc::msg_send(objCType)]
+    pub fn objc_type_ptr(&self) -> *const std::ffi::c_char;
+
+    /// `@encode` of the stored value.
+    #[doc(alias = "objCType")]
+    pub fn objc_type(&self) -> &std::ffi::CStr {
+        unsafe { std::ffi::CStr::from_ptr(self.objc_type_ptr()) }
+    }
 }
 
 define_obj_type!(
@@ -112,5 +112,5 @@ mod proxy_config;
 pub use proxy_config::ProxyCfg;
 pub use proxy_config::RelayHop;
 
-#[link(name = "Network", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Network", kind = "framework"))]
 unsafe extern "C" {}
@@ -137,5 +137,5 @@ unsafe extern "C-unwind" {
     );
 }
 
-#[link(name = "Security", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Security", kind = "framework"))]
 unsafe extern "C" {}
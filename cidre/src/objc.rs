@@ -1,12 +1,23 @@
 #[cfg(all(
+    target_vendor = "apple",
     target_arch = "aarch64",
     not(target_pointer_width = "32"),
     not(feature = "classic-objc-retain-release")
 ))]
 use std::arch::asm;
-use std::{borrow::Cow, ffi::c_void, marker::PhantomData, ptr::NonNull};
+#[cfg(feature = "ns")]
+use std::borrow::Cow;
+use std::{ffi::c_void, marker::PhantomData, ptr::NonNull};
 
-use crate::{arc, cf::Type, objc};
+#[cfg(feature = "cf")]
+use crate::cf::Type;
+use crate::{arc, objc};
+
+/// Opaque object storage, `cf::Type` when Core Foundation is enabled.
+#[cfg(not(feature = "cf"))]
+#[derive(Debug)]
+#[repr(transparent)]
+struct Type(NonNull<c_void>);
 
 #[derive(Debug)]
 #[repr(transparent)]
@@ -39,8 +50,15 @@ impl<T: Obj, I: Sized> std::ops::Deref for ClassInstExtra<T, I> {
 }
 
 // class_getInstanceSize([NSObject class]);
+// `isa` only on both Apple and GNUstep runtimes, GNUstep keeps refcount before object.
 pub const NS_OBJECT_SIZE: usize = std::mem::size_of::<usize>();
 
+/// Offset of inner value in `NSObject` subclass defined with `define_obj_type!`.
+#[inline]
+pub const fn ns_object_inner_offset<I>() -> usize {
+    ivar_offset(NS_OBJECT_SIZE, std::mem::align_of::<I>())
+}
+
 #[macro_export]
 macro_rules! init_with_default {
     ($NewType:ty, $InnerType:ty) => {{
@@ -69,7 +87,7 @@ macro_rules! init_with_default {
                     unsafe {
                         let ptr: *mut u8 = s.cast();
                         let d_ptr: *mut std::mem::ManuallyDrop<T> =
-                            ptr.add($crate::objc::NS_OBJECT_SIZE) as _;
+                            ptr.add($crate::objc::ns_object_inner_offset::<T>()) as _;
                         *d_ptr = std::mem::ManuallyDrop::new(T::default());
 
                         std::mem::transmute(ptr)
@@ -91,17 +109,88 @@ macro_rules! init_with_default {
     }};
 }
 
+/// `+allocWithZone:` of subclass which calls `super` and sets default inner value.
+///
+/// Done on allocation, so instances initialized with any initializer of superclass
+/// have inner value.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! alloc_super_with_default {
+    ($NewType:ty, $InnerType:ty) => {{
+        trait A {
+            fn alloc_fn(&self) -> Option<extern "C" fn()>;
+        }
+
+        struct B<T: ?Sized>(core::marker::PhantomData<T>);
+
+        impl<T: ?Sized> core::ops::Deref for B<T> {
+            type Target = ();
+            fn deref(&self) -> &Self::Target {
+                &()
+            }
+        }
+
+        impl<T: ?Sized> A for B<T>
+        where
+            T: Default,
+        {
+            fn alloc_fn(&self) -> Option<extern "C" fn()> {
+                extern "C" fn impl_alloc<T: Default>(
+                    cls: &$crate::objc::Class<$crate::objc::Id>,
+                    sel: Option<&$crate::objc::Sel>,
+                    zone: *mut std::ffi::c_void,
+                ) -> *mut $NewType {
+                    unsafe {
+                        // class methods are looked up in metaclass
+                        let sup_cls: &$crate::objc::Id =
+                            std::mem::transmute(<$NewType>::super_cls());
+                        let sup = $crate::objc::Super {
+                            receiver: cls as *const _ as _,
+                            super_class: $crate::objc::object_getClass(Some(sup_cls))
+                                .unwrap_unchecked(),
+                        };
+                        let (receiver, imp) = $crate::objc::super_imp(&sup, sel.unwrap_unchecked());
+                        let alloc: extern "C" fn(
+                            *const std::ffi::c_void,
+                            Option<&$crate::objc::Sel>,
+                            *mut std::ffi::c_void,
+                        ) -> *mut $NewType = std::mem::transmute(imp);
+                        let Some(s) = alloc(receiver, sel, zone).as_mut() else {
+                            return std::ptr::null_mut();
+                        };
+                        let ivar: *mut $crate::objc::Ivar<$InnerType> = s.ivar_mut();
+                        (*ivar.cast::<$crate::objc::Ivar<T>>()).set(T::default());
+                        s
+                    }
+                }
+
+                let ptr = unsafe { std::mem::transmute(impl_alloc::<T> as *const u8) };
+                Some(ptr)
+            }
+        }
+
+        impl A for () {
+            fn alloc_fn(&self) -> Option<extern "C" fn()> {
+                None
+            }
+        }
+
+        B::<$InnerType>(core::marker::PhantomData).alloc_fn()
+    }};
+}
+
 impl<T: Obj, I: Sized> ClassInstExtra<T, I> {
     #[inline]
     pub fn alloc_init(&self, var: I) -> arc::R<T> {
         unsafe {
-            let inst = class_createInstance(std::mem::transmute(self), std::mem::size_of::<I>());
+            // storage is reserved by ivar
+            let inst = class_createInstance(std::mem::transmute(self), 0);
 
             // we may skip init?
             // let inst = inst.init();
 
             let ptr: *mut u8 = std::mem::transmute(inst);
-            let d_ptr: *mut std::mem::ManuallyDrop<I> = ptr.add(NS_OBJECT_SIZE) as _;
+            let d_ptr: *mut std::mem::ManuallyDrop<I> = ptr.add(ns_object_inner_offset::<I>()) as _;
             *d_ptr = std::mem::ManuallyDrop::new(var);
 
             std::mem::transmute(ptr)
@@ -116,6 +205,7 @@ impl<T: Obj, I: Sized + Default> ClassInstExtra<T, I> {
 }
 
 impl<T: Obj> Class<T> {
+    #[cfg(feature = "cf")]
     #[inline]
     pub fn as_type_ref(&self) -> &Type {
         &self.0
@@ -130,7 +220,9 @@ impl<T: Obj> Class<T> {
     pub unsafe fn new(&self) -> arc::Retained<T>;
 }
 
-impl<T: Obj> Obj for Class<T> {}
+impl<T: Obj> Obj for Class<T> {
+    const ENCODING: &'static str = "#";
+}
 
 impl<T: Obj> arc::Release for T {
     #[inline]
@@ -146,10 +238,17 @@ impl<T: Obj> arc::Retain for T {
 }
 
 pub trait Obj: Sized + arc::Retain {
+    /// `@encode` of a pointer to the object.
+    const ENCODING: &'static str = "@";
+
     #[inline]
     unsafe fn retain(id: &Self) -> arc::R<Self> {
         unsafe {
-            #[cfg(all(target_arch = "aarch64", not(feature = "classic-objc-retain-release")))]
+            #[cfg(all(
+                target_vendor = "apple",
+                target_arch = "aarch64",
+                not(feature = "classic-objc-retain-release")
+            ))]
             {
                 let result: *mut Self;
                 core::arch::asm!(
@@ -164,7 +263,11 @@ pub trait Obj: Sized + arc::Retain {
                 std::mem::transmute(result)
             }
 
-            #[cfg(any(target_arch = "x86_64", feature = "classic-objc-retain-release"))]
+            #[cfg(any(
+                not(target_vendor = "apple"),
+                target_arch = "x86_64",
+                feature = "classic-objc-retain-release"
+            ))]
             {
                 std::mem::transmute(objc_retain(std::mem::transmute(id)))
             }
@@ -175,6 +278,7 @@ pub trait Obj: Sized + arc::Retain {
     unsafe fn release(id: &mut Self) {
         unsafe {
             #[cfg(all(
+                target_vendor = "apple",
                 target_arch = "aarch64",
                 target_pointer_width = "64",
                 not(feature = "classic-objc-retain-release")
@@ -193,6 +297,7 @@ pub trait Obj: Sized + arc::Retain {
             }
 
             #[cfg(any(
+                not(target_vendor = "apple"),
                 target_arch = "x86_64",
                 target_pointer_width = "32",
                 feature = "classic-objc-retain-release"
@@ -203,9 +308,11 @@ pub trait Obj: Sized + arc::Retain {
         }
     }
 
+    #[cfg(feature = "ns")]
     #[objc::msg_send(description)]
     fn desc(&self) -> arc::R<crate::ns::String>;
 
+    #[cfg(feature = "ns")]
     #[objc::msg_send(debugDescription)]
     fn debug_desc(&self) -> arc::R<crate::ns::String>;
 
@@ -268,6 +375,7 @@ impl Id {
     //     objc_retainAutoreleasedReturnValue(id)
     // }
 
+    #[cfg(feature = "cf")]
     #[inline]
     pub fn as_type_ref(&self) -> &Type {
         &self.0
@@ -291,6 +399,7 @@ impl Id {
 
 impl Obj for Id {}
 
+#[cfg(feature = "ns")]
 impl std::fmt::Debug for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let desc = self.debug_desc();
@@ -299,6 +408,13 @@ impl std::fmt::Debug for Id {
     }
 }
 
+#[cfg(not(feature = "ns"))]
+impl std::fmt::Debug for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Id").field(&self.as_ptr()).finish()
+    }
+}
+
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Sel(NonNull<c_void>);
@@ -307,6 +423,31 @@ pub mod autorelease_pool;
 pub mod ns;
 pub use autorelease_pool::AutoreleasePoolPage;
 
+mod encode;
+pub use encode::Encode;
+pub use encode::EncodeFn;
+pub use encode::MethodTypes;
+pub use encode::MethodTypesAny;
+pub use encode::MethodTypesEncode;
+pub use encode::RefEncode;
+
+mod subclass;
+pub use subclass::Ivar;
+pub use subclass::add_ivar;
+pub use subclass::ivar_offset;
+
+mod register;
+pub use register::ClassOnce;
+pub use register::RegisterError;
+pub use register::class_name;
+pub use register::instance_size;
+
+mod key_path;
+pub use key_path::KeyPath;
+pub use key_path::KvChange;
+pub use key_path::KvRaw;
+pub use key_path::KvValue;
+
 pub fn ar_pool<R, F>(f: F) -> R
 where
     F: FnOnce() -> R,
@@ -328,11 +469,45 @@ pub struct Super {
     pub super_class: *const Class<Id>,
 }
 
+/// First argument and implementation for sending `sel` to superclass.
+///
+/// Apple runtime dispatches with `objc_msgSendSuper(&sup, sel, ...)`,
+/// GNUstep runtime looks up `IMP` which is called with receiver: `imp(receiver, sel, ...)`.
+#[inline]
+pub unsafe fn super_imp(sup: &Super, sel: &Sel) -> (*const c_void, *const c_void) {
+    #[cfg(target_vendor = "apple")]
+    {
+        let _ = sel;
+        (sup as *const Super as _, objc_msgSendSuper as _)
+    }
+
+    #[cfg(not(target_vendor = "apple"))]
+    {
+        let imp = unsafe { objc_msg_lookup_super(sup, sel) };
+        (sup.receiver as _, imp)
+    }
+}
+
+/// Sends `sel` without arguments to superclass. Used for `dealloc`.
+#[inline]
+pub unsafe fn send_super(sup: &Super, sel: &Sel) {
+    unsafe {
+        let (receiver, imp) = super_imp(sup, sel);
+        let imp: extern "C" fn(*const c_void, &Sel) = std::mem::transmute(imp);
+        imp(receiver, sel)
+    }
+}
+
 #[link(name = "objc", kind = "dylib")]
 unsafe extern "C-unwind" {
-    #[cfg(any(target_arch = "x86_64", feature = "classic-objc-retain-release"))]
+    #[cfg(any(
+        not(target_vendor = "apple"),
+        target_arch = "x86_64",
+        feature = "classic-objc-retain-release"
+    ))]
     pub fn objc_retain<'a>(obj: &Id) -> &'a Id;
     #[cfg(any(
+        not(target_vendor = "apple"),
         target_arch = "x86_64",
         target_pointer_width = "32",
         feature = "classic-objc-retain-release"
@@ -364,6 +539,21 @@ unsafe extern "C-unwind" {
     pub fn objc_loadWeakRetained(location: *mut *mut Id) -> Option<arc::R<Id>>;
 
     pub fn object_getIndexedIvars(obj: *const c_void) -> *mut c_void;
+    pub fn class_getInstanceSize(cls: &Class<Id>) -> usize;
+    pub fn class_addIvar(
+        cls: &Class<Id>,
+        name: *const std::ffi::c_char,
+        size: usize,
+        alignment: u8,
+        types: *const std::ffi::c_char,
+    ) -> bool;
+    pub fn class_getInstanceVariable(
+        cls: &Class<Id>,
+        name: *const std::ffi::c_char,
+    ) -> Option<NonNull<c_void>>;
+    pub fn ivar_getOffset(ivar: NonNull<c_void>) -> isize;
+    pub fn class_getInstanceMethod(cls: &Class<Id>, name: &Sel) -> Option<NonNull<c_void>>;
+    pub fn method_getTypeEncoding(method: NonNull<c_void>) -> *const std::ffi::c_char;
     pub fn sel_registerName(str: *const i8) -> *const std::ffi::c_void;
     pub fn class_addMethod(
         cls: &Class<Id>,
@@ -388,7 +578,10 @@ unsafe extern "C-unwind" {
     pub fn objc_registerClassPair(cls: &Class<Id>);
     pub fn objc_getClass(name: *const u8) -> Option<&'static Class<Id>>;
     pub fn objc_getProtocol(name: *const i8) -> Option<&'static Protocol>;
+    #[cfg(target_vendor = "apple")]
     pub fn objc_msgSendSuper(s: &Super, sel: &Sel);
+    #[cfg(not(target_vendor = "apple"))]
+    pub fn objc_msg_lookup_super(s: &Super, sel: &Sel) -> *const c_void;
     pub static NS_OBJECT: &'static crate::objc::Class<Id>;
     fn objc_exception_throw(exception: &Id) -> !;
 }
@@ -412,6 +605,30 @@ macro_rules! define_cls_init {
             }
         }
     };
+    ($NewType:ident, $CLS:ident, $($platform:ident = $ver:literal),+) => {
+        impl $crate::arc::A<$NewType> {
+            #[$crate::objc::msg_send(init)]
+            pub fn init(self) -> arc::Retained<$NewType>;
+        }
+
+        impl $NewType {
+            $crate::define_cls!($CLS, $($platform = $ver),+);
+
+            /// shortcut to `Self::alloc().init()`
+            #[inline]
+            pub fn new() -> $crate::arc::R<$NewType> {
+                Self::alloc().init()
+            }
+
+            /// shortcut to `Self::alloc_available(av).init()`
+            #[inline]
+            pub fn new_available<const V: u32>(
+                av: $crate::api::Available<V>,
+            ) -> $crate::arc::R<$NewType> {
+                Self::alloc_available(av).init()
+            }
+        }
+    };
 }
 
 #[macro_export]
@@ -432,6 +649,30 @@ macro_rules! define_weak_cls_init {
             }
         }
     };
+    ($NewType:ident, $CLS:ident, $($platform:ident = $ver:literal),+) => {
+        impl $crate::arc::A<$NewType> {
+            #[$crate::objc::msg_send(init)]
+            pub fn init(self) -> arc::Retained<$NewType>;
+        }
+
+        impl $NewType {
+            $crate::define_weak_cls!($CLS, $($platform = $ver),+);
+
+            /// shortcut to `Self::alloc().init()`
+            #[inline]
+            pub fn new() -> Option<$crate::arc::R<$NewType>> {
+                Some(Self::alloc()?.init())
+            }
+
+            /// shortcut to `Self::alloc_available(av).init()`
+            #[inline]
+            pub fn new_available<const V: u32>(
+                av: $crate::api::Available<V>,
+            ) -> $crate::arc::R<$NewType> {
+                Self::alloc_available(av).init()
+            }
+        }
+    };
 }
 
 /// Defines class
@@ -457,6 +698,31 @@ macro_rules! define_cls {
             Self::cls().alloc()
         }
     };
+    ($CLS:ident, $($platform:ident = $ver:literal),+) => {
+        $crate::define_cls!($CLS);
+
+        /// `cls()` with proof from `api::if_available!`
+        #[inline]
+        pub fn cls_available<const V: u32>(
+            _av: $crate::api::Available<V>,
+        ) -> &'static $crate::objc::Class<Self> {
+            const {
+                assert!(
+                    V >= $crate::api::platform_version!($($platform = $ver),+),
+                    "proof is older than class availability"
+                )
+            };
+            Self::cls()
+        }
+
+        /// `alloc()` with proof from `api::if_available!`
+        #[inline]
+        pub fn alloc_available<const V: u32>(
+            av: $crate::api::Available<V>,
+        ) -> $crate::arc::A<Self> {
+            Self::cls_available(av).alloc()
+        }
+    };
 }
 
 #[macro_export]
@@ -477,6 +743,38 @@ macro_rules! define_weak_cls {
             Some(Self::cls()?.alloc())
         }
     };
+    ($CLS:ident, $($platform:ident = $ver:literal),+) => {
+        $crate::define_weak_cls!($CLS);
+
+        /// `cls()` with proof from `api::if_available!`
+        ///
+        /// # Panics
+        /// If class is missing despite the proof, e.g. one made with `Available::new_unchecked`
+        /// or its framework isn't linked.
+        #[inline]
+        pub fn cls_available<const V: u32>(
+            _av: $crate::api::Available<V>,
+        ) -> &'static $crate::objc::Class<Self> {
+            const {
+                assert!(
+                    V >= $crate::api::platform_version!($($platform = $ver),+),
+                    "proof is older than class availability"
+                )
+            };
+            match Self::cls() {
+                Some(cls) => cls,
+                None => panic!("`{}` is missing despite the proof", stringify!($CLS)),
+            }
+        }
+
+        /// `alloc()` with proof from `api::if_available!`
+        #[inline]
+        pub fn alloc_available<const V: u32>(
+            av: $crate::api::Available<V>,
+        ) -> $crate::arc::A<Self> {
+            Self::cls_available(av).alloc()
+        }
+    };
 }
 
 #[macro_export]
@@ -497,7 +795,7 @@ macro_rules! define_obj_type {
             pub fn inner(&self) -> &$InnerType {
                 unsafe {
                     let ptr =  self as *const Self as *const u8;
-                    let ptr = ptr.add($crate::objc::NS_OBJECT_SIZE);
+                    let ptr = ptr.add($crate::objc::ns_object_inner_offset::<$InnerType>());
                     &*(ptr as *const $InnerType)
                 }
             }
@@ -507,16 +805,21 @@ macro_rules! define_obj_type {
             pub fn inner_mut(&mut self) -> &mut $InnerType {
                 unsafe {
                     let ptr: *mut u8 = self as *mut Self as *mut u8;
-                    let ptr = ptr.add($crate::objc::NS_OBJECT_SIZE);
+                    let ptr = ptr.add($crate::objc::ns_object_inner_offset::<$InnerType>());
                     &mut *(ptr as *mut $InnerType)
                 }
             }
 
+            /// Class used for `super` calls.
             #[allow(dead_code)]
-            pub fn register_cls() -> &'static $crate::objc::ClassInstExtra<Self, $InnerType> {
-                let name = concat!(stringify!($CLS), "\0");
-                let cls = unsafe { $crate::objc::objc_allocateClassPair($crate::objc::NS_OBJECT, name.as_ptr(), 0) };
-                let cls = cls.unwrap();
+            #[inline]
+            pub fn super_cls() -> &'static $crate::objc::Class<$crate::objc::Id> {
+                unsafe { $crate::objc::NS_OBJECT }
+            }
+
+            fn register_cls_named(name: &std::ffi::CStr) -> Option<&'static $crate::objc::Class<$crate::objc::Id>> {
+                let cls = unsafe { $crate::objc::objc_allocateClassPair($crate::objc::NS_OBJECT, name.as_ptr().cast(), 0) }?;
+                assert!(unsafe { $crate::objc::add_ivar::<$InnerType>(cls) });
                 $(<Self as $TraitImpl>::cls_add_methods(cls);)*
                 $(<Self as $TraitImpl>::cls_add_protocol(cls);)*
 
@@ -524,21 +827,21 @@ macro_rules! define_obj_type {
                     unsafe {
                         let sel = $crate::objc::sel_reg_name(c"init".as_ptr() as _);
                         let imp: extern "C" fn() = init_fn_ptr;
-                        $crate::objc::class_addMethod(cls, sel, imp, std::ptr::null());
+                        $crate::objc::class_addMethod(cls, sel, imp, c"@@:".as_ptr().cast());
 
                         let sel = $crate::objc::sel_reg_name(c"alloc".as_ptr() as _);
                         let meta_cls = $crate::objc::object_getClass(Some(std::mem::transmute(cls))).unwrap();
 
-                        extern "C" fn alloc_impl(cls: &$crate::objc::Class<$crate::ns::Id>) -> $crate::arc::A<$NewType> {
+                        extern "C" fn alloc_impl(cls: &$crate::objc::Class<$crate::objc::Id>) -> $crate::arc::A<$NewType> {
                             unsafe {
-                                let inst = $crate::objc::class_createInstance(cls, std::mem::size_of::<$InnerType>());
+                                let inst = $crate::objc::class_createInstance(cls, 0);
                                 std::mem::transmute(inst)
                             }
 
                         }
 
 
-                        $crate::objc::class_addMethod(meta_cls, sel, std::mem::transmute(alloc_impl as *const u8), std::ptr::null());
+                        $crate::objc::class_addMethod(meta_cls, sel, std::mem::transmute(alloc_impl as *const u8), c"@#:".as_ptr().cast());
 
                     }
                 }
@@ -550,29 +853,56 @@ macro_rules! define_obj_type {
                             std::ptr::drop_in_place(ptr);
                             let sup = $crate::objc::Super {
                                 receiver: std::mem::transmute(s),
-                                super_class: $crate::objc::NS_OBJECT
+                                super_class: $NewType::super_cls()
                             };
-                            $crate::objc::objc_msgSendSuper(&sup, sel);
+                            $crate::objc::send_super(&sup, sel);
                         }
                     }
                     unsafe {
                         let sel = $crate::objc::sel_reg_name(c"dealloc".as_ptr() as _);
                         let imp: extern "C" fn() = std::mem::transmute(impl_dealloc as *const u8);
-                        $crate::objc::class_addMethod(cls, sel, imp, std::ptr::null());
+                        $crate::objc::class_addMethod(cls, sel, imp, c"v@:".as_ptr().cast());
                     }
                 }
                 unsafe { $crate::objc::objc_registerClassPair(cls) };
-                unsafe { std::mem::transmute(cls) }
+                Some(cls)
+            }
+
+            /// Registers class once per process.
+            ///
+            /// Fails if class with the same name has different layout.
+            #[allow(dead_code)]
+            pub fn try_cls() -> Result<&'static $crate::objc::ClassInstExtra<Self, $InnerType>, $crate::objc::RegisterError> {
+                static CLS: $crate::objc::ClassOnce = $crate::objc::ClassOnce::new();
+                let cls = CLS.get_or_register::<Self>(
+                    || {
+                        let name = $crate::objc::class_name(
+                            stringify!($CLS),
+                            env!("CARGO_PKG_NAME"),
+                            env!("CARGO_PKG_VERSION"),
+                            std::any::type_name::<Self>(),
+                        );
+                        let size = $crate::objc::instance_size(
+                            $crate::objc::NS_OBJECT_SIZE,
+                            std::mem::size_of::<$InnerType>(),
+                            std::mem::align_of::<$InnerType>(),
+                        );
+                        (name, size)
+                    },
+                    Self::register_cls_named,
+                )?;
+                Ok(unsafe { std::mem::transmute(cls) })
+            }
+
+            #[allow(dead_code)]
+            #[inline]
+            pub fn register_cls() -> &'static $crate::objc::ClassInstExtra<Self, $InnerType> {
+                Self::cls()
             }
 
             #[allow(dead_code)]
             pub fn cls() -> &'static $crate::objc::ClassInstExtra<Self, $InnerType> {
-                let name = concat!(stringify!($CLS), "\0");
-                let cls = unsafe { $crate::objc::objc_getClass(name.as_ptr()) };
-                match cls {
-                    Some(c) => unsafe { std::mem::transmute(c) }
-                    None => Self::register_cls()
-                }
+                Self::try_cls().unwrap_or_else(|e| panic!("{e}"))
             }
 
             #[allow(dead_code)]
@@ -599,26 +929,52 @@ macro_rules! define_obj_type {
 
         impl $NewType {
 
+            /// Class used for `super` calls.
             #[allow(dead_code)]
-            pub fn register_cls() -> &'static $crate::objc::ClassInstExtra<Self, ()> {
-                let name = concat!(stringify!($CLS), "\0");
-                let cls = unsafe { $crate::objc::objc_allocateClassPair($crate::objc::NS_OBJECT, name.as_ptr(), 0) };
-                let cls = cls.unwrap();
+            #[inline]
+            pub fn super_cls() -> &'static $crate::objc::Class<$crate::objc::Id> {
+                unsafe { $crate::objc::NS_OBJECT }
+            }
+
+            fn register_cls_named(name: &std::ffi::CStr) -> Option<&'static $crate::objc::Class<$crate::objc::Id>> {
+                let cls = unsafe { $crate::objc::objc_allocateClassPair($crate::objc::NS_OBJECT, name.as_ptr().cast(), 0) }?;
                 $(<Self as $TraitImpl>::cls_add_methods(cls);)*
                 $(<Self as $TraitImpl>::cls_add_protocol(cls);)*
 
                 unsafe { $crate::objc::objc_registerClassPair(cls) };
-                unsafe { std::mem::transmute(cls) }
+                Some(cls)
+            }
+
+            /// Registers class once per process.
+            ///
+            /// Fails if class with the same name has different layout.
+            #[allow(dead_code)]
+            pub fn try_cls() -> Result<&'static $crate::objc::ClassInstExtra<Self, ()>, $crate::objc::RegisterError> {
+                static CLS: $crate::objc::ClassOnce = $crate::objc::ClassOnce::new();
+                let cls = CLS.get_or_register::<Self>(
+                    || {
+                        let name = $crate::objc::class_name(
+                            stringify!($CLS),
+                            env!("CARGO_PKG_NAME"),
+                            env!("CARGO_PKG_VERSION"),
+                            std::any::type_name::<Self>(),
+                        );
+                        (name, $crate::objc::NS_OBJECT_SIZE)
+                    },
+                    Self::register_cls_named,
+                )?;
+                Ok(unsafe { std::mem::transmute(cls) })
+            }
+
+            #[allow(dead_code)]
+            #[inline]
+            pub fn register_cls() -> &'static $crate::objc::ClassInstExtra<Self, ()> {
+                Self::cls()
             }
 
             #[allow(dead_code)]
             pub fn cls() -> &'static $crate::objc::ClassInstExtra<Self, ()> {
-                let name = concat!(stringify!($CLS), "\0");
-                let cls = unsafe { $crate::objc::objc_getClass(name.as_ptr()) };
-                match cls {
-                    Some(c) => unsafe { std::mem::transmute(c) }
-                    None => Self::register_cls()
-                }
+                Self::try_cls().unwrap_or_else(|e| panic!("{e}"))
             }
 
             #[allow(dead_code)]
@@ -627,6 +983,159 @@ macro_rules! define_obj_type {
             }
         }
     };
+    (
+        $(#[$outer:meta])*
+        $vis:vis
+        $NewType:ident($SuperType:path) $(+ $TraitImpl:path)*, $InnerType:ty, $CLS:ident) => {
+        $crate::define_obj_type!(
+            $(#[$outer])*
+            $vis
+            $NewType($SuperType)
+        );
+
+        impl $NewType {
+            /// Class used for `super` calls.
+            #[allow(dead_code)]
+            #[inline]
+            pub fn super_cls() -> &'static $crate::objc::Class<$crate::objc::Id> {
+                unsafe { std::mem::transmute(<$SuperType>::cls()) }
+            }
+
+            fn ivar_offset() -> usize {
+                static OFFSET: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+                let offset = OFFSET.load(std::sync::atomic::Ordering::Relaxed);
+                if offset != 0 {
+                    return offset;
+                }
+                let cls: &$crate::objc::Class<$crate::objc::Id> = unsafe { std::mem::transmute(Self::cls()) };
+                let offset = $crate::objc::Ivar::<$InnerType>::offset_in(cls).unwrap();
+                OFFSET.store(offset, std::sync::atomic::Ordering::Relaxed);
+                offset
+            }
+
+            #[inline]
+            fn ivar(&self) -> &$crate::objc::Ivar<$InnerType> {
+                unsafe {
+                    let ptr = self as *const Self as *const u8;
+                    &*(ptr.add(Self::ivar_offset()) as *const $crate::objc::Ivar<$InnerType>)
+                }
+            }
+
+            #[inline]
+            fn ivar_mut(&mut self) -> &mut $crate::objc::Ivar<$InnerType> {
+                unsafe {
+                    let ptr = self as *mut Self as *mut u8;
+                    &mut *(ptr.add(Self::ivar_offset()) as *mut $crate::objc::Ivar<$InnerType>)
+                }
+            }
+
+            /// Set on allocation if inner type implements `Default`,
+            /// otherwise `None` until [`Self::set_inner`].
+            #[allow(dead_code)]
+            #[inline]
+            pub fn inner(&self) -> Option<&$InnerType> {
+                self.ivar().get()
+            }
+
+            #[allow(dead_code)]
+            #[inline]
+            pub fn inner_mut(&mut self) -> Option<&mut $InnerType> {
+                self.ivar_mut().get_mut()
+            }
+
+            /// Sets inner value from initializers not overridden in Rust.
+            #[allow(dead_code)]
+            pub fn set_inner(&mut self, val: $InnerType) -> Option<$InnerType> {
+                self.ivar_mut().set(val)
+            }
+
+            fn register_cls_named(name: &std::ffi::CStr) -> Option<&'static $crate::objc::Class<$crate::objc::Id>> {
+                let sup = Self::super_cls();
+                let cls = unsafe { $crate::objc::objc_allocateClassPair(sup, name.as_ptr().cast(), 0) }?;
+                assert!(unsafe { $crate::objc::Ivar::<$InnerType>::add_to(cls) });
+                debug_assert_eq!(
+                    $crate::objc::Ivar::<$InnerType>::offset_in(cls),
+                    Some($crate::objc::Ivar::<$InnerType>::offset_after(sup))
+                );
+                $(<Self as $TraitImpl>::cls_add_methods(cls);)*
+                $(<Self as $TraitImpl>::cls_add_protocol(cls);)*
+
+                if let Some(alloc_fn_ptr) = $crate::alloc_super_with_default!($NewType, $InnerType) {
+                    unsafe {
+                        let meta_cls = $crate::objc::object_getClass(Some(std::mem::transmute(cls))).unwrap();
+                        let sel = $crate::objc::sel_reg_name(c"allocWithZone:".as_ptr() as _);
+                        $crate::objc::class_addMethod(meta_cls, sel, alloc_fn_ptr, c"@#:^{_NSZone=}".as_ptr().cast());
+                    }
+                }
+
+                extern "C" fn impl_dealloc(s: &mut $NewType, sel: &$crate::objc::Sel) {
+                    unsafe {
+                        if std::mem::needs_drop::<$InnerType>() {
+                            let ptr = s as *mut $NewType as *mut u8;
+                            let ivar = ptr.add($NewType::ivar_offset()) as *mut $crate::objc::Ivar<$InnerType>;
+                            drop((*ivar).take());
+                        }
+                        let sup = $crate::objc::Super {
+                            receiver: std::mem::transmute(s),
+                            super_class: $NewType::super_cls(),
+                        };
+                        $crate::objc::send_super(&sup, sel);
+                    }
+                }
+                unsafe {
+                    let sel = $crate::objc::sel_reg_name(c"dealloc".as_ptr() as _);
+                    let imp: extern "C" fn() = std::mem::transmute(impl_dealloc as *const u8);
+                    $crate::objc::class_addMethod(cls, sel, imp, c"v@:".as_ptr().cast());
+                }
+                unsafe { $crate::objc::objc_registerClassPair(cls) };
+                Some(cls)
+            }
+
+            /// Registers class once per process.
+            ///
+            /// Fails if class with the same name has different layout.
+            #[allow(dead_code)]
+            pub fn try_cls() -> Result<&'static $crate::objc::Class<Self>, $crate::objc::RegisterError> {
+                static CLS: $crate::objc::ClassOnce = $crate::objc::ClassOnce::new();
+                CLS.get_or_register::<Self>(
+                    || {
+                        let name = $crate::objc::class_name(
+                            stringify!($CLS),
+                            env!("CARGO_PKG_NAME"),
+                            env!("CARGO_PKG_VERSION"),
+                            std::any::type_name::<Self>(),
+                        );
+                        let size = $crate::objc::instance_size(
+                            unsafe { $crate::objc::class_getInstanceSize(Self::super_cls()) },
+                            std::mem::size_of::<$crate::objc::Ivar<$InnerType>>(),
+                            std::mem::align_of::<$crate::objc::Ivar<$InnerType>>(),
+                        );
+                        (name, size)
+                    },
+                    Self::register_cls_named,
+                )
+            }
+
+            #[allow(dead_code)]
+            #[inline]
+            pub fn register_cls() -> &'static $crate::objc::Class<Self> {
+                Self::cls()
+            }
+
+            #[allow(dead_code)]
+            pub fn cls() -> &'static $crate::objc::Class<Self> {
+                Self::try_cls().unwrap_or_else(|e| panic!("{e}"))
+            }
+
+            /// `new` followed by [`Self::set_inner`].
+            #[allow(dead_code)]
+            pub fn with(inner: $InnerType) -> $crate::arc::R<Self> {
+                let mut res = unsafe { Self::cls().new() };
+                res.set_inner(inner);
+                res
+            }
+        }
+    };
     (
         $(#[$outer:meta])*
         $vis:vis
@@ -669,8 +1178,8 @@ macro_rules! define_obj_type {
             }
         }
 
-        impl AsRef<$crate::ns::Id> for $NewType {
-            fn as_ref(&self) -> &$crate::ns::Id {
+        impl AsRef<$crate::objc::Id> for $NewType {
+            fn as_ref(&self) -> &$crate::objc::Id {
                 self
             }
         }
@@ -715,7 +1224,7 @@ impl std::hash::Hash for Id {
 impl Eq for arc::R<Id> {}
 impl std::hash::Hash for arc::R<Id> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0.hash().hash(state);
+        Id::hash(self).hash(state);
     }
 }
 
@@ -730,7 +1239,8 @@ pub fn throw(obj: &Id) -> ! {
     unsafe { objc_exception_throw(obj) }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(target_vendor = "apple", link(name = "ns", kind = "static"))]
+#[cfg_attr(not(target_vendor = "apple"), link(name = "gnustep", kind = "static"))]
 unsafe extern "C-unwind" {
     fn cidre_try_catch<'ar>(
         during: extern "C" fn(ctx: *mut c_void),
@@ -758,6 +1268,28 @@ where
     }
 }
 
+/// Result of `try_*` methods generated for `#[objc::msg_send(sel, throws)]`.
+///
+/// Thrown exception is retained, so it outlives autorelease pool of the call.
+#[cfg(feature = "ns")]
+pub type TryResult<R = ()> = Result<R, arc::R<crate::ns::Exception>>;
+
+/// Same as [`try_catch`] but retains thrown exception.
+///
+/// Objective-C code almost always throws `NSException`, other thrown objects
+/// are wrapped into generic exception with their description as reason.
+#[cfg(feature = "ns")]
+pub fn try_catch_retained<F, R>(f: F) -> TryResult<R>
+where
+    F: FnOnce() -> R,
+{
+    use crate::ns;
+    try_catch(f).map_err(|e| match e.try_cast(ns::Exception::cls()) {
+        Some(ex) => ex.retained(),
+        None => ns::Exception::with_name(ns::ExceptionName::generic(), Some(&e.desc()), None),
+    })
+}
+
 #[inline]
 fn type_helper<F>(_t: &Option<F>) -> extern "C-unwind" fn(t: &mut Option<F>)
 where
@@ -773,7 +1305,7 @@ where
 }
 
 #[cfg(target_arch = "aarch64")]
-#[cfg(test)]
+#[cfg(all(test, feature = "dispatch"))]
 mod tests {
 
     use super::ar_pool;
@@ -802,14 +1334,28 @@ pub use cidre_macros::optional;
 pub use cidre_macros::protocol;
 
 /// Docs
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_vendor = "apple", target_arch = "aarch64"))]
 pub use cidre_macros::msg_send;
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_vendor = "apple", target_arch = "aarch64"))]
 pub use cidre_macros::msg_send_debug;
-#[cfg(target_arch = "x86_64")]
+/// GNUstep libobjc2 backend, dispatch with `objc_msg_lookup`.
+#[cfg(not(target_vendor = "apple"))]
+pub use cidre_macros::msg_send_gnustep as msg_send;
+#[cfg(not(target_vendor = "apple"))]
+pub use cidre_macros::msg_send_gnustep_debug as msg_send_debug;
+#[cfg(all(target_vendor = "apple", target_arch = "x86_64"))]
 pub use cidre_macros::msg_send_x86_64 as msg_send;
 
-#[cfg(test)]
+/// Structs larger than 16 bytes are returned in memory on x86_64 and need `objc_msgSend_stret`.
+/// `objc_msgSend_fpret` is only required for `long double` which rust doesn't have.
+#[doc(hidden)]
+#[cfg(all(target_vendor = "apple", target_arch = "x86_64"))]
+#[inline]
+pub const fn msg_send_stret<R>() -> bool {
+    std::mem::size_of::<R>() > 16
+}
+
+#[cfg(all(test, feature = "ns"))]
 mod tests2 {
 
     use std::collections::HashMap;
@@ -883,11 +1429,79 @@ mod tests2 {
             assert_eq!(d.prop().to_string(), "test");
             let _r = d.retained();
             let desc = d.desc();
-            assert!(desc.to_string().starts_with("<BLA_USIZE: "));
+            assert!(desc.to_string().starts_with("<BLA_USIZE_cidre_"));
         }
         assert!(unsafe { DROP_CALLED });
     }
 
+    #[objc::protocol(QueueOverrides)]
+    trait QueueOverrides: objc::Obj {
+        #[objc::msg_send(maxConcurrentOperationCount)]
+        fn max_concurrent_ops(&self) -> isize;
+    }
+
+    define_obj_type!(
+        Queue(ns::OpQueue) + QueueOverridesImpl,
+        Vec<isize>,
+        CIDRE_TEST_QUEUE
+    );
+
+    impl QueueOverrides for Queue {}
+
+    #[objc::add_methods]
+    impl QueueOverridesImpl for Queue {
+        extern "C" fn impl_max_concurrent_ops(&self, _cmd: Option<&objc::Sel>) -> isize {
+            self.inner().unwrap().len() as isize + self.super_max_concurrent_ops()
+        }
+    }
+
+    #[test]
+    fn subclass() {
+        let sup: &objc::Class<objc::Id> = unsafe { std::mem::transmute(ns::OpQueue::cls()) };
+        let cls: &objc::Class<objc::Id> = unsafe { std::mem::transmute(Queue::cls()) };
+        assert_eq!(
+            objc::Ivar::<Vec<isize>>::offset_in(cls),
+            Some(objc::Ivar::<Vec<isize>>::offset_after(sup))
+        );
+
+        let mut q = Queue::with(vec![1, 2, 3]);
+        assert_eq!(q.max_concurrent_ops(), 2);
+        q.set_max_concurrent_ops(4);
+        assert_eq!(q.max_concurrent_ops(), 7);
+        q.inner_mut().unwrap().clear();
+        assert_eq!(q.max_concurrent_ops(), 4);
+
+        q.set_name(Some(ns::str!(c"queue")));
+        assert_eq!(q.name().unwrap().to_string(), "queue");
+
+        let q = unsafe { Queue::cls().new() };
+        assert!(q.inner().unwrap().is_empty());
+    }
+
+    define_obj_type!(Err(ns::Error), Vec<isize>, CIDRE_TEST_ERR);
+
+    define_obj_type!(
+        ErrNoDefault(ns::Error),
+        std::num::NonZeroUsize,
+        CIDRE_TEST_ERR_NO_DEFAULT
+    );
+
+    #[test]
+    fn subclass_designated_init() {
+        let a: arc::A<ns::Error> = unsafe { std::mem::transmute(Err::cls().alloc()) };
+        let e = a.init_with_domain(ns::ErrorDomain::cocoa(), 1, None);
+        let e: arc::R<Err> = unsafe { std::mem::transmute(e) };
+        assert_eq!(e.code(), 1);
+        assert!(e.inner().unwrap().is_empty());
+
+        let a: arc::A<ns::Error> = unsafe { std::mem::transmute(ErrNoDefault::cls().alloc()) };
+        let e = a.init_with_domain(ns::ErrorDomain::cocoa(), 1, None);
+        let mut e: arc::R<ErrNoDefault> = unsafe { std::mem::transmute(e) };
+        assert!(e.inner().is_none());
+        e.set_inner(std::num::NonZeroUsize::MIN);
+        assert_eq!(e.inner().unwrap().get(), 1);
+    }
+
     #[test]
     fn hash() {
         fn foo() -> HashMap<arc::R<ns::Id>, arc::R<ns::String>> {
@@ -900,4 +1514,16 @@ mod tests2 {
 
         foo();
     }
+
+    #[test]
+    fn try_catch_retained() {
+        let reason = ns::String::with_str("not an exception, but thrown anyway");
+        let ex = objc::try_catch_retained(|| objc::throw(&reason)).expect_err("thrown");
+        assert!(ex.name().eq(ns::ExceptionName::generic()));
+        assert!(ex.reason().unwrap().eq(&reason));
+
+        let raised = ns::str!(c"raised");
+        let ex = objc::try_catch_retained(|| ns::Exception::raise(raised)).expect_err("raised");
+        assert!(ex.reason().unwrap().eq(raised));
+    }
 }
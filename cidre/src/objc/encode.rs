@@ -0,0 +1,662 @@
+//! Objective-C type encodings.
+//!
+//! The same strings clang produces for `@encode(T)`. They are attached
+//! to methods implemented in Rust, so `NSInvocation`, `methodSignatureForSelector:`
+//! and message forwarding work with them.
+
+use std::{ffi::CString, ffi::c_void, marker::PhantomData, ptr::NonNull};
+
+use crate::{arc, objc};
+
+/// Type with a known `@encode` representation.
+///
+/// # Safety
+///
+/// Encoding must describe memory layout of the type.
+pub unsafe trait Encode {
+    fn encode(buf: &mut String);
+
+    fn encoding() -> String {
+        let mut buf = String::new();
+        Self::encode(&mut buf);
+        buf
+    }
+}
+
+/// Encoding of a pointer to the type.
+///
+/// Objects encode pointers to them as `@`, blocks as `@?`, classes as `#` and selectors as `:`.
+///
+/// # Safety
+///
+/// Encoding must describe memory layout of pointer to the type.
+pub unsafe trait RefEncode {
+    fn encode_ref(buf: &mut String);
+}
+
+unsafe impl<T: objc::Obj> RefEncode for T {
+    #[inline]
+    fn encode_ref(buf: &mut String) {
+        buf.push_str(T::ENCODING)
+    }
+}
+
+unsafe impl RefEncode for objc::Sel {
+    #[inline]
+    fn encode_ref(buf: &mut String) {
+        buf.push(':')
+    }
+}
+
+unsafe impl RefEncode for c_void {
+    #[inline]
+    fn encode_ref(buf: &mut String) {
+        buf.push_str("^v")
+    }
+}
+
+macro_rules! encode {
+    ($($t:ty => $enc:literal),* $(,)?) => {
+        $(
+            unsafe impl $crate::objc::Encode for $t {
+                #[inline]
+                fn encode(buf: &mut String) {
+                    buf.push_str($enc)
+                }
+            }
+
+            unsafe impl $crate::objc::RefEncode for $t {
+                #[inline]
+                fn encode_ref(buf: &mut String) {
+                    buf.push_str(concat!("^", $enc))
+                }
+            }
+        )*
+    };
+}
+
+encode!(
+    () => "v",
+    bool => "B",
+    i16 => "s",
+    u16 => "S",
+    i32 => "i",
+    u32 => "I",
+    i64 => "q",
+    u64 => "Q",
+    f32 => "f",
+    f64 => "d",
+);
+
+#[cfg(target_pointer_width = "64")]
+encode!(isize => "q", usize => "Q");
+
+/// `NSInteger` is 32-bit `long` on arm64_32 and armv7k.
+#[cfg(target_pointer_width = "32")]
+encode!(isize => "l", usize => "L");
+
+unsafe impl Encode for i8 {
+    #[inline]
+    fn encode(buf: &mut String) {
+        buf.push('c')
+    }
+}
+
+unsafe impl Encode for u8 {
+    #[inline]
+    fn encode(buf: &mut String) {
+        buf.push('C')
+    }
+}
+
+/// `char *` and `unsigned char *` are C strings.
+unsafe impl RefEncode for i8 {
+    #[inline]
+    fn encode_ref(buf: &mut String) {
+        buf.push('*')
+    }
+}
+
+unsafe impl RefEncode for u8 {
+    #[inline]
+    fn encode_ref(buf: &mut String) {
+        buf.push('*')
+    }
+}
+
+macro_rules! encode_ptr {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl<T: RefEncode + ?Sized> Encode for $t {
+                #[inline]
+                fn encode(buf: &mut String) {
+                    T::encode_ref(buf)
+                }
+            }
+        )*
+    };
+}
+
+encode_ptr!(
+    &T,
+    &mut T,
+    *const T,
+    *mut T,
+    Option<&T>,
+    Option<&mut T>,
+    NonNull<T>,
+    Option<NonNull<T>>,
+);
+
+macro_rules! ref_encode_ptr {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl<T: RefEncode + ?Sized> RefEncode for $t {
+                #[inline]
+                fn encode_ref(buf: &mut String) {
+                    buf.push('^');
+                    T::encode_ref(buf)
+                }
+            }
+        )*
+    };
+}
+
+ref_encode_ptr!(*const T, *mut T, Option<&T>, Option<&mut T>, NonNull<T>);
+
+macro_rules! encode_arc {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl<T: objc::Obj> Encode for $t {
+                #[inline]
+                fn encode(buf: &mut String) {
+                    T::encode_ref(buf)
+                }
+            }
+
+            unsafe impl<T: objc::Obj> RefEncode for $t {
+                #[inline]
+                fn encode_ref(buf: &mut String) {
+                    buf.push('^');
+                    T::encode_ref(buf)
+                }
+            }
+        )*
+    };
+}
+
+encode_arc!(
+    arc::R<T>,
+    Option<arc::R<T>>,
+    arc::A<T>,
+    Option<arc::A<T>>,
+    arc::Rar<T>,
+    Option<arc::Rar<T>>,
+);
+
+unsafe impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode(buf: &mut String) {
+        buf.push('[');
+        buf.push_str(&N.to_string());
+        T::encode(buf);
+        buf.push(']');
+    }
+}
+
+/// Implements [`Encode`] for `#[repr(C)]` struct with clang's struct name and field types.
+#[macro_export]
+macro_rules! encode_struct {
+    ($t:ty, $name:literal, [$($field:ty),* $(,)?]) => {
+        unsafe impl $crate::objc::Encode for $t {
+            fn encode(buf: &mut String) {
+                buf.push_str(concat!("{", $name, "="));
+                $(<$field as $crate::objc::Encode>::encode(buf);)*
+                buf.push('}');
+            }
+        }
+
+        unsafe impl $crate::objc::RefEncode for $t {
+            fn encode_ref(buf: &mut String) {
+                buf.push('^');
+                <$t as $crate::objc::Encode>::encode(buf)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "cg")]
+mod cg_impls {
+    use crate::cg;
+
+    encode_struct!(cg::Point, "CGPoint", [cg::Float, cg::Float]);
+    encode_struct!(cg::Size, "CGSize", [cg::Float, cg::Float]);
+    encode_struct!(cg::Rect, "CGRect", [cg::Point, cg::Size]);
+    encode_struct!(cg::Vector, "CGVector", [cg::Float, cg::Float]);
+    encode_struct!(
+        cg::AffineTransform,
+        "CGAffineTransform",
+        [
+            cg::Float,
+            cg::Float,
+            cg::Float,
+            cg::Float,
+            cg::Float,
+            cg::Float
+        ]
+    );
+}
+
+#[cfg(feature = "ns")]
+mod ns_impls {
+    use crate::ns;
+
+    encode_struct!(ns::Range, "_NSRange", [ns::UInteger, ns::UInteger]);
+
+    encode!(ns::KvoOpts => "Q");
+
+    unsafe impl super::RefEncode for ns::Zone {
+        #[inline]
+        fn encode_ref(buf: &mut String) {
+            buf.push_str("^{_NSZone=}")
+        }
+    }
+
+    #[cfg(all(feature = "app", target_os = "macos"))]
+    encode_struct!(
+        ns::EdgeInsets,
+        "NSEdgeInsets",
+        [
+            crate::cg::Float,
+            crate::cg::Float,
+            crate::cg::Float,
+            crate::cg::Float
+        ]
+    );
+}
+
+#[cfg(feature = "at")]
+mod at_impls {
+    use crate::at::audio;
+
+    encode_struct!(
+        audio::ComponentDesc,
+        "AudioComponentDescription",
+        [u32, u32, u32, u32, u32]
+    );
+
+    encode!(audio::ComponentInstantiationOpts => "I");
+}
+
+#[cfg(feature = "cm")]
+mod cm_impls {
+    use crate::cm;
+
+    macro_rules! encode_opaque {
+        ($($t:ty => $enc:literal),* $(,)?) => {
+            $(
+                unsafe impl super::RefEncode for $t {
+                    #[inline]
+                    fn encode_ref(buf: &mut String) {
+                        buf.push_str($enc)
+                    }
+                }
+            )*
+        };
+    }
+
+    encode_opaque!(
+        cm::Timebase => "^{OpaqueCMTimebase=}",
+        cm::SampleBuf => "^{opaqueCMSampleBuffer=}",
+    );
+}
+
+#[cfg(feature = "simd")]
+mod simd_impls {
+    use crate::simd;
+
+    /// Clang encodes ext vectors as empty strings.
+    unsafe impl<T, const LANES: usize, const N: usize> super::Encode for simd::Simd<T, LANES, N> {
+        #[inline]
+        fn encode(_buf: &mut String) {}
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    macro_rules! encode_vector {
+        ($($t:ty),*) => {
+            $(
+                unsafe impl super::Encode for $t {
+                    #[inline]
+                    fn encode(_buf: &mut String) {}
+                }
+            )*
+        };
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    encode_vector!(simd::f32x2, simd::f32x3, simd::f32x4);
+
+    encode_struct!(simd::f32x2x2, "?", [[simd::f32x2; 2]]);
+    encode_struct!(simd::f32x3x2, "?", [[simd::f32x2; 3]]);
+    encode_struct!(simd::f32x4x2, "?", [[simd::f32x2; 4]]);
+    encode_struct!(simd::f32x2x3, "?", [[simd::f32x3; 2]]);
+    encode_struct!(simd::f32x3x3, "?", [[simd::f32x3; 3]]);
+    encode_struct!(simd::f32x4x4, "?", [[simd::f32x4; 4]]);
+    encode_struct!(simd::f32quat, "?", [simd::f32x4]);
+}
+
+/// Function which signature can be encoded as method types.
+///
+/// # Safety
+///
+/// Encoding must describe function signature.
+pub unsafe trait EncodeFn {
+    fn encode_fn(buf: &mut String);
+
+    fn method_types() -> CString {
+        let mut buf = String::new();
+        Self::encode_fn(&mut buf);
+        CString::new(buf).unwrap()
+    }
+}
+
+macro_rules! encode_fn {
+    ($($a:ident),*) => {
+        unsafe impl<R: Encode, $($a: Encode),*> EncodeFn for extern "C" fn($($a),*) -> R {
+            fn encode_fn(buf: &mut String) {
+                R::encode(buf);
+                $($a::encode(buf);)*
+            }
+        }
+
+        unsafe impl<R: Encode, $($a: Encode),*> EncodeFn for unsafe extern "C" fn($($a),*) -> R {
+            fn encode_fn(buf: &mut String) {
+                R::encode(buf);
+                $($a::encode(buf);)*
+            }
+        }
+
+        unsafe impl<R, $($a),*> Encode for extern "C" fn($($a),*) -> R {
+            #[inline]
+            fn encode(buf: &mut String) {
+                buf.push_str("^?")
+            }
+        }
+
+        unsafe impl<R, $($a),*> Encode for Option<extern "C" fn($($a),*) -> R> {
+            #[inline]
+            fn encode(buf: &mut String) {
+                buf.push_str("^?")
+            }
+        }
+    };
+}
+
+encode_fn!();
+encode_fn!(A);
+encode_fn!(A, B);
+encode_fn!(A, B, C);
+encode_fn!(A, B, C, D);
+encode_fn!(A, B, C, D, E);
+encode_fn!(A, B, C, D, E, F);
+encode_fn!(A, B, C, D, E, F, G);
+encode_fn!(A, B, C, D, E, F, G, H);
+encode_fn!(A, B, C, D, E, F, G, H, I);
+encode_fn!(A, B, C, D, E, F, G, H, I, J);
+encode_fn!(A, B, C, D, E, F, G, H, I, J, K);
+encode_fn!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Method types of `imp` for `class_addMethod`.
+///
+/// Falls back to no types if one of argument types doesn't implement [`Encode`],
+/// the fallback is deprecated so such methods show up as warnings:
+///
+/// ```ignore
+/// use objc::{MethodTypesAny as _, MethodTypesEncode as _};
+/// let types = objc::MethodTypes::of(imp).types();
+/// ```
+pub struct MethodTypes<F>(PhantomData<F>);
+
+impl<F> MethodTypes<F> {
+    #[inline]
+    pub fn of(_imp: F) -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub trait MethodTypesEncode {
+    fn types(&self) -> Option<CString>;
+}
+
+impl<F: EncodeFn> MethodTypesEncode for MethodTypes<F> {
+    #[inline]
+    fn types(&self) -> Option<CString> {
+        Some(F::method_types())
+    }
+}
+
+pub trait MethodTypesAny {
+    #[deprecated(
+        note = "argument or return type doesn't implement `objc::Encode`, method is added without type encoding"
+    )]
+    fn types(&self) -> Option<CString>;
+}
+
+impl<F> std::ops::Deref for MethodTypes<F> {
+    type Target = ();
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &()
+    }
+}
+
+impl MethodTypesAny for () {
+    #[inline]
+    fn types(&self) -> Option<CString> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::c_void;
+
+    use crate::{arc, objc, objc::Encode};
+
+    fn enc<T: Encode>() -> String {
+        T::encoding()
+    }
+
+    #[repr(C)]
+    struct Pair {
+        a: i32,
+        b: *const u8,
+    }
+
+    encode_struct!(Pair, "Pair", [i32, *const u8]);
+
+    #[test]
+    fn basics() {
+        assert_eq!(enc::<bool>(), "B");
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(enc::<isize>(), "q");
+            assert_eq!(enc::<usize>(), "Q");
+        }
+        assert_eq!(enc::<*const i8>(), "*");
+        assert_eq!(enc::<*mut c_void>(), "^v");
+        assert_eq!(enc::<*mut f32>(), "^f");
+        assert_eq!(enc::<&objc::Id>(), "@");
+        assert_eq!(enc::<Option<arc::R<objc::Id>>>(), "@");
+        assert_eq!(enc::<*mut Option<arc::R<objc::Id>>>(), "^@");
+        assert_eq!(enc::<Option<&objc::Sel>>(), ":");
+        assert_eq!(enc::<Option<extern "C" fn(*mut c_void)>>(), "^?");
+        assert_eq!(enc::<&objc::Class<objc::Id>>(), "#");
+        #[cfg(feature = "blocks")]
+        assert_eq!(enc::<&mut crate::blocks::EscBlock<fn()>>(), "@?");
+
+        assert_eq!(enc::<[f32; 4]>(), "[4f]");
+        assert_eq!(enc::<Pair>(), "{Pair=i*}");
+        assert_eq!(enc::<*const Pair>(), "^{Pair=i*}");
+
+        #[cfg(feature = "simd")]
+        {
+            use crate::simd;
+
+            assert_eq!(enc::<simd::f32x4>(), "");
+            assert_eq!(enc::<simd::f32x4x4>(), "{?=[4]}");
+            assert_eq!(enc::<simd::f32quat>(), "{?=}");
+        }
+    }
+
+    #[test]
+    fn method_types() {
+        use objc::{MethodTypesAny as _, MethodTypesEncode as _};
+
+        extern "C" fn imp(
+            _s: &objc::Id,
+            _cmd: Option<&objc::Sel>,
+            _pair: Pair,
+            _index: isize,
+        ) -> bool {
+            false
+        }
+
+        extern "C" fn no_enc(_s: &objc::Id, _cmd: Option<&objc::Sel>, _v: std::num::NonZeroU32) {}
+
+        let f: extern "C" fn(_, _, _, _) -> _ = imp;
+        let types = objc::MethodTypes::of(f).types().unwrap();
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(types.to_str().unwrap(), "B@:{Pair=i*}q");
+
+        let f: extern "C" fn(_, _, _) -> _ = no_enc;
+        #[allow(deprecated)]
+        let types = objc::MethodTypes::of(f).types();
+        assert!(types.is_none());
+    }
+}
+
+#[cfg(all(test, feature = "ns"))]
+mod ns_tests {
+    use crate::{arc, cg, ns, objc, objc::Encode};
+
+    fn enc<T: Encode>() -> String {
+        T::encoding()
+    }
+
+    #[test]
+    fn basics() {
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(enc::<ns::Integer>(), "q");
+            assert_eq!(enc::<ns::UInteger>(), "Q");
+        }
+        assert_eq!(enc::<&ns::String>(), "@");
+        assert_eq!(enc::<Option<arc::R<ns::Error>>>(), "@");
+        assert_eq!(enc::<*mut Option<arc::R<ns::Error>>>(), "^@");
+        assert_eq!(enc::<&objc::Class<ns::Id>>(), "#");
+
+        assert_eq!(enc::<ns::Range>(), "{_NSRange=QQ}");
+        assert_eq!(enc::<cg::Rect>(), "{CGRect={CGPoint=dd}{CGSize=dd}}");
+        assert_eq!(enc::<*const cg::Point>(), "^{CGPoint=dd}");
+        assert_eq!(enc::<cg::AffineTransform>(), "{CGAffineTransform=dddddd}");
+    }
+
+    #[test]
+    fn method_types() {
+        use objc::MethodTypesEncode as _;
+
+        extern "C" fn imp(
+            _s: &ns::Id,
+            _cmd: Option<&objc::Sel>,
+            _rect: cg::Rect,
+            _index: ns::Integer,
+        ) -> bool {
+            false
+        }
+
+        let f: extern "C" fn(_, _, _, _) -> _ = imp;
+        let types = objc::MethodTypes::of(f).types().unwrap();
+        assert_eq!(
+            types.to_str().unwrap(),
+            "B@:{CGRect={CGPoint=dd}{CGSize=dd}}q"
+        );
+    }
+
+    /// Method types with offsets stripped, as clang emitted them for the class.
+    #[cfg(target_vendor = "apple")]
+    fn runtime_types(cls: &std::ffi::CStr, sel: &std::ffi::CStr) -> String {
+        let types = unsafe {
+            let cls = objc::objc_getClass(cls.as_ptr().cast()).unwrap();
+            let sel = objc::sel_reg_name(sel.as_ptr().cast());
+            let m = objc::class_getInstanceMethod(cls, sel).unwrap();
+            std::ffi::CStr::from_ptr(objc::method_getTypeEncoding(m))
+        };
+        let types = types.to_str().unwrap();
+        types.chars().filter(|c| !c.is_ascii_digit()).collect()
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn clang() {
+        use objc::MethodTypesEncode as _;
+
+        let num = |n: arc::R<ns::Number>| n.objc_type().to_str().unwrap().to_string();
+        assert_eq!(num(ns::Number::with_i8(1)), enc::<i8>());
+        assert_eq!(num(ns::Number::with_i16(1)), enc::<i16>());
+        assert_eq!(num(ns::Number::with_i32(1)), enc::<i32>());
+        assert_eq!(num(ns::Number::with_i64(1)), enc::<i64>());
+        assert_eq!(num(ns::Number::with_isize(1)), enc::<ns::Integer>());
+        assert_eq!(num(ns::Number::with_f32(1.0)), enc::<f32>());
+        assert_eq!(num(ns::Number::with_f64(1.0)), enc::<f64>());
+
+        extern "C" fn hash(_s: &ns::Id, _cmd: Option<&objc::Sel>) -> ns::UInteger {
+            0
+        }
+        let f: extern "C" fn(_, _) -> _ = hash;
+        assert_eq!(
+            objc::MethodTypes::of(f).types().unwrap().to_str().unwrap(),
+            runtime_types(c"NSObject", c"hash")
+        );
+
+        extern "C" fn perform(
+            _s: &ns::Id,
+            _cmd: Option<&objc::Sel>,
+            _sel: Option<&objc::Sel>,
+            _obj: Option<&ns::Id>,
+        ) -> Option<arc::R<ns::Id>> {
+            None
+        }
+        let f: extern "C" fn(_, _, _, _) -> _ = perform;
+        assert_eq!(
+            objc::MethodTypes::of(f).types().unwrap().to_str().unwrap(),
+            runtime_types(c"NSObject", c"performSelector:withObject:")
+        );
+
+        extern "C" fn range(_s: &ns::Id, _cmd: Option<&objc::Sel>) -> ns::Range {
+            ns::Range::new(0, 0)
+        }
+        let f: extern "C" fn(_, _) -> _ = range;
+        assert_eq!(
+            objc::MethodTypes::of(f).types().unwrap().to_str().unwrap(),
+            runtime_types(c"NSValue", c"rangeValue")
+        );
+
+        // BOOL is `signed char` on x86_64
+        #[cfg(target_arch = "aarch64")]
+        {
+            extern "C" fn is_kind(
+                _s: &ns::Id,
+                _cmd: Option<&objc::Sel>,
+                _cls: &objc::Class<ns::Id>,
+            ) -> bool {
+                false
+            }
+            let f: extern "C" fn(_, _, _) -> _ = is_kind;
+            assert_eq!(
+                objc::MethodTypes::of(f).types().unwrap().to_str().unwrap(),
+                runtime_types(c"NSObject", c"isKindOfClass:")
+            );
+        }
+    }
+}
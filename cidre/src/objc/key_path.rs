@@ -0,0 +1,262 @@
+//! Typed key paths.
+//!
+//! `#[objc::msg_send(status, kvo)]` on a getter generates `KEY_PATH_STATUS`
+//! constant next to it:
+//!
+//! ```ignore
+//! impl Player {
+//!     #[objc::msg_send(status, kvo)]
+//!     pub fn status(&self) -> Status;
+//! }
+//!
+//! assert_eq!(av::Player::KEY_PATH_STATUS.as_str(), "status");
+//! ```
+//!
+//! Values of change dictionaries are decoded with [`KvValue`].
+
+use std::{ffi::CStr, marker::PhantomData};
+
+#[cfg(feature = "ns")]
+use crate::ns;
+use crate::{
+    arc,
+    objc::{Id, Obj},
+};
+
+/// Key path of `O` with value of type `T`.
+pub struct KeyPath<O: ?Sized, T> {
+    path: &'static CStr,
+    _marker: PhantomData<fn(&O) -> T>,
+}
+
+impl<O: ?Sized, T> KeyPath<O, T> {
+    /// # Safety
+    ///
+    /// `path` should be key path of `O` with values of type `T`,
+    /// objects decoded from changes are cast to `T` unchecked.
+    #[inline]
+    pub const unsafe fn new(path: &'static CStr) -> Self {
+        Self {
+            path,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub const fn as_c_str(&self) -> &'static CStr {
+        self.path
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        // key paths are ascii selectors
+        unsafe { std::str::from_utf8_unchecked(self.path.to_bytes()) }
+    }
+
+    #[cfg(feature = "ns")]
+    #[inline]
+    pub fn ns_string(&self) -> arc::R<ns::String> {
+        ns::String::with_str(self.as_str())
+    }
+}
+
+impl<O: ?Sized, T> Clone for KeyPath<O, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O: ?Sized, T> Copy for KeyPath<O, T> {}
+
+impl<O: ?Sized, T> PartialEq for KeyPath<O, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+impl<O: ?Sized, T> Eq for KeyPath<O, T> {}
+
+impl<O: ?Sized, T> std::fmt::Debug for KeyPath<O, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("KeyPath").field(&self.as_str()).finish()
+    }
+}
+
+#[doc(alias = "NSKeyValueChange")]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[repr(usize)]
+pub enum KvChange {
+    Setting = 1,
+    Insertion = 2,
+    Removal = 3,
+    Replacement = 4,
+}
+
+impl KvChange {
+    pub fn from_raw(val: usize) -> Option<Self> {
+        Some(match val {
+            1 => Self::Setting,
+            2 => Self::Insertion,
+            3 => Self::Removal,
+            4 => Self::Replacement,
+            _ => return None,
+        })
+    }
+}
+
+/// Old or new value from change dictionary.
+#[derive(Debug, Clone, Copy)]
+pub enum KvRaw<'a> {
+    /// `ns::Null`, used for `nil` values.
+    Null,
+    /// `ns::Number` with its value read as integer and as float.
+    Number {
+        int: i64,
+        float: f64,
+        obj: Option<&'a Id>,
+    },
+    Obj(&'a Id),
+}
+
+impl<'a> KvRaw<'a> {
+    #[inline]
+    pub fn int(val: i64) -> Self {
+        Self::Number {
+            int: val,
+            float: val as f64,
+            obj: None,
+        }
+    }
+
+    #[inline]
+    pub fn float(val: f64) -> Self {
+        Self::Number {
+            int: val as i64,
+            float: val,
+            obj: None,
+        }
+    }
+}
+
+/// Value of observed property.
+pub trait KvValue: Sized {
+    fn from_kv(raw: KvRaw) -> Option<Self>;
+}
+
+macro_rules! kv_value_int {
+    ($($t:ty),*) => {
+        $(
+            impl KvValue for $t {
+                #[inline]
+                fn from_kv(raw: KvRaw) -> Option<Self> {
+                    match raw {
+                        KvRaw::Number { int, .. } => int.try_into().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+kv_value_int!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl KvValue for f32 {
+    #[inline]
+    fn from_kv(raw: KvRaw) -> Option<Self> {
+        match raw {
+            KvRaw::Number { float, .. } => Some(float as _),
+            _ => None,
+        }
+    }
+}
+
+impl KvValue for f64 {
+    #[inline]
+    fn from_kv(raw: KvRaw) -> Option<Self> {
+        match raw {
+            KvRaw::Number { float, .. } => Some(float),
+            _ => None,
+        }
+    }
+}
+
+impl KvValue for bool {
+    #[inline]
+    fn from_kv(raw: KvRaw) -> Option<Self> {
+        match raw {
+            KvRaw::Number { int, .. } => Some(int != 0),
+            _ => None,
+        }
+    }
+}
+
+/// `nil` values are decoded as `Some(None)`.
+impl<T: KvValue> KvValue for Option<T> {
+    #[inline]
+    fn from_kv(raw: KvRaw) -> Option<Self> {
+        match raw {
+            KvRaw::Null => Some(None),
+            raw => T::from_kv(raw).map(Some),
+        }
+    }
+}
+
+/// Objects are not checked against `T`, see [`KeyPath::new`].
+impl<T: Obj> KvValue for arc::R<T> {
+    #[inline]
+    fn from_kv(raw: KvRaw) -> Option<Self> {
+        let obj: &Id = match raw {
+            KvRaw::Obj(obj) => obj,
+            KvRaw::Number { obj: Some(n), .. } => n,
+            _ => return None,
+        };
+        let obj = unsafe { &*(obj as *const Id).cast::<T>() };
+        Some(arc::Retain::retained(obj))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyPath, KvChange, KvRaw, KvValue};
+
+    struct Foo;
+
+    #[test]
+    fn basics() {
+        const KP: KeyPath<Foo, f64> = unsafe { KeyPath::new(c"fractionCompleted") };
+        assert_eq!(KP.as_str(), "fractionCompleted");
+        assert_eq!(KP.as_c_str(), c"fractionCompleted");
+        assert_eq!(KP, KP.clone());
+        assert_eq!(format!("{KP:?}"), "KeyPath(\"fractionCompleted\")");
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(KvChange::from_raw(1), Some(KvChange::Setting));
+        assert_eq!(KvChange::from_raw(4), Some(KvChange::Replacement));
+        assert_eq!(KvChange::from_raw(0), None);
+
+        assert_eq!(f64::from_kv(KvRaw::float(0.25)), Some(0.25));
+        assert_eq!(f32::from_kv(KvRaw::int(2)), Some(2.0));
+        assert_eq!(bool::from_kv(KvRaw::int(1)), Some(true));
+        assert_eq!(isize::from_kv(KvRaw::int(-3)), Some(-3));
+
+        // out of range and wrong value types are skipped
+        assert_eq!(u8::from_kv(KvRaw::int(256)), None);
+        assert_eq!(u8::from_kv(KvRaw::int(-1)), None);
+        assert_eq!(f64::from_kv(KvRaw::Null), None);
+        assert!(<crate::arc::R<crate::objc::Id>>::from_kv(KvRaw::Null).is_none());
+        assert!(<crate::arc::R<crate::objc::Id>>::from_kv(KvRaw::int(1)).is_none());
+
+        // `nil` is a value of optionals
+        assert_eq!(<Option<isize>>::from_kv(KvRaw::Null), Some(None));
+        assert_eq!(<Option<isize>>::from_kv(KvRaw::int(-3)), Some(Some(-3)));
+        assert_eq!(
+            <Option<bool>>::from_kv(KvRaw::float(0.0)),
+            Some(Some(false))
+        );
+    }
+}
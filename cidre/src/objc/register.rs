@@ -0,0 +1,173 @@
+//! Once-only registration of classes defined with `define_obj_type!`.
+//!
+//! Class names are mangled with crate name, crate version and a hash of Rust type name,
+//! so two crates picking the same identifier get different classes.
+
+use std::{
+    ffi::{CStr, CString},
+    sync::OnceLock,
+};
+
+use crate::objc::{self, Class, Id, Obj};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterError {
+    /// Class with the same name is already registered with different instance size.
+    IncompatibleInstanceSize {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// `objc_allocateClassPair` failed and there is no class with the name.
+    AllocateFailed { name: String },
+}
+
+impl std::fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IncompatibleInstanceSize {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "class {name} is already registered with instance size {found}, expected {expected}"
+            ),
+            Self::AllocateFailed { name } => write!(f, "failed to allocate class {name}"),
+        }
+    }
+}
+
+impl std::error::Error for RegisterError {}
+
+/// FNV-1a, stable across compilers and processes.
+const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
+/// `{cls}_{crate}_{version}_{type name hash}` with characters ObjC tooling
+/// doesn't like replaced by `_`.
+pub fn class_name(cls: &str, krate: &str, version: &str, type_name: &str) -> CString {
+    let hash = fnv1a(type_name.as_bytes());
+    let name: String = format!("{cls}_{krate}_{version}_{hash:016x}")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    CString::new(name).unwrap()
+}
+
+/// Instance size of class with ivar of `size` and `align` added after ivars
+/// of superclass, as `class_getInstanceSize` reports it.
+pub const fn instance_size(super_instance_size: usize, size: usize, align: usize) -> usize {
+    if size == 0 {
+        return super_instance_size;
+    }
+    let end = objc::ivar_offset(super_instance_size, align) + size;
+    objc::ivar_offset(end, std::mem::align_of::<usize>())
+}
+
+fn check(name: &CStr, cls: &'static Class<Id>, expected: usize) -> Result<usize, RegisterError> {
+    let found = unsafe { objc::class_getInstanceSize(cls) };
+    if found != expected {
+        return Err(RegisterError::IncompatibleInstanceSize {
+            name: name.to_string_lossy().into_owned(),
+            expected,
+            found,
+        });
+    }
+    Ok(cls as *const Class<Id> as usize)
+}
+
+/// Class registered once per process.
+pub struct ClassOnce(OnceLock<Result<usize, RegisterError>>);
+
+impl ClassOnce {
+    pub const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    /// Returns class declared by `decl`, registering it with `register` on first call.
+    ///
+    /// `decl` returns class name and expected instance size.
+    /// Class registered by another copy of the crate is reused if its instance size matches.
+    /// `register` returns `None` if `objc_allocateClassPair` fails.
+    pub fn get_or_register<T: Obj>(
+        &self,
+        decl: impl FnOnce() -> (CString, usize),
+        register: impl FnOnce(&CStr) -> Option<&'static Class<Id>>,
+    ) -> Result<&'static Class<T>, RegisterError> {
+        let res = self.0.get_or_init(|| unsafe {
+            let (name, instance_size) = decl();
+            let name = name.as_c_str();
+            if let Some(cls) = objc::objc_getClass(name.as_ptr().cast()) {
+                return check(name, cls, instance_size);
+            }
+            if let Some(cls) = register(name) {
+                return check(name, cls, instance_size);
+            }
+            // lost the race to another copy of the crate
+            match objc::objc_getClass(name.as_ptr().cast()) {
+                Some(cls) => check(name, cls, instance_size),
+                None => Err(RegisterError::AllocateFailed {
+                    name: name.to_string_lossy().into_owned(),
+                }),
+            }
+        });
+        match res {
+            Ok(cls) => Ok(unsafe { &*(*cls as *const Class<T>) }),
+            Err(err) => Err(err.clone()),
+        }
+    }
+}
+
+impl Default for ClassOnce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{class_name, instance_size};
+
+    #[test]
+    fn names() {
+        let a = class_name("OUTPUT_CLS", "cidre", "0.11.0", "cidre::sc::OutputObj");
+        let b = class_name("OUTPUT_CLS", "cidre", "0.11.0", "cidre::sc::DelegateObj");
+        let c = class_name("OUTPUT_CLS", "cidre", "0.12.0", "cidre::sc::OutputObj");
+        let s = a.to_str().unwrap();
+        assert!(s.starts_with("OUTPUT_CLS_cidre_0_11_0_"));
+        assert_eq!(s.len(), "OUTPUT_CLS_cidre_0_11_0_".len() + 16);
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(
+            a,
+            class_name("OUTPUT_CLS", "cidre", "0.11.0", "cidre::sc::OutputObj")
+        );
+
+        let d = class_name("CLS", "sc-record", "0.1.0-beta.1", "sc_record::Foo");
+        assert!(
+            d.to_str()
+                .unwrap()
+                .starts_with("CLS_sc_record_0_1_0_beta_1_")
+        );
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(instance_size(8, 0, 1), 8);
+        assert_eq!(instance_size(8, 8, 8), 16);
+        assert_eq!(instance_size(8, 1, 1), 16);
+        assert_eq!(instance_size(8, 16, 16), 32);
+        assert_eq!(instance_size(12, 4, 4), 16);
+        assert_eq!(instance_size(40, 24, 8), 64);
+    }
+}
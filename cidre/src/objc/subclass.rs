@@ -0,0 +1,160 @@
+//! Rust values stored in instances of Objective-C subclasses.
+//!
+//! `define_obj_type!(pub MyView(ns::View) + MyViewImpl, Inner, MY_VIEW)` registers
+//! `MY_VIEW` as a subclass of `NSView`. `Inner` lives in an ivar placed after
+//! the superclass ivars, it is set to `Inner::default()` in `+allocWithZone:`
+//! if `Inner` implements `Default`. Methods implemented in `#[objc::add_methods]`
+//! get `super_*` helpers, so overrides can call the superclass implementation:
+//!
+//! ```ignore
+//! #[objc::add_methods]
+//! impl MyViewImpl for MyView {
+//!     extern "C" fn impl_layout(&mut self, _cmd: Option<&objc::Sel>) {
+//!         self.super_layout();
+//!         if let Some(inner) = self.inner_mut() {
+//!             inner.layouts += 1;
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{ffi::CStr, mem::MaybeUninit};
+
+use crate::objc::{self, Class, Id};
+
+/// Offset of ivar with `align` alignment placed after ivars of superclass.
+#[inline]
+pub const fn ivar_offset(super_instance_size: usize, align: usize) -> usize {
+    (super_instance_size + align - 1) & !(align - 1)
+}
+
+/// Reserves storage for `T` in class which is not registered yet.
+pub unsafe fn add_ivar<T>(cls: &Class<Id>) -> bool {
+    if std::mem::size_of::<T>() == 0 {
+        return true;
+    }
+    unsafe {
+        objc::class_addIvar(
+            cls,
+            Ivar::<T>::NAME.as_ptr(),
+            std::mem::size_of::<T>(),
+            std::mem::align_of::<T>().trailing_zeros() as u8,
+            c"?".as_ptr(),
+        )
+    }
+}
+
+/// Storage of Rust value in Objective-C instance.
+///
+/// Instances are zeroed on allocation, so ivar is not initialized until
+/// `+allocWithZone:` of the subclass sets default value or [`Ivar::set`].
+#[repr(C)]
+pub struct Ivar<T> {
+    init: bool,
+    val: MaybeUninit<T>,
+}
+
+impl<T> Ivar<T> {
+    pub const NAME: &'static CStr = c"_cidre_inner";
+
+    #[inline]
+    pub const fn align_log2() -> u8 {
+        std::mem::align_of::<Self>().trailing_zeros() as u8
+    }
+
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        if self.init {
+            Some(unsafe { self.val.assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.init {
+            Some(unsafe { self.val.assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Replaces value and returns previous one.
+    pub fn set(&mut self, val: T) -> Option<T> {
+        let res = self.take();
+        self.val.write(val);
+        self.init = true;
+        res
+    }
+
+    pub fn take(&mut self) -> Option<T> {
+        if self.init {
+            self.init = false;
+            Some(unsafe { self.val.assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Adds ivar to class which is not registered yet.
+    #[inline]
+    pub unsafe fn add_to(cls: &Class<Id>) -> bool {
+        unsafe { add_ivar::<Self>(cls) }
+    }
+
+    /// Offset of ivar in instances of `cls` or its subclasses.
+    pub fn offset_in(cls: &Class<Id>) -> Option<usize> {
+        unsafe {
+            let ivar = objc::class_getInstanceVariable(cls, Self::NAME.as_ptr())?;
+            Some(objc::ivar_getOffset(ivar) as usize)
+        }
+    }
+
+    /// Expected offset of ivar in a direct subclass of `sup`.
+    pub fn offset_after(sup: &Class<Id>) -> usize {
+        let size = unsafe { objc::class_getInstanceSize(sup) };
+        ivar_offset(size, std::mem::align_of::<Self>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::{align_of, offset_of, size_of};
+
+    use super::{Ivar, ivar_offset};
+
+    #[allow(dead_code)]
+    #[repr(align(16))]
+    struct Aligned([u8; 3]);
+
+    #[test]
+    fn layout() {
+        assert_eq!(ivar_offset(8, 8), 8);
+        assert_eq!(ivar_offset(12, 8), 16);
+        assert_eq!(ivar_offset(12, 1), 12);
+        assert_eq!(ivar_offset(8, 16), 16);
+        assert_eq!(ivar_offset(33, 4), 36);
+
+        assert_eq!(size_of::<Ivar<()>>(), 1);
+        assert_eq!(Ivar::<()>::align_log2(), 0);
+        assert_eq!(size_of::<Ivar<u64>>(), 16);
+        assert_eq!(offset_of!(Ivar<u64>, val), 8);
+        assert_eq!(Ivar::<u64>::align_log2(), 3);
+        assert_eq!(align_of::<Ivar<Aligned>>(), 16);
+        assert_eq!(Ivar::<Aligned>::align_log2(), 4);
+        assert_eq!(offset_of!(Ivar<Aligned>, val), 16);
+    }
+
+    #[test]
+    fn basics() {
+        let mut ivar: Ivar<String> = unsafe { std::mem::zeroed() };
+        assert!(ivar.get().is_none());
+        assert!(ivar.set("a".to_string()).is_none());
+        assert_eq!(ivar.get().map(String::as_str), Some("a"));
+        ivar.get_mut().unwrap().push('b');
+        assert_eq!(ivar.set("c".to_string()).as_deref(), Some("ab"));
+        assert_eq!(ivar.take().as_deref(), Some("c"));
+        assert!(ivar.take().is_none());
+    }
+}
@@ -77,7 +77,10 @@ pub mod error;
         feature = "visionos_27_0"
     )
 ))]
-#[link(name = "ScreenCaptureKit", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "ScreenCaptureKit", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "sc", kind = "static")]
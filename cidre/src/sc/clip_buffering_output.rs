@@ -101,6 +101,15 @@ impl ClipBufferingOutput {
         }
     }
 
+    /// `with_delegate()` with proof from `api::if_available!`
+    #[inline]
+    pub fn with_delegate_available<const V: u32>(
+        delegate: &impl Delegate,
+        av: api::Available<V>,
+    ) -> arc::R<Self> {
+        Self::alloc_available(av).init_with_delegate_available(Some(delegate), av)
+    }
+
     #[inline]
     /// Creates a clip buffering output without a delegate.
     #[api::available(
@@ -134,6 +143,12 @@ impl ClipBufferingOutput {
         }
     }
 
+    /// `without_delegate()` with proof from `api::if_available!`
+    #[inline]
+    pub fn without_delegate_available<const V: u32>(av: api::Available<V>) -> arc::R<Self> {
+        Self::alloc_available(av).init_with_delegate_available::<AnyDelegate, V>(None, av)
+    }
+
     #[cfg(feature = "blocks")]
     /// Exports the most recent buffered content to a movie file.
     ///
@@ -118,6 +118,12 @@ impl RecordingEditor {
         }
     }
 
+    /// `with_url()` with proof from `api::if_available!`
+    #[inline]
+    pub fn with_url_available<const V: u32>(url: &ns::Url, av: api::Available<V>) -> arc::R<Self> {
+        Self::alloc_available(av).init_with_url_available(url, av)
+    }
+
     /// The recording editor delegate.
     #[objc::msg_send(delegate)]
     #[api::available(
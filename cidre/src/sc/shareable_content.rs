@@ -225,7 +225,7 @@ mod tests {
     #[objc::add_methods]
     impl OutputImpl for OutputObj {}
 
-    define_obj_type!(DelegateObj + DelegateImpl, usize, OUTPUT_CLS);
+    define_obj_type!(DelegateObj + DelegateImpl, usize, DELEGATE_CLS);
 
     impl Delegate for DelegateObj {}
 
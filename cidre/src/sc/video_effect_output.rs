@@ -46,6 +46,15 @@ impl VideoEffectOutput {
             Some(unsafe { Self::alloc()?.init_with_camera_device(device) })
         }
     }
+
+    /// `with_camera_device()` with proof from `api::if_available!`
+    #[inline]
+    pub fn with_camera_device_available<const V: u32>(
+        device: &av::CaptureDevice,
+        av: api::Available<V>,
+    ) -> arc::R<Self> {
+        Self::alloc_available(av).init_with_camera_device_available(device, av)
+    }
 }
 
 #[cfg(target_os = "ios")]
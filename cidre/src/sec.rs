@@ -32,5 +32,5 @@ pub use protocol_types::{ProtocolVerify, ProtocolVerifyComplete};
 pub mod policy;
 // pub use policy::Policy;
 
-#[link(name = "Security", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Security", kind = "framework"))]
 unsafe extern "C" {}
@@ -32,7 +32,10 @@ mod classification_result;
 pub use classification_result::Classification;
 pub use classification_result::ClassificationResult;
 
-#[link(name = "SoundAnalysis", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "SoundAnalysis", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "sn", kind = "static")]
@@ -97,7 +97,7 @@ unsafe extern "C" {
     fn qos_class_main() -> Class;
 }
 
-#[cfg(test)]
+#[cfg(all(test, target_vendor = "apple"))]
 mod tests {
     use crate::sys::qos;
 
@@ -383,7 +383,7 @@ unsafe extern "C-unwind" {
     fn TISRegisterInputSource(location: &cf::Url) -> os::Status;
 }
 
-#[link(name = "Carbon", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Carbon", kind = "framework"))]
 unsafe extern "C" {}
 
 #[cfg(test)]
@@ -377,7 +377,7 @@ pub fn app_main(
     }
 }
 
-#[link(name = "UIKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "UIKit", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "ui", kind = "static")]
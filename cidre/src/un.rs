@@ -71,7 +71,10 @@ impl ns::String {
     ) -> arc::R<ns::String>;
 }
 
-#[link(name = "UserNotifications", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "UserNotifications", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "un", kind = "static")]
@@ -2,7 +2,10 @@ mod _type;
 pub use _type::Type;
 pub mod core_types;
 
-#[link(name = "UniformTypeIdentifiers", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "UniformTypeIdentifiers", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "ut", kind = "static")]
@@ -1269,7 +1269,7 @@ pub fn u16_f32(a: &[u16], c: &mut [f32]) {
     unsafe { _u16_f32(a.as_ptr(), 1, c.as_mut_ptr(), 1, n) };
 }
 
-#[link(name = "Accelerate", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Accelerate", kind = "framework"))]
 unsafe extern "C-unwind" {
     #[link_name = "vDSP_vadd"]
     pub fn _add_f32(
@@ -22,5 +22,5 @@ pub mod conversion;
 pub mod utilities;
 pub use utilities::AllocatedBuf;
 
-#[link(name = "Accelerate", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Accelerate", kind = "framework"))]
 unsafe extern "C" {}
@@ -113,10 +113,10 @@ pub use video_processor::RequestProcessingOpts as VideoProcessorRequestProcessin
 pub use video_processor::TimeIntervalCadence as VideoProcessorTimeIntervalCadence;
 pub use video_processor::VideoProcessor;
 
-#[link(name = "Vision", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Vision", kind = "framework"))]
 unsafe extern "C" {}
 
-#[link(name = "CoreImage", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "CoreImage", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "vn", kind = "static")]
@@ -40,5 +40,8 @@ pub use utilities::cg_image_from_cv_pixel_buf;
 #[cfg(target_os = "macos")]
 pub mod professional_video_workflow;
 
-#[link(name = "VideoToolbox", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "VideoToolbox", kind = "framework")
+)]
 unsafe extern "C" {}
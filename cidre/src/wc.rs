@@ -6,7 +6,10 @@ mod error;
 pub use error::Code as ErrorCode;
 pub use error::Domain as ErrorDomain;
 
-#[link(name = "WatchConnectivity", kind = "framework")]
+#[cfg_attr(
+    target_vendor = "apple",
+    link(name = "WatchConnectivity", kind = "framework")
+)]
 unsafe extern "C" {}
 
 #[link(name = "wc", kind = "static")]
@@ -35,7 +35,7 @@ mod website_data_store;
 pub use website_data_store::WebsiteDataStore;
 
 #[cfg(not(any(target_os = "tvos", target_os = "watchos")))]
-#[link(name = "WebKit", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "WebKit", kind = "framework"))]
 unsafe extern "C" {}
 
 #[link(name = "wk", kind = "static")]
@@ -26,13 +26,14 @@ impl WebsiteDataStore {
     #[objc::available(macos = 14.0, ios = 17.0)]
     pub fn id(&self) -> Option<arc::R<ns::Uuid>>;
 
-    #[objc::msg_send(dataStoreForIdentifier:)]
+    #[objc::msg_send(dataStoreForIdentifier:, throws)]
     #[objc::available(macos = 14.0, ios = 17.0)]
     pub unsafe fn with_id_throws(id: &ns::Uuid) -> arc::R<Self>;
 
+    #[deprecated(note = "use `try_with_id`")]
     #[objc::available(macos = 14.0, ios = 17.0)]
     pub fn with_id<'ear>(id: &ns::Uuid) -> ns::ExResult<'ear, arc::R<Self>> {
-        ns::try_catch(|| unsafe { Self::with_id_throws(id) })
+        Self::try_with_id(id).map_err(|e| &*e.autoreleased())
     }
 }
 